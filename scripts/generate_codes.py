@@ -0,0 +1,90 @@
+#!/usr/bin/env python3
+"""Generates src/model/codes/tables.rs from the CSV files in scripts/codes.
+
+The CSV files are transcribed from the status and error code tables of the Infobip documentation
+(https://www.infobip.com/docs/essentials/response-status-and-error-codes). To update the tables,
+edit the CSV files and run this script from the repository root, then `cargo fmt`:
+
+    python3 scripts/generate_codes.py
+"""
+
+import csv
+import os
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+CODES_DIR = os.path.join(ROOT, "scripts", "codes")
+OUTPUT = os.path.join(ROOT, "src", "model", "codes", "tables.rs")
+
+
+def rust_str(value):
+    return '"{}"'.format(value.replace("\\", "\\\\").replace('"', '\\"'))
+
+
+def read_rows(file_name):
+    with open(os.path.join(CODES_DIR, file_name), newline="") as file:
+        return sorted(csv.DictReader(file), key=lambda row: int(row["id"]))
+
+
+def status_entry(row):
+    return (
+        "    StatusCodeInfo {{\n"
+        "        id: {id},\n"
+        "        name: {name},\n"
+        "        group_id: {group_id},\n"
+        "        group_name: {group_name},\n"
+        "        description: {description},\n"
+        "    }},\n"
+    ).format(
+        id=int(row["id"]),
+        name=rust_str(row["name"]),
+        group_id=int(row["group_id"]),
+        group_name=rust_str(row["group_name"]),
+        description=rust_str(row["description"]),
+    )
+
+
+def error_entry(row):
+    return (
+        "    ErrorCodeInfo {{\n"
+        "        id: {id},\n"
+        "        name: {name},\n"
+        "        group_id: {group_id},\n"
+        "        group_name: {group_name},\n"
+        "        permanent: {permanent},\n"
+        "        description: {description},\n"
+        "    }},\n"
+    ).format(
+        id=int(row["id"]),
+        name=rust_str(row["name"]),
+        group_id=int(row["group_id"]),
+        group_name=rust_str(row["group_name"]),
+        permanent=row["permanent"].strip().lower(),
+        description=rust_str(row["description"]),
+    )
+
+
+def main():
+    output = [
+        "// Generated by scripts/generate_codes.py from scripts/codes/*.csv. Do not edit.\n",
+        "\n",
+        "use super::{ErrorCodeInfo, StatusCodeInfo};\n",
+        "\n",
+        "/// Known status codes, sorted by ID.\n",
+        "pub const STATUS_CODES: &[StatusCodeInfo] = &[\n",
+    ]
+    output += [status_entry(row) for row in read_rows("status_codes.csv")]
+    output += [
+        "];\n",
+        "\n",
+        "/// Known error codes, sorted by ID.\n",
+        "pub const ERROR_CODES: &[ErrorCodeInfo] = &[\n",
+    ]
+    output += [error_entry(row) for row in read_rows("error_codes.csv")]
+    output.append("];\n")
+
+    with open(OUTPUT, "w") as file:
+        file.writelines(output)
+
+
+if __name__ == "__main__":
+    main()
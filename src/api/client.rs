@@ -0,0 +1,311 @@
+//! Module with the umbrella client, giving access to every enabled channel over one HTTP client.
+
+use std::time::{Duration, Instant};
+
+use reqwest::StatusCode;
+
+use crate::api::diagnostics::{self, DiagnosticEntry};
+use crate::api::{
+    build_api_error, build_http_client, endpoints, read_response_text, send_no_body_request,
+    RequestOptions, SdkError,
+};
+use crate::configuration::Configuration;
+
+#[cfg(feature = "email")]
+use crate::api::email::EmailClient;
+#[cfg(feature = "sms")]
+use crate::api::numbers::NumbersClient;
+#[cfg(feature = "sms")]
+use crate::api::sms::SmsClient;
+#[cfg(feature = "voice")]
+use crate::api::voice::VoiceClient;
+#[cfg(feature = "whatsapp")]
+use crate::api::whatsapp::WhatsappClient;
+
+/// Path of the endpoint called by `InfobipClient::health_check`.
+pub const PATH_HEALTH_CHECK: &str = endpoints::account::get_balance();
+
+/// Result of `InfobipClient::health_check`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// Whether the API answered the request.
+    pub reachable: bool,
+
+    /// Whether the API accepted the credentials. Always `false` if the API was not reachable.
+    pub authorized: bool,
+
+    /// Time from sending the request until the response headers were received, or until the
+    /// request failed.
+    pub latency: Duration,
+}
+
+impl HealthStatus {
+    /// Returns whether the API is reachable and accepts the credentials.
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.authorized
+    }
+}
+
+/// Asynchronous client for all enabled channels, built once from a `Configuration`. The channel
+/// clients share its configuration and `reqwest::Client`, and therefore its connection pool.
+#[derive(Clone, Debug)]
+pub struct InfobipClient {
+    configuration: Configuration,
+    http_client: reqwest::Client,
+    #[cfg(feature = "email")]
+    email: EmailClient,
+    #[cfg(feature = "sms")]
+    numbers: NumbersClient,
+    #[cfg(feature = "sms")]
+    sms: SmsClient,
+    #[cfg(feature = "voice")]
+    voice: VoiceClient,
+    #[cfg(feature = "whatsapp")]
+    whatsapp: WhatsappClient,
+}
+
+impl InfobipClient {
+    /// Builds and returns a new `InfobipClient` with a specified configuration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(all(feature = "sms", feature = "email"))]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use infobip_sdk::api::client::InfobipClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// let client = InfobipClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let email_client = client.email();
+    /// let sms_client = client.sms();
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(all(feature = "sms", feature = "email")))]
+    /// # fn main() {}
+    /// ```
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        InfobipClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new `InfobipClient` whose channel clients send requests through
+    /// `http_client`.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
+        InfobipClient {
+            #[cfg(feature = "email")]
+            email: EmailClient::with_http_client(configuration.clone(), http_client.clone()),
+            #[cfg(feature = "sms")]
+            numbers: NumbersClient::with_http_client(configuration.clone(), http_client.clone()),
+            #[cfg(feature = "sms")]
+            sms: SmsClient::with_http_client(configuration.clone(), http_client.clone()),
+            #[cfg(feature = "voice")]
+            voice: VoiceClient::with_http_client(configuration.clone(), http_client.clone()),
+            #[cfg(feature = "whatsapp")]
+            whatsapp: WhatsappClient::with_http_client(configuration.clone(), http_client.clone()),
+            configuration,
+            http_client,
+        }
+    }
+
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::client::InfobipClient;
+    /// # use infobip_sdk::api::RequestOptions;
+    /// # use infobip_sdk::configuration::{ApiKey, AuthMethod, Configuration};
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = InfobipClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let tenant_options = RequestOptions::new()
+    ///     .with_auth(AuthMethod::ApiKey(ApiKey::new("tenant-api-key".to_string())));
+    /// let message = Message::new(vec![Destination::new("123456789012")]);
+    ///
+    /// client
+    ///     .with_request_options(&tenant_options)
+    ///     .sms()
+    ///     .send(SendRequestBody::new(vec![message]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        InfobipClient {
+            configuration: options.apply(&self.configuration),
+            http_client: self.http_client.clone(),
+            #[cfg(feature = "email")]
+            email: self.email.with_request_options(options),
+            #[cfg(feature = "sms")]
+            numbers: self.numbers.with_request_options(options),
+            #[cfg(feature = "sms")]
+            sms: self.sms.with_request_options(options),
+            #[cfg(feature = "voice")]
+            voice: self.voice.with_request_options(options),
+            #[cfg(feature = "whatsapp")]
+            whatsapp: self.whatsapp.with_request_options(options),
+        }
+    }
+
+    /// Returns the configuration shared by the channel clients.
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Stops sending new requests and waits up to `grace` for the requests in flight to complete,
+    /// e.g. before a rolling restart drops the runtime. Returns how many requests are still in
+    /// flight when it returns, zero if all completed.
+    ///
+    /// Shutdown applies to every client sharing the configuration of this client, including its
+    /// channel clients and the copies returned by `with_request_options`: their calls fail with
+    /// `SdkError::ShuttingDown` without sending anything. A request is in flight from when it is
+    /// sent until its response is read, or its call is dropped. Requests still in flight after
+    /// `grace` are not canceled. Dropping the returned future stops waiting, but the clients stay
+    /// shut down.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use infobip_sdk::api::client::InfobipClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = InfobipClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let remaining = client.shutdown(Duration::from_secs(10)).await;
+    /// if remaining > 0 {
+    ///     eprintln!("{} requests did not complete in time", remaining);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&self, grace: Duration) -> usize {
+        self.configuration.request_tracker().shutdown(grace).await
+    }
+
+    /// Returns whether `shutdown` was called on this client or another one sharing its
+    /// configuration.
+    pub fn is_shutting_down(&self) -> bool {
+        self.configuration.request_tracker().is_shutting_down()
+    }
+
+    /// Returns the number of requests in flight of the clients sharing the configuration of this
+    /// client.
+    pub fn in_flight_requests(&self) -> usize {
+        self.configuration.request_tracker().in_flight()
+    }
+
+    /// Returns the last requests sent by the clients sharing the configuration of this client,
+    /// oldest first, with their responses. Empty unless `Configuration::with_diagnostics` is
+    /// enabled.
+    pub fn diagnostics(&self) -> Vec<DiagnosticEntry> {
+        diagnostics::entries(&self.configuration)
+    }
+
+    /// Forgets the requests recorded by the diagnostics of the configuration of this client.
+    pub fn clear_diagnostics(&self) {
+        diagnostics::clear(&self.configuration)
+    }
+
+    /// Checks that the API is reachable and accepts the configured credentials, without
+    /// sending a message, by fetching the account balance. Meant for readiness probes.
+    ///
+    /// A single request is made, bounded by the timeout of the configuration. Connection
+    /// failures and timeouts are reported as not reachable, and 401 or 403 answers as not
+    /// authorized, instead of errors. Other error answers of the API are returned as errors.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::client::InfobipClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = InfobipClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let health = client.health_check().await?;
+    /// if !health.is_healthy() {
+    ///     eprintln!("Infobip is not ready: {:?}", health);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> Result<HealthStatus, SdkError> {
+        let started = Instant::now();
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            PATH_HEALTH_CHECK,
+        )
+        .await;
+        let latency = started.elapsed();
+
+        let response = match response {
+            Ok(response) => response,
+            Err(SdkError::Reqwest(_)) => {
+                return Ok(HealthStatus {
+                    reachable: false,
+                    authorized: false,
+                    latency,
+                })
+            }
+            Err(error) => return Err(error),
+        };
+
+        let status = response.status();
+        if status.is_success()
+            || status == StatusCode::UNAUTHORIZED
+            || status == StatusCode::FORBIDDEN
+        {
+            return Ok(HealthStatus {
+                reachable: true,
+                authorized: status.is_success(),
+                latency,
+            });
+        }
+        let text = read_response_text(response, &self.configuration).await?;
+
+        Err(build_api_error(status, &text, &self.configuration))
+    }
+
+    /// Returns the HTTP client shared by the channel clients.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+
+    /// Returns the Email channel client.
+    #[cfg(feature = "email")]
+    pub fn email(&self) -> &EmailClient {
+        &self.email
+    }
+
+    /// Returns the Numbers API client.
+    #[cfg(feature = "sms")]
+    pub fn numbers(&self) -> &NumbersClient {
+        &self.numbers
+    }
+
+    /// Returns the SMS channel client.
+    #[cfg(feature = "sms")]
+    pub fn sms(&self) -> &SmsClient {
+        &self.sms
+    }
+
+    /// Returns the Voice channel client.
+    #[cfg(feature = "voice")]
+    pub fn voice(&self) -> &VoiceClient {
+        &self.voice
+    }
+
+    /// Returns the WhatsApp channel client.
+    #[cfg(feature = "whatsapp")]
+    pub fn whatsapp(&self) -> &WhatsappClient {
+        &self.whatsapp
+    }
+}
@@ -0,0 +1,275 @@
+//! Opt-in record of the last requests sent by the clients sharing a `Configuration`, enabled with
+//! `Configuration::with_diagnostics`, to look into a problem after the fact without having had
+//! tracing enabled.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use crate::configuration::Configuration;
+
+/// Maximum number of bytes of a request or response body kept by a `DiagnosticEntry`.
+pub const DIAGNOSTICS_BODY_LIMIT: usize = 4 * 1024;
+
+/// Response header read into `DiagnosticEntry::request_id`.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+// Placeholder written instead of the value of a secret field or query parameter.
+const REDACTED: &str = "[REDACTED]";
+
+// Normalized names, lowercase without `_` or `-`, of the fields and query parameters whose values
+// are redacted when they contain one of these words.
+const SECRET_WORDS: &[&str] = &["apikey", "authorization", "password", "secret", "token"];
+
+// Normalized names of the fields and query parameters whose values are redacted, like the PIN
+// sent to verify a 2FA code.
+const SECRET_NAMES: &[&str] = &["pin"];
+
+/// Request recorded by the diagnostics of a `Configuration`. Values of fields and query
+/// parameters named like secrets, e.g. `password`, `apiKey` or `pin`, are replaced with
+/// `[REDACTED]`, and bodies are truncated to `DIAGNOSTICS_BODY_LIMIT` bytes. Credentials sent in
+/// headers are never recorded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticEntry {
+    /// Time the request was sent.
+    pub sent_at: DateTime<Utc>,
+
+    /// HTTP method of the request.
+    pub method: String,
+
+    /// Path of the request, with the query string if any.
+    pub endpoint: String,
+
+    /// Status code of the response, `None` if no response was received.
+    pub status: Option<StatusCode>,
+
+    /// Time from sending the request until its response body was read, or until the request
+    /// failed or its response was dropped.
+    pub latency: Duration,
+
+    /// Value of the `X-Request-Id` response header, if present.
+    pub request_id: Option<String>,
+
+    /// Request body, `None` for requests without a body and for multipart requests, whose
+    /// attachments are not recorded.
+    pub request_body: Option<String>,
+
+    /// Response body, `None` if it was not read, e.g. when streaming results, or is not text.
+    pub response_body: Option<String>,
+
+    /// Error that failed the request or the reading of its response, if any.
+    pub error: Option<String>,
+}
+
+/// Returns the requests recorded by the diagnostics of the configuration, oldest first.
+pub(crate) fn entries(configuration: &Configuration) -> Vec<DiagnosticEntry> {
+    configuration
+        .diagnostics()
+        .map(|diagnostics| diagnostics.entries())
+        .unwrap_or_default()
+}
+
+/// Forgets the requests recorded by the diagnostics of the configuration.
+pub(crate) fn clear(configuration: &Configuration) {
+    if let Some(diagnostics) = configuration.diagnostics() {
+        diagnostics.clear();
+    }
+}
+
+// Last requests of a `Configuration`, oldest first, shared by all of its clones.
+#[derive(Debug)]
+pub(crate) struct DiagnosticsBuffer {
+    capacity: usize,
+    entries: Mutex<VecDeque<DiagnosticEntry>>,
+}
+
+impl DiagnosticsBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        DiagnosticsBuffer {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn entries(&self) -> Vec<DiagnosticEntry> {
+        self.lock().iter().cloned().collect()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().clear();
+    }
+
+    // Adds an entry, evicting the oldest ones once the capacity is reached.
+    fn record(&self, entry: DiagnosticEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.lock();
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<DiagnosticEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Collects the details of a request while it is in flight, recording them in the diagnostics
+/// buffer when dropped.
+#[derive(Debug)]
+pub(crate) struct DiagnosticRecorder {
+    buffer: Arc<DiagnosticsBuffer>,
+    started: Instant,
+    entry: DiagnosticEntry,
+}
+
+impl DiagnosticRecorder {
+    /// Starts recording a request, if the configuration has diagnostics enabled.
+    pub(crate) fn start(
+        configuration: &Configuration,
+        method: &Method,
+        url: &str,
+        body: Option<&[u8]>,
+    ) -> Option<DiagnosticRecorder> {
+        let buffer = configuration.diagnostics()?;
+
+        Some(DiagnosticRecorder {
+            buffer: Arc::clone(buffer),
+            started: Instant::now(),
+            entry: DiagnosticEntry {
+                sent_at: Utc::now(),
+                method: method.to_string(),
+                endpoint: redacted_endpoint(url),
+                status: None,
+                latency: Duration::ZERO,
+                request_id: None,
+                request_body: body.and_then(redacted_body),
+                response_body: None,
+                error: None,
+            },
+        })
+    }
+
+    /// Records the status and the request ID of the response.
+    pub(crate) fn received(&mut self, status: StatusCode, headers: &HeaderMap) {
+        self.entry.status = Some(status);
+        self.entry.request_id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+    }
+
+    /// Records the response body.
+    pub(crate) fn response_body(&mut self, body: &[u8]) {
+        self.entry.response_body = redacted_body(body);
+    }
+
+    /// Records the error that failed the request.
+    pub(crate) fn failed(&mut self, error: &dyn fmt::Display) {
+        self.entry.error = Some(error.to_string());
+    }
+}
+
+impl Drop for DiagnosticRecorder {
+    fn drop(&mut self) {
+        let mut entry = self.entry.clone();
+        entry.latency = self.started.elapsed();
+
+        self.buffer.record(entry);
+    }
+}
+
+// Returns the path and the query string of a URL, with secret query parameters redacted.
+fn redacted_endpoint(url: &str) -> String {
+    let url = match reqwest::Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return url.to_string(),
+    };
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if is_secret(&key) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+
+    match serde_urlencoded::to_string(&query) {
+        Ok(query) if !query.is_empty() => format!("{}?{}", url.path(), query),
+        _ => url.path().to_string(),
+    }
+}
+
+// Returns a body as text, with secret fields redacted if it is JSON, truncated to
+// `DIAGNOSTICS_BODY_LIMIT` bytes. Bodies that are not text are not kept.
+fn redacted_body(body: &[u8]) -> Option<String> {
+    if body.is_empty() {
+        return None;
+    }
+
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => std::str::from_utf8(body).ok()?.to_string(),
+    };
+
+    Some(truncate(text, DIAGNOSTICS_BODY_LIMIT))
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if is_secret(key) {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+fn is_secret(name: &str) -> bool {
+    let name: String = name
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    SECRET_NAMES.contains(&name.as_str()) || SECRET_WORDS.iter().any(|word| name.contains(word))
+}
+
+fn truncate(mut text: String, limit: usize) -> String {
+    if text.len() > limit {
+        let mut end = limit;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+
+    text
+}
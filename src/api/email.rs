@@ -2,138 +2,472 @@
 
 use std::collections::HashMap;
 use std::io;
+use std::sync::{Arc, Mutex};
 
-use reqwest::multipart::Form;
-use reqwest::multipart::Part;
-use tokio::io::AsyncReadExt;
+use bytes::Bytes;
 use validator::Validate;
 
+use tokio::io::AsyncWrite;
+
+use crate::api::diagnostics::{self, DiagnosticEntry};
+use crate::api::export::{export_pages, ExportFormat, ExportProgress, EXPORT_PAGE_SIZE};
+use crate::api::polling::{StatusFuture, StatusSource};
 use crate::api::{
-    build_api_error, send_multipart_request, send_no_body_request, send_valid_json_request,
-    SdkError, SdkResponse,
+    build_api_error, build_http_client, build_optional_response, build_response_or_default,
+    connection_info, endpoints, fill_path, parse_response, prepare_multipart_request,
+    read_response_bytes, read_response_text, record_sent_ids, send_download_request,
+    send_multipart_request, send_no_body_request, send_valid_json_request, stream_results,
+    MultipartContent, MultipartField, PreparedPart, PreparedRequest, PullReports,
+    PullReportsFuture, ReportsCursor, RequestOptions, SdkError, SdkResponse, SentIds,
 };
-use crate::configuration::Configuration;
+use crate::configuration::{AttachmentMeta, Configuration};
+use crate::model::email::address::parse_address_list;
 use crate::model::email::{
-    AddDomainRequestBody, AddDomainResponseBody, GetBulksQueryParameters, GetBulksResponseBody,
-    GetDeliveryReportsQueryParameters, GetDeliveryReportsResponseBody, GetDomainResponseBody,
-    GetDomainsQueryParameters, GetDomainsResponseBody, GetLogsQueryParameters, GetLogsResponseBody,
-    GetScheduledStatusQueryParameters, GetScheduledStatusResponseBody, RescheduleQueryParameters,
-    RescheduleRequestBody, RescheduleResponseBody, SendRequestBody, SendResponseBody,
-    UpdateScheduledStatusQueryParameters, UpdateScheduledStatusRequestBody,
-    UpdateScheduledStatusResponseBody, UpdateTrackingRequestBody, UpdateTrackingResponseBody,
+    AddDomainRequestBody, AddDomainResponseBody, CreateTemplateRequestBody,
+    CreateTemplateResponseBody, GetAttachmentsResponseBody, GetBulksQueryParameters,
+    GetBulksResponseBody, GetDeliveryReportsQueryParameters, GetDeliveryReportsResponseBody,
+    GetDomainResponseBody, GetDomainsQueryParameters, GetDomainsResponseBody,
+    GetLogsQueryParameters, GetLogsResponseBody, GetScheduledMessagesQueryParameters,
+    GetScheduledMessagesResponseBody, GetScheduledStatusQueryParameters,
+    GetScheduledStatusResponseBody, GetSuppressionsQueryParameters, GetSuppressionsResponseBody,
+    Log, Report, RescheduleQueryParameters, RescheduleRequestBody, RescheduleResponseBody,
+    SendRequestBody, SendResponseBody, Status, UpdateScheduledStatusQueryParameters,
+    UpdateScheduledStatusRequestBody, UpdateScheduledStatusResponseBody, UpdateTemplateRequestBody,
+    UpdateTemplateResponseBody, UpdateTrackingRequestBody, UpdateTrackingResponseBody,
     ValidateAddressRequestBody, ValidateAddressResponseBody,
 };
+use crate::model::{ToQueryString, WireNames};
+
+pub const PATH_ADD_DOMAIN: &str = endpoints::email::add_domain();
+pub const PATH_CREATE_TEMPLATE: &str = endpoints::email::create_template();
+pub const PATH_DELETE_DOMAIN: &str = endpoints::email::delete_domain();
+pub const PATH_DELETE_TEMPLATE: &str = endpoints::email::delete_template();
+pub const PATH_DOWNLOAD_ATTACHMENT: &str = endpoints::email::download_attachment();
+pub const PATH_GET_ATTACHMENTS: &str = endpoints::email::get_attachments();
+pub const PATH_GET_BULKS: &str = endpoints::email::get_bulks();
+pub const PATH_GET_DELIVERY_REPORTS: &str = endpoints::email::get_delivery_reports();
+pub const PATH_GET_DOMAIN: &str = endpoints::email::get_domain();
+pub const PATH_GET_DOMAINS: &str = endpoints::email::get_domains();
+pub const PATH_GET_LOGS: &str = endpoints::email::get_logs();
+pub const PATH_GET_SCHEDULED_MESSAGES: &str = endpoints::email::get_scheduled_messages();
+pub const PATH_GET_SCHEDULED_STATUS: &str = endpoints::email::get_scheduled_status();
+pub const PATH_GET_SUPPRESSIONS: &str = endpoints::email::get_suppressions();
+pub const PATH_RESCHEDULE: &str = endpoints::email::reschedule();
+pub const PATH_SEND: &str = endpoints::email::send();
+pub const PATH_UPDATE_SCHEDULED_STATUS: &str = endpoints::email::update_scheduled_status();
+pub const PATH_UPDATE_TEMPLATE: &str = endpoints::email::update_template();
+pub const PATH_UPDATE_TRACKING: &str = endpoints::email::update_tracking();
+pub const PATH_VALIDATE: &str = endpoints::email::validate();
+pub const PATH_VERIFY_DOMAIN: &str = endpoints::email::verify_domain();
+
+// Holds the value of a multipart form field, either inline text or the path of a file to attach.
+enum FormValue {
+    Text(String),
+    File(String),
+}
 
-pub const PATH_ADD_DOMAIN: &str = "/email/1/domains";
-pub const PATH_DELETE_DOMAIN: &str = "/email/1/domains/{domainName}";
-pub const PATH_GET_BULKS: &str = "/email/1/bulks";
-pub const PATH_GET_DELIVERY_REPORTS: &str = "/email/1/reports";
-pub const PATH_GET_DOMAIN: &str = "/email/1/domains/{domainName}";
-pub const PATH_GET_DOMAINS: &str = "/email/1/domains";
-pub const PATH_GET_LOGS: &str = "/email/1/logs";
-pub const PATH_GET_SCHEDULED_STATUS: &str = "/email/1/bulks/status";
-pub const PATH_RESCHEDULE: &str = "/email/1/bulks";
-pub const PATH_SEND: &str = "/email/3/send";
-pub const PATH_UPDATE_SCHEDULED_STATUS: &str = "/email/1/bulks/status";
-pub const PATH_UPDATE_TRACKING: &str = "/email/1/domains/{domainName}/tracking";
-pub const PATH_VALIDATE: &str = "/email/2/validation";
-pub const PATH_VERIFY_DOMAIN: &str = "/email/1/domains/{domainName}/verify";
-
-async fn get_file_part(file_name: String) -> io::Result<Part> {
-    let mut file = tokio::fs::File::open(file_name.clone()).await?;
-    let mut buffer = Vec::new();
-    let count = file.read_to_end(&mut buffer).await?;
-
-    Ok(Part::stream_with_length(buffer, count as u64).file_name(file_name))
+// Returns the form field name of a `SendRequestBody` field, as written by serde. Files are the
+// exception: the form takes one `attachment` or `inlineImage` part per file.
+fn wire_name(field: &'static str) -> &'static str {
+    SendRequestBody::wire_name(field).unwrap_or(field)
 }
 
-async fn build_form(request_body: SendRequestBody) -> io::Result<Form> {
-    let mut form = Form::new().text("to", request_body.to.clone());
+fn form_fields(request_body: SendRequestBody) -> Vec<(&'static str, FormValue)> {
+    let mut fields = Vec::new();
+
+    if !request_body.to.is_empty() {
+        fields.push((wire_name("to"), FormValue::Text(request_body.to)));
+    }
+    if let Some(recipients) = request_body.recipients {
+        for recipient in recipients {
+            fields.push((
+                wire_name("to"),
+                FormValue::Text(serde_json::json!(recipient).to_string()),
+            ));
+        }
+    }
 
     if let Some(from) = request_body.from {
-        form = form.text("from", from);
+        fields.push((wire_name("from"), FormValue::Text(from)));
     }
     if let Some(cc) = request_body.cc {
-        form = form.text("cc", cc);
+        fields.push((wire_name("cc"), FormValue::Text(cc)));
     }
     if let Some(bcc) = request_body.bcc {
-        form = form.text("bcc", bcc);
+        fields.push((wire_name("bcc"), FormValue::Text(bcc)));
     }
     if let Some(subject) = request_body.subject {
-        form = form.text("subject", subject);
+        fields.push((wire_name("subject"), FormValue::Text(subject)));
     }
     if let Some(text) = request_body.text {
-        form = form.text("text", text);
+        fields.push((wire_name("text"), FormValue::Text(text)));
     }
     if let Some(html) = request_body.html {
-        form = form.text("html", html);
+        fields.push((wire_name("html"), FormValue::Text(html)));
     }
     if let Some(amp_html) = request_body.amp_html {
-        form = form.text("ampHtml", amp_html);
+        fields.push((wire_name("amp_html"), FormValue::Text(amp_html)));
     }
     if let Some(template_id) = request_body.template_id {
-        form = form.text("templateId", template_id.to_string());
+        fields.push((
+            wire_name("template_id"),
+            FormValue::Text(template_id.to_string()),
+        ));
     }
     if let Some(attachments) = request_body.attachments {
         for attachment in attachments {
-            form = form.part("attachment", get_file_part(attachment).await?);
+            fields.push(("attachment", FormValue::File(attachment)));
         }
     }
     if let Some(inline_images) = request_body.inline_images {
         for inline_image in inline_images {
-            form = form.part("inlineImage", get_file_part(inline_image).await?);
+            fields.push(("inlineImage", FormValue::File(inline_image)));
         }
     }
     if let Some(intermediate_report) = request_body.intermediate_report {
-        form = form.text("intermediateReport", intermediate_report.to_string());
+        fields.push((
+            wire_name("intermediate_report"),
+            FormValue::Text(intermediate_report.to_string()),
+        ));
     }
     if let Some(notify_url) = request_body.notify_url {
-        form = form.text("notifyUrl", notify_url);
+        fields.push((wire_name("notify_url"), FormValue::Text(notify_url)));
     }
     if let Some(notify_content_type) = request_body.notify_content_type {
-        form = form.text("notifyContentType", notify_content_type);
+        fields.push((
+            wire_name("notify_content_type"),
+            FormValue::Text(notify_content_type.as_str().to_string()),
+        ));
     }
     if let Some(callback_data) = request_body.callback_data {
-        form = form.text("callbackData", callback_data);
+        fields.push((wire_name("callback_data"), FormValue::Text(callback_data)));
     }
     if let Some(track) = request_body.track {
-        form = form.text("track", track.to_string());
+        fields.push((wire_name("track"), FormValue::Text(track.to_string())));
     }
     if let Some(track_clicks) = request_body.track_clicks {
-        form = form.text("trackClicks", track_clicks.to_string());
+        fields.push((
+            wire_name("track_clicks"),
+            FormValue::Text(track_clicks.to_string()),
+        ));
     }
     if let Some(track_opens) = request_body.track_opens {
-        form = form.text("trackOpens", track_opens.to_string());
+        fields.push((
+            wire_name("track_opens"),
+            FormValue::Text(track_opens.to_string()),
+        ));
     }
     if let Some(tracking_url) = request_body.tracking_url {
-        form = form.text("trackingUrl", tracking_url);
+        fields.push((wire_name("tracking_url"), FormValue::Text(tracking_url)));
     }
     if let Some(bulk_id) = request_body.bulk_id {
-        form = form.text("bulkId", bulk_id);
+        fields.push((wire_name("bulk_id"), FormValue::Text(bulk_id)));
     }
     if let Some(message_id) = request_body.message_id {
-        form = form.text("messageId", message_id);
+        fields.push((wire_name("message_id"), FormValue::Text(message_id)));
     }
     if let Some(reply_to) = request_body.reply_to {
-        form = form.text("replyTo", reply_to);
+        fields.push((wire_name("reply_to"), FormValue::Text(reply_to)));
     }
     if let Some(default_placeholders) = request_body.default_placeholders {
-        form = form.text("defaultPlaceholders", default_placeholders);
+        fields.push((
+            wire_name("default_placeholders"),
+            FormValue::Text(default_placeholders),
+        ));
     }
     if let Some(preserve_recipients) = request_body.preserve_recipients {
-        form = form.text("preserveRecipients", preserve_recipients.to_string());
+        fields.push((
+            wire_name("preserve_recipients"),
+            FormValue::Text(preserve_recipients.to_string()),
+        ));
     }
     if let Some(send_at) = request_body.send_at {
-        form = form.text("sendAt", send_at);
+        fields.push((wire_name("send_at"), FormValue::Text(send_at)));
     }
     if let Some(landing_page_placeholders) = request_body.landing_page_placeholders {
-        form = form.text("landingPagePlaceholders", landing_page_placeholders);
+        fields.push((
+            wire_name("landing_page_placeholders"),
+            FormValue::Text(landing_page_placeholders),
+        ));
     }
     if let Some(landing_page_id) = request_body.landing_page_id {
-        form = form.text("landingPageId", landing_page_id);
+        fields.push((
+            wire_name("landing_page_id"),
+            FormValue::Text(landing_page_id),
+        ));
+    }
+    if let Some(client_priority) = request_body.client_priority {
+        fields.push((
+            wire_name("client_priority"),
+            FormValue::Text(client_priority.as_str().to_string()),
+        ));
+    }
+    if let Some(headers) = request_body.headers {
+        fields.push((
+            wire_name("headers"),
+            FormValue::Text(serde_json::json!(headers).to_string()),
+        ));
+    }
+
+    fields
+}
+
+// Builds the fields of the multipart body. Files are opened, so missing ones fail here, but only
+// read while the body is sent.
+async fn build_multipart_fields(request_body: SendRequestBody) -> io::Result<Vec<MultipartField>> {
+    let mut fields = Vec::new();
+
+    for (name, value) in form_fields(request_body) {
+        fields.push(match value {
+            FormValue::Text(text) => MultipartField {
+                name: name.to_string(),
+                file_name: None,
+                content: MultipartContent::Bytes(text.into_bytes()),
+            },
+            FormValue::File(file_name) => {
+                let file = tokio::fs::File::open(&file_name).await?;
+                let length = file.metadata().await?.len();
+
+                MultipartField {
+                    name: name.to_string(),
+                    file_name: Some(file_name),
+                    content: MultipartContent::File(file, length),
+                }
+            }
+        });
+    }
+
+    Ok(fields)
+}
+
+// Returns the attachments and inline images of the request, with whether each is an inline image.
+fn attached_files(request_body: &SendRequestBody) -> impl Iterator<Item = (&String, bool)> {
+    let attachments = request_body
+        .attachments
+        .iter()
+        .flatten()
+        .map(|f| (f, false));
+    let inline_images = request_body
+        .inline_images
+        .iter()
+        .flatten()
+        .map(|f| (f, true));
+
+    attachments.chain(inline_images)
+}
+
+// Reads the sizes of the attached files from their metadata.
+async fn file_sizes(request_body: &SendRequestBody) -> io::Result<HashMap<String, u64>> {
+    let mut sizes = HashMap::new();
+    for (file_name, _) in attached_files(request_body) {
+        sizes.insert(
+            file_name.clone(),
+            tokio::fs::metadata(file_name).await?.len(),
+        );
+    }
+
+    Ok(sizes)
+}
+
+// Blocking version of file_sizes, for `prepare_send`.
+fn file_sizes_blocking(request_body: &SendRequestBody) -> io::Result<HashMap<String, u64>> {
+    attached_files(request_body)
+        .map(|(file_name, _)| Ok((file_name.clone(), std::fs::metadata(file_name)?.len())))
+        .collect()
+}
+
+// Lists the parts of the multipart body, with the file sizes read by `file_sizes`.
+fn prepared_parts(
+    request_body: SendRequestBody,
+    sizes: &HashMap<String, u64>,
+) -> Vec<PreparedPart> {
+    form_fields(request_body)
+        .into_iter()
+        .map(|(name, value)| match value {
+            FormValue::Text(text) => PreparedPart {
+                name: name.to_string(),
+                file_name: None,
+                size: text.len() as u64,
+            },
+            FormValue::File(file_name) => PreparedPart {
+                name: name.to_string(),
+                size: sizes.get(&file_name).copied().unwrap_or_default(),
+                file_name: Some(file_name),
+            },
+        })
+        .collect()
+}
+
+// Length of the boundary generated by reqwest for multipart bodies.
+const MULTIPART_BOUNDARY_LENGTH: u64 = 67;
+
+/// Estimates the size in bytes of the multipart body built from `parts`: their content plus the
+/// boundaries and part headers around it. File sizes come from file metadata, so nothing is read.
+pub fn estimate_multipart_size(parts: &[PreparedPart]) -> u64 {
+    let part_sizes: u64 = parts
+        .iter()
+        .map(|part| {
+            // --boundary\r\nContent-Disposition: form-data; name=""\r\n\r\n ... \r\n
+            let mut header = 2 + MULTIPART_BOUNDARY_LENGTH + 2 + 39 + part.name.len() as u64 + 4;
+            if let Some(file_name) = &part.file_name {
+                // ; filename=""
+                header += 13 + file_name.len() as u64;
+            }
+
+            header + part.size + 2
+        })
+        .sum();
+
+    // --boundary--\r\n
+    part_sizes + 2 + MULTIPART_BOUNDARY_LENGTH + 4
+}
+
+// Fails with `SdkError::PayloadTooLarge` when the estimated multipart body is over the limit of
+// the configuration, instead of uploading it only to have the connection reset.
+fn check_payload_size(
+    configuration: &Configuration,
+    parts: &[PreparedPart],
+) -> Result<(), SdkError> {
+    let estimated = estimate_multipart_size(parts);
+    let limit = configuration.max_multipart_body_size();
+
+    if estimated > limit {
+        return Err(SdkError::PayloadTooLarge { estimated, limit });
+    }
+
+    Ok(())
+}
+
+// Checks the request against the send policy of the configuration, if any.
+fn check_send_policy(
+    configuration: &Configuration,
+    request_body: &SendRequestBody,
+) -> Result<(), SdkError> {
+    let policy = match configuration.send_policy() {
+        Some(policy) => policy,
+        None => return Ok(()),
+    };
+    let violation = |rule, detail: &str| SdkError::PolicyViolation {
+        rule,
+        detail: detail.to_string(),
+    };
+    let is_set = |field: &Option<String>| {
+        field
+            .as_deref()
+            .map_or(false, |value| !value.trim().is_empty())
+    };
+
+    if policy.forbid_cc_bcc {
+        if is_set(&request_body.cc) {
+            return Err(violation("forbid_cc_bcc", "cc recipients are not allowed"));
+        }
+        if is_set(&request_body.bcc) {
+            return Err(violation("forbid_cc_bcc", "bcc recipients are not allowed"));
+        }
+    }
+
+    if policy.require_template && request_body.template_id.is_none() {
+        return Err(violation("require_template", "templateId is not set"));
+    }
+
+    if !policy.allowed_from_domains.is_empty() {
+        let from = request_body.from.as_deref().unwrap_or_default();
+        let domain = parse_address_list(from)
+            .ok()
+            .and_then(|addresses| addresses.into_iter().next())
+            .and_then(|address| {
+                address
+                    .address
+                    .rsplit_once('@')
+                    .map(|(_, domain)| domain.to_string())
+            });
+
+        match domain {
+            Some(domain)
+                if policy
+                    .allowed_from_domains
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(&domain)) => {}
+            Some(domain) => {
+                return Err(violation(
+                    "allowed_from_domains",
+                    &format!("from domain {} is not allowed", domain),
+                ))
+            }
+            None => {
+                return Err(violation(
+                    "allowed_from_domains",
+                    "from is not set to an address",
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Sets the default `from` of the configuration, if any, when the email has none.
+fn apply_default_from(configuration: &Configuration, request_body: &mut SendRequestBody) {
+    if request_body.from.is_none() {
+        request_body.from = configuration.default_email_from().map(str::to_string);
+    }
+}
+
+fn file_extension(file_name: &str) -> Option<String> {
+    std::path::Path::new(file_name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+// Checks attachments and inline images against the blocked extensions of the configuration, so
+// rejected files fail before their metadata is read.
+fn check_blocked_extensions(
+    configuration: &Configuration,
+    request_body: &SendRequestBody,
+) -> Result<(), SdkError> {
+    for (file_name, _) in attached_files(request_body) {
+        if let Some(extension) = file_extension(file_name) {
+            if configuration
+                .blocked_attachment_extensions()
+                .iter()
+                .any(|blocked| blocked.eq_ignore_ascii_case(&extension))
+            {
+                return Err(SdkError::AttachmentRejected(
+                    file_name.clone(),
+                    format!("extension .{} is blocked", extension),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Checks attachments and inline images with the attachment inspector of the configuration, if
+// any, so rejected files fail before any network call.
+fn inspect_attachments(
+    configuration: &Configuration,
+    request_body: &SendRequestBody,
+    sizes: &HashMap<String, u64>,
+) -> Result<(), SdkError> {
+    let inspector = match configuration.attachment_inspector() {
+        Some(inspector) => inspector,
+        None => return Ok(()),
+    };
+
+    for (file_name, inline) in attached_files(request_body) {
+        let attachment = AttachmentMeta {
+            file_name: file_name.clone(),
+            extension: file_extension(file_name),
+            size: sizes.get(file_name).copied().unwrap_or_default(),
+            inline,
+        };
+        inspector
+            .inspect(&attachment)
+            .map_err(|reason| SdkError::AttachmentRejected(file_name.clone(), reason))?;
     }
 
-    Ok(form)
+    Ok(())
 }
 
 /// Main asynchronous client for the Infobip Email channel.
@@ -141,18 +475,55 @@ async fn build_form(request_body: SendRequestBody) -> io::Result<Form> {
 pub struct EmailClient {
     pub configuration: Configuration,
     pub http_client: reqwest::Client,
+    sent_ids: Arc<Mutex<SentIds>>,
 }
 
 impl EmailClient {
     /// Builds and returns a new asynchronous `EmailClient` with a specified configuration.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        EmailClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new asynchronous `EmailClient` sending requests through `http_client`,
+    /// e.g. to share its connection pool with other clients.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
         EmailClient {
             configuration,
-            http_client: reqwest::Client::new(),
+            http_client,
+            sent_ids: Arc::new(Mutex::new(SentIds::default())),
         }
     }
 
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        EmailClient {
+            configuration: options.apply(&self.configuration),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the last requests sent by the clients sharing the configuration of this client,
+    /// oldest first, with their responses. Empty unless `Configuration::with_diagnostics` is
+    /// enabled.
+    pub fn diagnostics(&self) -> Vec<DiagnosticEntry> {
+        diagnostics::entries(&self.configuration)
+    }
+
+    /// Forgets the requests recorded by the diagnostics of the configuration of this client.
+    pub fn clear_diagnostics(&self) {
+        diagnostics::clear(&self.configuration)
+    }
+
     /// Send an email or multiple emails to a recipient or multiple recipients with CC/BCC enabled.
+    /// Emails without `from` are sent from `Configuration::default_email_from`, if set.
+    ///
+    /// Attachments with an extension blocked by the configuration, or refused by its attachment
+    /// inspector, are rejected with `SdkError::AttachmentRejected` before the request is sent.
+    /// Requests estimated to be larger than `Configuration::max_multipart_body_size` fail with
+    /// `SdkError::PayloadTooLarge`, also before the request is sent.
     ///
     /// # Example
     /// ```no_run
@@ -181,32 +552,153 @@ impl EmailClient {
         &self,
         request_body: SendRequestBody,
     ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        let fields = self.checked_send_fields(request_body).await?;
+
+        self.send_fields(fields).await
+    }
+
+    // Runs every check `send` does before the network call and builds the multipart fields.
+    async fn checked_send_fields(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<Vec<MultipartField>, SdkError> {
+        apply_default_from(&self.configuration, &mut request_body);
         request_body.validate()?;
+        check_send_policy(&self.configuration, &request_body)?;
+        check_blocked_extensions(&self.configuration, &request_body)?;
+
+        let sizes = file_sizes(&request_body).await?;
+        inspect_attachments(&self.configuration, &request_body, &sizes)?;
+        check_payload_size(
+            &self.configuration,
+            &prepared_parts(request_body.clone(), &sizes),
+        )?;
 
-        let form = build_form(request_body).await?;
+        Ok(build_multipart_fields(request_body).await?)
+    }
 
+    async fn send_fields(
+        &self,
+        fields: Vec<MultipartField>,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
         let response = send_multipart_request(
             &self.http_client,
             &self.configuration,
-            form,
+            fields,
             reqwest::Method::POST,
             PATH_SEND,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Same as `send`, but refuses to send a request whose bulk ID and message ID were already
+    /// sent by this client, failing with `SdkError::DuplicateSend` before any network call. IDs
+    /// are remembered once the request passes all the checks of `send`, just before it is sent,
+    /// so a request rejected locally can be fixed and sent again, but a retry of a request that
+    /// failed over the network is rejected. Requires enabling the guard with
+    /// `Configuration::with_send_once`, otherwise it behaves like `send`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::SendRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client =
+    ///     EmailClient::with_configuration(Configuration::from_env_api_key()?.with_send_once(1000));
+    ///
+    /// let mut request_body = SendRequestBody::new("someone@domain.com");
+    /// request_body.subject = Some("Order confirmed".to_string());
+    /// request_body.message_id = Some("order-1234-confirmation".to_string());
+    ///
+    /// let response = client.send_once(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_once(
+        &self,
+        request_body: SendRequestBody,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        let ids = vec![(
+            request_body.bulk_id.clone(),
+            request_body.message_id.clone(),
+        )];
+        let fields = self.checked_send_fields(request_body).await?;
+
+        record_sent_ids(&self.configuration, &self.sent_ids, ids)?;
+
+        self.send_fields(fields).await
+    }
+
+    /// Build the request that `send` would perform, without sending it, including the default
+    /// `from` of the configuration. Credentials are redacted and the multipart body is returned as
+    /// a listing of its parts, with file sizes read from disk.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::SendRequestBody;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = SendRequestBody::new("someone@domain.com");
+    /// request_body.subject = Some("Test subject".to_string());
+    /// request_body.attachments = Some(vec!["path/to/attachment".to_string()]);
+    ///
+    /// let prepared = client.prepare_send(request_body)?;
+    ///
+    /// println!("{} {}", prepared.method, prepared.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_send(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<PreparedRequest, SdkError> {
+        apply_default_from(&self.configuration, &mut request_body);
+        request_body.validate()?;
+        check_send_policy(&self.configuration, &request_body)?;
+        check_blocked_extensions(&self.configuration, &request_body)?;
+
+        let sizes = file_sizes_blocking(&request_body)?;
+        inspect_attachments(&self.configuration, &request_body, &sizes)?;
+
+        let parts = prepared_parts(request_body, &sizes);
+        check_payload_size(&self.configuration, &parts)?;
+
+        Ok(prepare_multipart_request(
+            &self.configuration,
+            parts,
+            reqwest::Method::POST,
+            PATH_SEND,
+        ))
+    }
+
     /// See the scheduled time of your Email messages.
     ///
     /// # Example
@@ -234,30 +726,84 @@ impl EmailClient {
     ) -> Result<SdkResponse<GetBulksResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_BULKS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Same as `get_bulks`, but returns `Ok(None)` instead of an error when the API answers
+    /// with a 404 because the bulk does not exist. Other failures are errors as usual.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::GetBulksQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .get_bulks_opt(GetBulksQueryParameters::new("some-bulk-id"))
+    ///     .await?;
+    ///
+    /// match response {
+    ///     Some(response) => println!("{:?}", response.body.bulks),
+    ///     None => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bulks_opt(
+        &self,
+        query_parameters: GetBulksQueryParameters,
+    ) -> Result<Option<SdkResponse<GetBulksResponseBody>>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_BULKS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_optional_response(status, &text, &self.configuration, connection)
+    }
+
     /// Change the date and time for sending scheduled messages.
     ///
     /// # Example
@@ -287,28 +833,80 @@ impl EmailClient {
     ) -> Result<SdkResponse<RescheduleResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            &query_string,
             reqwest::Method::PUT,
             PATH_RESCHEDULE,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_response_or_default(status, &text, &self.configuration, connection)
+    }
+
+    /// List the messages of a scheduled bulk, with their recipients, scheduled times and
+    /// statuses. Results are paged, see `fetch_all_pages` to get all of them.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::GetScheduledMessagesQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_params = GetScheduledMessagesQueryParameters::new("some-bulk-id");
+    ///
+    /// let response = client.get_scheduled_messages(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_scheduled_messages(
+        &self,
+        query_parameters: GetScheduledMessagesQueryParameters,
+    ) -> Result<SdkResponse<GetScheduledMessagesResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_SCHEDULED_MESSAGES,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -339,27 +937,33 @@ impl EmailClient {
     ) -> Result<SdkResponse<GetScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED_STATUS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -392,29 +996,23 @@ impl EmailClient {
     ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            &query_string,
             reqwest::Method::PUT,
             PATH_UPDATE_SCHEDULED_STATUS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response_or_default(status, &text, &self.configuration, connection)
     }
 
     /// Get one-time delivery reports for all sent emails.
@@ -440,164 +1038,494 @@ impl EmailClient {
     /// ```
     pub async fn get_delivery_reports(
         &self,
-        query_parameters: GetDeliveryReportsQueryParameters,
+        mut query_parameters: GetDeliveryReportsQueryParameters,
     ) -> Result<SdkResponse<GetDeliveryReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
+        query_parameters
+            .limit
+            .get_or_insert(GetDeliveryReportsQueryParameters::DEFAULT_LIMIT);
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_DELIVERY_REPORTS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
-    /// Get email logs of sent Email messagesId for request. Email logs
-    /// are available for the last 48 hours.
+    /// Like `get_delivery_reports`, but calls `on_report` with each report as soon as it is
+    /// received, instead of collecting the whole response body, so memory use stays proportional
+    /// to a single report on large pulls. The maximum response body size of the configuration does not apply.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::email::EmailClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::email::GetLogsQueryParameters;
-    /// # use reqwest::StatusCode;
+    /// # use infobip_sdk::model::email::GetDeliveryReportsQueryParameters;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let query_params = GetLogsQueryParameters::default();
-    ///
-    /// let response = client.get_logs(query_params).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
+    /// let mut count = 0;
+    /// client
+    ///     .for_each_delivery_report(GetDeliveryReportsQueryParameters::new(), |_report| {
+    ///         count += 1
+    ///     })
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_logs(
+    pub async fn for_each_delivery_report<F>(
         &self,
-        query_parameters: GetLogsQueryParameters,
-    ) -> Result<SdkResponse<GetLogsResponseBody>, SdkError> {
+        mut query_parameters: GetDeliveryReportsQueryParameters,
+        on_report: F,
+    ) -> Result<reqwest::StatusCode, SdkError>
+    where
+        F: FnMut(Report),
+    {
         query_parameters.validate()?;
+        query_parameters
+            .limit
+            .get_or_insert(GetDeliveryReportsQueryParameters::DEFAULT_LIMIT);
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(to) = query_parameters.to {
-            parameters_map.insert("to".to_string(), to);
-        }
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(general_status) = query_parameters.general_status {
-            parameters_map.insert("generalStatus".to_string(), general_status);
-        }
-        if let Some(sent_since) = query_parameters.sent_since {
-            parameters_map.insert("sentSince".to_string(), sent_since);
-        }
-        if let Some(sent_until) = query_parameters.sent_until {
-            parameters_map.insert("sentUntil".to_string(), sent_until);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
-            PATH_GET_LOGS,
+            PATH_GET_DELIVERY_REPORTS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        stream_results(response, &self.configuration, on_report).await
     }
 
-    /// Run validation to identify poor quality emails to clean up your recipient list.
+    /// Get the delivery report of a single message, or `None` if Infobip has not produced one
+    /// yet.
+    ///
+    /// Delivery reports are returned only once: a report fetched here is consumed and will not
+    /// be returned again by this method or by `get_delivery_reports`.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::email::EmailClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::email::ValidateAddressRequestBody;
-    /// # use reqwest::StatusCode;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let request_body = ValidateAddressRequestBody::new("someone@somewhere.com");
-    ///
-    /// let response = client.validate_address(request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
+    /// if let Some(report) = client.get_report_for_message("some-message-id").await? {
+    ///     println!("{:?}", report.status);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn validate_address(
+    pub async fn get_report_for_message(
         &self,
-        request_body: ValidateAddressRequestBody,
-    ) -> Result<SdkResponse<ValidateAddressResponseBody>, SdkError> {
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            PATH_VALIDATE,
-        )
-        .await?;
+        message_id: &str,
+    ) -> Result<Option<Report>, SdkError> {
+        let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+        query_parameters.message_id = Some(message_id.into());
+        query_parameters.limit = Some(1);
+
+        let response = self.get_delivery_reports(query_parameters).await?;
+
+        Ok(response
+            .body
+            .results
+            .and_then(|results| results.into_iter().next()))
+    }
+
+    /// Get email logs of sent Email messagesId for request. Email logs
+    /// are available for the last 48 hours.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::GetLogsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_params = GetLogsQueryParameters::default();
+    ///
+    /// let response = client.get_logs(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_logs(
+        &self,
+        query_parameters: GetLogsQueryParameters,
+    ) -> Result<SdkResponse<GetLogsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_LOGS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Get the list of files attached to a sent email.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.get_attachments("some-message-id").await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_attachments(
+        &self,
+        message_id: &str,
+    ) -> Result<SdkResponse<GetAttachmentsResponseBody>, SdkError> {
+        let path = fill_path(PATH_GET_ATTACHMENTS, &[("messageId", message_id)]);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Download the content of a file attached to a sent email, as returned by the API. The body
+    /// is not parsed, and the maximum response body size of the configuration applies.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .download_attachment("some-message-id", "some-attachment-id")
+    ///     .await?;
+    ///
+    /// std::fs::write("attachment.pdf", &response.body)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_attachment(
+        &self,
+        message_id: &str,
+        attachment_id: &str,
+    ) -> Result<SdkResponse<Bytes>, SdkError> {
+        let path = fill_path(
+            PATH_DOWNLOAD_ATTACHMENT,
+            &[("messageId", message_id), ("attachmentId", attachment_id)],
+        );
+
+        let response =
+            send_download_request(&self.http_client, &self.configuration, path.as_str()).await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        if !status.is_success() {
+            let text = read_response_text(response, &self.configuration).await?;
+            return Err(build_api_error(status, &text, &self.configuration));
+        }
+
+        Ok(SdkResponse {
+            body: read_response_bytes(response, &self.configuration).await?,
+            status,
+            sandbox: self.configuration.is_sandbox(),
+            warnings: Vec::new(),
+            connection,
+        })
+    }
+
+    /// Like `get_logs`, but calls `on_log` with each log as soon as it is received, instead
+    /// of collecting the whole response body, so memory use stays proportional to a single log
+    /// on large pulls. The maximum response body size of the configuration does not apply.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::GetLogsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut count = 0;
+    /// client
+    ///     .for_each_log(GetLogsQueryParameters::new(), |_log| count += 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_each_log<F>(
+        &self,
+        query_parameters: GetLogsQueryParameters,
+        on_log: F,
+    ) -> Result<reqwest::StatusCode, SdkError>
+    where
+        F: FnMut(Log),
+    {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_LOGS,
+        )
+        .await?;
+
+        stream_results(response, &self.configuration, on_log).await
+    }
+
+    /// Get the log of a single sent email, with its subject and HTML content, e.g. to see what
+    /// was actually sent during a support investigation. The body is `None` when no log matches
+    /// the message ID, like after the 48 hours for which logs are kept.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.get_message_content("some-message-id").await?;
+    ///
+    /// if let Some(log) = response.body {
+    ///     println!("{:?}\n{:?}", log.subject, log.html);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_message_content(
+        &self,
+        message_id: &str,
+    ) -> Result<SdkResponse<Option<Log>>, SdkError> {
+        let response = self
+            .get_logs(GetLogsQueryParameters::message_content(message_id))
+            .await?;
+
+        Ok(SdkResponse {
+            body: response
+                .body
+                .results
+                .and_then(|results| results.into_iter().next()),
+            status: response.status,
+            sandbox: self.configuration.is_sandbox(),
+            warnings: response.warnings,
+            connection: response.connection,
+        })
+    }
+
+    /// Export the logs matching the query parameters into a writer, as newline-delimited JSON or
+    /// CSV. Logs are fetched one page of `limit` logs at a time, walking back from `sent_until`,
+    /// and the writer is flushed after each page. The progress callback is called after each
+    /// page; to resume an interrupted export, set `sent_until` to the `last_sent_at` of the last
+    /// reported progress.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::export::ExportFormat;
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::GetLogsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let file = tokio::fs::File::create("logs.csv").await?;
+    ///
+    /// let progress = client
+    ///     .export_logs(GetLogsQueryParameters::new(), file, ExportFormat::Csv, |progress| {
+    ///         println!("{} rows written", progress.rows);
+    ///     })
+    ///     .await?;
+    ///
+    /// println!("exported {} rows", progress.rows);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_logs<W, P>(
+        &self,
+        query_parameters: GetLogsQueryParameters,
+        writer: W,
+        format: ExportFormat,
+        progress: P,
+    ) -> Result<ExportProgress, SdkError>
+    where
+        W: AsyncWrite + Unpin,
+        P: FnMut(&ExportProgress),
+    {
+        query_parameters.validate()?;
+
+        let limit = query_parameters.limit.unwrap_or(EXPORT_PAGE_SIZE);
+        let sent_until = query_parameters.sent_until.clone();
+
+        export_pages(
+            writer,
+            format,
+            limit,
+            sent_until,
+            |sent_until| {
+                let mut query_parameters = query_parameters.clone();
+                query_parameters.sent_until = sent_until;
+                query_parameters.limit = Some(limit);
+
+                async move {
+                    Ok(self
+                        .get_logs(query_parameters)
+                        .await?
+                        .body
+                        .results
+                        .unwrap_or_default())
+                }
+            },
+            progress,
+        )
+        .await
+    }
+
+    /// Run validation to identify poor quality emails to clean up your recipient list.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::ValidateAddressRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_body = ValidateAddressRequestBody::new("someone@somewhere.com");
+    ///
+    /// let response = client.validate_address(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_address(
+        &self,
+        request_body: ValidateAddressRequestBody,
+    ) -> Result<SdkResponse<ValidateAddressResponseBody>, SdkError> {
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            "",
+            reqwest::Method::POST,
+            PATH_VALIDATE,
+        )
+        .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -629,33 +1557,33 @@ impl EmailClient {
     ) -> Result<SdkResponse<GetDomainsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(size) = query_parameters.size {
-            parameters_map.insert("size".to_string(), size.to_string());
-        }
-        if let Some(page) = query_parameters.page {
-            parameters_map.insert("page".to_string(), page.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_DOMAINS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -689,22 +1617,28 @@ impl EmailClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_ADD_DOMAIN,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -736,25 +1670,74 @@ impl EmailClient {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::GET,
             path.as_str(),
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Same as `get_domain`, but returns `Ok(None)` instead of an error when the API answers
+    /// with a 404 because the domain does not exist. Other failures are errors as usual.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.get_domain_opt("example.com").await?;
+    ///
+    /// match response {
+    ///     Some(response) => println!("{:?}", response.body.active),
+    ///     None => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_domain_opt(
+        &self,
+        domain_name: &str,
+    ) -> Result<Option<SdkResponse<GetDomainResponseBody>>, SdkError> {
+        let path = PATH_GET_DOMAIN.replace("{domainName}", domain_name);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_optional_response(status, &text, &self.configuration, connection)
+    }
+
     /// This method allows you to delete an existing domain.
     ///
     /// # Example
@@ -779,7 +1762,7 @@ impl EmailClient {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::DELETE,
             path.as_str(),
         )
@@ -790,8 +1773,8 @@ impl EmailClient {
         if status.is_success() {
             Ok(status)
         } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
+            let text = read_response_text(response, &self.configuration).await?;
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -829,22 +1812,28 @@ impl EmailClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::PUT,
             path.as_str(),
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -872,7 +1861,7 @@ impl EmailClient {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             path.as_str(),
         )
@@ -883,8 +1872,283 @@ impl EmailClient {
         if status.is_success() {
             Ok(status)
         } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
+            let text = read_response_text(response, &self.configuration).await?;
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
+
+    /// Create an email template, whose ID can then be used as `template_id` when sending, e.g. to
+    /// keep templates in sync with a repository from CI.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::CreateTemplateRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = CreateTemplateRequestBody::new("welcome", "Welcome {{name}}!");
+    /// request_body.html = Some("<p>Hello {{name}}, welcome aboard.</p>".to_string());
+    ///
+    /// let response = client.create_template(request_body).await?;
+    ///
+    /// println!("{:?}", response.body.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_template(
+        &self,
+        request_body: CreateTemplateRequestBody,
+    ) -> Result<SdkResponse<CreateTemplateResponseBody>, SdkError> {
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            "",
+            reqwest::Method::POST,
+            PATH_CREATE_TEMPLATE,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Update the fields of an email template that are set in the request body.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::UpdateTemplateRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = UpdateTemplateRequestBody::new();
+    /// request_body.subject = Some("Welcome aboard {{name}}!".to_string());
+    ///
+    /// let response = client.update_template(1234, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_template(
+        &self,
+        template_id: i32,
+        request_body: UpdateTemplateRequestBody,
+    ) -> Result<SdkResponse<UpdateTemplateResponseBody>, SdkError> {
+        let path = PATH_UPDATE_TEMPLATE.replace("{templateId}", &template_id.to_string());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            "",
+            reqwest::Method::PUT,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Delete an email template.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let status = client.delete_template(1234).await?;
+    ///
+    /// assert_eq!(status, StatusCode::NO_CONTENT);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_template(&self, template_id: i32) -> Result<reqwest::StatusCode, SdkError> {
+        let path = PATH_DELETE_TEMPLATE.replace("{templateId}", &template_id.to_string());
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::DELETE,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(status)
+        } else {
+            let text = read_response_text(response, &self.configuration).await?;
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Get the suppressed addresses of a domain, that is, addresses emails are no longer sent to
+    /// because of bounces, spam complaints or unsubscribes through an opt out landing page. Use
+    /// `fetch_all_pages` to export the whole list.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::{GetSuppressionsQueryParameters, SuppressionType};
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_params =
+    ///     GetSuppressionsQueryParameters::new("example.com", SuppressionType::UNSUBSCRIBE);
+    ///
+    /// let response = client.get_suppressions(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_suppressions(
+        &self,
+        query_parameters: GetSuppressionsQueryParameters,
+    ) -> Result<SdkResponse<GetSuppressionsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_SUPPRESSIONS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Returns a cursor over the delivery reports of this client, which can be shared between
+    /// tasks without splitting reports between concurrent requests.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let cursor = client.reports_cursor();
+    ///
+    /// let reports = cursor.drain_all(5000).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reports_cursor(&self) -> ReportsCursor<EmailClient> {
+        ReportsCursor::new(self.clone())
+    }
+}
+
+impl PullReports for EmailClient {
+    type Report = Report;
+
+    const MAX_LIMIT: i32 = GetDeliveryReportsQueryParameters::MAX_LIMIT;
+
+    fn pull_reports(&self, limit: i32) -> PullReportsFuture<'_, Report> {
+        Box::pin(async move {
+            let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+            query_parameters.limit = Some(limit);
+
+            let response = self.get_delivery_reports(query_parameters).await?;
+
+            Ok(response.body.results.unwrap_or_default())
+        })
+    }
+}
+
+// Reads the status from the logs, which are not consumed on read and include messages that are
+// still pending, unlike delivery reports.
+impl StatusSource for EmailClient {
+    type Status = Status;
+
+    fn fetch_status<'a>(&'a self, message_id: &'a str) -> StatusFuture<'a, Status> {
+        Box::pin(async move {
+            let mut query_parameters = GetLogsQueryParameters::new();
+            query_parameters.message_id = Some(message_id.into());
+            query_parameters.limit = Some(1);
+
+            let response = self.get_logs(query_parameters).await?;
+
+            Ok(response
+                .body
+                .results
+                .and_then(|logs| logs.into_iter().next())
+                .and_then(|log| log.status))
+        })
+    }
 }
@@ -0,0 +1,338 @@
+//! Paths of the API endpoints used by the clients, grouped by channel.
+//!
+//! Every path starts with the channel segment and the API version, e.g. `/email/3/send`, so a
+//! version override from the `Configuration` can be applied with `resolve_path`.
+
+use std::borrow::Cow;
+
+use crate::configuration::{ApiChannel, Configuration};
+
+/// Account endpoints, shared by all channels.
+pub(crate) mod account {
+    pub(crate) const fn get_balance() -> &'static str {
+        "/account/1/balance"
+    }
+}
+
+/// Email endpoints.
+#[cfg(feature = "email")]
+pub(crate) mod email {
+    pub(crate) const fn add_domain() -> &'static str {
+        "/email/1/domains"
+    }
+
+    pub(crate) const fn create_template() -> &'static str {
+        "/email/1/templates"
+    }
+
+    pub(crate) const fn delete_domain() -> &'static str {
+        "/email/1/domains/{domainName}"
+    }
+
+    pub(crate) const fn delete_template() -> &'static str {
+        "/email/1/templates/{templateId}"
+    }
+
+    pub(crate) const fn download_attachment() -> &'static str {
+        "/email/1/messages/{messageId}/attachments/{attachmentId}"
+    }
+
+    pub(crate) const fn get_attachments() -> &'static str {
+        "/email/1/messages/{messageId}/attachments"
+    }
+
+    pub(crate) const fn get_bulks() -> &'static str {
+        "/email/1/bulks"
+    }
+
+    pub(crate) const fn get_delivery_reports() -> &'static str {
+        "/email/1/reports"
+    }
+
+    pub(crate) const fn get_domain() -> &'static str {
+        "/email/1/domains/{domainName}"
+    }
+
+    pub(crate) const fn get_domains() -> &'static str {
+        "/email/1/domains"
+    }
+
+    pub(crate) const fn get_logs() -> &'static str {
+        "/email/1/logs"
+    }
+
+    pub(crate) const fn get_scheduled_messages() -> &'static str {
+        "/email/1/bulks/messages"
+    }
+
+    pub(crate) const fn get_scheduled_status() -> &'static str {
+        "/email/1/bulks/status"
+    }
+
+    pub(crate) const fn get_suppressions() -> &'static str {
+        "/email/1/suppressions"
+    }
+
+    pub(crate) const fn reschedule() -> &'static str {
+        "/email/1/bulks"
+    }
+
+    pub(crate) const fn send() -> &'static str {
+        "/email/3/send"
+    }
+
+    pub(crate) const fn update_scheduled_status() -> &'static str {
+        "/email/1/bulks/status"
+    }
+
+    pub(crate) const fn update_template() -> &'static str {
+        "/email/1/templates/{templateId}"
+    }
+
+    pub(crate) const fn update_tracking() -> &'static str {
+        "/email/1/domains/{domainName}/tracking"
+    }
+
+    pub(crate) const fn validate() -> &'static str {
+        "/email/2/validation"
+    }
+
+    pub(crate) const fn verify_domain() -> &'static str {
+        "/email/1/domains/{domainName}/verify"
+    }
+}
+
+/// Numbers endpoints.
+#[cfg(feature = "sms")]
+pub(crate) mod numbers {
+    pub(crate) const fn get_number() -> &'static str {
+        "/numbers/1/numbers/{numberKey}"
+    }
+
+    pub(crate) const fn get_numbers() -> &'static str {
+        "/numbers/1/numbers"
+    }
+
+    pub(crate) const fn update_number_sms_configuration() -> &'static str {
+        "/numbers/2/numbers/{numberKey}/sms"
+    }
+}
+
+/// SMS endpoints.
+#[cfg(feature = "sms")]
+pub(crate) mod sms {
+    pub(crate) const fn get_delivery_reports() -> &'static str {
+        "/sms/1/reports"
+    }
+
+    pub(crate) const fn get_inbound() -> &'static str {
+        "/sms/1/inbox/reports"
+    }
+
+    pub(crate) const fn get_logs() -> &'static str {
+        "/sms/1/logs"
+    }
+
+    pub(crate) const fn get_scheduled() -> &'static str {
+        "/sms/1/bulks"
+    }
+
+    pub(crate) const fn get_scheduled_status() -> &'static str {
+        "/sms/1/bulks/status"
+    }
+
+    pub(crate) const fn preview() -> &'static str {
+        "/sms/1/preview"
+    }
+
+    pub(crate) const fn reschedule() -> &'static str {
+        "/sms/1/bulks"
+    }
+
+    pub(crate) const fn send_advanced() -> &'static str {
+        "/sms/2/text/advanced"
+    }
+
+    pub(crate) const fn send_binary_advanced() -> &'static str {
+        "/sms/2/binary/advanced"
+    }
+
+    pub(crate) const fn send_over_query_parameters() -> &'static str {
+        "/sms/1/text/query"
+    }
+
+    pub(crate) const fn send_text_single() -> &'static str {
+        "/sms/2/text/single"
+    }
+
+    pub(crate) const fn update_scheduled_status() -> &'static str {
+        "/sms/1/bulks/status"
+    }
+}
+
+/// 2FA endpoints.
+#[cfg(feature = "sms")]
+pub(crate) mod tfa {
+    pub(crate) const fn create_application() -> &'static str {
+        "/2fa/2/applications"
+    }
+
+    pub(crate) const fn create_message_template() -> &'static str {
+        "/2fa/2/applications/{appId}/messages"
+    }
+
+    pub(crate) const fn get_application() -> &'static str {
+        "/2fa/2/applications/{appId}"
+    }
+
+    pub(crate) const fn get_applications() -> &'static str {
+        "/2fa/2/applications"
+    }
+
+    pub(crate) const fn get_message_template() -> &'static str {
+        "/2fa/2/applications/{appId}/messages/{msgId}"
+    }
+
+    pub(crate) const fn get_message_templates() -> &'static str {
+        "/2fa/2/applications/{appId}/messages"
+    }
+
+    pub(crate) const fn get_verification_status() -> &'static str {
+        "/2fa/2/applications/{appId}/verifications"
+    }
+
+    pub(crate) const fn resend_pin_over_sms() -> &'static str {
+        "/2fa/2/pin/{pinId}/resend"
+    }
+
+    pub(crate) const fn resend_pin_over_voice() -> &'static str {
+        "/2fa/2/pin/{pinId}/resend/voice"
+    }
+
+    pub(crate) const fn send_pin_over_sms() -> &'static str {
+        "/2fa/2/pin"
+    }
+
+    pub(crate) const fn send_pin_over_voice() -> &'static str {
+        "/2fa/2/pin/voice"
+    }
+
+    pub(crate) const fn update_application() -> &'static str {
+        "/2fa/2/applications/{appId}"
+    }
+
+    pub(crate) const fn update_message_template() -> &'static str {
+        "/2fa/2/applications/{appId}/messages/{msgId}"
+    }
+
+    pub(crate) const fn verify_phone_number() -> &'static str {
+        "/2fa/2/pin/{pinId}/verify"
+    }
+}
+
+/// Voice endpoints.
+#[cfg(feature = "voice")]
+pub(crate) mod voice {
+    pub(crate) const fn get_delivery_reports() -> &'static str {
+        "/tts/3/reports"
+    }
+}
+
+/// WhatsApp endpoints.
+#[cfg(feature = "whatsapp")]
+pub(crate) mod whatsapp {
+    pub(crate) const fn create_template() -> &'static str {
+        "/whatsapp/2/senders/{sender}/templates"
+    }
+
+    pub(crate) const fn delete_template() -> &'static str {
+        "/whatsapp/2/senders/{sender}/templates/{templateName}"
+    }
+
+    pub(crate) const fn get_sender_webhook() -> &'static str {
+        "/whatsapp/1/senders/{sender}/webhook"
+    }
+
+    pub(crate) const fn get_senders() -> &'static str {
+        "/whatsapp/1/senders"
+    }
+
+    pub(crate) const fn get_templates() -> &'static str {
+        "/whatsapp/2/senders/{sender}/templates"
+    }
+
+    pub(crate) const fn send_audio() -> &'static str {
+        "/whatsapp/1/message/audio"
+    }
+
+    pub(crate) const fn send_contact() -> &'static str {
+        "/whatsapp/1/message/contact"
+    }
+
+    pub(crate) const fn send_document() -> &'static str {
+        "/whatsapp/1/message/document"
+    }
+
+    pub(crate) const fn send_image() -> &'static str {
+        "/whatsapp/1/message/image"
+    }
+
+    pub(crate) const fn send_interactive_buttons() -> &'static str {
+        "/whatsapp/1/message/interactive/buttons"
+    }
+
+    pub(crate) const fn send_interactive_list() -> &'static str {
+        "/whatsapp/1/message/interactive/list"
+    }
+
+    pub(crate) const fn send_interactive_multiproduct() -> &'static str {
+        "/whatsapp/1/message/interactive/multi-product"
+    }
+
+    pub(crate) const fn send_interactive_product() -> &'static str {
+        "/whatsapp/1/message/interactive/product"
+    }
+
+    pub(crate) const fn send_location() -> &'static str {
+        "/whatsapp/1/message/location"
+    }
+
+    pub(crate) const fn send_sticker() -> &'static str {
+        "/whatsapp/1/message/sticker"
+    }
+
+    pub(crate) const fn send_template() -> &'static str {
+        "/whatsapp/1/message/template"
+    }
+
+    pub(crate) const fn send_text() -> &'static str {
+        "/whatsapp/1/message/text"
+    }
+
+    pub(crate) const fn send_video() -> &'static str {
+        "/whatsapp/1/message/video"
+    }
+
+    pub(crate) const fn update_sender_webhook() -> &'static str {
+        "/whatsapp/1/senders/{sender}/webhook"
+    }
+}
+
+/// Returns the path with its version segment replaced by the override configured for its
+/// channel, if any.
+pub(crate) fn resolve_path<'a>(configuration: &Configuration, path: &'a str) -> Cow<'a, str> {
+    let mut segments = path.splitn(4, '/');
+    let (channel, rest) = match (segments.next(), segments.next(), segments.next()) {
+        (Some(""), Some(channel), Some(_version)) => (channel, segments.next()),
+        _ => return Cow::Borrowed(path),
+    };
+
+    let version = ApiChannel::from_path_segment(channel)
+        .and_then(|channel| configuration.api_version_override(channel));
+
+    match (version, rest) {
+        (Some(version), Some(rest)) => Cow::Owned(format!("/{}/{}/{}", channel, version, rest)),
+        (Some(version), None) => Cow::Owned(format!("/{}/{}", channel, version)),
+        (None, _) => Cow::Borrowed(path),
+    }
+}
@@ -0,0 +1,243 @@
+//! Streaming export of logs into an `AsyncWrite`, as newline-delimited JSON or CSV.
+//!
+//! Logs are pulled page by page, walking back in time by moving `sentUntil` to the send time of
+//! the oldest log of each page, so the export never holds more than one page in memory.
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::api::SdkError;
+use crate::model::common::money_to_f64;
+
+/// Page size used by exports when the query parameters have no `limit`.
+pub const EXPORT_PAGE_SIZE: i32 = 1000;
+
+/// Format of the exported rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line, with the same fields as the API response.
+    NdJson,
+    /// Comma-separated values with a header line, quoted as in RFC 4180.
+    Csv,
+}
+
+/// State of an export, passed to the progress callback after each page and returned when the
+/// export finishes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportProgress {
+    /// Number of pages fetched so far.
+    pub pages: usize,
+
+    /// Number of rows written so far.
+    pub rows: usize,
+
+    /// Send time of the oldest row written so far. An interrupted export can be resumed by
+    /// setting it as `sent_until` of the query parameters; rows sent at exactly that time may be
+    /// written again.
+    pub last_sent_at: Option<String>,
+}
+
+/// Log entry that can be exported.
+pub trait ExportRow: Serialize {
+    /// Names of the CSV columns, in output order.
+    const CSV_COLUMNS: &'static [&'static str];
+
+    /// Values of the CSV columns, in the order of `CSV_COLUMNS`. Missing values are empty.
+    fn csv_values(&self) -> Vec<String>;
+
+    /// ID of the message the row belongs to.
+    fn message_id(&self) -> Option<&str>;
+
+    /// Send time of the message, used to request the next page.
+    fn sent_at(&self) -> Option<&str>;
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_line<I: IntoIterator<Item = S>, S: AsRef<str>>(values: I) -> String {
+    let mut line = values
+        .into_iter()
+        .map(|value| csv_field(value.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+
+    line
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+// Writes the pages returned by `fetch` until a page is shorter than `limit` or has no new rows.
+// `fetch` receives the `sentUntil` of the page to request, starting with `sent_until`. Rows already
+// written on the previous page boundary, which the API returns again since `sentUntil` is
+// inclusive, are skipped.
+pub(crate) async fn export_pages<R, W, F, Fut, P>(
+    mut writer: W,
+    format: ExportFormat,
+    limit: i32,
+    sent_until: Option<String>,
+    mut fetch: F,
+    mut progress: P,
+) -> Result<ExportProgress, SdkError>
+where
+    R: ExportRow,
+    W: AsyncWrite + Unpin,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Vec<R>, SdkError>>,
+    P: FnMut(&ExportProgress),
+{
+    let mut state = ExportProgress::default();
+    let mut next_sent_until = sent_until;
+    let mut boundary_ids: HashSet<String> = HashSet::new();
+
+    if format == ExportFormat::Csv {
+        writer
+            .write_all(csv_line(R::CSV_COLUMNS).as_bytes())
+            .await?;
+    }
+
+    loop {
+        let rows = fetch(next_sent_until.clone()).await?;
+        let page_len = rows.len();
+        let mut written = 0;
+
+        for row in &rows {
+            if row
+                .message_id()
+                .map_or(false, |id| boundary_ids.contains(id))
+            {
+                continue;
+            }
+
+            let line = match format {
+                ExportFormat::NdJson => {
+                    let mut line = serde_json::to_string(row)?;
+                    line.push('\n');
+                    line
+                }
+                ExportFormat::Csv => csv_line(row.csv_values()),
+            };
+            writer.write_all(line.as_bytes()).await?;
+            written += 1;
+
+            if let Some(sent_at) = row.sent_at() {
+                if state.last_sent_at.as_deref() != Some(sent_at) {
+                    boundary_ids.clear();
+                    state.last_sent_at = Some(sent_at.to_string());
+                }
+                if let Some(id) = row.message_id() {
+                    boundary_ids.insert(id.to_string());
+                }
+            }
+        }
+        writer.flush().await?;
+
+        state.pages += 1;
+        state.rows += written;
+        progress(&state);
+
+        if written == 0 || page_len < limit.max(1) as usize || state.last_sent_at.is_none() {
+            return Ok(state);
+        }
+        next_sent_until = state.last_sent_at.clone();
+    }
+}
+
+#[cfg(feature = "email")]
+impl ExportRow for crate::model::email::Log {
+    const CSV_COLUMNS: &'static [&'static str] = &[
+        "messageId",
+        "bulkId",
+        "from",
+        "to",
+        "sentAt",
+        "doneAt",
+        "messageCount",
+        "status",
+        "pricePerMessage",
+        "currency",
+        "text",
+    ];
+
+    fn csv_values(&self) -> Vec<String> {
+        let price = self.price.as_ref();
+        vec![
+            optional(self.message_id.as_ref()),
+            optional(self.bulk_id.as_ref()),
+            optional(self.from.as_ref()),
+            optional(self.to.as_ref()),
+            optional(self.sent_at.as_ref()),
+            optional(self.done_at.as_ref()),
+            optional(self.message_count),
+            optional(self.status.as_ref().and_then(|status| status.name.as_ref())),
+            optional(price.and_then(|price| price.price_per_message.map(money_to_f64))),
+            optional(price.and_then(|price| price.currency.as_ref())),
+            optional(self.text.as_ref()),
+        ]
+    }
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn sent_at(&self) -> Option<&str> {
+        self.sent_at.as_deref()
+    }
+}
+
+#[cfg(feature = "sms")]
+impl ExportRow for crate::model::sms::Log {
+    const CSV_COLUMNS: &'static [&'static str] = &[
+        "messageId",
+        "bulkId",
+        "from",
+        "to",
+        "sentAt",
+        "doneAt",
+        "smsCount",
+        "mccMnc",
+        "status",
+        "error",
+        "pricePerMessage",
+        "currency",
+        "text",
+    ];
+
+    fn csv_values(&self) -> Vec<String> {
+        let price = self.price.as_ref();
+        vec![
+            optional(self.message_id.as_ref()),
+            optional(self.bulk_id.as_ref()),
+            optional(self.from.as_ref()),
+            optional(self.to.as_ref()),
+            optional(self.sent_at.as_ref()),
+            optional(self.done_at.as_ref()),
+            optional(self.sms_count),
+            optional(self.mcc_mnc.as_ref()),
+            optional(self.status.as_ref().and_then(|status| status.name.as_ref())),
+            optional(self.error.as_ref().and_then(|error| error.name.as_ref())),
+            optional(price.and_then(|price| price.price_per_message.map(money_to_f64))),
+            optional(price.and_then(|price| price.currency.as_ref())),
+            optional(self.text.as_ref()),
+        ]
+    }
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn sent_at(&self) -> Option<&str> {
+        self.sent_at.as_deref()
+    }
+}
@@ -0,0 +1,131 @@
+//! Tracking of the requests in flight, so clients can be shut down after the requests already
+//! sent complete. A request is in flight from the moment it is sent until its response body is
+//! read, or until the call is dropped.
+
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use crate::api::diagnostics::DiagnosticRecorder;
+use crate::api::SdkError;
+
+/// Counts the requests in flight of the clients sharing a `Configuration`.
+#[derive(Debug, Default)]
+pub(crate) struct RequestTracker {
+    in_flight: AtomicUsize,
+    shutting_down: AtomicBool,
+    drained: Notify,
+}
+
+impl RequestTracker {
+    /// Registers a new request, failing with `SdkError::ShuttingDown` once shutdown started.
+    pub(crate) fn start(self: &Arc<Self>) -> Result<InFlightGuard, SdkError> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        // Registered before checking, so shutdown either rejects the request or waits for it.
+        let guard = InFlightGuard {
+            tracker: Arc::clone(self),
+        };
+
+        if self.shutting_down.load(Ordering::SeqCst) {
+            Err(SdkError::ShuttingDown)
+        } else {
+            Ok(guard)
+        }
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Rejects new requests and waits up to `grace` for the ones in flight, returning how many
+    /// are still in flight.
+    pub(crate) async fn shutdown(&self, grace: Duration) -> usize {
+        self.shutting_down.store(true, Ordering::SeqCst);
+
+        let drained = async {
+            loop {
+                let notified = self.drained.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        // Timing out only stops waiting; the requests in flight are left to complete.
+        let _ = tokio::time::timeout(grace, drained).await;
+
+        self.in_flight()
+    }
+}
+
+/// Marks a request as in flight until dropped.
+#[derive(Debug)]
+pub(crate) struct InFlightGuard {
+    tracker: Arc<RequestTracker>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.tracker.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.tracker.drained.notify_waiters();
+        }
+    }
+}
+
+/// Response of a request that stays in flight until the response is dropped, e.g. after reading
+/// its body. The request is recorded in the diagnostics, if enabled, when the response is dropped.
+#[derive(Debug)]
+pub(crate) struct Tracked<R> {
+    response: R,
+    _guard: InFlightGuard,
+    diagnostic: Option<DiagnosticRecorder>,
+}
+
+impl<R> Tracked<R> {
+    pub(crate) fn new(
+        response: R,
+        guard: InFlightGuard,
+        diagnostic: Option<DiagnosticRecorder>,
+    ) -> Self {
+        Tracked {
+            response,
+            _guard: guard,
+            diagnostic,
+        }
+    }
+
+    /// Returns the recorder of the request, if diagnostics are enabled.
+    pub(crate) fn diagnostic_mut(&mut self) -> Option<&mut DiagnosticRecorder> {
+        self.diagnostic.as_mut()
+    }
+
+    /// Returns the response, the guard keeping it in flight, and the recorder of the request.
+    #[cfg(feature = "sms")]
+    pub(crate) fn into_parts(self) -> (R, InFlightGuard, Option<DiagnosticRecorder>) {
+        (self.response, self._guard, self.diagnostic)
+    }
+}
+
+impl<R> Deref for Tracked<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.response
+    }
+}
+
+impl<R> DerefMut for Tracked<R> {
+    fn deref_mut(&mut self) -> &mut R {
+        &mut self.response
+    }
+}
@@ -1,26 +1,70 @@
 //! Endpoint functions and base response and error types
-use crate::configuration::{ApiKey, Configuration};
+use crate::configuration::{ApiKey, AuthMethod, Configuration, SendPolicy};
+use crate::model::common::Paged;
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+use crate::model::ApiRequest;
+use crate::model::ApiResponse;
+use diagnostics::DiagnosticRecorder;
+pub(crate) use in_flight::RequestTracker;
+use in_flight::{InFlightGuard, Tracked};
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use reqwest;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_derive::Serialize;
-use std::{collections::HashMap, fmt};
+use std::collections::HashMap;
+#[cfg(any(feature = "sms", feature = "email"))]
+use std::collections::{HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(any(feature = "sms", feature = "email"))]
+use std::sync::Mutex;
+use std::{fmt, future::Future};
 use thiserror::Error;
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
 use validator::Validate;
 
+pub mod client;
+
+pub mod diagnostics;
+
 #[cfg(feature = "email")]
 pub mod email;
 
+mod endpoints;
+
+#[cfg(any(feature = "sms", feature = "email"))]
+pub mod export;
+
+mod in_flight;
+
+#[cfg(feature = "sms")]
+pub mod numbers;
+
+pub mod polling;
+
+#[cfg(any(feature = "sms", feature = "email"))]
+mod results;
+
 #[cfg(feature = "sms")]
 pub mod sms;
 
+#[cfg(feature = "voice")]
+pub mod voice;
+
+pub mod webhook;
+
 #[cfg(feature = "whatsapp")]
 pub mod whatsapp;
 
 /// Holds the possible errors that can happen when calling the Infobip API.
 #[derive(Error, Debug)]
 pub enum SdkError {
-    #[error("request body has field errors")]
+    #[error("request body has field errors: {}", display_field_errors(.0))]
     Validation(#[from] validator::ValidationErrors),
 
     #[error("HTTP client error")]
@@ -29,11 +73,320 @@ pub enum SdkError {
     #[error("serialization error")]
     Serde(#[from] serde_json::Error),
 
+    #[error("response deserialization error: {0}")]
+    Deserialization(#[source] serde_json::Error, RawBody),
+
     #[error("API request error")]
     ApiRequestError(#[from] ApiError),
 
+    #[error("unauthorized, check the credentials{}", display_hint(.hint))]
+    Unauthorized {
+        /// Details of the error, unless the response body is not an API error, like the HTML
+        /// page of a gateway.
+        error: Option<Box<ApiError>>,
+        hint: Option<String>,
+    },
+
+    #[error("forbidden, the credentials lack permission{}", display_hint(.hint))]
+    Forbidden {
+        /// Details of the error, unless the response body is not an API error, like the HTML
+        /// page of a gateway.
+        error: Option<Box<ApiError>>,
+        hint: Option<String>,
+    },
+
     #[error("IO error")]
     Io(#[from] std::io::Error),
+
+    #[error("query string serialization error")]
+    QueryString(#[from] serde_urlencoded::ser::Error),
+
+    #[error("attachment {0} rejected: {1}")]
+    AttachmentRejected(String, String),
+
+    #[error("already sent with bulk ID {0:?} and message ID {1:?}")]
+    DuplicateSend(Option<String>, Option<String>),
+
+    #[error("conversation window of {0} with {1} is closed, only templates can be sent")]
+    ConversationWindowClosed(String, String),
+
+    #[error("template {template} in language {language} is not registered for sender {sender}")]
+    TemplateNotFound {
+        sender: String,
+        template: String,
+        language: String,
+    },
+
+    #[error("message does not match template {template} in language {language}: {problem}")]
+    TemplateMismatch {
+        template: String,
+        language: String,
+        problem: String,
+    },
+
+    #[error("request body of about {estimated} bytes exceeds the limit of {limit} bytes")]
+    PayloadTooLarge { estimated: u64, limit: u64 },
+
+    #[error("response body of at least {received} bytes exceeds the limit of {limit} bytes")]
+    ResponseTooLarge { received: u64, limit: u64 },
+
+    #[error("request signing failed: {0}")]
+    RequestSigning(#[source] crate::configuration::SigningError),
+
+    #[error("client is shutting down, no new requests are sent")]
+    ShuttingDown,
+
+    #[error("send policy rule {rule} breached: {detail}")]
+    PolicyViolation { rule: &'static str, detail: String },
+
+    #[cfg(feature = "xml")]
+    #[error("XML deserialization error")]
+    Xml(#[from] quick_xml::DeError),
+}
+
+impl SdkError {
+    /// Returns the raw response body that failed to deserialize, if this is a
+    /// `SdkError::Deserialization`, e.g. to parse it into a looser type with `replay_parse`.
+    pub fn into_raw_body(self) -> Option<RawBody> {
+        match self {
+            SdkError::Deserialization(_, raw_body) => Some(raw_body),
+            _ => None,
+        }
+    }
+}
+
+/// Maximum number of bytes of a response body kept by `SdkError::Deserialization`.
+pub const RAW_BODY_CAPTURE_LIMIT: usize = 64 * 1024;
+
+/// Response body that failed to deserialize, truncated to `RAW_BODY_CAPTURE_LIMIT` bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawBody {
+    /// The body, or its first `RAW_BODY_CAPTURE_LIMIT` bytes, cut at a character boundary.
+    pub text: String,
+
+    /// Size of the whole body in bytes.
+    pub len: usize,
+}
+
+impl RawBody {
+    pub(crate) fn capture(body: &str) -> RawBody {
+        let mut end = body.len().min(RAW_BODY_CAPTURE_LIMIT);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        RawBody {
+            text: body[..end].to_string(),
+            len: body.len(),
+        }
+    }
+
+    /// Returns whether the body was longer than `RAW_BODY_CAPTURE_LIMIT` and was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.text.len() < self.len
+    }
+}
+
+/// Parses a captured response body into another type, e.g. `serde_json::Value`, to inspect a
+/// response that did not match the SDK model. Truncated bodies are usually not valid JSON.
+pub fn replay_parse<T: DeserializeOwned>(raw_body: &RawBody) -> Result<T, serde_json::Error> {
+    serde_json::from_str(&raw_body.text)
+}
+
+// Deserializes a response body, keeping the raw body in the error when it does not match `T`.
+fn parse_response_body<T: ApiResponse>(text: &str) -> Result<T, SdkError> {
+    serde_json::from_str(text)
+        .map_err(|error| SdkError::Deserialization(error, RawBody::capture(text)))
+}
+
+// Top-level arrays of a successful response body that may hold warnings. Any other field is
+// skipped.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+#[derive(Deserialize)]
+struct ResponseNotices {
+    #[serde(default)]
+    warnings: Vec<Notice>,
+
+    #[serde(default)]
+    messages: Vec<Notice>,
+}
+
+// Entry of a notices array: either the text of the notice, or an object holding it. Other
+// entries, like the sent messages of a send response, are ignored.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Notice {
+    Text(String),
+    Object {
+        #[serde(alias = "message", alias = "description")]
+        text: String,
+    },
+    Other(serde::de::IgnoredAny),
+}
+
+// Deserializes a successful response body and collects its warnings, parsing the body only once.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+fn parse_response<T: ApiResponse>(text: &str) -> Result<(T, Vec<String>), SdkError> {
+    let value: serde_json::Value = serde_json::from_str(text)
+        .map_err(|error| SdkError::Deserialization(error, RawBody::capture(text)))?;
+    let warnings = response_warnings(&value);
+    let body = serde_json::from_value(value)
+        .map_err(|error| SdkError::Deserialization(error, RawBody::capture(text)))?;
+
+    Ok((body, warnings))
+}
+
+// Collects the warnings of a successful response body: the entries of a top-level `warnings`
+// array, and the text entries of a top-level `messages` array. Bodies that do not have this shape
+// have no warnings, so reading them never fails the call.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+fn response_warnings(value: &serde_json::Value) -> Vec<String> {
+    let notices = match ResponseNotices::deserialize(value) {
+        Ok(notices) => notices,
+        Err(_) => return Vec::new(),
+    };
+
+    let warnings = notices.warnings.into_iter().map(|notice| match notice {
+        Notice::Text(text) | Notice::Object { text } => Some(text),
+        Notice::Other(_) => None,
+    });
+    let messages = notices.messages.into_iter().map(|notice| match notice {
+        Notice::Text(text) => Some(text),
+        _ => None,
+    });
+
+    warnings.chain(messages).flatten().collect()
+}
+
+/// Validation error of a single field, with the path of the field in the request body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    /// Dotted path of the field, with list indexes, e.g.
+    /// `messages[3].deliveryTimeWindow.to.minute`. Errors raised by struct-level validators use
+    /// the path of the struct, which is empty for the request body itself.
+    ///
+    /// Validation errors only carry the Rust field names, so each name is converted to camel case
+    /// as a guess of its serialized name. The guess is wrong for fields serialized under another
+    /// name, e.g. `opens` of `UpdateTrackingRequestBody`, sent as `open`.
+    pub path: String,
+
+    /// Code of the failed validation, e.g. `range` or `length`.
+    pub code: String,
+
+    /// Description of the error, or the code if the validation has no message.
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Flattens nested and list validation errors into one `FieldError` per failed validation,
+/// sorted by path.
+pub fn format_validation_errors(errors: &validator::ValidationErrors) -> Vec<FieldError> {
+    let mut field_errors = Vec::new();
+    collect_field_errors("", errors, &mut field_errors);
+    field_errors.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.code.cmp(&b.code)));
+
+    field_errors
+}
+
+fn collect_field_errors(
+    parent: &str,
+    errors: &validator::ValidationErrors,
+    field_errors: &mut Vec<FieldError>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = if *field == "__all__" {
+            parent.to_string()
+        } else if parent.is_empty() {
+            camel_case(field)
+        } else {
+            format!("{}.{}", parent, camel_case(field))
+        };
+
+        match kind {
+            validator::ValidationErrorsKind::Field(errors) => {
+                field_errors.extend(errors.iter().map(|error| {
+                    FieldError {
+                        path: path.clone(),
+                        code: error.code.to_string(),
+                        message: error
+                            .message
+                            .as_ref()
+                            .map_or_else(|| error.code.to_string(), |message| message.to_string()),
+                    }
+                }));
+            }
+            validator::ValidationErrorsKind::Struct(errors) => {
+                collect_field_errors(&path, errors, field_errors);
+            }
+            validator::ValidationErrorsKind::List(errors) => {
+                for (index, errors) in errors {
+                    collect_field_errors(&format!("{}[{}]", path, index), errors, field_errors);
+                }
+            }
+        }
+    }
+}
+
+// Guesses the serialized name of a field from its Rust name, see `FieldError::path`.
+fn camel_case(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn display_hint(hint: &Option<String>) -> String {
+    hint.as_ref()
+        .map(|hint| format!(": {}", hint))
+        .unwrap_or_default()
+}
+
+fn display_field_errors(errors: &validator::ValidationErrors) -> String {
+    format_validation_errors(errors)
+        .iter()
+        .map(FieldError::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Holds the status code and error details when a 4xx or 5xx response is received.
@@ -84,6 +437,8 @@ pub struct ApiErrorDetails {
     pub request_error: RequestError,
 }
 
+impl ApiResponse for ApiErrorDetails {}
+
 impl fmt::Display for ApiErrorDetails {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -99,6 +454,390 @@ impl fmt::Display for ApiErrorDetails {
 pub struct SdkResponse<T> {
     pub body: T,
     pub status: StatusCode,
+
+    /// Whether the response comes from a client in sandbox mode, see
+    /// `Configuration::with_sandbox`.
+    pub sandbox: bool,
+
+    /// Non-fatal warnings sent along with the response body, e.g. that `html` was ignored
+    /// because a template was used. Empty when the response has none.
+    pub warnings: Vec<String>,
+
+    /// Where the request was sent, when `Configuration::with_connection_info` is enabled.
+    pub connection: Option<ConnectionInfo>,
+}
+
+/// Target of a request, captured for auditing where API calls went.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// Host of the request URL.
+    pub host: String,
+
+    /// Address the response was received from, when the HTTP client reports it. Reflects the
+    /// address pinned with `Configuration::pin_host`, if any.
+    pub remote_addr: Option<SocketAddr>,
+}
+
+/// Overrides applied to the requests of a single client call, e.g. to send on behalf of one of
+/// many tenants with its own API key. Pass them to the `with_request_options` method of a client,
+/// which returns a copy of the client sharing its HTTP connection pool, leaving the original
+/// client untouched.
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    /// Credentials used instead of the ones of the configuration.
+    pub auth_override: Option<AuthMethod>,
+
+    /// Headers added to the request, replacing the headers of the SDK with the same name.
+    pub extra_headers: HeaderMap,
+
+    /// Email send policy used instead of the one of the configuration.
+    pub send_policy: Option<SendPolicy>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the credentials used instead of the ones of the configuration.
+    pub fn with_auth(mut self, auth: AuthMethod) -> Self {
+        self.auth_override = Some(auth);
+        self
+    }
+
+    /// Sets the email send policy used instead of the one of the configuration.
+    pub fn with_send_policy(mut self, policy: SendPolicy) -> Self {
+        self.send_policy = Some(policy);
+        self
+    }
+
+    /// Adds a header to the request.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.extra_headers.insert(name, value);
+        self
+    }
+
+    // Returns a copy of `configuration` with the options applied. The configuration is copied on
+    // write, so the original is never modified.
+    pub(crate) fn apply(&self, configuration: &Configuration) -> Configuration {
+        let mut configuration = configuration.clone();
+
+        if let Some(auth) = &self.auth_override {
+            configuration = configuration.with_auth(auth.clone());
+        }
+        if let Some(policy) = &self.send_policy {
+            configuration = configuration.with_send_policy(policy.clone());
+        }
+        if !self.extra_headers.is_empty() {
+            let mut headers = configuration.extra_headers().clone();
+            for (name, value) in &self.extra_headers {
+                headers.insert(name, value.clone());
+            }
+            configuration = configuration.with_extra_headers(headers);
+        }
+
+        configuration
+    }
+}
+
+/// Holds an HTTP request exactly as it would be sent to the Infobip API, without performing any
+/// I/O. Useful for inspecting payloads before wiring production credentials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedRequest {
+    /// HTTP method of the request.
+    pub method: reqwest::Method,
+
+    /// Full URL, including the query string.
+    pub url: String,
+
+    /// Request headers. Credentials in the `Authorization` header are redacted.
+    pub headers: Vec<(String, String)>,
+
+    /// Serialized request body.
+    pub body: PreparedBody,
+}
+
+/// Holds the body of a `PreparedRequest`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreparedBody {
+    /// The request has no body.
+    Empty,
+
+    /// The request body as serialized JSON.
+    Json(String),
+
+    /// Listing of the parts of a multipart form.
+    Multipart(Vec<PreparedPart>),
+}
+
+/// Holds the description of a single part of a multipart request body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedPart {
+    /// Name of the form field.
+    pub name: String,
+
+    /// File name, only present for file parts.
+    pub file_name: Option<String>,
+
+    /// Size of the part content in bytes.
+    pub size: u64,
+}
+
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+const REDACTED: &str = "[REDACTED]";
+
+#[cfg(any(feature = "sms", feature = "email"))]
+type SentId = (Option<String>, Option<String>);
+
+// Remembers the most recently used (bulk ID, message ID) pairs for the `send_once` methods. A
+// pair is used when it is sent and when a send of it is refused. The least recently used pairs
+// are evicted when the capacity is reached.
+#[cfg(any(feature = "sms", feature = "email"))]
+#[derive(Debug, Default)]
+struct SentIds {
+    // Tick of the last use of each pair.
+    last_used: HashMap<SentId, u64>,
+    // Uses of the pairs, oldest first. A use is stale once its pair has been used again.
+    uses: VecDeque<(u64, SentId)>,
+    clock: u64,
+}
+
+#[cfg(any(feature = "sms", feature = "email"))]
+impl SentIds {
+    // Records all the pairs, unless one of them was already sent, which is returned instead.
+    // Pairs repeated in the same request are recorded once.
+    fn record(&mut self, ids: Vec<SentId>, capacity: usize) -> Result<(), SentId> {
+        let mut seen = HashSet::with_capacity(ids.len());
+        let ids: Vec<SentId> = ids
+            .into_iter()
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+
+        let result = match ids.iter().find(|id| self.last_used.contains_key(*id)) {
+            Some(duplicate) => {
+                let duplicate = duplicate.clone();
+                self.touch(duplicate.clone());
+                Err(duplicate)
+            }
+            None => {
+                ids.into_iter().for_each(|id| self.touch(id));
+                Ok(())
+            }
+        };
+        self.evict(capacity);
+
+        result
+    }
+
+    #[cfg(all(test, feature = "sms"))]
+    fn len(&self) -> usize {
+        self.last_used.len()
+    }
+
+    fn touch(&mut self, id: SentId) {
+        self.clock += 1;
+        self.last_used.insert(id.clone(), self.clock);
+        self.uses.push_back((self.clock, id));
+    }
+
+    fn is_current(&self, tick: u64, id: &SentId) -> bool {
+        self.last_used.get(id) == Some(&tick)
+    }
+
+    fn evict(&mut self, capacity: usize) {
+        while self.last_used.len() > capacity {
+            match self.uses.pop_front() {
+                Some((tick, id)) if self.is_current(tick, &id) => {
+                    self.last_used.remove(&id);
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        // Stale uses are only dropped when reaching the front, so they are swept once they
+        // outnumber the pairs.
+        if self.uses.len() > 2 * self.last_used.len().max(1) {
+            let last_used = &self.last_used;
+            self.uses
+                .retain(|(tick, id)| last_used.get(id) == Some(tick));
+        }
+    }
+}
+
+// Marks the IDs of a request as consumed, failing with `DuplicateSend` if any was already sent.
+// Pairs without any ID can't be told apart and are not tracked.
+#[cfg(any(feature = "sms", feature = "email"))]
+fn record_sent_ids(
+    configuration: &Configuration,
+    sent_ids: &Mutex<SentIds>,
+    ids: Vec<SentId>,
+) -> Result<(), SdkError> {
+    let capacity = match configuration.send_once_capacity() {
+        Some(capacity) => capacity,
+        None => return Ok(()),
+    };
+    let ids = ids
+        .into_iter()
+        .filter(|(bulk_id, message_id)| bulk_id.is_some() || message_id.is_some())
+        .collect();
+
+    sent_ids
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .record(ids, capacity)
+        .map_err(|(bulk_id, message_id)| SdkError::DuplicateSend(bulk_id, message_id))
+}
+
+/// Fetches consecutive pages of a paged endpoint, starting at `first_page`, and returns the bodies
+/// of all of them. Fetching stops when a page comes back empty, when all the `totalResults` items
+/// have been collected, or after the first page if the response has no paging details.
+///
+/// # Example
+/// ```no_run
+/// # #[cfg(feature = "email")]
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use infobip_sdk::api::email::EmailClient;
+/// # use infobip_sdk::api::fetch_all_pages;
+/// # use infobip_sdk::configuration::Configuration;
+/// # use infobip_sdk::model::email::GetDomainsQueryParameters;
+/// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+///
+/// let pages = fetch_all_pages(1, |page| {
+///     let mut query_parameters = GetDomainsQueryParameters::new();
+///     query_parameters.page = Some(page);
+///
+///     client.get_domains(query_parameters)
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "email"))]
+/// # fn main() {}
+/// ```
+pub async fn fetch_all_pages<T, F, Fut>(first_page: i32, mut fetch: F) -> Result<Vec<T>, SdkError>
+where
+    T: Paged,
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = Result<SdkResponse<T>, SdkError>>,
+{
+    let mut pages = Vec::new();
+    let mut page = first_page;
+    let mut fetched_items = 0;
+
+    loop {
+        let body = fetch(page).await?.body;
+        let items = body.items_len();
+        fetched_items += items;
+
+        let done = items == 0
+            || match body.paging().and_then(|paging| paging.total_results) {
+                Some(total_results) => fetched_items >= total_results.max(0) as usize,
+                None => true,
+            };
+        pages.push(body);
+
+        if done {
+            return Ok(pages);
+        }
+        page += 1;
+    }
+}
+
+/// Future returned by `PullReports::pull_reports`.
+pub type PullReportsFuture<'a, R> =
+    Pin<Box<dyn Future<Output = Result<Vec<R>, SdkError>> + Send + 'a>>;
+
+/// Clients with a delivery reports endpoint that can be pulled through a `ReportsCursor`.
+pub trait PullReports {
+    /// Type of the delivery reports returned by the endpoint.
+    type Report;
+
+    /// Maximum number of reports that can be pulled with one request.
+    const MAX_LIMIT: i32;
+
+    /// Pulls at most `limit` delivery reports. Pulled reports are consumed by the API.
+    fn pull_reports(&self, limit: i32) -> PullReportsFuture<'_, Self::Report>;
+}
+
+/// Error of `ReportsCursor::drain_all`, with the reports fetched before the failing request.
+#[derive(Error, Debug)]
+#[error("draining delivery reports failed after {} reports: {source}", .reports.len())]
+pub struct DrainError<R> {
+    /// Reports fetched, and so consumed by the API, before the failure.
+    pub reports: Vec<R>,
+    #[source]
+    pub source: SdkError,
+}
+
+/// Shared access to the delivery reports endpoint of a client.
+///
+/// The delivery reports endpoint consumes reports on read: each report is returned only once, so
+/// two concurrent requests split the available reports between them unpredictably. A cursor
+/// serializes its requests, so application code can share one cursor (e.g. in an `Arc`) between
+/// tasks and every batch is fetched only after the previous one has been returned.
+#[derive(Debug)]
+pub struct ReportsCursor<C> {
+    client: C,
+    lock: tokio::sync::Mutex<()>,
+    fetched: AtomicUsize,
+}
+
+impl<C: PullReports> ReportsCursor<C> {
+    pub fn new(client: C) -> Self {
+        ReportsCursor {
+            client,
+            lock: tokio::sync::Mutex::new(()),
+            fetched: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the total number of reports fetched through this cursor.
+    pub fn fetched(&self) -> usize {
+        self.fetched.load(Ordering::SeqCst)
+    }
+
+    /// Fetches the next batch of at most `limit` reports, waiting for any other request of this
+    /// cursor to finish first.
+    pub async fn next_batch(&self, limit: i32) -> Result<Vec<C::Report>, SdkError> {
+        let _guard = self.lock.lock().await;
+
+        self.pull(limit).await
+    }
+
+    /// Fetches batches until no reports are left or `max` reports have been fetched by this
+    /// call. Other requests of this cursor wait until draining is done.
+    ///
+    /// Reports are consumed by the API as they are pulled, so when a request fails after some
+    /// batches were fetched, the returned `DrainError` still holds the reports fetched so far.
+    pub async fn drain_all(&self, max: usize) -> Result<Vec<C::Report>, DrainError<C::Report>> {
+        let _guard = self.lock.lock().await;
+        let mut reports = Vec::new();
+
+        while reports.len() < max {
+            let limit = (max - reports.len()).min(C::MAX_LIMIT as usize) as i32;
+            let batch = match self.pull(limit).await {
+                Ok(batch) => batch,
+                Err(source) => return Err(DrainError { reports, source }),
+            };
+            let exhausted = batch.len() < limit as usize;
+            reports.extend(batch);
+
+            if exhausted {
+                break;
+            }
+        }
+
+        Ok(reports)
+    }
+
+    async fn pull(&self, limit: i32) -> Result<Vec<C::Report>, SdkError> {
+        let batch = self.client.pull_reports(limit).await?;
+        self.fetched.fetch_add(batch.len(), Ordering::SeqCst);
+
+        Ok(batch)
+    }
 }
 
 fn api_key_authorization_value(api_key: &ApiKey) -> String {
@@ -127,6 +866,57 @@ fn add_auth(mut builder: RequestBuilder, configuration: &Configuration) -> Reque
     builder
 }
 
+// Applies the request timeout of the configuration, if any.
+fn add_timeout(builder: RequestBuilder, configuration: &Configuration) -> RequestBuilder {
+    match configuration.timeout() {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    }
+}
+
+// Blocking version of add_timeout, uses blocking request builder.
+#[cfg(feature = "sms")]
+fn add_timeout_blocking(
+    builder: reqwest::blocking::RequestBuilder,
+    configuration: &Configuration,
+) -> reqwest::blocking::RequestBuilder {
+    match configuration.timeout() {
+        Some(timeout) => builder.timeout(timeout),
+        None => builder,
+    }
+}
+
+// Adds the sandbox headers, in sandbox mode, and the extra headers of the configuration, replacing
+// headers with the same name.
+fn add_extra_headers(mut builder: RequestBuilder, configuration: &Configuration) -> RequestBuilder {
+    if configuration.is_sandbox() {
+        builder = builder.headers(configuration.sandbox_headers().clone());
+    }
+
+    if configuration.extra_headers().is_empty() {
+        builder
+    } else {
+        builder.headers(configuration.extra_headers().clone())
+    }
+}
+
+// Blocking version of add_extra_headers, uses blocking request builder.
+#[cfg(feature = "sms")]
+fn add_extra_headers_blocking(
+    mut builder: reqwest::blocking::RequestBuilder,
+    configuration: &Configuration,
+) -> reqwest::blocking::RequestBuilder {
+    if configuration.is_sandbox() {
+        builder = builder.headers(configuration.sandbox_headers().clone());
+    }
+
+    if configuration.extra_headers().is_empty() {
+        builder
+    } else {
+        builder.headers(configuration.extra_headers().clone())
+    }
+}
+
 #[inline]
 fn user_agent() -> &'static str {
     include!("../../version.txt")
@@ -138,6 +928,7 @@ fn add_user_agent(builder: RequestBuilder) -> RequestBuilder {
 }
 
 // Adds user agent to the request builder. Synchronous version.
+#[cfg(feature = "sms")]
 fn add_user_agent_blocking(
     builder: reqwest::blocking::RequestBuilder,
 ) -> reqwest::blocking::RequestBuilder {
@@ -145,6 +936,7 @@ fn add_user_agent_blocking(
 }
 
 // Blocking version of add_auth, uses blocking request builder.
+#[cfg(feature = "sms")]
 fn add_auth_blocking(
     mut builder: reqwest::blocking::RequestBuilder,
     configuration: &Configuration,
@@ -163,83 +955,708 @@ fn add_auth_blocking(
     builder
 }
 
-fn build_api_error(status: StatusCode, text: &str) -> SdkError {
-    match serde_json::from_str(text) {
-        Ok(details) => SdkError::ApiRequestError(ApiError { details, status }),
-        Err(e) => SdkError::Serde(e),
+// Returns the `Authorization` header value with the credentials replaced by a placeholder.
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+fn redacted_authorization_value(configuration: &Configuration) -> Option<String> {
+    if let Some(api_key) = configuration.api_key() {
+        let prefix = api_key.prefix.as_deref().unwrap_or("App");
+        Some(format!("{} {}", prefix, REDACTED))
+    } else if configuration.basic_auth().is_some() {
+        Some(format!("Basic {}", REDACTED))
+    } else {
+        configuration
+            .bearer_access_token()
+            .map(|_| format!("Bearer {}", REDACTED))
+    }
+}
+
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+fn prepared_headers(configuration: &Configuration, content_type: &str) -> Vec<(String, String)> {
+    let mut headers = Vec::new();
+    if let Some(authorization) = redacted_authorization_value(configuration) {
+        headers.push(("Authorization".to_string(), authorization));
+    }
+    headers.push(("User-Agent".to_string(), user_agent().to_string()));
+    headers.push(("Content-Type".to_string(), content_type.to_string()));
+
+    let sandbox_headers = configuration
+        .is_sandbox()
+        .then(|| configuration.sandbox_headers());
+    for (name, value) in sandbox_headers
+        .into_iter()
+        .flatten()
+        .chain(configuration.extra_headers())
+    {
+        let value = if name == reqwest::header::AUTHORIZATION {
+            REDACTED.to_string()
+        } else {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        };
+        headers.retain(|(header, _)| !header.eq_ignore_ascii_case(name.as_str()));
+        headers.push((name.to_string(), value));
+    }
+
+    headers
+}
+
+#[cfg(any(feature = "sms", feature = "whatsapp"))]
+fn prepare_json_request<T: ApiRequest + Validate>(
+    configuration: &Configuration,
+    request_body: &T,
+    query_string: &str,
+    method: reqwest::Method,
+    path: &str,
+) -> Result<PreparedRequest, SdkError> {
+    request_body.validate()?;
+
+    Ok(PreparedRequest {
+        method,
+        url: build_url(configuration, path, query_string),
+        headers: prepared_headers(configuration, "application/json"),
+        body: PreparedBody::Json(serde_json::to_string(request_body)?),
+    })
+}
+
+#[cfg(feature = "email")]
+fn prepare_multipart_request(
+    configuration: &Configuration,
+    parts: Vec<PreparedPart>,
+    method: reqwest::Method,
+    path: &str,
+) -> PreparedRequest {
+    PreparedRequest {
+        method,
+        url: build_url(configuration, path, ""),
+        headers: prepared_headers(configuration, "multipart/form-data"),
+        body: PreparedBody::Multipart(parts),
+    }
+}
+
+// Characters escaped in path parameters: all but the unreserved characters of RFC 3986, so a
+// `/`, `?` or `#` in an ID cannot change the requested path.
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Replaces the `{name}` parameters of an endpoint path with their values, each percent-encoded
+/// as a single path segment.
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+pub(crate) fn fill_path(path: &str, parameters: &[(&str, &str)]) -> String {
+    parameters
+        .iter()
+        .fold(path.to_string(), |path, (name, value)| {
+            path.replace(&format!("{{{}}}", name), &encode_path_segment(value))
+        })
+}
+
+// Dot segments are resolved by URL parsers, so `.` and `..` are escaped in full.
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+fn encode_path_segment(value: &str) -> String {
+    match value {
+        "." => "%2E".to_string(),
+        ".." => "%2E%2E".to_string(),
+        value => utf8_percent_encode(value, PATH_SEGMENT).to_string(),
+    }
+}
+
+// Appends the query string to the endpoint URL, omitting the `?` when there are no parameters.
+// The path is resolved against the API version overrides of the configuration.
+fn build_url(configuration: &Configuration, path: &str, query_string: &str) -> String {
+    let path = endpoints::resolve_path(configuration, path);
+
+    if query_string.is_empty() {
+        format!("{}{}", configuration.base_url(), path)
+    } else {
+        format!("{}{}?{}", configuration.base_url(), path, query_string)
     }
 }
 
+// Builds the error of a failed request. A 401 or 403 becomes `SdkError::Unauthorized` or
+// `SdkError::Forbidden`, with a hint derived from the auth method and base URL of the
+// configuration, even when its body is not an API error.
+fn build_api_error(status: StatusCode, text: &str, configuration: &Configuration) -> SdkError {
+    let parsed = parse_response_body(text).map(|details| ApiError { details, status });
+
+    match status {
+        StatusCode::UNAUTHORIZED => SdkError::Unauthorized {
+            error: parsed.ok().map(Box::new),
+            hint: configuration.credential_hint(status),
+        },
+        StatusCode::FORBIDDEN => SdkError::Forbidden {
+            error: parsed.ok().map(Box::new),
+            hint: configuration.credential_hint(status),
+        },
+        _ => match parsed {
+            Ok(error) => SdkError::ApiRequestError(error),
+            Err(error) => error,
+        },
+    }
+}
+
+// Builds the response of an endpoint that may answer with an empty body, like a 204, which is then
+// read as the default value of the body type instead of failing to deserialize.
+#[cfg(any(feature = "sms", feature = "email"))]
+fn build_response_or_default<T: ApiResponse + Default>(
+    status: StatusCode,
+    text: &str,
+    configuration: &Configuration,
+    connection: Option<ConnectionInfo>,
+) -> Result<SdkResponse<T>, SdkError> {
+    if !status.is_success() {
+        return Err(build_api_error(status, text, configuration));
+    }
+
+    let (body, warnings) = if text.trim().is_empty() {
+        (T::default(), Vec::new())
+    } else {
+        parse_response(text)?
+    };
+
+    Ok(SdkResponse {
+        body,
+        status,
+        sandbox: configuration.is_sandbox(),
+        warnings,
+        connection,
+    })
+}
+
+// Builds the response of a lookup whose 404 means that the resource does not exist, which is then
+// read as `None` instead of an error.
+#[cfg(any(feature = "sms", feature = "email"))]
+fn build_optional_response<T: ApiResponse>(
+    status: StatusCode,
+    text: &str,
+    configuration: &Configuration,
+    connection: Option<ConnectionInfo>,
+) -> Result<Option<SdkResponse<T>>, SdkError> {
+    if status == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        return Err(build_api_error(status, text, configuration));
+    }
+
+    let (body, warnings) = parse_response(text)?;
+
+    Ok(Some(SdkResponse {
+        body,
+        status,
+        sandbox: configuration.is_sandbox(),
+        warnings,
+        connection,
+    }))
+}
+
+// Builds the HTTP client used by the clients created from a configuration alone, resolving the
+// host of the base URL to the pinned address, if any.
+pub(crate) fn build_http_client(configuration: &Configuration) -> reqwest::Client {
+    let builder = reqwest::Client::builder();
+    let builder = match pinned_target(configuration) {
+        Some((host, address)) => builder.resolve(&host, address),
+        None => builder,
+    };
+
+    builder.build().expect("HTTP client cannot be initialized")
+}
+
+#[cfg(feature = "sms")]
+pub(crate) fn build_blocking_http_client(
+    configuration: &Configuration,
+) -> reqwest::blocking::Client {
+    let builder = reqwest::blocking::Client::builder();
+    let builder = match pinned_target(configuration) {
+        Some((host, address)) => builder.resolve(&host, address),
+        None => builder,
+    };
+
+    builder.build().expect("HTTP client cannot be initialized")
+}
+
+// The port of a resolved address is ignored by reqwest, which keeps the port of the URL.
+fn pinned_target(configuration: &Configuration) -> Option<(String, SocketAddr)> {
+    let address = configuration.pinned_address()?;
+    let host = configuration.host()?;
+
+    Some((host, SocketAddr::new(address, 0)))
+}
+
+// Captures where a request was sent, if the configuration asks for it.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+fn connection_info(response: &Response, configuration: &Configuration) -> Option<ConnectionInfo> {
+    if !configuration.captures_connection_info() {
+        return None;
+    }
+
+    Some(ConnectionInfo {
+        host: response.url().host_str().unwrap_or_default().to_string(),
+        remote_addr: response.remote_addr(),
+    })
+}
+
+#[cfg(feature = "sms")]
+fn connection_info_blocking(
+    response: &reqwest::blocking::Response,
+    configuration: &Configuration,
+) -> Option<ConnectionInfo> {
+    if !configuration.captures_connection_info() {
+        return None;
+    }
+
+    Some(ConnectionInfo {
+        host: response.url().host_str().unwrap_or_default().to_string(),
+        remote_addr: response.remote_addr(),
+    })
+}
+
+fn check_response_size(received: u64, configuration: &Configuration) -> Result<(), SdkError> {
+    match configuration.max_response_body_size() {
+        Some(limit) if received > limit => Err(SdkError::ResponseTooLarge { received, limit }),
+        _ => Ok(()),
+    }
+}
+
+// Reads the response body as text, failing as soon as it exceeds the maximum response size of the
+// configuration, which is checked against the `Content-Length` before reading anything.
+async fn read_response_text(
+    response: Tracked<Response>,
+    configuration: &Configuration,
+) -> Result<String, SdkError> {
+    let body = read_response_bytes(response, configuration).await?;
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+// Reads the response body as is, without assuming any encoding, with the same size checks as
+// `read_response_text`.
+async fn read_response_bytes(
+    mut response: Tracked<Response>,
+    configuration: &Configuration,
+) -> Result<bytes::Bytes, SdkError> {
+    let result = read_body_chunks(&mut response, configuration).await;
+    if let Some(recorder) = response.diagnostic_mut() {
+        match &result {
+            Ok(body) => recorder.response_body(body),
+            Err(error) => recorder.failed(error),
+        }
+    }
+
+    result.map(bytes::Bytes::from)
+}
+
+async fn read_body_chunks(
+    response: &mut Response,
+    configuration: &Configuration,
+) -> Result<Vec<u8>, SdkError> {
+    if let Some(length) = response.content_length() {
+        check_response_size(length, configuration)?;
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        check_response_size(body.len() as u64, configuration)?;
+    }
+
+    Ok(body)
+}
+
+#[cfg(feature = "sms")]
+fn read_response_text_blocking(
+    response: Tracked<reqwest::blocking::Response>,
+    configuration: &Configuration,
+) -> Result<String, SdkError> {
+    let (response, _guard, mut recorder) = response.into_parts();
+
+    let result = read_body_blocking(response, configuration);
+    if let Some(recorder) = recorder.as_mut() {
+        match &result {
+            Ok(body) => recorder.response_body(body),
+            Err(error) => recorder.failed(error),
+        }
+    }
+
+    result.map(|body| String::from_utf8_lossy(&body).into_owned())
+}
+
+#[cfg(feature = "sms")]
+fn read_body_blocking(
+    response: reqwest::blocking::Response,
+    configuration: &Configuration,
+) -> Result<Vec<u8>, SdkError> {
+    use std::io::Read;
+
+    if let Some(length) = response.content_length() {
+        check_response_size(length, configuration)?;
+    }
+
+    let mut body = Vec::new();
+    match configuration.max_response_body_size() {
+        Some(limit) => {
+            response
+                .take(limit.saturating_add(1))
+                .read_to_end(&mut body)?;
+            check_response_size(body.len() as u64, configuration)?;
+        }
+        None => {
+            let mut response = response;
+            response.read_to_end(&mut body)?;
+        }
+    }
+
+    Ok(body)
+}
+
+// Calls `on_item` with every item of the `results` array of a successful response as soon as it
+// is received, keeping only one item in memory. Error responses are read whole and returned as
+// API errors.
+#[cfg(any(feature = "sms", feature = "email"))]
+async fn stream_results<T, F>(
+    mut response: Tracked<Response>,
+    configuration: &Configuration,
+    mut on_item: F,
+) -> Result<StatusCode, SdkError>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    let status = response.status();
+    if !status.is_success() {
+        let text = read_response_text(response, configuration).await?;
+        return Err(build_api_error(status, &text, configuration));
+    }
+
+    let mut scanner = results::ResultsScanner::new();
+    while let Some(chunk) = response.chunk().await? {
+        scanner.push(&chunk, &mut on_item)?;
+    }
+
+    Ok(status)
+}
+
+// Runs the request signer of the configuration, if any, over the method, the path with the query
+// string, and the body bytes of a request, returning the headers to add to it.
+fn sign_request(
+    configuration: &Configuration,
+    method: &reqwest::Method,
+    url: &str,
+    body: &[u8],
+) -> Result<HeaderMap, SdkError> {
+    let mut headers = HeaderMap::new();
+    let signer = match configuration.request_signer() {
+        Some(signer) => signer,
+        None => return Ok(headers),
+    };
+
+    let path = match reqwest::Url::parse(url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    };
+    for (name, value) in signer
+        .sign(method, &path, body)
+        .map_err(SdkError::RequestSigning)?
+    {
+        headers.insert(name, value);
+    }
+
+    Ok(headers)
+}
+
+// Field of a multipart request body.
+#[cfg(feature = "email")]
+struct MultipartField {
+    name: String,
+    file_name: Option<String>,
+    content: MultipartContent,
+}
+
+// Content of a multipart field, either in memory or an open file with its length, streamed while
+// the body is sent.
+#[cfg(feature = "email")]
+enum MultipartContent {
+    Bytes(Vec<u8>),
+    File(tokio::fs::File, u64),
+}
+
+#[cfg(feature = "email")]
+fn build_multipart_form(fields: Vec<MultipartField>) -> reqwest::multipart::Form {
+    let mut form = reqwest::multipart::Form::new();
+
+    for field in fields {
+        let part = match field.content {
+            MultipartContent::Bytes(content) => reqwest::multipart::Part::bytes(content),
+            MultipartContent::File(file, length) => {
+                reqwest::multipart::Part::stream_with_length(file, length)
+            }
+        };
+        let part = match field.file_name {
+            Some(file_name) => part.file_name(file_name),
+            None => part,
+        };
+        form = form.part(field.name, part);
+    }
+
+    form
+}
+
+// Returns a random boundary with the length of the ones generated by reqwest.
+#[cfg(feature = "email")]
+fn multipart_boundary() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let random = || RandomState::new().build_hasher().finish();
+
+    format!(
+        "{:016x}-{:016x}-{:016x}-{:016x}",
+        random(),
+        random(),
+        random(),
+        random()
+    )
+}
+
+// Encodes multipart fields with the same framing as reqwest, so that a signed body can be sent
+// as is. Files are read in full, since the signature covers the whole body.
+#[cfg(feature = "email")]
+async fn encode_multipart(fields: Vec<MultipartField>, boundary: &str) -> std::io::Result<Vec<u8>> {
+    use tokio::io::AsyncReadExt;
+
+    let escape = |value: &str| {
+        value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    };
+    let mut body = Vec::new();
+
+    for field in fields {
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        body.extend_from_slice(
+            format!(
+                "Content-Disposition: form-data; name=\"{}\"",
+                escape(&field.name)
+            )
+            .as_bytes(),
+        );
+        if let Some(file_name) = &field.file_name {
+            body.extend_from_slice(format!("; filename=\"{}\"", escape(file_name)).as_bytes());
+        }
+        body.extend_from_slice(b"\r\n\r\n");
+        match field.content {
+            MultipartContent::Bytes(content) => body.extend_from_slice(&content),
+            MultipartContent::File(mut file, _) => {
+                file.read_to_end(&mut body).await?;
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    Ok(body)
+}
+
 async fn send_no_body_request(
     client: &reqwest::Client,
     configuration: &Configuration,
-    query_parameters: HashMap<String, String>,
+    query_string: &str,
     method: reqwest::Method,
     path: &str,
-) -> Result<Response, SdkError> {
-    let url = format!("{}{}", configuration.base_url(), path);
-    let mut builder = client.request(method, url).query(&query_parameters);
+) -> Result<Tracked<Response>, SdkError> {
+    let guard = configuration.request_tracker().start()?;
+    let url = build_url(configuration, path, query_string);
+    let signed_headers = sign_request(configuration, &method, &url, &[])?;
+    let recorder = DiagnosticRecorder::start(configuration, &method, &url, None);
+    let builder = client.request(method, url);
+
+    send_no_body_builder(builder, configuration, signed_headers, guard, recorder).await
+}
+
+// Sends a GET request for a binary file, like an attachment, accepting any content type instead
+// of JSON.
+#[cfg(feature = "email")]
+async fn send_download_request(
+    client: &reqwest::Client,
+    configuration: &Configuration,
+    path: &str,
+) -> Result<Tracked<Response>, SdkError> {
+    let guard = configuration.request_tracker().start()?;
+    let url = build_url(configuration, path, "");
+    let signed_headers = sign_request(configuration, &reqwest::Method::GET, &url, &[])?;
+    let recorder = DiagnosticRecorder::start(configuration, &reqwest::Method::GET, &url, None);
+    let builder = client
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/octet-stream");
 
+    send_no_body_builder(builder, configuration, signed_headers, guard, recorder).await
+}
+
+async fn send_no_body_builder(
+    mut builder: RequestBuilder,
+    configuration: &Configuration,
+    signed_headers: HeaderMap,
+    guard: InFlightGuard,
+    recorder: Option<DiagnosticRecorder>,
+) -> Result<Tracked<Response>, SdkError> {
     builder = add_auth(builder, configuration);
+    builder = add_timeout(builder, configuration);
     builder = add_user_agent(builder);
+    builder = add_extra_headers(builder, configuration);
 
-    Ok(builder.send().await?)
+    send_tracked(builder.headers(signed_headers), guard, recorder).await
 }
 
-async fn send_valid_json_request<T: Validate + serde::Serialize>(
+// Sends a request, keeping it in flight until its response is dropped, and recording the
+// response or the error in the diagnostics.
+async fn send_tracked(
+    builder: RequestBuilder,
+    guard: InFlightGuard,
+    mut recorder: Option<DiagnosticRecorder>,
+) -> Result<Tracked<Response>, SdkError> {
+    match builder.send().await {
+        Ok(response) => {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.received(response.status(), response.headers());
+            }
+            Ok(Tracked::new(response, guard, recorder))
+        }
+        Err(error) => {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.failed(&error);
+            }
+            Err(error.into())
+        }
+    }
+}
+
+#[cfg(any(feature = "sms", feature = "email", feature = "whatsapp"))]
+async fn send_valid_json_request<T: ApiRequest + Validate>(
     client: &reqwest::Client,
     configuration: &Configuration,
     request_body: T,
-    query_parameters: HashMap<String, String>,
+    query_string: &str,
     method: reqwest::Method,
     path: &str,
-) -> Result<Response, SdkError> {
+) -> Result<Tracked<Response>, SdkError> {
     request_body.validate()?;
+    let guard = configuration.request_tracker().start()?;
 
-    let url = format!("{}{}", configuration.base_url(), path);
+    let url = build_url(configuration, path, query_string);
+    let body = serde_json::to_vec(&request_body)?;
+    let signed_headers = sign_request(configuration, &method, &url, &body)?;
+    let recorder = DiagnosticRecorder::start(configuration, &method, &url, Some(&body));
     let mut builder = client
         .request(method, url)
-        .json(&request_body)
-        .query(&query_parameters);
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body);
 
     builder = add_auth(builder, configuration);
+    builder = add_timeout(builder, configuration);
     builder = add_user_agent(builder);
+    builder = add_extra_headers(builder, configuration);
 
-    Ok(builder.send().await?)
+    send_tracked(builder.headers(signed_headers), guard, recorder).await
 }
 
+// Sends a multipart body, built by reqwest, or encoded in full when a request signer needs its
+// bytes.
+#[cfg(feature = "email")]
 async fn send_multipart_request(
     client: &reqwest::Client,
     configuration: &Configuration,
-    form: reqwest::multipart::Form,
+    fields: Vec<MultipartField>,
     method: reqwest::Method,
     path: &str,
-) -> Result<Response, SdkError> {
-    let url = format!("{}{}", configuration.base_url(), path);
-    let mut builder = client.request(method, url);
+) -> Result<Tracked<Response>, SdkError> {
+    let guard = configuration.request_tracker().start()?;
+    let url = build_url(configuration, path, "");
+    let recorder = DiagnosticRecorder::start(configuration, &method, &url, None);
+    let mut builder = client.request(method.clone(), url.clone());
 
     builder = add_auth(builder, configuration);
+    builder = add_timeout(builder, configuration);
     builder = add_user_agent(builder);
+    builder = add_extra_headers(builder, configuration);
 
-    Ok(builder.multipart(form).send().await?)
+    if configuration.request_signer().is_none() {
+        return send_tracked(
+            builder.multipart(build_multipart_form(fields)),
+            guard,
+            recorder,
+        )
+        .await;
+    }
+
+    let boundary = multipart_boundary();
+    let body = encode_multipart(fields, &boundary).await?;
+    let signed_headers = sign_request(configuration, &method, &url, &body)?;
+
+    send_tracked(
+        builder
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", boundary),
+            )
+            .body(body)
+            .headers(signed_headers),
+        guard,
+        recorder,
+    )
+    .await
 }
 
-fn send_blocking_valid_json_request<T: Validate + serde::Serialize>(
+#[cfg(feature = "sms")]
+fn send_blocking_valid_json_request<T: ApiRequest + Validate>(
     client: &reqwest::blocking::Client,
     configuration: &Configuration,
     request_body: T,
     method: reqwest::Method,
     path: &str,
-) -> Result<reqwest::blocking::Response, SdkError> {
+) -> Result<Tracked<reqwest::blocking::Response>, SdkError> {
     request_body.validate()?;
+    let guard = configuration.request_tracker().start()?;
 
-    let url = format!("{}{}", configuration.base_url(), path);
+    let url = build_url(configuration, path, "");
+    let body = serde_json::to_vec(&request_body)?;
+    let signed_headers = sign_request(configuration, &method, &url, &body)?;
+    let mut recorder = DiagnosticRecorder::start(configuration, &method, &url, Some(&body));
     let mut builder = client.request(method, url);
 
     builder = add_auth_blocking(builder, configuration);
+    builder = add_timeout_blocking(builder, configuration);
     builder = add_user_agent_blocking(builder);
+    builder = add_extra_headers_blocking(builder, configuration);
 
-    Ok(builder.json(&request_body).send()?)
+    let sent = builder
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .headers(signed_headers)
+        .send();
+    match sent {
+        Ok(response) => {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.received(response.status(), response.headers());
+            }
+            Ok(Tracked::new(response, guard, recorder))
+        }
+        Err(error) => {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.failed(&error);
+            }
+            Err(error.into())
+        }
+    }
 }
 
 mod tests;
@@ -0,0 +1,277 @@
+//! Module with client and endpoint functions for the Numbers API, used to manage the numbers
+//! purchased in the account and their inbound SMS configuration.
+
+use validator::Validate;
+
+use crate::api::{
+    build_api_error, build_http_client, build_optional_response, connection_info, endpoints,
+    fill_path, parse_response, read_response_text, send_no_body_request, send_valid_json_request,
+    RequestOptions, SdkError, SdkResponse,
+};
+use crate::configuration::Configuration;
+use crate::model::numbers::{
+    GetNumberResponseBody, GetNumbersQueryParameters, GetNumbersResponseBody,
+    UpdateNumberSmsConfigurationRequestBody, UpdateNumberSmsConfigurationResponseBody,
+};
+use crate::model::ToQueryString;
+
+pub const PATH_GET_NUMBER: &str = endpoints::numbers::get_number();
+pub const PATH_GET_NUMBERS: &str = endpoints::numbers::get_numbers();
+pub const PATH_UPDATE_NUMBER_SMS_CONFIGURATION: &str =
+    endpoints::numbers::update_number_sms_configuration();
+
+/// Main asynchronous client for the Infobip Numbers API.
+#[derive(Clone, Debug)]
+pub struct NumbersClient {
+    pub configuration: Configuration,
+    pub http_client: reqwest::Client,
+}
+
+impl NumbersClient {
+    /// Builds and returns a new asynchronous `NumbersClient` with a specified configuration.
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        NumbersClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new asynchronous `NumbersClient` sending requests through `http_client`,
+    /// e.g. to share its connection pool with other clients.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
+        NumbersClient {
+            configuration,
+            http_client,
+        }
+    }
+
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        NumbersClient {
+            configuration: options.apply(&self.configuration),
+            ..self.clone()
+        }
+    }
+
+    /// Get the numbers purchased in the account, one page at a time.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::numbers::NumbersClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::numbers::GetNumbersQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = NumbersClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_parameters = GetNumbersQueryParameters::new();
+    /// query_parameters.limit = Some(10);
+    ///
+    /// let response = client.get_numbers(query_parameters).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_numbers(
+        &self,
+        query_parameters: GetNumbersQueryParameters,
+    ) -> Result<SdkResponse<GetNumbersResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_NUMBERS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Get the details of a purchased number, like its country, type and capabilities.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::numbers::NumbersClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = NumbersClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .get_number("8F0792F86035A9F4290821F1EE6BC06A")
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_number(
+        &self,
+        number_key: &str,
+    ) -> Result<SdkResponse<GetNumberResponseBody>, SdkError> {
+        let path = fill_path(PATH_GET_NUMBER, &[("numberKey", number_key)]);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Same as `get_number`, but returns `Ok(None)` instead of an error when the API answers
+    /// with a 404 because the number is not owned by the account. Other failures are errors as usual.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::numbers::NumbersClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = NumbersClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .get_number_opt("8F0792F86035A9F4290821F1EE6BC06A")
+    ///     .await?;
+    ///
+    /// match response {
+    ///     Some(response) => println!("{:?}", response.body.number),
+    ///     None => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_number_opt(
+        &self,
+        number_key: &str,
+    ) -> Result<Option<SdkResponse<GetNumberResponseBody>>, SdkError> {
+        let path = fill_path(PATH_GET_NUMBER, &[("numberKey", number_key)]);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_optional_response(status, &text, &self.configuration, connection)
+    }
+
+    /// Update how inbound SMS messages received on a purchased number are handled, like the
+    /// keyword they are matched by and the URL they are forwarded to.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::numbers::NumbersClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::numbers::{NumberSmsConfiguration, SmsForwarding};
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = NumbersClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = NumberSmsConfiguration::new();
+    /// request_body.keyword = Some("PROMO".to_string());
+    /// request_body.forwarding = Some(SmsForwarding::http_post("https://example.com/inbound"));
+    ///
+    /// let response = client
+    ///     .update_number_sms_configuration("8F0792F86035A9F4290821F1EE6BC06A", request_body)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_number_sms_configuration(
+        &self,
+        number_key: &str,
+        request_body: UpdateNumberSmsConfigurationRequestBody,
+    ) -> Result<SdkResponse<UpdateNumberSmsConfigurationResponseBody>, SdkError> {
+        let path = fill_path(
+            PATH_UPDATE_NUMBER_SMS_CONFIGURATION,
+            &[("numberKey", number_key)],
+        );
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            "",
+            reqwest::Method::PUT,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+}
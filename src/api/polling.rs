@@ -0,0 +1,100 @@
+//! Polling of the status of a sent message until it is delivered or has failed, shared by all
+//! channels.
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::api::SdkError;
+use crate::model::DeliveryStatus;
+
+/// Maximum fraction of the interval added as jitter between polls, so many pollers started at
+/// the same time spread their requests.
+pub const POLL_JITTER: f64 = 0.2;
+
+/// Future returned by `StatusSource::fetch_status`.
+pub type StatusFuture<'a, S> =
+    Pin<Box<dyn Future<Output = Result<Option<S>, SdkError>> + Send + 'a>>;
+
+/// Clients that can look up the current status of a sent message.
+pub trait StatusSource {
+    /// Type of the status of the channel.
+    type Status: DeliveryStatus;
+
+    /// Fetches the current status of a message, or `None` if it is not known yet.
+    fn fetch_status<'a>(&'a self, message_id: &'a str) -> StatusFuture<'a, Self::Status>;
+}
+
+/// Result of `poll_until_terminal`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PollOutcome<S> {
+    /// The message reached a terminal status.
+    Terminal(S),
+
+    /// The timeout elapsed first. Holds the last observed status, if any.
+    TimedOut(Option<S>),
+}
+
+/// Fetches the status of a message every `interval`, plus jitter, until it is delivered or has
+/// failed, or until `timeout` elapses.
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use infobip_sdk::api::polling::{poll_until_terminal, PollOutcome};
+/// # use infobip_sdk::api::sms::SmsClient;
+/// # use infobip_sdk::configuration::Configuration;
+/// # use infobip_sdk::model::DeliveryStatus;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+///
+/// let outcome = poll_until_terminal(
+///     &sms_client,
+///     "some-message-id",
+///     Duration::from_secs(5),
+///     Duration::from_secs(120),
+/// )
+/// .await?;
+///
+/// if let PollOutcome::Terminal(status) = outcome {
+///     println!("delivered: {}", status.is_delivered());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn poll_until_terminal<S: StatusSource>(
+    source: &S,
+    message_id: &str,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<PollOutcome<S::Status>, SdkError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut last_status = None;
+
+    loop {
+        if let Some(status) = source.fetch_status(message_id).await? {
+            if status.is_terminal() {
+                return Ok(PollOutcome::Terminal(status));
+            }
+            last_status = Some(status);
+        }
+
+        let now = tokio::time::Instant::now();
+        if now >= deadline {
+            return Ok(PollOutcome::TimedOut(last_status));
+        }
+        tokio::time::sleep(jittered(interval).min(deadline - now)).await;
+    }
+}
+
+// Adds a random jitter of up to `POLL_JITTER` of the interval.
+pub(crate) fn jittered(interval: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    let fraction = (random % 1000) as f64 / 1000.0 * POLL_JITTER;
+
+    interval + interval.mul_f64(fraction)
+}
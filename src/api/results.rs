@@ -0,0 +1,109 @@
+//! Incremental parsing of the `results` array of a JSON response body, so the items of large
+//! report and log responses can be handled one at a time while the body is being received.
+
+use serde::de::DeserializeOwned;
+
+use crate::api::{RawBody, SdkError};
+
+/// Splits a JSON object, fed in chunks of any size, into the items of its top level `results`
+/// array. Only the bytes of the item being received are kept; everything outside of the array is
+/// skipped.
+#[derive(Debug, Default)]
+pub(crate) struct ResultsScanner {
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    key: Vec<u8>,
+    last_string: Vec<u8>,
+    expecting_results: bool,
+    in_results: bool,
+    item: Vec<u8>,
+}
+
+impl ResultsScanner {
+    pub(crate) fn new() -> Self {
+        ResultsScanner::default()
+    }
+
+    /// Feeds the next chunk of the body, calling `on_item` with every item completed by it.
+    pub(crate) fn push<T, F>(&mut self, chunk: &[u8], on_item: &mut F) -> Result<(), SdkError>
+    where
+        T: DeserializeOwned,
+        F: FnMut(T),
+    {
+        for &byte in chunk {
+            let in_item = self.in_results && self.depth > 2;
+
+            if self.in_string {
+                if in_item {
+                    self.item.push(byte);
+                } else if self.depth == 1 && byte != b'"' {
+                    self.key.push(byte);
+                }
+
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                    if self.depth == 1 {
+                        self.last_string = std::mem::take(&mut self.key);
+                    }
+                }
+                continue;
+            }
+
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+                    if in_item {
+                        self.item.push(byte);
+                    }
+                }
+                b'[' if self.depth == 1 && self.expecting_results => {
+                    self.in_results = true;
+                    self.expecting_results = false;
+                    self.depth += 1;
+                }
+                b'{' | b'[' => {
+                    self.depth += 1;
+                    if self.in_results && self.depth > 2 {
+                        self.item.push(byte);
+                    }
+                }
+                b'}' | b']' => {
+                    if in_item {
+                        self.item.push(byte);
+                    }
+                    self.depth = self.depth.saturating_sub(1);
+                    if in_item && self.depth == 2 {
+                        on_item(parse_item(&self.item)?);
+                        self.item.clear();
+                    } else if self.in_results && self.depth == 1 {
+                        self.in_results = false;
+                    }
+                }
+                b':' if self.depth == 1 => {
+                    self.expecting_results = self.last_string == b"results";
+                }
+                b',' if self.depth == 1 => {
+                    self.expecting_results = false;
+                }
+                _ => {
+                    if in_item {
+                        self.item.push(byte);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_item<T: DeserializeOwned>(item: &[u8]) -> Result<T, SdkError> {
+    serde_json::from_slice(item).map_err(|error| {
+        SdkError::Deserialization(error, RawBody::capture(&String::from_utf8_lossy(item)))
+    })
+}
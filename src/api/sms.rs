@@ -1,12 +1,22 @@
 //! Module with client and endpoint functions for the SMS channel.
 
-use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use validator::Validate;
 
+use tokio::io::AsyncWrite;
+
+use crate::api::diagnostics::{self, DiagnosticEntry};
+use crate::api::export::{export_pages, ExportFormat, ExportProgress, EXPORT_PAGE_SIZE};
+use crate::api::polling::{StatusFuture, StatusSource};
 use crate::api::{
-    build_api_error, send_blocking_valid_json_request, send_no_body_request,
-    send_valid_json_request, ApiError, SdkError, SdkResponse,
+    build_api_error, build_blocking_http_client, build_http_client, build_optional_response,
+    build_response_or_default, connection_info, connection_info_blocking, endpoints, fill_path,
+    parse_response, prepare_json_request, read_response_text, read_response_text_blocking,
+    record_sent_ids, send_blocking_valid_json_request, send_no_body_request,
+    send_valid_json_request, stream_results, PreparedRequest, PullReports, PullReportsFuture,
+    ReportsCursor, RequestOptions, SdkError, SdkResponse, SentIds,
 };
 use crate::model::sms::{
     CreateTfaApplicationRequestBody, CreateTfaApplicationResponseBody,
@@ -17,13 +27,14 @@ use crate::model::sms::{
     GetScheduledStatusQueryParameters, GetScheduledStatusResponseBody,
     GetTfaApplicationResponseBody, GetTfaApplicationsResponseBody,
     GetTfaMessageTemplateResponseBody, GetTfaMessageTemplatesResponseBody,
-    GetTfaVerificationStatusQueryParameters, GetTfaVerificationStatusResponseBody,
+    GetTfaVerificationStatusQueryParameters, GetTfaVerificationStatusResponseBody, Log, Report,
     RescheduleQueryParameters, RescheduleRequestBody, RescheduleResponseBody,
     ResendPinOverSmsRequestBody, ResendPinOverSmsResponseBody, ResendPinOverVoiceRequestBody,
     ResendPinOverVoiceResponseBody, SendBinaryRequestBody, SendBinaryResponseBody,
     SendOverQueryParametersQueryParameters, SendOverQueryParametersResponseBody,
     SendPinOverSmsQueryParameters, SendPinOverSmsRequestBody, SendPinOverSmsResponseBody,
     SendPinOverVoiceRequestBody, SendPinOverVoiceResponseBody, SendRequestBody, SendResponseBody,
+    SendSingleRequestBody, SendSingleResponseBody, SentMessageDetails, Status,
     UpdateScheduledStatusQueryParameters, UpdateScheduledStatusRequestBody,
     UpdateScheduledStatusResponseBody, UpdateTfaApplicationRequestBody,
     UpdateTfaApplicationResponseBody, UpdateTfaMessageTemplateRequestBody,
@@ -33,50 +44,165 @@ use crate::model::sms::{
 use crate::{
     configuration::Configuration,
     model::sms::{PreviewRequestBody, PreviewResponseBody},
+    model::ToQueryString,
 };
 
-pub const PATH_GET_DELIVERY_REPORTS: &str = "/sms/1/reports";
-pub const PATH_GET_INBOUND: &str = "/sms/1/inbox/reports";
-pub const PATH_GET_LOGS: &str = "/sms/1/logs";
-pub const PATH_GET_SCHEDULED: &str = "/sms/1/bulks";
-pub const PATH_GET_SCHEDULED_STATUS: &str = "/sms/1/bulks/status";
-pub const PATH_PREVIEW: &str = "/sms/1/preview";
-pub const PATH_RESCHEDULE: &str = "/sms/1/bulks";
-pub const PATH_SEND: &str = "/sms/2/text/advanced";
-pub const PATH_SEND_BINARY: &str = "/sms/2/binary/advanced";
-pub const PATH_SEND_OVER_QUERY_PARAMS: &str = "/sms/1/text/query";
-pub const PATH_UPDATE_SCHEDULED_STATUS: &str = "/sms/1/bulks/status";
-pub const PATH_GET_TFA_APPLICATIONS: &str = "/2fa/2/applications";
-pub const PATH_CREATE_TFA_APPLICATION: &str = "/2fa/2/applications";
-pub const PATH_GET_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
-pub const PATH_UPDATE_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
-pub const PATH_GET_TFA_MESSAGE_TEMPLATES: &str = "/2fa/2/applications/{appId}/messages";
-pub const PATH_CREATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages";
-pub const PATH_GET_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
-pub const PATH_UPDATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
-pub const PATH_SEND_PIN_OVER_SMS: &str = "/2fa/2/pin";
-pub const PATH_RESEND_PIN_OVER_SMS: &str = "/2fa/2/pin/{pinId}/resend";
-pub const PATH_SEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/voice";
-pub const PATH_RESEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/{pinId}/resend/voice";
-pub const PATH_VERIFY_PHONE_NUMBER: &str = "/2fa/2/pin/{pinId}/verify";
-pub const PATH_GET_TFA_VERIFICATION_STATUS: &str = "/2fa/2/applications/{appId}/verifications";
+pub const PATH_GET_DELIVERY_REPORTS: &str = endpoints::sms::get_delivery_reports();
+pub const PATH_GET_INBOUND: &str = endpoints::sms::get_inbound();
+pub const PATH_GET_LOGS: &str = endpoints::sms::get_logs();
+pub const PATH_GET_SCHEDULED: &str = endpoints::sms::get_scheduled();
+pub const PATH_GET_SCHEDULED_STATUS: &str = endpoints::sms::get_scheduled_status();
+pub const PATH_PREVIEW: &str = endpoints::sms::preview();
+pub const PATH_RESCHEDULE: &str = endpoints::sms::reschedule();
+pub const PATH_SEND: &str = endpoints::sms::send_advanced();
+pub const PATH_SEND_BINARY: &str = endpoints::sms::send_binary_advanced();
+pub const PATH_SEND_OVER_QUERY_PARAMS: &str = endpoints::sms::send_over_query_parameters();
+pub const PATH_SEND_SINGLE: &str = endpoints::sms::send_text_single();
+pub const PATH_UPDATE_SCHEDULED_STATUS: &str = endpoints::sms::update_scheduled_status();
+pub const PATH_GET_TFA_APPLICATIONS: &str = endpoints::tfa::get_applications();
+pub const PATH_CREATE_TFA_APPLICATION: &str = endpoints::tfa::create_application();
+pub const PATH_GET_TFA_APPLICATION: &str = endpoints::tfa::get_application();
+pub const PATH_UPDATE_TFA_APPLICATION: &str = endpoints::tfa::update_application();
+pub const PATH_GET_TFA_MESSAGE_TEMPLATES: &str = endpoints::tfa::get_message_templates();
+pub const PATH_CREATE_TFA_MESSAGE_TEMPLATE: &str = endpoints::tfa::create_message_template();
+pub const PATH_GET_TFA_MESSAGE_TEMPLATE: &str = endpoints::tfa::get_message_template();
+pub const PATH_UPDATE_TFA_MESSAGE_TEMPLATE: &str = endpoints::tfa::update_message_template();
+pub const PATH_SEND_PIN_OVER_SMS: &str = endpoints::tfa::send_pin_over_sms();
+pub const PATH_RESEND_PIN_OVER_SMS: &str = endpoints::tfa::resend_pin_over_sms();
+pub const PATH_SEND_PIN_OVER_VOICE: &str = endpoints::tfa::send_pin_over_voice();
+pub const PATH_RESEND_PIN_OVER_VOICE: &str = endpoints::tfa::resend_pin_over_voice();
+pub const PATH_VERIFY_PHONE_NUMBER: &str = endpoints::tfa::verify_phone_number();
+pub const PATH_GET_TFA_VERIFICATION_STATUS: &str = endpoints::tfa::get_verification_status();
+
+/// Outcome of one of the requests sent by `SmsClient::send_chunked`.
+#[derive(Debug)]
+pub struct ChunkOutcome {
+    /// Position of the chunk, starting at 0.
+    pub index: usize,
+
+    /// Request body of the chunk, e.g. to send it again if it failed.
+    pub request_body: SendRequestBody,
+
+    /// Response or error of the chunk.
+    pub result: Result<SdkResponse<SendResponseBody>, SdkError>,
+}
+
+/// Result of `SmsClient::send_chunked`, with the outcome of every chunk in sending order.
+#[derive(Debug)]
+pub struct ChunkedSendResult {
+    /// Bulk ID shared by all chunks, used to correlate their reports.
+    pub bulk_id: String,
+
+    /// Outcomes of the chunks.
+    pub chunks: Vec<ChunkOutcome>,
+}
+
+impl ChunkedSendResult {
+    /// Returns whether every chunk was sent successfully.
+    pub fn is_success(&self) -> bool {
+        self.chunks.iter().all(|chunk| chunk.result.is_ok())
+    }
+
+    /// Returns the outcomes of the chunks that failed.
+    pub fn failed_chunks(&self) -> impl Iterator<Item = &ChunkOutcome> {
+        self.chunks.iter().filter(|chunk| chunk.result.is_err())
+    }
+
+    /// Returns the details of the messages of all successful chunks.
+    pub fn sent_messages(&self) -> impl Iterator<Item = &SentMessageDetails> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| chunk.result.as_ref().ok())
+            .flat_map(|response| response.body.messages.iter().flatten())
+    }
+}
+
+// Sets the default SMS sender of the configuration, if any, when `from` is not set.
+fn apply_default_sender(configuration: &Configuration, from: &mut Option<String>) {
+    if from.is_none() {
+        *from = configuration.default_sms_sender().map(str::to_string);
+    }
+}
+
+// Builds a bulk ID for chunked sends without one, unique within the process.
+fn generate_bulk_id() -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    format!(
+        "sdk-{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
 
 /// Main asynchronous client for the Infobip SMS channel.
 #[derive(Clone, Debug)]
 pub struct SmsClient {
     pub configuration: Configuration,
     pub http_client: reqwest::Client,
+    sent_ids: Arc<Mutex<SentIds>>,
 }
 
 impl SmsClient {
-    /// Builds and returns a new asynchronous `SmsClient` with specified configuration.
+    /// Builds and returns a new asynchronous `SmsClient` with a specified configuration.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        SmsClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new asynchronous `SmsClient` sending requests through `http_client`,
+    /// e.g. to share its connection pool with other clients.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
         SmsClient {
             configuration,
-            http_client: reqwest::Client::new(),
+            http_client,
+            sent_ids: Arc::new(Mutex::new(SentIds::default())),
         }
     }
 
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        SmsClient {
+            configuration: options.apply(&self.configuration),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the last requests sent by the clients sharing the configuration of this client,
+    /// oldest first, with their responses. Empty unless `Configuration::with_diagnostics` is
+    /// enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client =
+    ///     SmsClient::with_configuration(Configuration::from_env_api_key()?.with_diagnostics(50));
+    ///
+    /// let message = Message::new(vec![Destination::new("123456789012")]);
+    /// let _ = client.send(SendRequestBody::new(vec![message])).await;
+    ///
+    /// for entry in client.diagnostics() {
+    ///     println!("{} {} {:?} {:?}", entry.method, entry.endpoint, entry.status, entry.latency);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diagnostics(&self) -> Vec<DiagnosticEntry> {
+        diagnostics::entries(&self.configuration)
+    }
+
+    /// Forgets the requests recorded by the diagnostics of the configuration of this client.
+    pub fn clear_diagnostics(&self) {
+        diagnostics::clear(&self.configuration)
+    }
+
     /// Check how different message configurations will affect your message text, number of
     /// characters, and message parts.
     ///
@@ -109,22 +235,28 @@ impl SmsClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_PREVIEW,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -156,49 +288,138 @@ impl SmsClient {
     /// ```
     pub async fn get_delivery_reports(
         &self,
-        query_parameters: GetDeliveryReportsQueryParameters,
+        mut query_parameters: GetDeliveryReportsQueryParameters,
     ) -> Result<SdkResponse<GetDeliveryReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
+        query_parameters
+            .limit
+            .get_or_insert(GetDeliveryReportsQueryParameters::DEFAULT_LIMIT);
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_DELIVERY_REPORTS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Like `get_delivery_reports`, but calls `on_report` with each report as soon as it is
+    /// received, instead of collecting the whole response body, so memory use stays proportional
+    /// to a single report on large pulls. The maximum response body size of the configuration
+    /// does not apply.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::GetDeliveryReportsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut count = 0;
+    /// sms_client
+    ///     .for_each_delivery_report(GetDeliveryReportsQueryParameters::new(), |_report| {
+    ///         count += 1
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_each_delivery_report<F>(
+        &self,
+        mut query_parameters: GetDeliveryReportsQueryParameters,
+        on_report: F,
+    ) -> Result<reqwest::StatusCode, SdkError>
+    where
+        F: FnMut(Report),
+    {
+        query_parameters.validate()?;
+        query_parameters
+            .limit
+            .get_or_insert(GetDeliveryReportsQueryParameters::DEFAULT_LIMIT);
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_DELIVERY_REPORTS,
+        )
+        .await?;
+
+        stream_results(response, &self.configuration, on_report).await
+    }
+
+    /// Get the delivery report of a single message, or `None` if Infobip has not produced one
+    /// yet.
+    ///
+    /// Delivery reports are returned only once: a report fetched here is consumed and will not
+    /// be returned again by this method or by `get_delivery_reports`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// if let Some(report) = sms_client.get_report_for_message("some-message-id").await? {
+    ///     println!("{:?}", report.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_report_for_message(
+        &self,
+        message_id: &str,
+    ) -> Result<Option<Report>, SdkError> {
+        let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+        query_parameters.message_id = Some(message_id.into());
+        query_parameters.limit = Some(1);
+
+        let response = self.get_delivery_reports(query_parameters).await?;
+
+        Ok(response
+            .body
+            .results
+            .and_then(|results| results.into_iter().next()))
+    }
+
     /// Send a single, or multiple SMS messages to one or many destinations.
     ///
     /// Everything from sending a simple single message to a single destination, up to batch
     /// sending of personalized messages to the thousands of recipients with a single API request.
     /// Language, transliteration, scheduling and every advanced feature you can think of is
-    /// supported.
+    /// supported. Messages without `from` are sent from `Configuration::default_sms_sender`, if
+    /// set.
     ///
     /// # Example
     /// ```no_run
@@ -225,31 +446,244 @@ impl SmsClient {
     /// ```
     pub async fn send(
         &self,
-        request_body: SendRequestBody,
+        mut request_body: SendRequestBody,
     ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        for message in &mut request_body.messages {
+            apply_default_sender(&self.configuration, &mut message.from);
+        }
+
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_SEND,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Send a request of any size by splitting it into requests of at most `chunk_size`
+    /// destinations each, sent one after the other. All chunks share the bulk ID of the request,
+    /// or a generated one if it has none, so their reports can be correlated. A failed chunk does
+    /// not stop the following ones; the outcome of every chunk is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let destinations = (0..2500)
+    ///     .map(|index| Destination::new(&format!("4179302{:04}", index)))
+    ///     .collect();
+    /// let mut message = Message::new(destinations);
+    /// message.text = Some("Your package has been delivered.".to_string());
+    ///
+    /// let result = sms_client
+    ///     .send_chunked(SendRequestBody::new(vec![message]), 1000)
+    ///     .await;
+    ///
+    /// assert!(result.is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_chunked(
+        &self,
+        mut request_body: SendRequestBody,
+        chunk_size: usize,
+    ) -> ChunkedSendResult {
+        let bulk_id = request_body
+            .bulk_id
+            .get_or_insert_with(generate_bulk_id)
+            .clone();
+
+        let mut chunks = Vec::new();
+        for (index, chunk) in request_body
+            .split_into_chunks(chunk_size)
+            .into_iter()
+            .enumerate()
+        {
+            let result = self.send(chunk.clone()).await;
+            chunks.push(ChunkOutcome {
+                index,
+                request_body: chunk,
+                result,
+            });
+        }
+
+        ChunkedSendResult { bulk_id, chunks }
+    }
+
+    /// Same as `send`, but refuses to send a request whose bulk ID and destination message IDs
+    /// were already sent by this client, failing with `SdkError::DuplicateSend` before any
+    /// network call. IDs are remembered once the request passes validation, just before it is
+    /// sent, so a request rejected locally can be fixed and sent again, but a retry of a request
+    /// that failed over the network is rejected. Requires enabling the guard with
+    /// `Configuration::with_send_once`, otherwise it behaves like `send`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client =
+    ///     SmsClient::with_configuration(Configuration::from_env_api_key()?.with_send_once(1000));
+    ///
+    /// let mut destination = Destination::new("555555555555");
+    /// destination.message_id = Some("order-1234-confirmation".to_string());
+    /// let mut message = Message::new(vec![destination]);
+    /// message.text = Some("Your order was confirmed.".to_string());
+    ///
+    /// let response = sms_client.send_once(SendRequestBody::new(vec![message])).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_once(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        for message in &mut request_body.messages {
+            apply_default_sender(&self.configuration, &mut message.from);
+        }
+        request_body.validate()?;
+
+        let ids = request_body
+            .messages
+            .iter()
+            .flat_map(|message| message.destinations.iter().flatten())
+            .map(|destination| (request_body.bulk_id.clone(), destination.message_id.clone()))
+            .collect();
+        record_sent_ids(&self.configuration, &self.sent_ids, ids)?;
+
+        self.send(request_body).await
+    }
+
+    /// Send a single text message to one or more destinations, without building an advanced
+    /// request. The response holds one message per destination.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::SendSingleRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body =
+    ///     SendSingleRequestBody::new(vec!["41793026727".to_string()], "Hello Rustacean!");
+    /// request_body.from = Some("Infobip".to_string());
+    ///
+    /// let response = sms_client.send_single(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_single(
+        &self,
+        mut request_body: SendSingleRequestBody,
+    ) -> Result<SdkResponse<SendSingleResponseBody>, SdkError> {
+        apply_default_sender(&self.configuration, &mut request_body.from);
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            "",
+            reqwest::Method::POST,
+            PATH_SEND_SINGLE,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Build the request that `send` would perform, without sending it, including the default
+    /// sender of the configuration. Credentials in the returned headers are redacted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut message = Message::new(vec![Destination::new("555555555555")]);
+    /// message.text = Some("Hello Rustacean!".to_string());
+    ///
+    /// let prepared = sms_client.prepare_send(SendRequestBody::new(vec![message]))?;
+    ///
+    /// println!("{} {}", prepared.method, prepared.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_send(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<PreparedRequest, SdkError> {
+        for message in &mut request_body.messages {
+            apply_default_sender(&self.configuration, &mut message.from);
+        }
+
+        prepare_json_request(
+            &self.configuration,
+            &request_body,
+            "",
+            reqwest::Method::POST,
+            PATH_SEND,
+        )
+    }
+
     /// Send single or multiple binary messages to one or more destination addresses.
     ///
     /// # Example
@@ -276,28 +710,38 @@ impl SmsClient {
     /// ```
     pub async fn send_binary(
         &self,
-        request_body: SendBinaryRequestBody,
+        mut request_body: SendBinaryRequestBody,
     ) -> Result<SdkResponse<SendBinaryResponseBody>, SdkError> {
+        for message in request_body.messages.iter_mut().flatten() {
+            apply_default_sender(&self.configuration, &mut message.from);
+        }
+
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_SEND_BINARY,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -329,28 +773,33 @@ impl SmsClient {
     ) -> Result<SdkResponse<GetScheduledResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -383,60 +832,149 @@ impl SmsClient {
     ) -> Result<SdkResponse<GetLogsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(to) = query_parameters.to {
-            parameters_map.insert("to".to_string(), to);
-        }
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(general_status) = query_parameters.general_status {
-            parameters_map.insert("generalStatus".to_string(), general_status);
-        }
-        if let Some(sent_since) = query_parameters.sent_since {
-            parameters_map.insert("sentSince".to_string(), sent_since);
-        }
-        if let Some(sent_until) = query_parameters.sent_until {
-            parameters_map.insert("sentUntil".to_string(), sent_until);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-        if let Some(mcc) = query_parameters.mcc {
-            parameters_map.insert("mcc".to_string(), mcc);
-        }
-        if let Some(mnc) = query_parameters.mnc {
-            parameters_map.insert("mnc".to_string(), mnc);
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_LOGS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Like `get_logs`, but calls `on_log` with each log as soon as it is received, instead
+    /// of collecting the whole response body, so memory use stays proportional to a single log
+    /// on large pulls. The maximum response body size of the configuration does not apply.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::GetLogsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut count = 0;
+    /// sms_client
+    ///     .for_each_log(GetLogsQueryParameters::new(), |_log| count += 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_each_log<F>(
+        &self,
+        query_parameters: GetLogsQueryParameters,
+        on_log: F,
+    ) -> Result<reqwest::StatusCode, SdkError>
+    where
+        F: FnMut(Log),
+    {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_LOGS,
+        )
+        .await?;
+
+        stream_results(response, &self.configuration, on_log).await
+    }
+
+    /// Export the logs matching the query parameters into a writer, as newline-delimited JSON or
+    /// CSV. Logs are fetched one page of `limit` logs at a time, walking back from `sent_until`,
+    /// and the writer is flushed after each page. The progress callback is called after each
+    /// page; to resume an interrupted export, set `sent_until` to the `last_sent_at` of the last
+    /// reported progress.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::export::ExportFormat;
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::GetLogsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let file = tokio::fs::File::create("logs.csv").await?;
+    ///
+    /// let progress = client
+    ///     .export_logs(GetLogsQueryParameters::new(), file, ExportFormat::Csv, |progress| {
+    ///         println!("{} rows written", progress.rows);
+    ///     })
+    ///     .await?;
+    ///
+    /// println!("exported {} rows", progress.rows);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_logs<W, P>(
+        &self,
+        query_parameters: GetLogsQueryParameters,
+        writer: W,
+        format: ExportFormat,
+        progress: P,
+    ) -> Result<ExportProgress, SdkError>
+    where
+        W: AsyncWrite + Unpin,
+        P: FnMut(&ExportProgress),
+    {
+        query_parameters.validate()?;
+
+        let limit = query_parameters.limit.unwrap_or(EXPORT_PAGE_SIZE);
+        let sent_until = query_parameters.sent_until.clone();
+
+        export_pages(
+            writer,
+            format,
+            limit,
+            sent_until,
+            |sent_until| {
+                let mut query_parameters = query_parameters.clone();
+                query_parameters.sent_until = sent_until;
+                query_parameters.limit = Some(limit);
+
+                async move {
+                    Ok(self
+                        .get_logs(query_parameters)
+                        .await?
+                        .body
+                        .results
+                        .unwrap_or_default())
+                }
+            },
+            progress,
+        )
+        .await
+    }
+
     /// If for some reason you are unable to receive incoming SMS to the endpoint of your choice
     /// in real time, you can use this API call to fetch messages. Each request will return a
     /// batch of received messages - only once. The API request will only return new messages
@@ -467,30 +1005,33 @@ impl SmsClient {
     ) -> Result<SdkResponse<GetInboundReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_INBOUND,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -523,97 +1064,38 @@ impl SmsClient {
     /// ```
     pub async fn send_over_query_parameters(
         &self,
-        query_parameters: SendOverQueryParametersQueryParameters,
+        mut query_parameters: SendOverQueryParametersQueryParameters,
     ) -> Result<SdkResponse<SendOverQueryParametersResponseBody>, SdkError> {
+        apply_default_sender(&self.configuration, &mut query_parameters.from);
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        parameters_map.insert("username".to_string(), query_parameters.username);
-        parameters_map.insert("password".to_string(), query_parameters.password);
-        parameters_map.insert("to".to_string(), query_parameters.to.join(","));
-
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(text) = query_parameters.text {
-            parameters_map.insert("text".to_string(), text);
-        }
-        if let Some(flash) = query_parameters.flash {
-            parameters_map.insert("flash".to_string(), flash.to_string());
-        }
-        if let Some(transliteration) = query_parameters.transliteration {
-            parameters_map.insert("transliteration".to_string(), transliteration);
-        }
-        if let Some(language_code) = query_parameters.language_code {
-            parameters_map.insert("languageCode".to_string(), language_code);
-        }
-        if let Some(intermediate_report) = query_parameters.intermediate_report {
-            parameters_map.insert(
-                "intermediateReport".to_string(),
-                intermediate_report.to_string(),
-            );
-        }
-        if let Some(notify_url) = query_parameters.notify_url {
-            parameters_map.insert("notifyUrl".to_string(), notify_url);
-        }
-        if let Some(notify_content_type) = query_parameters.notify_content_type {
-            parameters_map.insert("notifyContentType".to_string(), notify_content_type);
-        }
-        if let Some(callback_data) = query_parameters.callback_data {
-            parameters_map.insert("callbackData".to_string(), callback_data);
-        }
-        if let Some(validity_period) = query_parameters.validity_period {
-            parameters_map.insert("validityPeriod".to_string(), validity_period.to_string());
-        }
-        if let Some(send_at) = query_parameters.send_at {
-            parameters_map.insert("sendAt".to_string(), send_at);
-        }
-        if let Some(track) = query_parameters.track {
-            parameters_map.insert("track".to_string(), track);
-        }
-        if let Some(process_key) = query_parameters.process_key {
-            parameters_map.insert("processKey".to_string(), process_key);
-        }
-        if let Some(tracking_type) = query_parameters.tracking_type {
-            parameters_map.insert("trackingType".to_string(), tracking_type);
-        }
-        if let Some(india_dlt_content_template_id) = query_parameters.india_dlt_content_template_id
-        {
-            parameters_map.insert(
-                "indiaDltContentTemplateId".to_string(),
-                india_dlt_content_template_id,
-            );
-        }
-        if let Some(india_dlt_principal_entity_id) = query_parameters.india_dlt_principal_entity_id
-        {
-            parameters_map.insert(
-                "indiaDltPrincipalEntityId".to_string(),
-                india_dlt_principal_entity_id,
-            );
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_SEND_OVER_QUERY_PARAMS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -647,29 +1129,34 @@ impl SmsClient {
     ) -> Result<SdkResponse<RescheduleResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            &query_string,
             reqwest::Method::PUT,
             PATH_RESCHEDULE,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -700,28 +1187,33 @@ impl SmsClient {
     ) -> Result<SdkResponse<GetScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            &query_string,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED_STATUS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -755,30 +1247,23 @@ impl SmsClient {
     ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            &query_string,
             reqwest::Method::PUT,
             PATH_UPDATE_SCHEDULED_STATUS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response_or_default(status, &text, &self.configuration, connection)
     }
 
     /// Get a list of your 2FA applications.
@@ -804,22 +1289,28 @@ impl SmsClient {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::GET,
             PATH_GET_TFA_APPLICATIONS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -850,22 +1341,28 @@ impl SmsClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_CREATE_TFA_APPLICATION,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -891,30 +1388,81 @@ impl SmsClient {
         &self,
         application_id: &str,
     ) -> Result<SdkResponse<GetTfaApplicationResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_APPLICATION.replace("{appId}", application_id);
+        let path = &fill_path(PATH_GET_TFA_APPLICATION, &[("appId", application_id)]);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::GET,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Same as `get_tfa_application`, but returns `Ok(None)` instead of an error when the API answers
+    /// with a 404 because the application does not exist. Other failures are errors as usual.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .get_tfa_application_opt("02CC3CAAFD733136AA15DFAC720A0C42")
+    ///     .await?;
+    ///
+    /// match response {
+    ///     Some(response) => println!("{:?}", response.body.name),
+    ///     None => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tfa_application_opt(
+        &self,
+        application_id: &str,
+    ) -> Result<Option<SdkResponse<GetTfaApplicationResponseBody>>, SdkError> {
+        let path = &fill_path(PATH_GET_TFA_APPLICATION, &[("appId", application_id)]);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_optional_response(status, &text, &self.configuration, connection)
+    }
+
     /// Change configuration options for your existing 2FA application.
     /// # Example
     /// ```no_run
@@ -940,28 +1488,34 @@ impl SmsClient {
         application_id: &str,
         request_body: UpdateTfaApplicationRequestBody,
     ) -> Result<SdkResponse<UpdateTfaApplicationResponseBody>, SdkError> {
-        let path = &PATH_UPDATE_TFA_APPLICATION.replace("{appId}", application_id);
+        let path = &fill_path(PATH_UPDATE_TFA_APPLICATION, &[("appId", application_id)]);
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::PUT,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -987,27 +1541,33 @@ impl SmsClient {
         &self,
         application_id: &str,
     ) -> Result<SdkResponse<GetTfaMessageTemplatesResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_MESSAGE_TEMPLATES.replace("{appId}", application_id);
+        let path = &fill_path(PATH_GET_TFA_MESSAGE_TEMPLATES, &[("appId", application_id)]);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::GET,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1037,28 +1597,37 @@ impl SmsClient {
         application_id: &str,
         request_body: CreateTfaMessageTemplateRequestBody,
     ) -> Result<SdkResponse<CreateTfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_CREATE_TFA_MESSAGE_TEMPLATE.replace("{appId}", application_id);
+        let path = &fill_path(
+            PATH_CREATE_TFA_MESSAGE_TEMPLATE,
+            &[("appId", application_id)],
+        );
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1086,32 +1655,89 @@ impl SmsClient {
         application_id: &str,
         template_id: &str,
     ) -> Result<SdkResponse<GetTfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_MESSAGE_TEMPLATE
-            .replace("{appId}", application_id)
-            .replace("{msgId}", template_id);
+        let path = &fill_path(
+            PATH_GET_TFA_MESSAGE_TEMPLATE,
+            &[("appId", application_id), ("msgId", template_id)],
+        );
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             reqwest::Method::GET,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Same as `get_tfa_message_template`, but returns `Ok(None)` instead of an error when the API answers
+    /// with a 404 because the application or the template does not exist. Other failures are errors as usual.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// match client
+    ///     .get_tfa_message_template_opt(
+    ///         "02CC3CAAFD733136AA15DFAC720A0C42",
+    ///         "16A8B5FE2BCD6CA716A2D780CB3F3390",
+    ///     )
+    ///     .await? {
+    ///     Some(response) => println!("{:?}", response.body.message_text),
+    ///     None => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tfa_message_template_opt(
+        &self,
+        application_id: &str,
+        template_id: &str,
+    ) -> Result<Option<SdkResponse<GetTfaMessageTemplateResponseBody>>, SdkError> {
+        let path = &fill_path(
+            PATH_GET_TFA_MESSAGE_TEMPLATE,
+            &[("appId", application_id), ("msgId", template_id)],
+        );
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        build_optional_response(status, &text, &self.configuration, connection)
+    }
+
     /// Change configuration options for your existing 2FA application message template.
     /// # Example
     /// ```no_run
@@ -1141,30 +1767,37 @@ impl SmsClient {
         template_id: &str,
         request_body: UpdateTfaMessageTemplateRequestBody,
     ) -> Result<SdkResponse<UpdateTfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_UPDATE_TFA_MESSAGE_TEMPLATE
-            .replace("{appId}", application_id)
-            .replace("{msgId}", template_id);
+        let path = &fill_path(
+            PATH_UPDATE_TFA_MESSAGE_TEMPLATE,
+            &[("appId", application_id), ("msgId", template_id)],
+        );
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::PUT,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1196,31 +1829,34 @@ impl SmsClient {
         request_body: SendPinOverSmsRequestBody,
     ) -> Result<SdkResponse<SendPinOverSmsResponseBody>, SdkError> {
         query_parameters.validate()?;
-        let mut parameters_map = HashMap::new();
-        if let Some(nc_needed) = query_parameters.nc_needed {
-            parameters_map.insert("ncNeeded".to_string(), nc_needed.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            &query_string,
             reqwest::Method::POST,
             PATH_SEND_PIN_OVER_SMS,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1250,28 +1886,34 @@ impl SmsClient {
         pin_id: &str,
         request_body: ResendPinOverSmsRequestBody,
     ) -> Result<SdkResponse<ResendPinOverSmsResponseBody>, SdkError> {
-        let path = &PATH_RESEND_PIN_OVER_SMS.replace("{pinId}", pin_id);
+        let path = &fill_path(PATH_RESEND_PIN_OVER_SMS, &[("pinId", pin_id)]);
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1303,22 +1945,28 @@ impl SmsClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             PATH_SEND_PIN_OVER_VOICE,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1348,28 +1996,34 @@ impl SmsClient {
         pin_id: &str,
         request_body: ResendPinOverVoiceRequestBody,
     ) -> Result<SdkResponse<ResendPinOverVoiceResponseBody>, SdkError> {
-        let path = &PATH_RESEND_PIN_OVER_VOICE.replace("{pinId}", pin_id);
+        let path = &fill_path(PATH_RESEND_PIN_OVER_VOICE, &[("pinId", pin_id)]);
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1399,28 +2053,34 @@ impl SmsClient {
         pin_id: &str,
         request_body: VerifyPhoneNumberRequestBody,
     ) -> Result<SdkResponse<VerifyPhoneNumberResponseBody>, SdkError> {
-        let path = &PATH_VERIFY_PHONE_NUMBER.replace("{pinId}", pin_id);
+        let path = &fill_path(PATH_VERIFY_PHONE_NUMBER, &[("pinId", pin_id)]);
 
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            "",
             reqwest::Method::POST,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -1449,39 +2109,60 @@ impl SmsClient {
         app_id: &str,
         query_parameters: GetTfaVerificationStatusQueryParameters,
     ) -> Result<SdkResponse<GetTfaVerificationStatusResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", app_id);
+        let path = &fill_path(PATH_GET_TFA_VERIFICATION_STATUS, &[("appId", app_id)]);
 
         query_parameters.validate()?;
-        let mut parameters_map = HashMap::new();
-        parameters_map.insert("msisdn".to_string(), query_parameters.msisdn);
-        if let Some(verified) = query_parameters.verified {
-            parameters_map.insert("verified".to_string(), verified.to_string());
-        }
-        if let Some(sent) = query_parameters.sent {
-            parameters_map.insert("sent".to_string(), sent.to_string());
-        }
+        let query_string = query_parameters.to_query_string()?;
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            &query_string,
             reqwest::Method::GET,
             path,
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
+
+    /// Returns a cursor over the delivery reports of this client, which can be shared between
+    /// tasks without splitting reports between concurrent requests.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let cursor = client.reports_cursor();
+    ///
+    /// let reports = cursor.drain_all(5000).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reports_cursor(&self) -> ReportsCursor<SmsClient> {
+        ReportsCursor::new(self.clone())
+    }
 }
 
 /// Blocking client for the Infobip SMS channel.
@@ -1494,11 +2175,32 @@ impl BlockingSmsClient {
     /// Builds and returns a new `BlockingSmsClient` with a specified configuration.
     pub fn with_configuration(configuration: Configuration) -> BlockingSmsClient {
         BlockingSmsClient {
+            client: build_blocking_http_client(&configuration),
             configuration,
-            client: reqwest::blocking::Client::new(),
         }
     }
 
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        BlockingSmsClient {
+            configuration: options.apply(&self.configuration),
+            client: self.client.clone(),
+        }
+    }
+
+    /// Returns the last requests sent by the clients sharing the configuration of this client,
+    /// oldest first, with their responses. Empty unless `Configuration::with_diagnostics` is
+    /// enabled.
+    pub fn diagnostics(&self) -> Vec<DiagnosticEntry> {
+        diagnostics::entries(&self.configuration)
+    }
+
+    /// Forgets the requests recorded by the diagnostics of the configuration of this client.
+    pub fn clear_diagnostics(&self) {
+        diagnostics::clear(&self.configuration)
+    }
+
     /// Check how different message configurations will affect your message text, number of
     /// characters and message parts. This is the blocking version.
     pub fn preview(
@@ -1514,20 +2216,60 @@ impl BlockingSmsClient {
         )?;
 
         let status = response.status();
-        let text = response.text()?;
+        let connection = connection_info_blocking(&response, &self.configuration);
+        let text = read_response_text_blocking(response, &self.configuration)?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            let api_error = ApiError {
-                details: serde_json::from_str(&text)?,
-                status,
-            };
-
-            Err(SdkError::ApiRequestError(api_error))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 }
+
+impl PullReports for SmsClient {
+    type Report = Report;
+
+    const MAX_LIMIT: i32 = GetDeliveryReportsQueryParameters::MAX_LIMIT;
+
+    fn pull_reports(&self, limit: i32) -> PullReportsFuture<'_, Report> {
+        Box::pin(async move {
+            let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+            query_parameters.limit = Some(limit);
+
+            let response = self.get_delivery_reports(query_parameters).await?;
+
+            Ok(response.body.results.unwrap_or_default())
+        })
+    }
+}
+
+// Reads the status from the logs, which are not consumed on read and include messages that are
+// still pending, unlike delivery reports.
+impl StatusSource for SmsClient {
+    type Status = Status;
+
+    fn fetch_status<'a>(&'a self, message_id: &'a str) -> StatusFuture<'a, Status> {
+        Box::pin(async move {
+            let mut query_parameters = GetLogsQueryParameters::new();
+            query_parameters.message_id = Some(message_id.into());
+            query_parameters.limit = Some(1);
+
+            let response = self.get_logs(query_parameters).await?;
+
+            Ok(response
+                .body
+                .results
+                .and_then(|logs| logs.into_iter().next())
+                .and_then(|log| log.status))
+        })
+    }
+}
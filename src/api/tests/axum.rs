@@ -0,0 +1,216 @@
+use axum::body::{Body, HttpBody};
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use tower::ServiceExt;
+
+use crate::api::webhook::axum::*;
+use crate::api::webhook::WebhookDeduplicator;
+
+const DELIVERY_REPORTS: &str =
+    include_str!("../../../tests/fixtures/sms/get_delivery_reports_response.json");
+const INBOUND_MESSAGES: &str = include_str!("../../../tests/fixtures/sms/inbound_messages.json");
+const SECRET_HEADER: &str = "x-webhook-secret";
+
+async fn delivery_reports(InfobipDeliveryReports(reports): InfobipDeliveryReports) -> String {
+    reports
+        .iter()
+        .filter_map(|report| report.message_id.clone())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+async fn inbound_sms(InfobipInboundSms(webhook): InfobipInboundSms) -> String {
+    webhook
+        .results
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|message| message.message_id.clone())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn test_router(options: WebhookOptions) -> Router {
+    Router::new()
+        .route("/reports", post(delivery_reports))
+        .route("/inbound", post(inbound_sms))
+        .with_state(options)
+}
+
+fn verified_options() -> WebhookOptions {
+    WebhookOptions::new().with_verifier(|headers: &HeaderMap, _body: &[u8]| {
+        headers
+            .get(SECRET_HEADER)
+            .map_or(false, |value| value == "some-secret")
+    })
+}
+
+fn webhook_request(path: &str, content_type: &str, secret: &str, body: &str) -> Request<Body> {
+    Request::post(path)
+        .header("content-type", content_type)
+        .header(SECRET_HEADER, secret)
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+async fn call(router: &Router, request: Request<Body>) -> (StatusCode, String) {
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let mut body = response.into_body();
+    let mut text = Vec::new();
+    while let Some(chunk) = body.data().await {
+        text.extend_from_slice(&chunk.unwrap());
+    }
+
+    (status, String::from_utf8(text).unwrap())
+}
+
+#[tokio::test]
+async fn test_delivery_reports_json() {
+    let router = test_router(verified_options());
+    let request = webhook_request(
+        "/reports",
+        "application/json; charset=utf-8",
+        "some-secret",
+        DELIVERY_REPORTS,
+    );
+
+    let (status, body) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "MESSAGE-ID-123-xyz");
+}
+
+#[cfg(feature = "xml")]
+#[tokio::test]
+async fn test_delivery_reports_xml() {
+    let router = test_router(verified_options());
+    let request = webhook_request(
+        "/reports",
+        "application/xml",
+        "some-secret",
+        include_str!("../../../tests/fixtures/sms/delivery_reports.xml"),
+    );
+
+    let (status, body) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "MESSAGE-ID-123-xyz");
+}
+
+#[tokio::test]
+async fn test_delivery_reports_without_state() {
+    let router = Router::new().route("/reports", post(delivery_reports));
+    let request = Request::post("/reports")
+        .body(Body::from(DELIVERY_REPORTS))
+        .unwrap();
+
+    let (status, body) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "MESSAGE-ID-123-xyz");
+}
+
+#[tokio::test]
+async fn test_delivery_reports_unverified() {
+    let router = test_router(verified_options());
+    let request = webhook_request(
+        "/reports",
+        "application/json",
+        "wrong-secret",
+        DELIVERY_REPORTS,
+    );
+
+    let (status, _) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_delivery_reports_invalid_body() {
+    let router = test_router(verified_options());
+    let request = webhook_request(
+        "/reports",
+        "application/json",
+        "some-secret",
+        r#"{"results": "not-a-list"}"#,
+    );
+
+    let (status, body) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.starts_with("invalid webhook body"));
+}
+
+#[tokio::test]
+async fn test_delivery_reports_unsupported_content_type() {
+    let router = test_router(verified_options());
+    let request = webhook_request("/reports", "text/plain", "some-secret", DELIVERY_REPORTS);
+
+    let (status, _) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn test_delivery_reports_deduplicated() {
+    let router = test_router(verified_options().with_deduplicator(WebhookDeduplicator::default()));
+
+    for expected in ["MESSAGE-ID-123-xyz", ""] {
+        let request = webhook_request(
+            "/reports",
+            "application/json",
+            "some-secret",
+            DELIVERY_REPORTS,
+        );
+
+        let (status, body) = call(&router, request).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_inbound_sms() {
+    let router = test_router(verified_options().with_deduplicator(WebhookDeduplicator::default()));
+
+    for expected in ["817790313235066447", ""] {
+        let request = webhook_request(
+            "/inbound",
+            "application/json",
+            "some-secret",
+            INBOUND_MESSAGES,
+        );
+
+        let (status, body) = call(&router, request).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_inbound_sms_unverified() {
+    let router = test_router(verified_options());
+    let request = webhook_request(
+        "/inbound",
+        "application/json",
+        "wrong-secret",
+        INBOUND_MESSAGES,
+    );
+
+    let (status, _) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_inbound_sms_invalid_body() {
+    let router = test_router(verified_options());
+    let request = webhook_request("/inbound", "application/json", "some-secret", "not json");
+
+    let (status, _) = call(&router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,438 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use httpmock::prelude::*;
+use reqwest::header::{HeaderName, HeaderValue};
+
+use crate::api::client::InfobipClient;
+#[cfg(feature = "email")]
+use crate::api::email;
+use crate::api::tests::get_test_configuration;
+use crate::api::tests::harness::{ExpectedCall, Harness};
+use crate::api::{sms, RequestOptions, SdkError};
+use crate::configuration::{ApiKey, AuthMethod};
+use crate::model;
+
+#[cfg(feature = "email")]
+#[tokio::test]
+async fn channel_clients_share_http_client() {
+    use reqwest::header::HeaderMap;
+
+    let server = MockServer::start_async().await;
+    let email_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(email::PATH_GET_LOGS)
+            .header("x-shared-client", "yes");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let sms_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(sms::PATH_GET_LOGS)
+            .header("x-shared-client", "yes");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-shared-client", HeaderValue::from_static("yes"));
+    let http_client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let client =
+        InfobipClient::with_http_client(get_test_configuration(&server.base_url()), http_client);
+
+    client
+        .email()
+        .get_logs(model::email::GetLogsQueryParameters::new())
+        .await
+        .unwrap();
+    client
+        .sms()
+        .get_logs(model::sms::GetLogsQueryParameters::new())
+        .await
+        .unwrap();
+
+    email_mock.assert_async().await;
+    sms_mock.assert_async().await;
+    assert_eq!(
+        client.email().configuration.base_url(),
+        client.sms().configuration.base_url()
+    );
+}
+
+#[tokio::test]
+async fn request_options_override_auth_per_call() {
+    let server = MockServer::start_async().await;
+    let tenant_a_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(sms::PATH_GET_LOGS)
+            .header("authorization", "App tenant-a-key")
+            .header("x-tenant", "a");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let tenant_b_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(sms::PATH_GET_LOGS)
+            .header("authorization", "Bearer tenant-b-token");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let default_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(sms::PATH_GET_LOGS)
+            .header("authorization", "App some-api-key");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+
+    let client = InfobipClient::with_configuration(get_test_configuration(&server.base_url()));
+    let tenant_a = RequestOptions::new()
+        .with_auth(AuthMethod::ApiKey(ApiKey::new("tenant-a-key".to_string())))
+        .with_header(
+            HeaderName::from_static("x-tenant"),
+            HeaderValue::from_static("a"),
+        );
+    let tenant_b = RequestOptions::new()
+        .with_auth(AuthMethod::BearerAccessToken("tenant-b-token".to_string()));
+
+    for options in [&tenant_a, &tenant_b, &tenant_a, &tenant_b] {
+        client
+            .with_request_options(options)
+            .sms()
+            .get_logs(model::sms::GetLogsQueryParameters::new())
+            .await
+            .unwrap();
+        client
+            .sms()
+            .get_logs(model::sms::GetLogsQueryParameters::new())
+            .await
+            .unwrap();
+    }
+
+    tenant_a_mock.assert_hits_async(2).await;
+    tenant_b_mock.assert_hits_async(2).await;
+    default_mock.assert_hits_async(4).await;
+}
+
+#[test]
+fn request_options_prepared_request() {
+    let client = sms::SmsClient::with_configuration(get_test_configuration("https://some.url"));
+    let options = RequestOptions::new()
+        .with_auth(AuthMethod::ApiKey(ApiKey::new("tenant-key".to_string())))
+        .with_header(
+            HeaderName::from_static("x-tenant"),
+            HeaderValue::from_static("a"),
+        );
+
+    let message = model::sms::Message::new(vec![model::sms::Destination::new("123456789012")]);
+    let prepared = client
+        .with_request_options(&options)
+        .prepare_send(model::sms::SendRequestBody::new(vec![message]))
+        .unwrap();
+
+    assert!(prepared
+        .headers
+        .contains(&("Authorization".to_string(), "App [REDACTED]".to_string())));
+    assert!(prepared
+        .headers
+        .contains(&("x-tenant".to_string(), "a".to_string())));
+    assert!(client.configuration.extra_headers().is_empty());
+    assert_eq!(client.configuration.api_key().unwrap().key, "some-api-key");
+}
+
+#[cfg(feature = "email")]
+#[tokio::test]
+async fn sandbox_tags_sends_and_responses() {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    let mut sandbox_headers = HeaderMap::new();
+    sandbox_headers.insert("X-Test-Traffic", HeaderValue::from_static("true"));
+
+    let harness = Harness::start().await;
+    let sms_mock = harness
+        .expect(
+            ExpectedCall::new(POST, sms::PATH_SEND)
+                .header("X-Test-Traffic", "true")
+                .responds(200, r#"{"bulkId": "some-bulk-id", "messages": []}"#),
+        )
+        .await;
+    let email_mock = harness
+        .expect(
+            ExpectedCall::new(POST, email::PATH_SEND)
+                .header("X-Test-Traffic", "true")
+                .responds(200, r#"{"bulkId": "some-bulk-id", "messages": []}"#),
+        )
+        .await;
+    let client = InfobipClient::with_configuration(
+        harness
+            .configuration()
+            .with_sandbox_headers(sandbox_headers)
+            .with_sandbox(true)
+            .unwrap(),
+    );
+
+    let message = model::sms::Message::new(vec![model::sms::Destination::new("41793026727")]);
+    let sms_response = client
+        .sms()
+        .send(model::sms::SendRequestBody::new(vec![message]))
+        .await
+        .unwrap();
+    let mut request_body = model::email::SendRequestBody::new("someone@company.com");
+    request_body.from = Some("sender@company.com".to_string());
+    request_body.text = Some("Some text".to_string());
+    let email_response = client.email().send(request_body).await.unwrap();
+
+    sms_mock.assert_async().await;
+    email_mock.assert_async().await;
+    assert!(sms_response.sandbox);
+    assert!(email_response.sandbox);
+}
+
+#[tokio::test]
+async fn sandbox_disabled_sends_no_marker() {
+    let harness = Harness::start().await;
+    let mock = harness
+        .expect(ExpectedCall::new(GET, sms::PATH_GET_LOGS).responds(200, r#"{"results": []}"#))
+        .await;
+    let client = InfobipClient::with_configuration(harness.configuration());
+
+    let response = client
+        .sms()
+        .get_logs(model::sms::GetLogsQueryParameters::new())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(!response.sandbox);
+}
+
+#[tokio::test]
+async fn shutdown_drains_in_flight_requests() {
+    let server = MockServer::start_async().await;
+    for (message_id, delay) in [("fast", 100), ("medium", 200), ("slow", 5000)] {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(sms::PATH_GET_LOGS)
+                .query_param("messageId", message_id);
+            then.status(200)
+                .header("content-type", "application/json")
+                .delay(Duration::from_millis(delay))
+                .body(r#"{"results": []}"#);
+        });
+    }
+    let client = InfobipClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let calls: Vec<_> = ["fast", "medium", "slow"]
+        .iter()
+        .map(|message_id| {
+            let client = client.clone();
+            let mut query_parameters = model::sms::GetLogsQueryParameters::new();
+            query_parameters.message_id = Some(message_id.to_string());
+            tokio::spawn(async move { client.sms().get_logs(query_parameters).await })
+        })
+        .collect();
+    while client.in_flight_requests() < 3 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let remaining = client.shutdown(Duration::from_secs(1)).await;
+
+    assert_eq!(remaining, 1);
+    assert!(client.is_shutting_down());
+    let mut calls = calls.into_iter();
+    assert!(calls.next().unwrap().await.unwrap().is_ok());
+    assert!(calls.next().unwrap().await.unwrap().is_ok());
+
+    let started = Instant::now();
+    let error = client
+        .with_request_options(&RequestOptions::new())
+        .sms()
+        .get_logs(model::sms::GetLogsQueryParameters::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(error, SdkError::ShuttingDown));
+    assert!(started.elapsed() < Duration::from_millis(100));
+
+    let slow_call = calls.next().unwrap();
+    slow_call.abort();
+    assert!(slow_call.await.is_err());
+    assert_eq!(client.in_flight_requests(), 0);
+}
+
+#[tokio::test]
+async fn health_check_ok() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(crate::api::client::PATH_HEALTH_CHECK)
+            .header("Authorization", "App some-api-key");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"balance": 47.79, "currency": "EUR"}"#);
+    });
+    let client = InfobipClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let health = client.health_check().await.unwrap();
+
+    assert!(health.reachable);
+    assert!(health.authorized);
+    assert!(health.is_healthy());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn health_check_unauthorized() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(crate::api::client::PATH_HEALTH_CHECK);
+        then.status(401)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#);
+    });
+    let client = InfobipClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let health = client.health_check().await.unwrap();
+
+    assert!(health.reachable);
+    assert!(!health.authorized);
+    assert!(!health.is_healthy());
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn health_check_server_error() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(crate::api::client::PATH_HEALTH_CHECK);
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR", "text": "Something went wrong"}}}"#);
+    });
+    let client = InfobipClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client.health_check().await.unwrap_err();
+
+    assert!(matches!(error, SdkError::ApiRequestError(_)));
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn health_check_connection_refused() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    drop(listener);
+    let client =
+        InfobipClient::with_configuration(get_test_configuration(&format!("http://{}", address)));
+
+    let health = client.health_check().await.unwrap();
+
+    assert!(!health.reachable);
+    assert!(!health.authorized);
+    assert_eq!(client.in_flight_requests(), 0);
+}
+
+#[tokio::test]
+async fn health_check_respects_timeout() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(crate::api::client::PATH_HEALTH_CHECK);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body("{}")
+            .delay(Duration::from_secs(5));
+    });
+    let configuration = crate::configuration::Configuration::builder()
+        .base_url(&server.base_url())
+        .api_key(ApiKey::new("some-api-key".to_string()))
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let client = InfobipClient::with_configuration(configuration);
+
+    let started = Instant::now();
+    let health = client.health_check().await.unwrap();
+
+    assert!(!health.reachable);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn pinned_host_resolves_to_address() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(sms::PATH_GET_LOGS);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let configuration =
+        get_test_configuration(&format!("http://infobip-pinned.invalid:{}", server.port()))
+            .pin_host(IpAddr::V4(Ipv4Addr::LOCALHOST))
+            .with_connection_info(true);
+    let client = sms::SmsClient::with_configuration(configuration);
+
+    let response = client
+        .get_logs(model::sms::GetLogsQueryParameters::new())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let connection = response.connection.unwrap();
+    assert_eq!(connection.host, "infobip-pinned.invalid");
+    assert_eq!(
+        connection.remote_addr,
+        Some(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server.port()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn connection_info_disabled_by_default() {
+    let harness = Harness::start().await;
+    harness
+        .expect(ExpectedCall::new(GET, sms::PATH_GET_LOGS).responds(200, r#"{"results": []}"#))
+        .await;
+    let client = sms::SmsClient::with_configuration(harness.configuration());
+
+    let response = client
+        .get_logs(model::sms::GetLogsQueryParameters::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.connection, None);
+}
+
+#[test]
+fn pinned_host_blocking_client() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(sms::PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"originalText": "Hi", "previews": []}"#);
+    });
+    let configuration =
+        get_test_configuration(&format!("http://infobip-pinned.invalid:{}", server.port()))
+            .pin_host(IpAddr::V4(Ipv4Addr::LOCALHOST))
+            .with_connection_info(true);
+    let client = sms::BlockingSmsClient::with_configuration(configuration);
+
+    let response = client
+        .preview(model::sms::PreviewRequestBody::new("Hi"))
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.connection.unwrap().host, "infobip-pinned.invalid");
+}
@@ -0,0 +1,201 @@
+use httpmock::prelude::*;
+
+use crate::api::diagnostics::DIAGNOSTICS_BODY_LIMIT;
+use crate::api::sms::*;
+use crate::api::tests::get_test_configuration;
+use crate::model::sms::*;
+
+const PREVIEW_RESPONSE: &str = r#"{"originalText": "Some text", "previews": []}"#;
+
+#[tokio::test]
+async fn test_diagnostics_disabled_by_default() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200).body(PREVIEW_RESPONSE);
+    });
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    client
+        .preview(PreviewRequestBody::new("text"))
+        .await
+        .unwrap();
+
+    assert_eq!(client.configuration.diagnostics_capacity(), None);
+    assert!(client.diagnostics().is_empty());
+}
+
+#[tokio::test]
+async fn test_diagnostics_rotation() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("X-Request-Id", "some-request-id")
+            .body(PREVIEW_RESPONSE);
+    });
+    let configuration = get_test_configuration(&server.base_url()).with_diagnostics(3);
+    let client = SmsClient::with_configuration(configuration);
+
+    for index in 0..5 {
+        client
+            .preview(PreviewRequestBody::new(&format!("text {}", index)))
+            .await
+            .unwrap();
+    }
+
+    mock.assert_hits_async(5).await;
+    let entries = client.diagnostics();
+    assert_eq!(entries.len(), 3);
+    for (entry, index) in entries.iter().zip(2..) {
+        assert_eq!(entry.method, "POST");
+        assert_eq!(entry.endpoint, PATH_PREVIEW);
+        assert_eq!(entry.status, Some(reqwest::StatusCode::OK));
+        assert_eq!(entry.request_id.as_deref(), Some("some-request-id"));
+        assert!(entry
+            .request_body
+            .as_ref()
+            .unwrap()
+            .contains(&format!("text {}", index)));
+        assert!(entry.response_body.as_ref().unwrap().contains("Some text"));
+        assert!(entry.error.is_none());
+    }
+    assert!(entries[0].sent_at <= entries[2].sent_at);
+
+    client.clear_diagnostics();
+    assert!(client.diagnostics().is_empty());
+}
+
+#[tokio::test]
+async fn test_diagnostics_redaction() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(POST).path("/2fa/2/pin/some-pin-id/verify");
+        then.status(200)
+            .body(r#"{"pinId": "some-pin-id", "verified": true, "accessToken": "some-token"}"#);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path(PATH_SEND_OVER_QUERY_PARAMS);
+        then.status(200).body(r#"{"messages": []}"#);
+    });
+    let configuration = get_test_configuration(&server.base_url()).with_diagnostics(10);
+    let client = SmsClient::with_configuration(configuration);
+
+    client
+        .verify_phone_number("some-pin-id", VerifyPhoneNumberRequestBody::new("1234"))
+        .await
+        .unwrap();
+    client
+        .send_over_query_parameters(SendOverQueryParametersQueryParameters::new(
+            "some-user",
+            "some-password",
+            vec!["41793026727".to_string()],
+        ))
+        .await
+        .unwrap();
+
+    let entries = client.diagnostics();
+    assert_eq!(entries.len(), 2);
+
+    let verify = &entries[0];
+    assert_eq!(
+        verify.request_body.as_deref(),
+        Some(r#"{"pin":"[REDACTED]"}"#)
+    );
+    let response_body = verify.response_body.as_deref().unwrap();
+    assert!(response_body.contains(r#""accessToken":"[REDACTED]""#));
+    assert!(response_body.contains(r#""pinId":"some-pin-id""#));
+
+    let send = &entries[1];
+    assert!(send.endpoint.starts_with(PATH_SEND_OVER_QUERY_PARAMS));
+    assert!(send.endpoint.contains("password=%5BREDACTED%5D"));
+    assert!(send.endpoint.contains("username=some-user"));
+    assert!(!send.endpoint.contains("some-password"));
+}
+
+#[tokio::test]
+async fn test_diagnostics_truncates_bodies() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(400).body("é".repeat(DIAGNOSTICS_BODY_LIMIT));
+    });
+    let configuration = get_test_configuration(&server.base_url()).with_diagnostics(1);
+    let client = SmsClient::with_configuration(configuration);
+
+    assert!(client
+        .preview(PreviewRequestBody::new(
+            &"a".repeat(2 * DIAGNOSTICS_BODY_LIMIT)
+        ))
+        .await
+        .is_err());
+
+    let entries = client.diagnostics();
+    assert_eq!(entries[0].status, Some(reqwest::StatusCode::BAD_REQUEST));
+    assert_eq!(
+        entries[0].request_body.as_ref().unwrap().len(),
+        DIAGNOSTICS_BODY_LIMIT
+    );
+    assert_eq!(
+        entries[0].response_body.as_ref().unwrap().len(),
+        DIAGNOSTICS_BODY_LIMIT
+    );
+}
+
+#[tokio::test]
+async fn test_diagnostics_records_failed_requests() {
+    let configuration = get_test_configuration("http://127.0.0.1:1").with_diagnostics(5);
+    let client = SmsClient::with_configuration(configuration);
+
+    assert!(client
+        .preview(PreviewRequestBody::new("text"))
+        .await
+        .is_err());
+
+    let entries = client.diagnostics();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].status, None);
+    assert!(entries[0].error.is_some());
+}
+
+#[cfg(feature = "email")]
+#[tokio::test]
+async fn test_diagnostics_shared_by_clients() {
+    use crate::api::client::InfobipClient;
+    use crate::api::email::PATH_GET_BULKS;
+    use crate::model::email::GetBulksQueryParameters;
+
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(GET).path(PATH_GET_BULKS);
+        then.status(200)
+            .body(r#"{"externalBulkId": "some-bulk-id", "bulks": []}"#);
+    });
+    server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200).body(PREVIEW_RESPONSE);
+    });
+    let configuration = get_test_configuration(&server.base_url()).with_diagnostics(2);
+    let client = InfobipClient::with_configuration(configuration);
+
+    client
+        .email()
+        .get_bulks(GetBulksQueryParameters::new("some-bulk-id"))
+        .await
+        .unwrap();
+    client
+        .sms()
+        .preview(PreviewRequestBody::new("text"))
+        .await
+        .unwrap();
+
+    let entries = client.email().diagnostics();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[0].endpoint,
+        format!("{}?bulkId=some-bulk-id", PATH_GET_BULKS)
+    );
+    assert_eq!(entries[1].endpoint, PATH_PREVIEW);
+    assert_eq!(client.sms().diagnostics(), entries);
+    assert_eq!(client.diagnostics(), entries);
+}
@@ -1,6 +1,7 @@
 use crate::api::email::*;
 use crate::api::tests::{get_test_configuration, mock_json_endpoint};
-use crate::api::SdkError;
+use crate::api::{PreparedBody, PreparedPart, RequestOptions, SdkError};
+use crate::configuration::SendPolicy;
 use crate::model::email::*;
 
 const DUMMY_BASE_URL: &str = "https://some.url";
@@ -55,6 +56,62 @@ async fn test_send_valid() {
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_send_with_warnings() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        include_str!("../../../tests/fixtures/email/send_response_with_warnings.json"),
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = SendRequestBody::new("john.smith@somedomain.com");
+    request_body.template_id = Some(1234);
+    request_body.html = Some("<p>Ignored</p>".to_string());
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(
+        response.warnings,
+        vec![
+            "html was ignored because templateId is set".to_string(),
+            "text was ignored because templateId is set".to_string(),
+        ]
+    );
+    assert_eq!(
+        response.body.bulk_id.as_deref(),
+        Some("snxemd8u52byvsbqw5zp")
+    );
+    let messages = response.body.messages.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].message_id.as_deref(),
+        Some("wm6cwnoh2m7gxmfvl7h2")
+    );
+}
+
+#[test]
+fn test_response_warnings() {
+    use crate::api::response_warnings;
+    use serde_json::json;
+
+    assert_eq!(
+        response_warnings(
+            &json!({"messages": ["Field ignored.", {"to": "41793026727"}], "warnings": []})
+        ),
+        vec!["Field ignored.".to_string()]
+    );
+    assert_eq!(
+        response_warnings(&json!({"warnings": [{"description": "Deprecated."}, 3]})),
+        vec!["Deprecated.".to_string()]
+    );
+    assert!(response_warnings(&json!({"warnings": "not an array"})).is_empty());
+    assert!(response_warnings(&json!([{"warnings": ["nested"]}])).is_empty());
+}
+
 #[tokio::test]
 async fn test_send_invalid_request() {
     let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
@@ -197,6 +254,52 @@ async fn get_scheduled_status_valid() {
     assert_eq!(response.status, reqwest::StatusCode::OK);
 }
 
+#[tokio::test]
+async fn get_scheduled_status_external_bulk_id() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(PATH_GET_SCHEDULED_STATUS)
+                .query_param("externalBulkId", "external-id")
+                .matches(|request| {
+                    !request
+                        .query_params
+                        .iter()
+                        .flatten()
+                        .any(|(key, _)| key == "bulkId")
+                });
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"externalBulkId": "external-id", "bulks": []}"#);
+        })
+        .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let query_parameters = GetScheduledStatusQueryParameters::with_external_bulk_id("external-id");
+
+    let response = client.get_scheduled_status(query_parameters).await.unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(
+        response.body.external_bulk_id,
+        Some("external-id".to_string())
+    );
+}
+
+#[tokio::test]
+async fn get_bulks_both_ids() {
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut query_parameters = GetBulksQueryParameters::new("bulk-id");
+    query_parameters.external_bulk_id = Some("external-id".to_string());
+
+    let error = client.get_bulks(query_parameters).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
 #[tokio::test]
 async fn update_scheduled_status_valid() {
     let expected_response = r#"
@@ -227,6 +330,54 @@ async fn update_scheduled_status_valid() {
     assert_eq!(response.status, reqwest::StatusCode::OK);
 }
 
+#[tokio::test]
+async fn update_scheduled_status_no_content() {
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        PATH_UPDATE_SCHEDULED_STATUS,
+        "",
+        reqwest::StatusCode::NO_CONTENT,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let query_parameters = UpdateScheduledStatusQueryParameters::new("bulk-id");
+    let request_body = UpdateScheduledStatusRequestBody::new(BulkStatus::CANCELED);
+
+    let response = client
+        .update_scheduled_status(query_parameters, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::NO_CONTENT);
+    assert_eq!(response.body, UpdateScheduledStatusResponseBody::default());
+}
+
+#[tokio::test]
+async fn reschedule_empty_body() {
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        PATH_RESCHEDULE,
+        "",
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let query_parameters = RescheduleQueryParameters::new("bulk-id");
+    let request_body = RescheduleRequestBody::new("2035-08-25T16:00:00.000+0000");
+
+    let response = client
+        .reschedule(query_parameters, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body, RescheduleResponseBody::default());
+}
+
 #[tokio::test]
 async fn get_delivery_reports_valid() {
     let expected_response = r#"
@@ -329,6 +480,40 @@ async fn get_logs_valid() {
     assert_eq!(response.status, reqwest::StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_for_each_log() {
+    let expected_response = r#"
+    {
+      "results": [
+        {"messageId": "first", "to": "john.smith@somedomain.com", "messageCount": 1},
+        {"messageId": "second", "to": "jane.smith@somedomain.com", "messageCount": 1}
+      ],
+      "paging": {"page": 0, "size": 2}
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_LOGS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut message_ids = Vec::new();
+    let status = client
+        .for_each_log(GetLogsQueryParameters::default(), |log| {
+            message_ids.push(log.message_id.unwrap())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(status, reqwest::StatusCode::OK);
+    assert_eq!(message_ids, vec!["first", "second"]);
+}
+
 #[tokio::test]
 async fn validate_address_valid() {
     let expected_response = r#"
@@ -517,6 +702,108 @@ async fn delete_domain_valid() {
     assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
 }
 
+#[tokio::test]
+async fn get_domain_opt_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/email/1/domains/example.com",
+        r#"{"domainId": 1, "domainName": "example.com", "active": true}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.get_domain_opt("example.com").await.unwrap().unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.domain_name.as_deref(), Some("example.com"));
+}
+
+#[tokio::test]
+async fn get_domain_opt_not_found() {
+    let expected_response = r#"
+    {
+      "requestError": {
+        "serviceException": {
+          "messageId": "NOT_FOUND",
+          "text": "Domain not found."
+        }
+      }
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/email/1/domains/example.com",
+        expected_response,
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    assert!(client
+        .get_domain_opt("example.com")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(client.get_domain("example.com").await.is_err());
+}
+
+#[tokio::test]
+async fn get_domain_opt_server_error() {
+    let expected_response = r#"
+    {
+      "requestError": {
+        "serviceException": {
+          "messageId": "GENERAL_ERROR",
+          "text": "Something went wrong."
+        }
+      }
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/email/1/domains/example.com",
+        expected_response,
+        reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client.get_domain_opt("example.com").await.unwrap_err();
+
+    match error {
+        SdkError::ApiRequestError(error) => {
+            assert_eq!(error.status, reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn get_bulks_opt_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_BULKS,
+        "",
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_bulks_opt(GetBulksQueryParameters::new("some-bulk-id"))
+        .await
+        .unwrap();
+
+    assert!(response.is_none());
+}
+
 #[tokio::test]
 async fn update_tracking_valid() {
     let expected_response = r#"
@@ -584,3 +871,1334 @@ async fn verify_domain_valid() {
 
     assert_eq!(status, reqwest::StatusCode::ACCEPTED);
 }
+
+#[tokio::test]
+async fn test_prepare_send_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200);
+    });
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.subject = Some("Some subject".to_string());
+    request_body.attachments = Some(vec!["tests/image.png".to_string()]);
+
+    let prepared = client.prepare_send(request_body).unwrap();
+
+    assert_eq!(prepared.method, reqwest::Method::POST);
+    assert_eq!(prepared.url, format!("{}{}", server.base_url(), PATH_SEND));
+    assert!(prepared
+        .headers
+        .contains(&("Authorization".to_string(), "App [REDACTED]".to_string())));
+    assert_eq!(
+        prepared.body,
+        PreparedBody::Multipart(vec![
+            PreparedPart {
+                name: "to".to_string(),
+                file_name: None,
+                size: 19,
+            },
+            PreparedPart {
+                name: "subject".to_string(),
+                file_name: None,
+                size: 12,
+            },
+            PreparedPart {
+                name: "attachment".to_string(),
+                file_name: Some("tests/image.png".to_string()),
+                size: std::fs::metadata("tests/image.png").unwrap().len(),
+            },
+        ])
+    );
+    mock.assert_hits_async(0).await;
+}
+
+#[tokio::test]
+async fn test_fetch_all_pages_domains() {
+    let server = httpmock::MockServer::start_async().await;
+    let page_bodies = [
+        (
+            1,
+            r#"[{"domainName": "one.com"}, {"domainName": "two.com"}]"#,
+        ),
+        (
+            2,
+            r#"[{"domainName": "three.com"}, {"domainName": "four.com"}]"#,
+        ),
+        (3, r#"[{"domainName": "five.com"}]"#),
+    ];
+    let mocks: Vec<_> = page_bodies
+        .iter()
+        .map(|(page, results)| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path(PATH_GET_DOMAINS)
+                    .query_param("page", page.to_string());
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{"paging": {{"page": {}, "size": 2, "totalPages": 3, "totalResults": 5}}, "results": {}}}"#,
+                        page, results
+                    ));
+            })
+        })
+        .collect();
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let pages = crate::api::fetch_all_pages(1, |page| {
+        let mut query_parameters = GetDomainsQueryParameters::new();
+        query_parameters.page = Some(page);
+
+        client.get_domains(query_parameters)
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(pages.len(), 3);
+    let domain_names: Vec<String> = pages
+        .into_iter()
+        .flat_map(|page| page.results.unwrap())
+        .map(|domain| domain.domain_name.unwrap())
+        .collect();
+    assert_eq!(
+        domain_names,
+        vec!["one.com", "two.com", "three.com", "four.com", "five.com"]
+    );
+    for mock in mocks {
+        mock.assert_hits_async(1).await;
+    }
+}
+
+#[tokio::test]
+async fn test_get_scheduled_messages_pages() {
+    let server = httpmock::MockServer::start_async().await;
+    let page_bodies = [
+        (
+            0,
+            r#"[{"messageId": "one", "to": "one@company.com"}, {"messageId": "two", "to": "two@company.com"}]"#,
+        ),
+        (1, r#"[{"messageId": "three", "to": "three@company.com"}]"#),
+    ];
+    let mocks: Vec<_> = page_bodies
+        .iter()
+        .map(|(page, results)| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path(PATH_GET_SCHEDULED_MESSAGES)
+                    .query_param("bulkId", "some bulk&id")
+                    .query_param("page", page.to_string())
+                    .query_param("size", "2");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{"bulkId": "some bulk&id", "paging": {{"page": {}, "size": 2, "totalPages": 2, "totalResults": 3}}, "results": {}}}"#,
+                        page, results
+                    ));
+            })
+        })
+        .collect();
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let pages = crate::api::fetch_all_pages(0, |page| {
+        let mut query_parameters = GetScheduledMessagesQueryParameters::new("some bulk&id");
+        query_parameters.page = Some(page);
+        query_parameters.size = Some(2);
+
+        client.get_scheduled_messages(query_parameters)
+    })
+    .await
+    .unwrap();
+
+    let message_ids: Vec<String> = pages
+        .into_iter()
+        .flat_map(|page| page.results.unwrap())
+        .map(|message| message.message_id.unwrap())
+        .collect();
+    assert_eq!(message_ids, vec!["one", "two", "three"]);
+    for mock in mocks {
+        mock.assert_hits_async(1).await;
+    }
+}
+
+#[tokio::test]
+async fn test_get_scheduled_messages_invalid_page_size() {
+    let client = EmailClient::with_configuration(get_test_configuration("https://some.url"));
+    let mut query_parameters = GetScheduledMessagesQueryParameters::new("some-bulk-id");
+    query_parameters.size = Some(MAX_SCHEDULED_MESSAGES_PAGE_SIZE + 1);
+
+    let error = client
+        .get_scheduled_messages(query_parameters)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_send_blocked_attachment_extension() {
+    let client = EmailClient::with_configuration(get_test_configuration("https://some.url"));
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.attachments = Some(vec!["tests/installer.EXE".to_string()]);
+
+    let error = client.send(request_body).await.unwrap_err();
+
+    if let SdkError::AttachmentRejected(file_name, reason) = error {
+        assert_eq!(file_name, "tests/installer.EXE");
+        assert!(reason.contains(".exe"));
+    } else {
+        panic!("not attachment rejected error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_attachment_inspector_rejection() {
+    let configuration =
+        get_test_configuration("https://some.url").with_attachment_inspector(|attachment| {
+            if attachment.size > 10 {
+                Err(format!("{} bytes is too big", attachment.size))
+            } else {
+                Ok(())
+            }
+        });
+    let client = EmailClient::with_configuration(configuration);
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.inline_images = Some(vec!["tests/image.png".to_string()]);
+
+    let error = client.send(request_body).await.unwrap_err();
+
+    if let SdkError::AttachmentRejected(file_name, reason) = error {
+        assert_eq!(file_name, "tests/image.png");
+        assert!(reason.contains("too big"));
+    } else {
+        panic!("not attachment rejected error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_attachment_default_pass_through() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"bulkId": "some-bulk-id"}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(
+        get_test_configuration(&server.base_url()).with_blocked_attachment_extensions(vec![]),
+    );
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.attachments = Some(vec!["tests/image.png".to_string()]);
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+// Creates a sparse file of `size` bytes in the temporary directory, without writing its content.
+fn sparse_temp_file(name: &str, size: u64) -> String {
+    let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), name));
+    let file = std::fs::File::create(&path).unwrap();
+    file.set_len(size).unwrap();
+
+    path.to_string_lossy().to_string()
+}
+
+#[tokio::test]
+async fn test_send_streams_attachment() {
+    let path = std::env::temp_dir().join(format!("{}-streamed.txt", std::process::id()));
+    std::fs::write(&path, "streamed attachment content").unwrap();
+    let file_name = path.to_string_lossy().to_string();
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("streamed attachment content");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.attachments = Some(vec![file_name.clone()]);
+
+    let result = client.send(request_body).await;
+    std::fs::remove_file(file_name).unwrap();
+
+    result.unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_payload_too_large_file() {
+    let file_name = sparse_temp_file("large-attachment.pdf", 300 * 1024 * 1024);
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.attachments = Some(vec![file_name.clone()]);
+
+    let error = client.send(request_body).await.unwrap_err();
+    std::fs::remove_file(file_name).unwrap();
+
+    if let SdkError::PayloadTooLarge { estimated, limit } = error {
+        assert!(estimated > 300 * 1024 * 1024);
+        assert_eq!(limit, crate::configuration::DEFAULT_MAX_MULTIPART_BODY_SIZE);
+    } else {
+        panic!("not payload too large error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_payload_too_large_text() {
+    let client = EmailClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_max_multipart_body_size(1024),
+    );
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.html = Some("a".repeat(2048));
+
+    let error = client.send(request_body).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        SdkError::PayloadTooLarge { estimated, limit: 1024 } if estimated > 2048
+    ));
+}
+
+#[test]
+fn test_prepare_send_raised_payload_limit() {
+    let file_name = sparse_temp_file("raised-limit-attachment.pdf", 30 * 1024 * 1024);
+    let client = EmailClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_max_multipart_body_size(50 * 1024 * 1024),
+    );
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.attachments = Some(vec![file_name.clone()]);
+
+    let prepared = client.prepare_send(request_body);
+    std::fs::remove_file(file_name).unwrap();
+
+    if let PreparedBody::Multipart(parts) = prepared.unwrap().body {
+        assert!(estimate_multipart_size(&parts) > 30 * 1024 * 1024);
+    } else {
+        panic!("not multipart body")
+    }
+}
+
+#[tokio::test]
+async fn test_estimate_multipart_size_matches_content_length() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.subject = Some("Some subject".to_string());
+    request_body.inline_images = Some(vec!["tests/image.png".to_string()]);
+
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+    let estimated = match client.prepare_send(request_body.clone()).unwrap().body {
+        PreparedBody::Multipart(parts) => estimate_multipart_size(&parts),
+        _ => panic!("not multipart body"),
+    };
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .header("content-length", estimated.to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+    client.send(request_body).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_once_rejects_duplicate() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+
+    let client = EmailClient::with_configuration(
+        get_test_configuration(&server.base_url()).with_send_once(10),
+    );
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+    request_body.message_id = Some("some-message-id".to_string());
+
+    client.send_once(request_body.clone()).await.unwrap();
+    let error = client.send_once(request_body.clone()).await.unwrap_err();
+
+    if let SdkError::DuplicateSend(bulk_id, message_id) = error {
+        assert_eq!(bulk_id.unwrap(), "some-bulk-id");
+        assert_eq!(message_id.unwrap(), "some-message-id");
+    } else {
+        panic!("not duplicate send error")
+    }
+
+    request_body.message_id = Some("other-message-id".to_string());
+    client.send_once(request_body).await.unwrap();
+
+    mock.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn test_send_once_after_local_rejection() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+
+    let client = EmailClient::with_configuration(
+        get_test_configuration(&server.base_url())
+            .with_send_once(10)
+            .with_max_multipart_body_size(1024),
+    );
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+    request_body.message_id = Some("some-message-id".to_string());
+    request_body.html = Some("a".repeat(2048));
+
+    let error = client.send_once(request_body.clone()).await.unwrap_err();
+    assert!(matches!(error, SdkError::PayloadTooLarge { .. }));
+
+    request_body.html = Some("Hello world!".to_string());
+    client.send_once(request_body).await.unwrap();
+
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_get_suppressions_all_pages() {
+    let server = httpmock::MockServer::start_async().await;
+    let page_bodies = [
+        (
+            0,
+            r#"[{"emailAddress": "one@example.com"}, {"emailAddress": "two@example.com"}]"#,
+        ),
+        (1, r#"[{"emailAddress": "three@example.com"}]"#),
+    ];
+    let mocks: Vec<_> = page_bodies
+        .iter()
+        .map(|(page, results)| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path(PATH_GET_SUPPRESSIONS)
+                    .query_param("domainName", "example.com")
+                    .query_param("type", "UNSUBSCRIBE")
+                    .query_param("page", page.to_string());
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{"paging": {{"page": {}, "size": 2, "totalPages": 2, "totalResults": 3}}, "results": {}}}"#,
+                        page, results
+                    ));
+            })
+        })
+        .collect();
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let pages = crate::api::fetch_all_pages(0, |page| {
+        let mut query_parameters =
+            GetSuppressionsQueryParameters::new("example.com", SuppressionType::UNSUBSCRIBE);
+        query_parameters.page = Some(page);
+
+        client.get_suppressions(query_parameters)
+    })
+    .await
+    .unwrap();
+
+    let addresses: Vec<String> = pages
+        .into_iter()
+        .flat_map(|page| page.results.unwrap())
+        .map(|suppression| suppression.email_address.unwrap())
+        .collect();
+    assert_eq!(
+        addresses,
+        vec!["one@example.com", "two@example.com", "three@example.com"]
+    );
+    for mock in mocks {
+        mock.assert_hits_async(1).await;
+    }
+}
+
+#[tokio::test]
+async fn test_get_suppressions_no_domain() {
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let error = client
+        .get_suppressions(GetSuppressionsQueryParameters::new(
+            "",
+            SuppressionType::UNSUBSCRIBE,
+        ))
+        .await
+        .unwrap_err();
+
+    if let SdkError::Validation(validation_error) = error {
+        assert!(!validation_error.errors().is_empty());
+    } else {
+        panic!("not validation error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_recipients_with_placeholders() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains(r#"{"placeholders":{"name":"John"},"to":"john@company.com"}"#)
+            .body_contains(r#"{"placeholders":{"name":"Jane"},"to":"jane@company.com"}"#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+                  "bulkId": "some-bulk-id",
+                  "messages": [
+                    {"to": "john@company.com", "messageId": "john-message-id"},
+                    {"to": "jane@company.com", "messageId": "jane-message-id"}
+                  ]
+                }"#,
+            );
+    });
+
+    let mut john = EmailRecipient::new("john@company.com");
+    john.placeholders
+        .insert("name".to_string(), "John".to_string());
+    let mut jane = EmailRecipient::new("jane@company.com");
+    jane.placeholders
+        .insert("name".to_string(), "Jane".to_string());
+    let recipients = vec![john, jane];
+
+    let mut request_body = SendRequestBody::new("");
+    request_body.template_id = Some(2);
+    request_body.recipients = Some(recipients.clone());
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+    let message_ids: Vec<_> = response
+        .body
+        .details_for(&recipients)
+        .into_iter()
+        .map(|(recipient, details)| {
+            (
+                recipient.to.as_str(),
+                details.unwrap().message_id.as_deref().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        message_ids,
+        vec![
+            ("john@company.com", "john-message-id"),
+            ("jane@company.com", "jane-message-id")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_send_recipients_with_callback_data() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains(
+                r#"{"callbackData":"tenant-a","messageId":"john-message-id","to":"john@company.com"}"#,
+            )
+            .body_contains(
+                r#"{"callbackData":"tenant-b","messageId":"jane-message-id","to":"jane@company.com"}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+                  "bulkId": "some-bulk-id",
+                  "messages": [
+                    {"to": "john@company.com", "messageId": "john-message-id"},
+                    {"to": "jane@company.com", "messageId": "jane-message-id"}
+                  ]
+                }"#,
+            );
+    });
+
+    let mut john = EmailRecipient::new("john@company.com");
+    john.message_id = Some("john-message-id".to_string());
+    john.callback_data = Some("tenant-a".to_string());
+    let mut jane = EmailRecipient::new("jane@company.com");
+    jane.message_id = Some("jane-message-id".to_string());
+    jane.callback_data = Some("tenant-b".to_string());
+
+    let mut request_body = SendRequestBody::new("");
+    request_body.subject = Some("Some subject".to_string());
+    request_body.text = Some("Some text".to_string());
+    request_body.recipients = Some(vec![john, jane]);
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_send_with_api_version_override() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        "/email/4/send",
+        r#"{"bulkId": "some-bulk-id", "messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let configuration = get_test_configuration(&server.base_url())
+        .with_api_version_override(crate::configuration::ApiChannel::Email, 4);
+    let client = EmailClient::with_configuration(configuration);
+
+    let response = client
+        .send(SendRequestBody::new("some@mail.com"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[test]
+fn test_prepare_send_with_api_version_override() {
+    let configuration = get_test_configuration(DUMMY_BASE_URL)
+        .with_api_version_override(crate::configuration::ApiChannel::Email, 4);
+    let client = EmailClient::with_configuration(configuration);
+
+    let prepared = client
+        .prepare_send(SendRequestBody::new("some@mail.com"))
+        .unwrap();
+
+    assert_eq!(prepared.url, format!("{}/email/4/send", DUMMY_BASE_URL));
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_default_limit() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param(
+                "limit",
+                GetDeliveryReportsQueryParameters::DEFAULT_LIMIT.to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_reports_cursor_serializes_concurrent_batches() {
+    let delay = std::time::Duration::from_millis(300);
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", "10");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(delay)
+            .body(r#"{"results": [{"messageId": "one"}]}"#);
+    });
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+    let cursor = std::sync::Arc::new(client.reports_cursor());
+
+    let started = std::time::Instant::now();
+    let first = {
+        let cursor = cursor.clone();
+        tokio::spawn(async move { cursor.next_batch(10).await })
+    };
+    let second = {
+        let cursor = cursor.clone();
+        tokio::spawn(async move { cursor.drain_all(10).await })
+    };
+
+    assert_eq!(first.await.unwrap().unwrap().len(), 1);
+    assert_eq!(second.await.unwrap().unwrap().len(), 1);
+    assert!(started.elapsed() >= delay * 2);
+    assert_eq!(cursor.fetched(), 2);
+    mock.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn test_send_one_click_unsubscribe_headers() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("name=\"headers\"")
+            .body_contains(r#"{"List-Unsubscribe":"<mailto:unsubscribe@company.com>, <https://company.com/unsubscribe>","List-Unsubscribe-Post":"List-Unsubscribe=One-Click"}"#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": []}"#);
+    });
+
+    let request_body = SendRequestBody::new("someone@company.com")
+        .with_one_click_unsubscribe(
+            "unsubscribe@company.com",
+            "https://company.com/unsubscribe",
+            false,
+        )
+        .unwrap();
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_send_client_priority() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("name=\"clientPriority\"\r\n\r\nHIGH\r\n");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": []}"#);
+    });
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.subject = Some("Reset your password".to_string());
+    request_body.text = Some("Some text".to_string());
+    request_body.client_priority = Some(ClientPriority::High);
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+}
+
+fn mock_logs_page<'a>(
+    server: &'a httpmock::MockServer,
+    sent_until: &str,
+    logs: &[(&str, &str)],
+) -> httpmock::Mock<'a> {
+    let results: Vec<String> = logs
+        .iter()
+        .map(|(message_id, sent_at)| {
+            format!(
+                r#"{{"messageId": "{}", "to": "john@example.com", "sentAt": "{}", "text": "Hi, \"John\"\nBye"}}"#,
+                message_id, sent_at
+            )
+        })
+        .collect();
+    let body = format!(r#"{{"results": [{}]}}"#, results.join(","));
+
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("limit", "3")
+            .query_param("sentUntil", sent_until);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    })
+}
+
+#[tokio::test]
+async fn export_logs_csv() {
+    let server = httpmock::MockServer::start_async().await;
+    let mocks = vec![
+        mock_logs_page(
+            &server,
+            "2023-01-01T12:00:00.000+0000",
+            &[
+                ("a", "2023-01-01T11:05:00.000+0000"),
+                ("b", "2023-01-01T11:04:00.000+0000"),
+                ("c", "2023-01-01T11:03:00.000+0000"),
+            ],
+        ),
+        mock_logs_page(
+            &server,
+            "2023-01-01T11:03:00.000+0000",
+            &[
+                ("c", "2023-01-01T11:03:00.000+0000"),
+                ("d", "2023-01-01T11:02:00.000+0000"),
+                ("e", "2023-01-01T11:01:00.000+0000"),
+            ],
+        ),
+        mock_logs_page(
+            &server,
+            "2023-01-01T11:01:00.000+0000",
+            &[
+                ("e", "2023-01-01T11:01:00.000+0000"),
+                ("f", "2023-01-01T11:00:00.000+0000"),
+            ],
+        ),
+    ];
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_until = Some("2023-01-01T12:00:00.000+0000".to_string());
+    query_parameters.limit = Some(3);
+
+    let mut buffer = Vec::new();
+    let mut reported_pages = Vec::new();
+    let progress = client
+        .export_logs(
+            query_parameters,
+            &mut buffer,
+            crate::api::export::ExportFormat::Csv,
+            |progress| reported_pages.push(progress.pages),
+        )
+        .await
+        .unwrap();
+
+    for mock in mocks {
+        mock.assert_hits(1);
+    }
+    assert_eq!(reported_pages, vec![1, 2, 3]);
+    assert_eq!(progress.rows, 6);
+    assert_eq!(
+        progress.last_sent_at.as_deref(),
+        Some("2023-01-01T11:00:00.000+0000")
+    );
+
+    let csv = String::from_utf8(buffer).unwrap();
+    assert!(csv.starts_with(
+        "messageId,bulkId,from,to,sentAt,doneAt,messageCount,status,pricePerMessage,currency,text\n"
+    ));
+    assert!(csv.contains("\"Hi, \"\"John\"\"\nBye\"\n"));
+    assert_eq!(csv.matches("john@example.com").count(), 6);
+}
+
+#[tokio::test]
+async fn test_update_scheduled_status_finished_rejected() {
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let query_parameters = UpdateScheduledStatusQueryParameters::new("bulk-id");
+    let request_body = UpdateScheduledStatusRequestBody::new(BulkStatus::FINISHED);
+
+    let error = client
+        .update_scheduled_status(query_parameters, request_body)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_get_message_content_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("messageId", "MSG/TEST 123&x")
+            .query_param("includeContent", "true");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"results": [{"messageId": "MSG/TEST 123&x", "subject": "Hi",
+                "html": "<p>Hi \u003cb\u003ethere\u003c/b\u003e &amp; welcome</p>"}]}"#,
+            );
+    });
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.get_message_content("MSG/TEST 123&x").await.unwrap();
+    let log = response.body.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(log.subject.as_deref(), Some("Hi"));
+    assert_eq!(
+        log.html.as_deref(),
+        Some("<p>Hi <b>there</b> &amp; welcome</p>")
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_content_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_LOGS,
+        r#"{"results": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.get_message_content("some-message-id").await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(response.body.is_none());
+}
+
+#[tokio::test]
+async fn create_template_valid() {
+    let expected_response = r#"
+    {
+      "id": 1234,
+      "name": "welcome",
+      "subject": "Welcome {{name}}!",
+      "createdAt": "2023-05-05T17:32:28.777+0000",
+      "updatedAt": "2023-05-05T17:32:28.777+0000"
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_CREATE_TEMPLATE,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = CreateTemplateRequestBody::new("welcome", "Welcome {{name}}!");
+    request_body.html = Some("<p>Hello {{name}}</p>".to_string());
+
+    let response = client.create_template(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.id, Some(1234));
+    assert!(response.body.created_at.is_some());
+}
+
+#[tokio::test]
+async fn create_template_empty_name() {
+    let client = EmailClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut request_body = CreateTemplateRequestBody::new("", "Welcome!");
+    request_body.text = Some("Hello".to_string());
+
+    let error = client.create_template(request_body).await.unwrap_err();
+
+    if let SdkError::Validation(errors) = error {
+        assert!(errors.field_errors().contains_key("name"));
+    } else {
+        panic!("not validation error")
+    }
+}
+
+#[tokio::test]
+async fn update_template_valid() {
+    let path = PATH_UPDATE_TEMPLATE.replace("{templateId}", "1234");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        path.as_str(),
+        r#"{"id": 1234, "name": "welcome", "updatedAt": "2023-05-06T10:00:00.000+0000"}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = UpdateTemplateRequestBody::new();
+    request_body.subject = Some("Welcome aboard!".to_string());
+
+    let response = client.update_template(1234, request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.id, Some(1234));
+}
+
+#[tokio::test]
+async fn delete_template_valid() {
+    let path = PATH_DELETE_TEMPLATE.replace("{templateId}", "1234");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::DELETE,
+        path.as_str(),
+        "",
+        reqwest::StatusCode::NO_CONTENT,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let status = client.delete_template(1234).await.unwrap();
+
+    assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
+}
+
+fn get_full_send_policy() -> SendPolicy {
+    SendPolicy {
+        forbid_cc_bcc: true,
+        require_template: true,
+        allowed_from_domains: vec!["company.com".to_string()],
+    }
+}
+
+fn get_policy_compliant_request_body() -> SendRequestBody {
+    let mut request_body = SendRequestBody::new("someone@customer.com");
+    request_body.from = Some("Company <noreply@Company.com>".to_string());
+    request_body.template_id = Some(1234);
+    request_body
+}
+
+#[tokio::test]
+async fn test_send_policy_violations() {
+    let client = EmailClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_send_policy(get_full_send_policy()),
+    );
+
+    let mut with_cc = get_policy_compliant_request_body();
+    with_cc.cc = Some("manager@company.com".to_string());
+    let mut with_bcc = get_policy_compliant_request_body();
+    with_bcc.bcc = Some("archive@company.com".to_string());
+    let mut without_template = get_policy_compliant_request_body();
+    without_template.template_id = None;
+    without_template.text = Some("Some text".to_string());
+    let mut other_domain = get_policy_compliant_request_body();
+    other_domain.from = Some("noreply@other.com".to_string());
+    let mut without_from = get_policy_compliant_request_body();
+    without_from.from = None;
+
+    let cases = [
+        (with_cc, "forbid_cc_bcc", "cc"),
+        (with_bcc, "forbid_cc_bcc", "bcc"),
+        (without_template, "require_template", "templateId"),
+        (other_domain, "allowed_from_domains", "other.com"),
+        (without_from, "allowed_from_domains", "not set"),
+    ];
+
+    for (request_body, expected_rule, expected_detail) in cases {
+        match client.send(request_body).await.unwrap_err() {
+            SdkError::PolicyViolation { rule, detail } => {
+                assert_eq!(rule, expected_rule);
+                assert!(detail.contains(expected_detail), "{}", detail);
+            }
+            error => panic!("not a policy violation: {:?}", error),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_send_policy_runs_after_validation() {
+    let client = EmailClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_send_policy(get_full_send_policy()),
+    );
+
+    let mut request_body = get_policy_compliant_request_body();
+    request_body.to = "".to_string();
+    request_body.cc = Some("manager@company.com".to_string());
+
+    assert!(matches!(
+        client.send(request_body).await.unwrap_err(),
+        SdkError::Validation(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_send_policy_compliant_request() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"bulkId": "some-bulk-id", "messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()))
+        .with_request_options(&RequestOptions::new().with_send_policy(get_full_send_policy()));
+
+    let response = client
+        .send(get_policy_compliant_request_body())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+async fn mock_report_for_message(body: &'static str) -> (httpmock::MockServer, EmailClient) {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("messageId", "some-message-id")
+            .query_param("limit", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_found() {
+    let (_server, client) =
+        mock_report_for_message(r#"{"results": [{"messageId": "some-message-id"}]}"#).await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("some-message-id"));
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_not_yet_available() {
+    let (_server, client) = mock_report_for_message(r#"{"results": []}"#).await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap();
+
+    assert!(report.is_none());
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_takes_first() {
+    let (_server, client) = mock_report_for_message(
+        r#"{"results": [{"messageId": "some-message-id"}, {"messageId": "other-message-id"}]}"#,
+    )
+    .await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("some-message-id"));
+}
+
+#[tokio::test]
+async fn test_get_attachments() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/email/1/messages/some-message-id/attachments");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+                  "messageId": "some-message-id",
+                  "attachments": [{
+                    "attachmentId": "some-attachment-id",
+                    "filename": "invoice.pdf",
+                    "contentType": "application/pdf",
+                    "size": 6
+                  }]
+                }"#,
+            );
+    });
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client.get_attachments("some-message-id").await.unwrap();
+
+    let attachment = &response.body.attachments.unwrap()[0];
+    assert_eq!(
+        attachment.attachment_id.as_deref(),
+        Some("some-attachment-id")
+    );
+    assert_eq!(attachment.file_name.as_deref(), Some("invoice.pdf"));
+    assert_eq!(attachment.content_type.as_deref(), Some("application/pdf"));
+    assert_eq!(attachment.size, Some(6));
+}
+
+#[tokio::test]
+async fn test_download_attachment_binary_body() {
+    let content: &[u8] = &[0x25, 0x50, 0x44, 0x46, 0x00, 0xff, 0xfe, 0x80, 0x0a];
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/email/1/messages/some-message-id/attachments/some-attachment-id")
+            .header("accept", "application/octet-stream");
+        then.status(200)
+            .header("content-type", "application/pdf")
+            .body(content);
+    });
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .download_attachment("some-message-id", "some-attachment-id")
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.as_ref(), content);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_download_attachment_encodes_ids() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/email/1/messages/a%2F..%2Fb/attachments/c%3Fd%23e");
+        then.status(200).body("content");
+    });
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    client.download_attachment("a/../b", "c?d#e").await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_download_attachment_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/email/1/messages/some-message-id/attachments/some-attachment-id",
+        r#"{"requestError": {"serviceException": {"messageId": "NOT_FOUND", "text": "Attachment not found"}}}"#,
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+    let client = EmailClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client
+        .download_attachment("some-message-id", "some-attachment-id")
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::ApiRequestError(api_error) => {
+            assert_eq!(api_error.status, reqwest::StatusCode::NOT_FOUND)
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_download_attachment_response_too_large() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/email/1/messages/some-message-id/attachments/some-attachment-id");
+        then.status(200).body(vec![0u8; 2048]);
+    });
+    let client = EmailClient::with_configuration(
+        get_test_configuration(&server.base_url()).with_max_response_body_size(1024),
+    );
+
+    let error = client
+        .download_attachment("some-message-id", "some-attachment-id")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::ResponseTooLarge { .. }));
+}
+
+#[tokio::test]
+async fn test_send_default_from() {
+    let server = httpmock::MockServer::start_async().await;
+    let default_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("noreply@company.com");
+        then.status(200).body(r#"{"messages": []}"#);
+    });
+    let explicit_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("sales@company.com");
+        then.status(200).body(r#"{"messages": []}"#);
+    });
+    let client = EmailClient::with_configuration(
+        get_test_configuration(&server.base_url())
+            .with_default_email_from("Company <noreply@company.com>")
+            .with_send_policy(get_full_send_policy()),
+    );
+
+    let mut request_body = get_policy_compliant_request_body();
+    request_body.from = None;
+    client.send(request_body.clone()).await.unwrap();
+    assert_eq!(request_body.from, None);
+
+    request_body.from = Some("sales@company.com".to_string());
+    client.send(request_body).await.unwrap();
+
+    default_mock.assert_hits_async(1).await;
+    explicit_mock.assert_hits_async(1).await;
+}
+
+#[test]
+fn test_prepare_send_default_from() {
+    let configuration = get_test_configuration(DUMMY_BASE_URL);
+    let request_body = SendRequestBody::new("someone@customer.com");
+    let part_names =
+        |client: &EmailClient| match client.prepare_send(request_body.clone()).unwrap().body {
+            PreparedBody::Multipart(parts) => parts
+                .into_iter()
+                .map(|part| (part.name, part.size))
+                .collect::<Vec<_>>(),
+            body => panic!("not a multipart body: {:?}", body),
+        };
+
+    let with_default = EmailClient::with_configuration(
+        configuration
+            .clone()
+            .with_default_email_from("noreply@company.com"),
+    );
+    assert_eq!(
+        part_names(&with_default),
+        vec![("to".to_string(), 20), ("from".to_string(), 19)]
+    );
+
+    let without_default = EmailClient::with_configuration(configuration);
+    assert_eq!(part_names(&without_default), vec![("to".to_string(), 20)]);
+}
+
+#[tokio::test]
+async fn test_send_without_default_from() {
+    let client = EmailClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_send_policy(get_full_send_policy()),
+    );
+    assert_eq!(client.configuration.default_email_from(), None);
+
+    let mut request_body = get_policy_compliant_request_body();
+    request_body.from = None;
+
+    match client.send(request_body).await.unwrap_err() {
+        SdkError::PolicyViolation { rule, detail } => {
+            assert_eq!(rule, "allowed_from_domains");
+            assert!(detail.contains("not set"), "{}", detail);
+        }
+        error => panic!("not a policy violation: {:?}", error),
+    }
+}
@@ -0,0 +1,439 @@
+use httpmock::Method::{DELETE, GET, POST, PUT};
+use serde_json::json;
+
+use crate::api::tests::harness::{ExpectedCall, Harness};
+
+#[cfg(feature = "email")]
+mod email {
+    use super::*;
+    use crate::api::email::EmailClient;
+    use crate::model::email::*;
+
+    const BULK_RESPONSE: &str = r#"{"bulkId": "some-bulk-id", "status": "PAUSED"}"#;
+
+    #[tokio::test]
+    async fn send() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/email/3/send")
+                    .body_contains("name=\"to\"\r\n\r\nsomeone@company.com")
+                    .body_contains("name=\"subject\"\r\n\r\nSome subject")
+                    .responds(200, r#"{"bulkId": "some-bulk-id", "messages": []}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        let mut request_body = SendRequestBody::new("someone@company.com");
+        request_body.from = Some("sender@company.com".to_string());
+        request_body.subject = Some("Some subject".to_string());
+        request_body.text = Some("Some text".to_string());
+        client.send(request_body).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_bulks() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/bulks")
+                    .query_param("bulkId", "some-bulk-id")
+                    .responds(200, r#"{"externalBulkId": "some-bulk-id", "bulks": []}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .get_bulks(GetBulksQueryParameters::new("some-bulk-id"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn reschedule() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(PUT, "/email/1/bulks")
+                    .query_param("bulkId", "some-bulk-id")
+                    .json_body(json!({"sendAt": "2030-01-01T10:00:00.000+0000"}))
+                    .responds(
+                        200,
+                        r#"{"bulkId": "some-bulk-id", "sendAt": "2030-01-01T10:00:00.000+0000"}"#,
+                    ),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .reschedule(
+                RescheduleQueryParameters::new("some-bulk-id"),
+                RescheduleRequestBody::new("2030-01-01T10:00:00.000+0000"),
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_scheduled_messages() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/bulks/messages")
+                    .query_param("bulkId", "some-bulk-id")
+                    .responds(200, "{}"),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .get_scheduled_messages(GetScheduledMessagesQueryParameters::new("some-bulk-id"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_scheduled_status() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/bulks/status")
+                    .query_param("bulkId", "some-bulk-id")
+                    .responds(200, BULK_RESPONSE),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .get_scheduled_status(GetScheduledStatusQueryParameters::new("some-bulk-id"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn update_scheduled_status() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(PUT, "/email/1/bulks/status")
+                    .query_param("bulkId", "some-bulk-id")
+                    .json_body(json!({"status": "PAUSED"}))
+                    .responds(200, BULK_RESPONSE),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .update_scheduled_status(
+                UpdateScheduledStatusQueryParameters::new("some-bulk-id"),
+                UpdateScheduledStatusRequestBody::new(BulkStatus::PAUSED),
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_delivery_reports() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/reports")
+                    .query_param("bulkId", "some-bulk-id")
+                    .query_param("limit", "50")
+                    .responds(200, r#"{"results": []}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+        query_parameters.bulk_id = Some("some-bulk-id".to_string());
+        client.get_delivery_reports(query_parameters).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_logs() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/logs")
+                    .query_param("messageId", "some-message-id")
+                    .responds(200, r#"{"results": []}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        let mut query_parameters = GetLogsQueryParameters::new();
+        query_parameters.message_id = Some("some-message-id".to_string());
+        client.get_logs(query_parameters).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_attachments() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/messages/some-message-id/attachments").responds(
+                    200,
+                    r#"{"messageId": "some-message-id", "attachments": []}"#,
+                ),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client.get_attachments("some-message-id").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn validate_address() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/email/2/validation")
+                    .json_body(json!({"to": "someone@company.com"}))
+                    .responds(
+                        200,
+                        r#"{"to": "someone@company.com", "validMailbox": "true"}"#,
+                    ),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .validate_address(ValidateAddressRequestBody::new("someone@company.com"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_domains() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/domains")
+                    .query_param("page", "2")
+                    .responds(200, r#"{"results": []}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        let mut query_parameters = GetDomainsQueryParameters::new();
+        query_parameters.page = Some(2);
+        client.get_domains(query_parameters).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn add_domain() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/email/1/domains")
+                    .json_body(json!({"domainName": "example.com"}))
+                    .responds(200, r#"{"domainName": "example.com"}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client
+            .add_domain(AddDomainRequestBody::new("example.com"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_domain() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/email/1/domains/example.com")
+                    .responds(200, r#"{"domainName": "example.com"}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client.get_domain("example.com").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn delete_domain() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(ExpectedCall::new(DELETE, "/email/1/domains/example.com").responds(204, ""))
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client.delete_domain("example.com").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn update_tracking() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(PUT, "/email/1/domains/example.com/tracking")
+                    .json_body(json!({"open": true}))
+                    .responds(200, r#"{"domainName": "example.com"}"#),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        let mut request_body = UpdateTrackingRequestBody::new();
+        request_body.opens = Some(true);
+        client
+            .update_tracking("example.com", request_body)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn verify_domain() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/email/1/domains/example.com/verify").responds(202, ""),
+            )
+            .await;
+        let client = EmailClient::with_configuration(harness.configuration());
+
+        client.verify_domain("example.com").await.unwrap();
+
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(feature = "sms")]
+mod sms {
+    use super::*;
+    use crate::api::sms::SmsClient;
+    use crate::model::sms::*;
+
+    #[tokio::test]
+    async fn send() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/sms/2/text/advanced")
+                    .json_body(json!({
+                        "messages": [{"destinations": [{"to": "41793026727"}], "text": "Hi"}]
+                    }))
+                    .responds(200, r#"{"bulkId": "some-bulk-id", "messages": []}"#),
+            )
+            .await;
+        let client = SmsClient::with_configuration(harness.configuration());
+
+        let mut message = Message::new(vec![Destination::new("41793026727")]);
+        message.text = Some("Hi".to_string());
+        client
+            .send(SendRequestBody::new(vec![message]))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn preview() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(POST, "/sms/1/preview")
+                    .json_body(json!({"text": "Hi"}))
+                    .responds(200, r#"{"originalText": "Hi", "previews": []}"#),
+            )
+            .await;
+        let client = SmsClient::with_configuration(harness.configuration());
+
+        client.preview(PreviewRequestBody::new("Hi")).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_delivery_reports() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/sms/1/reports")
+                    .query_param("messageId", "some-message-id")
+                    .query_param("limit", "50")
+                    .responds(200, r#"{"results": []}"#),
+            )
+            .await;
+        let client = SmsClient::with_configuration(harness.configuration());
+
+        let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+        query_parameters.message_id = Some("some-message-id".to_string());
+        client.get_delivery_reports(query_parameters).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_logs() {
+        let harness = Harness::start().await;
+        let mock = harness
+            .expect(
+                ExpectedCall::new(GET, "/sms/1/logs")
+                    .query_param("bulkId", "some-bulk-id")
+                    .responds(200, r#"{"results": []}"#),
+            )
+            .await;
+        let client = SmsClient::with_configuration(harness.configuration());
+
+        let mut query_parameters = GetLogsQueryParameters::new();
+        query_parameters.bulk_id = Some("some-bulk-id".to_string());
+        client.get_logs(query_parameters).await.unwrap();
+
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(any(feature = "sms", feature = "whatsapp"))]
+#[test]
+fn fill_path_encodes_parameters() {
+    use crate::api::fill_path;
+
+    let path = "/some/{first}/path/{second}";
+
+    assert_eq!(
+        fill_path(path, &[("first", "a-b.c_d~e"), ("second", "f g")]),
+        "/some/a-b.c_d~e/path/f%20g"
+    );
+    assert_eq!(
+        fill_path(path, &[("first", "a/b?c#d"), ("second", "..")]),
+        "/some/a%2Fb%3Fc%23d/path/%2E%2E"
+    );
+    assert_eq!(
+        fill_path(path, &[("first", ".")]),
+        "/some/%2E/path/{second}"
+    );
+}
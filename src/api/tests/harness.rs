@@ -0,0 +1,117 @@
+//! Shared harness for endpoint tests. Every expected call checks the method, the path and the
+//! authorization header of the request, plus the query parameters and body given to it, so a
+//! client method calling the wrong endpoint, or calling it the wrong way, fails its test. New
+//! client methods get a test in `endpoints.rs` built on it.
+
+use httpmock::{Method, Mock, MockServer};
+use serde_json::Value;
+
+use crate::api::tests::get_test_configuration;
+use crate::configuration::Configuration;
+
+/// Authorization header sent with the configuration of `Harness::configuration`.
+pub(crate) const TEST_AUTHORIZATION: &str = "App some-api-key";
+
+/// Request expected by a test, and the response returned for it.
+pub(crate) struct ExpectedCall {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    json_body: Option<Value>,
+    body_parts: Vec<String>,
+    status: u16,
+    response: String,
+}
+
+impl ExpectedCall {
+    pub(crate) fn new(method: Method, path: &str) -> Self {
+        ExpectedCall {
+            method,
+            path: path.to_string(),
+            query: Vec::new(),
+            headers: Vec::new(),
+            json_body: None,
+            body_parts: Vec::new(),
+            status: 200,
+            response: "{}".to_string(),
+        }
+    }
+
+    /// Expects the query parameter in the query string of the request.
+    pub(crate) fn query_param(mut self, name: &str, value: &str) -> Self {
+        self.query.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expects the header in the request, besides the authorization header.
+    pub(crate) fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expects a JSON body equal to `body`.
+    pub(crate) fn json_body(mut self, body: Value) -> Self {
+        self.json_body = Some(body);
+        self
+    }
+
+    /// Expects `part` in the body, e.g. a form field of a multipart body.
+    pub(crate) fn body_contains(mut self, part: &str) -> Self {
+        self.body_parts.push(part.to_string());
+        self
+    }
+
+    /// Answers the request with `status` and the JSON `body`.
+    pub(crate) fn responds(mut self, status: u16, body: &str) -> Self {
+        self.status = status;
+        self.response = body.to_string();
+        self
+    }
+}
+
+/// Mock server answering the calls expected by a test.
+pub(crate) struct Harness {
+    server: MockServer,
+}
+
+impl Harness {
+    pub(crate) async fn start() -> Self {
+        Harness {
+            server: MockServer::start_async().await,
+        }
+    }
+
+    /// Returns the configuration of clients calling this harness.
+    pub(crate) fn configuration(&self) -> Configuration {
+        get_test_configuration(&self.server.base_url())
+    }
+
+    /// Registers the call, returning the mock to assert it was made with `assert_async`.
+    pub(crate) async fn expect(&self, call: ExpectedCall) -> Mock<'_> {
+        self.server
+            .mock_async(|when, then| {
+                let mut when = when
+                    .method(call.method)
+                    .path(call.path)
+                    .header("authorization", TEST_AUTHORIZATION);
+                for (name, value) in call.query {
+                    when = when.query_param(name, value);
+                }
+                for (name, value) in call.headers {
+                    when = when.header(name, value);
+                }
+                if let Some(body) = call.json_body {
+                    when = when.json_body(body);
+                }
+                for part in call.body_parts {
+                    when = when.body_contains(part);
+                }
+
+                then.status(call.status)
+                    .header("content-type", "application/json")
+                    .body(call.response);
+            })
+            .await
+    }
+}
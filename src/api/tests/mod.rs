@@ -1,18 +1,73 @@
 #![cfg(test)]
+// Helpers shared by the channel tests are only all used when every channel is compiled.
+#![cfg_attr(
+    not(all(
+        feature = "sms",
+        feature = "email",
+        feature = "whatsapp",
+        feature = "voice"
+    )),
+    allow(dead_code, unused_imports)
+)]
 
 use httpmock::prelude::*;
 
 use crate::configuration::{ApiKey, Configuration};
 
 #[cfg(test)]
+#[cfg(feature = "sms")]
+mod client;
+
+#[cfg(test)]
+#[cfg(feature = "sms")]
 mod sms;
 
 #[cfg(test)]
+#[cfg(feature = "whatsapp")]
 mod whatsapp;
 
 #[cfg(test)]
+#[cfg(feature = "email")]
 mod email;
 
+#[cfg(test)]
+#[cfg(feature = "sms")]
+mod numbers;
+
+#[cfg(test)]
+#[cfg(feature = "voice")]
+mod voice;
+
+#[cfg(test)]
+#[cfg(feature = "sms")]
+mod polling;
+
+#[cfg(test)]
+#[cfg(feature = "sms")]
+mod diagnostics;
+
+#[cfg(test)]
+#[cfg(feature = "sms")]
+mod webhook;
+
+#[cfg(test)]
+#[cfg(all(feature = "axum", feature = "sms"))]
+mod axum;
+
+#[cfg(test)]
+#[cfg(any(feature = "sms", feature = "email"))]
+mod results;
+
+#[cfg(test)]
+#[cfg(any(feature = "sms", feature = "email"))]
+mod signing;
+
+#[cfg(test)]
+mod harness;
+
+#[cfg(test)]
+mod endpoints;
+
 const DUMMY_TEXT: &str = "Some text for tests.";
 
 async fn mock_json_endpoint(
@@ -0,0 +1,191 @@
+use httpmock::prelude::*;
+
+use crate::api::numbers::*;
+use crate::api::tests::{get_test_configuration, mock_json_endpoint};
+use crate::api::SdkError;
+use crate::model::numbers::*;
+
+const DUMMY_BASE_URL: &str = "https://some.url";
+
+#[tokio::test]
+async fn get_numbers_valid() {
+    let expected_response = r#"
+    {
+      "numbers": [
+        {
+          "numberKey": "8F0792F86035A9F4290821F1EE6BC06A",
+          "number": "447860041117",
+          "country": "GB",
+          "countryName": "United Kingdom",
+          "type": "VIRTUAL_LONG_NUMBER",
+          "capabilities": ["SMS", "VOICE"],
+          "shared": false
+        }
+      ],
+      "numberCount": 1
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_NUMBERS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = NumbersClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_numbers(GetNumbersQueryParameters::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.number_count, Some(1));
+    assert_eq!(response.body.numbers.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn get_numbers_bad_parameters() {
+    let client = NumbersClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut query_parameters = GetNumbersQueryParameters::new();
+    query_parameters.limit = Some(0);
+
+    let error = client.get_numbers(query_parameters).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn get_number_valid() {
+    let expected_response = r#"
+    {
+      "numberKey": "8F0792F86035A9F4290821F1EE6BC06A",
+      "number": "447860041117",
+      "country": "GB",
+      "countryName": "United Kingdom",
+      "type": "VIRTUAL_LONG_NUMBER",
+      "capabilities": ["SMS"]
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/numbers/1/numbers/8F0792F86035A9F4290821F1EE6BC06A",
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = NumbersClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_number("8F0792F86035A9F4290821F1EE6BC06A")
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.capabilities,
+        Some(vec![NumberCapability::Sms])
+    );
+}
+
+#[tokio::test]
+async fn get_number_encodes_number_key() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/numbers/1/numbers/a%2F..%2Fb");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("{}");
+        })
+        .await;
+
+    let client = NumbersClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    client.get_number("a/../b").await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn update_number_sms_configuration_valid() {
+    let expected_response = r#"
+    {
+      "keyword": "PROMO",
+      "forwarding": {
+        "type": "HTTP_FORWARD_POST",
+        "url": "https://example.com/inbound"
+      }
+    }
+    "#;
+
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(PUT)
+                .path("/numbers/2/numbers/8F0792F86035A9F4290821F1EE6BC06A/sms")
+                .body_contains(r#""keyword":"PROMO""#)
+                .body_contains(r#""type":"HTTP_FORWARD_POST""#);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(expected_response);
+        })
+        .await;
+
+    let client = NumbersClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = NumberSmsConfiguration::new();
+    request_body.keyword = Some("PROMO".to_string());
+    request_body.forwarding = Some(SmsForwarding::http_post("https://example.com/inbound"));
+
+    let response = client
+        .update_number_sms_configuration("8F0792F86035A9F4290821F1EE6BC06A", request_body.clone())
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body, request_body);
+}
+
+#[tokio::test]
+async fn update_number_sms_configuration_missing_url() {
+    let client = NumbersClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut request_body = NumberSmsConfiguration::new();
+    request_body.forwarding = Some(SmsForwarding {
+        forward_type: SmsForwardType::HttpForwardPost,
+        url: None,
+    });
+
+    let error = client
+        .update_number_sms_configuration("some-key", request_body)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn get_number_opt_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/numbers/1/numbers/some-number-key",
+        "",
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+
+    let client = NumbersClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    assert!(client
+        .get_number_opt("some-number-key")
+        .await
+        .unwrap()
+        .is_none());
+}
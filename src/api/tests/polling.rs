@@ -0,0 +1,125 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::api::polling::*;
+use crate::model::sms::Status;
+use crate::model::DeliveryStatus;
+
+const INTERVAL: Duration = Duration::from_millis(10);
+
+// Returns the statuses in order, one per fetch, repeating the last one once exhausted.
+struct FakeStatusSource {
+    statuses: Vec<Option<Status>>,
+    fetches: AtomicUsize,
+}
+
+impl FakeStatusSource {
+    fn new(statuses: Vec<Option<Status>>) -> Self {
+        FakeStatusSource {
+            statuses,
+            fetches: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl StatusSource for FakeStatusSource {
+    type Status = Status;
+
+    fn fetch_status<'a>(&'a self, message_id: &'a str) -> StatusFuture<'a, Status> {
+        Box::pin(async move {
+            assert_eq!(message_id, "some-message-id");
+            let fetch = self.fetches.fetch_add(1, Ordering::SeqCst);
+
+            Ok(self.statuses[fetch.min(self.statuses.len() - 1)].clone())
+        })
+    }
+}
+
+fn status(group_id: i32, group_name: &str) -> Status {
+    Status {
+        group_id: Some(group_id),
+        group_name: Some(group_name.to_string()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_poll_until_terminal_pending_then_delivered() {
+    let source = FakeStatusSource::new(vec![
+        None,
+        Some(status(1, "PENDING")),
+        Some(status(1, "PENDING")),
+        Some(status(3, "DELIVERED")),
+    ]);
+
+    let outcome = poll_until_terminal(&source, "some-message-id", INTERVAL, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, PollOutcome::Terminal(status(3, "DELIVERED")));
+    assert_eq!(source.fetches.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_poll_until_terminal_failed() {
+    let source = FakeStatusSource::new(vec![
+        Some(status(1, "PENDING")),
+        Some(status(5, "REJECTED")),
+    ]);
+
+    let outcome = poll_until_terminal(&source, "some-message-id", INTERVAL, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    match outcome {
+        PollOutcome::Terminal(status) => {
+            assert!(status.is_failed());
+            assert!(!status.is_delivered());
+        }
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn test_poll_until_terminal_timeout_returns_last_status() {
+    let source = FakeStatusSource::new(vec![Some(status(1, "PENDING")), None]);
+
+    let outcome = poll_until_terminal(
+        &source,
+        "some-message-id",
+        INTERVAL,
+        Duration::from_millis(50),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(outcome, PollOutcome::TimedOut(Some(status(1, "PENDING"))));
+    assert!(source.fetches.load(Ordering::SeqCst) > 1);
+}
+
+#[tokio::test]
+async fn test_poll_until_terminal_timeout_without_status() {
+    let source = FakeStatusSource::new(vec![None]);
+
+    let outcome = poll_until_terminal(
+        &source,
+        "some-message-id",
+        INTERVAL,
+        Duration::from_millis(30),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(outcome, PollOutcome::TimedOut(None));
+}
+
+#[test]
+fn test_jittered_interval_bounds() {
+    let interval = Duration::from_millis(1000);
+
+    for _ in 0..100 {
+        let jittered = jittered(interval);
+        assert!(jittered >= interval);
+        assert!(jittered <= interval.mul_f64(1.0 + POLL_JITTER));
+    }
+}
@@ -0,0 +1,68 @@
+use serde_json::{json, Value};
+
+use crate::api::results::ResultsScanner;
+use crate::api::SdkError;
+
+const BODY: &str = r#"
+    {
+      "bulkId": "results",
+      "paging": {"results": [{"id": "skipped"}]},
+      "results": [
+        {"id": 1, "text": "a ] tricky } \"string\" [", "nested": {"results": [1, 2]}},
+        {"id": 2, "list": [[], {}], "escaped": "\\"}
+      ],
+      "trailing": [{"id": "skipped"}]
+    }
+"#;
+
+fn scan(body: &str, chunk_size: usize) -> Result<Vec<Value>, SdkError> {
+    let mut scanner = ResultsScanner::new();
+    let mut items = Vec::new();
+    for chunk in body.as_bytes().chunks(chunk_size) {
+        scanner.push(chunk, &mut |item: Value| items.push(item))?;
+    }
+
+    Ok(items)
+}
+
+#[test]
+fn test_results_scanner_splits_items() {
+    let expected = vec![
+        json!({"id": 1, "text": "a ] tricky } \"string\" [", "nested": {"results": [1, 2]}}),
+        json!({"id": 2, "list": [[], {}], "escaped": "\\"}),
+    ];
+
+    assert_eq!(scan(BODY, BODY.len()).unwrap(), expected);
+}
+
+#[test]
+fn test_results_scanner_any_chunk_size() {
+    let expected = scan(BODY, BODY.len()).unwrap();
+
+    for chunk_size in 1..16 {
+        assert_eq!(scan(BODY, chunk_size).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_results_scanner_no_results() {
+    assert!(scan(r#"{"results": []}"#, 1).unwrap().is_empty());
+    assert!(scan(r#"{"results": null, "other": [{}]}"#, 1)
+        .unwrap()
+        .is_empty());
+    assert!(scan("{}", 1).unwrap().is_empty());
+}
+
+#[test]
+fn test_results_scanner_invalid_item() {
+    let mut scanner = ResultsScanner::new();
+
+    let error = scanner
+        .push(br#"{"results": [{"id": "text"}]}"#, &mut |_: u32| {})
+        .unwrap_err();
+
+    match error {
+        SdkError::Deserialization(_, raw_body) => assert_eq!(raw_body.text, r#"{"id": "text"}"#),
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
@@ -0,0 +1,240 @@
+use std::sync::{Arc, Mutex};
+
+use hmac::{Hmac, Mac};
+use httpmock::prelude::*;
+use reqwest::header::{HeaderName, HeaderValue};
+use sha2::Sha256;
+
+use crate::api::tests::get_test_configuration;
+use crate::configuration::{RequestSigner, SigningError};
+
+// Path and body of every request signed by a `HmacSigner`.
+type SignedRequests = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+// Signs `method\npath\nbody` with HMAC-SHA256, like an egress gateway would expect, and records
+// what it signed.
+struct HmacSigner {
+    key: Vec<u8>,
+    signed: SignedRequests,
+}
+
+impl HmacSigner {
+    fn new(key: &str) -> (HmacSigner, SignedRequests) {
+        let signed = Arc::new(Mutex::new(Vec::new()));
+        let signer = HmacSigner {
+            key: key.as_bytes().to_vec(),
+            signed: Arc::clone(&signed),
+        };
+
+        (signer, signed)
+    }
+}
+
+impl RequestSigner for HmacSigner {
+    fn sign(
+        &self,
+        method: &reqwest::Method,
+        path: &str,
+        body: &[u8],
+    ) -> Result<Vec<(HeaderName, HeaderValue)>, SigningError> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key)?;
+        mac.update(format!("{}\n{}\n", method, path).as_bytes());
+        mac.update(body);
+        let signature: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        self.signed
+            .lock()
+            .unwrap()
+            .push((path.to_string(), body.to_vec()));
+
+        Ok(vec![(
+            HeaderName::from_static("x-signature"),
+            HeaderValue::from_str(&signature)?,
+        )])
+    }
+}
+
+#[cfg(feature = "sms")]
+struct FailingSigner;
+
+#[cfg(feature = "sms")]
+impl RequestSigner for FailingSigner {
+    fn sign(
+        &self,
+        _method: &reqwest::Method,
+        _path: &str,
+        _body: &[u8],
+    ) -> Result<Vec<(HeaderName, HeaderValue)>, SigningError> {
+        Err("signing key unavailable".into())
+    }
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn test_hmac_signer_json_body() {
+    use crate::api::sms::{SmsClient, PATH_PREVIEW};
+    use crate::model::sms::PreviewRequestBody;
+
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(PATH_PREVIEW)
+            .body(r#"{"text":"Hello"}"#)
+            .header(
+                "x-signature",
+                "f9a1bd19f751b2eb9543071744652b1567aa5d4ac0d60ef973571b81ec75af52",
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"originalText": "Hello", "previews": []}"#);
+    });
+
+    let (signer, _) = HmacSigner::new("gateway-secret");
+    let configuration = get_test_configuration(&server.base_url()).with_request_signer(signer);
+    let client = SmsClient::with_configuration(configuration);
+
+    client
+        .preview(PreviewRequestBody::new("Hello"))
+        .await
+        .unwrap();
+
+    mock.assert();
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn test_signer_sees_query_string() {
+    use crate::api::sms::{SmsClient, PATH_GET_DELIVERY_REPORTS};
+    use crate::model::sms::GetDeliveryReportsQueryParameters;
+
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .header_exists("x-signature");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+
+    let (signer, signed) = HmacSigner::new("gateway-secret");
+    let configuration = get_test_configuration(&server.base_url()).with_request_signer(signer);
+    let client = SmsClient::with_configuration(configuration);
+
+    client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    mock.assert();
+    let signed = signed.lock().unwrap();
+    assert_eq!(signed[0].0, "/sms/1/reports?limit=50");
+    assert!(signed[0].1.is_empty());
+}
+
+#[cfg(feature = "email")]
+#[tokio::test]
+async fn test_signer_multipart_body() {
+    use crate::api::email::{EmailClient, PATH_SEND};
+    use crate::model::email::SendRequestBody;
+
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(PATH_SEND)
+            .header_exists("x-signature")
+            .body_contains("Content-Disposition: form-data; name=\"subject\"\r\n\r\nSigned\r\n");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": []}"#);
+    });
+
+    let (signer, signed) = HmacSigner::new("gateway-secret");
+    let configuration = get_test_configuration(&server.base_url()).with_request_signer(signer);
+    let client = EmailClient::with_configuration(configuration);
+
+    let mut request_body = SendRequestBody::new("someone@domain.com");
+    request_body.from = Some("someone@company.com".to_string());
+    request_body.subject = Some("Signed".to_string());
+    request_body.text = Some("Signed body".to_string());
+
+    client.send(request_body).await.unwrap();
+
+    mock.assert();
+    let body = String::from_utf8(signed.lock().unwrap()[0].1.clone()).unwrap();
+    assert!(body.contains("name=\"text\"\r\n\r\nSigned body\r\n"));
+    assert!(body.ends_with("--\r\n"));
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn test_signer_error_aborts_request() {
+    use crate::api::sms::{SmsClient, PATH_PREVIEW};
+    use crate::api::SdkError;
+    use crate::model::sms::PreviewRequestBody;
+
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200);
+    });
+
+    let configuration =
+        get_test_configuration(&server.base_url()).with_request_signer(FailingSigner);
+    let client = SmsClient::with_configuration(configuration);
+
+    let error = client
+        .preview(PreviewRequestBody::new("Hello"))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::RequestSigning(source) => {
+            assert_eq!(source.to_string(), "signing key unavailable")
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+    mock.assert_hits(0);
+}
+
+#[cfg(feature = "email")]
+#[tokio::test]
+async fn test_encode_multipart_matches_estimate() {
+    use crate::api::{encode_multipart, MultipartContent, MultipartField, PreparedPart};
+
+    let parts = vec![
+        PreparedPart {
+            name: "subject".to_string(),
+            file_name: None,
+            size: 5,
+        },
+        PreparedPart {
+            name: "attachment".to_string(),
+            file_name: Some("report.pdf".to_string()),
+            size: 1000,
+        },
+    ];
+    let fields = vec![
+        MultipartField {
+            name: "subject".to_string(),
+            file_name: None,
+            content: MultipartContent::Bytes(b"Hello".to_vec()),
+        },
+        MultipartField {
+            name: "attachment".to_string(),
+            file_name: Some("report.pdf".to_string()),
+            content: MultipartContent::Bytes(vec![0; 1000]),
+        },
+    ];
+
+    let body = encode_multipart(fields, &"b".repeat(67)).await.unwrap();
+
+    assert_eq!(
+        body.len() as u64,
+        crate::api::email::estimate_multipart_size(&parts)
+    );
+}
@@ -1,10 +1,12 @@
 use crate::api::sms::*;
+use crate::api::tests::harness::{ExpectedCall, Harness};
 use crate::api::tests::{
     get_test_configuration, mock_blocking_json_endpoint, mock_json_endpoint, DUMMY_TEXT,
 };
-use crate::api::SdkError;
+use crate::api::{replay_parse, PreparedBody, SdkError, SentIds, RAW_BODY_CAPTURE_LIMIT};
 use crate::model::sms::ScheduledStatus::PAUSED;
 use crate::model::sms::*;
+use validator::Validate;
 
 const DUMMY_BASE_URL: &str = "https://some.url";
 
@@ -121,7 +123,11 @@ async fn test_preview_server_error() {
     let request_body = PreviewRequestBody::new(DUMMY_TEXT);
 
     let error = client.preview(request_body).await.unwrap_err();
-    if let SdkError::ApiRequestError(api_error) = error {
+    if let SdkError::Unauthorized {
+        error: Some(api_error),
+        ..
+    } = error
+    {
         assert_eq!(api_error.status, expected_status);
         assert!(!api_error
             .details
@@ -239,6 +245,127 @@ async fn test_get_delivery_reports_bad_parameters() {
     }
 }
 
+fn synthetic_delivery_reports_body(count: usize) -> String {
+    let reports: Vec<String> = (0..count)
+        .map(|index| {
+            format!(
+                r#"{{"bulkId":"BULK-ID","messageId":"MESSAGE-ID-{}","to":"41793026727","smsCount":1,"status":{{"groupId":3,"groupName":"DELIVERED","id":5,"name":"DELIVERED_TO_HANDSET","description":"Message delivered [to] handset, \"ok\""}}}}"#,
+                index
+            )
+        })
+        .collect();
+
+    format!(r#"{{"results": [{}]}}"#, reports.join(","))
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_response_too_large() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        &synthetic_delivery_reports_body(100),
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let configuration =
+        get_test_configuration(&server.base_url()).with_max_response_body_size(1024);
+    let client = SmsClient::with_configuration(configuration);
+
+    let error = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::ResponseTooLarge { received, limit } => {
+            assert!(received > 1024);
+            assert_eq!(limit, 1024);
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_within_response_size_limit() {
+    let body = synthetic_delivery_reports_body(2);
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        &body,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let configuration =
+        get_test_configuration(&server.base_url()).with_max_response_body_size(body.len() as u64);
+    let client = SmsClient::with_configuration(configuration);
+
+    let response = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.results.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_for_each_delivery_report_streams_large_payload() {
+    let count = 20_000;
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        &synthetic_delivery_reports_body(count),
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let configuration =
+        get_test_configuration(&server.base_url()).with_max_response_body_size(1024);
+    let client = SmsClient::with_configuration(configuration);
+
+    let mut message_ids = Vec::new();
+    let status = client
+        .for_each_delivery_report(GetDeliveryReportsQueryParameters::new(), |report| {
+            message_ids.push(report.message_id.unwrap())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(status, reqwest::StatusCode::OK);
+    assert_eq!(message_ids.len(), count);
+    assert_eq!(message_ids[0], "MESSAGE-ID-0");
+    assert_eq!(message_ids[count - 1], format!("MESSAGE-ID-{}", count - 1));
+}
+
+#[tokio::test]
+async fn test_for_each_delivery_report_api_error() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#,
+        reqwest::StatusCode::UNAUTHORIZED,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client
+        .for_each_delivery_report(GetDeliveryReportsQueryParameters::new(), |_: Report| {
+            panic!("no report expected")
+        })
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::Unauthorized {
+            error: Some(api_error),
+            ..
+        } => assert_eq!(api_error.status, reqwest::StatusCode::UNAUTHORIZED),
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
+
 #[tokio::test]
 async fn test_send_valid() {
     let expected_response = r#"
@@ -287,6 +414,7 @@ async fn test_send_valid() {
     let response = client.send(request_body).await.unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(response.warnings.is_empty());
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
@@ -550,6 +678,30 @@ async fn test_update_scheduled_status_valid() {
     assert_eq!(response.body.status.unwrap(), PAUSED);
 }
 
+#[tokio::test]
+async fn test_update_scheduled_status_empty_response_body() {
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        PATH_UPDATE_SCHEDULED_STATUS,
+        "",
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let query_parameters = UpdateScheduledStatusQueryParameters::new("BULK-ID-123-xyz");
+    let request_body = UpdateScheduledStatusRequestBody::new(PAUSED);
+
+    let response = client
+        .update_scheduled_status(query_parameters, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body, UpdateScheduledStatusResponseBody::default());
+}
+
 #[tokio::test]
 async fn test_update_scheduled_status_empty_bulk_id() {
     let client = SmsClient::with_configuration(get_test_configuration("https://some.url"));
@@ -1309,3 +1461,1019 @@ async fn test_get_tfa_verification_status_empty_msisdn() {
         .await
         .is_err());
 }
+
+#[tokio::test]
+async fn test_prepare_send_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some(DUMMY_TEXT.to_string());
+    message.from = Some("InfoSMS".to_string());
+    let mut request_body = SendRequestBody::new(vec![message]);
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+
+    let prepared = client.prepare_send(request_body).unwrap();
+
+    assert_eq!(prepared.method, reqwest::Method::POST);
+    assert_eq!(prepared.url, format!("{}{}", server.base_url(), PATH_SEND));
+    assert!(prepared
+        .headers
+        .contains(&("Authorization".to_string(), "App [REDACTED]".to_string())));
+    if let PreparedBody::Json(body) = prepared.body {
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "bulkId": "some-bulk-id",
+                "messages": [{
+                    "destinations": [{"to": "41793026727"}],
+                    "from": "InfoSMS",
+                    "text": DUMMY_TEXT
+                }]
+            })
+        );
+    } else {
+        panic!("not a JSON body")
+    }
+    mock.assert_hits_async(0).await;
+}
+
+fn get_sender_request_body(from: Option<&str>) -> SendRequestBody {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some(DUMMY_TEXT.to_string());
+    message.from = from.map(str::to_string);
+
+    SendRequestBody::new(vec![message])
+}
+
+fn prepared_sender(client: &SmsClient, request_body: SendRequestBody) -> serde_json::Value {
+    match client.prepare_send(request_body).unwrap().body {
+        PreparedBody::Json(body) => {
+            let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+            body["messages"][0]["from"].clone()
+        }
+        body => panic!("not a JSON body: {:?}", body),
+    }
+}
+
+#[test]
+fn test_prepare_send_default_sender() {
+    let configuration = get_test_configuration(DUMMY_BASE_URL);
+    let with_default =
+        SmsClient::with_configuration(configuration.clone().with_default_sms_sender("InfoSMS"));
+    let without_default = SmsClient::with_configuration(configuration);
+
+    assert_eq!(
+        prepared_sender(&with_default, get_sender_request_body(None)),
+        "InfoSMS"
+    );
+    assert_eq!(
+        prepared_sender(&with_default, get_sender_request_body(Some("Shop"))),
+        "Shop"
+    );
+    assert_eq!(
+        prepared_sender(&without_default, get_sender_request_body(None)),
+        serde_json::Value::Null
+    );
+}
+
+#[tokio::test]
+async fn test_send_default_sender() {
+    let server = httpmock::MockServer::start_async().await;
+    let default_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .json_body_partial(r#"{"messages": [{"from": "InfoSMS"}]}"#);
+        then.status(200).body(r#"{"messages": []}"#);
+    });
+    let explicit_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .json_body_partial(r#"{"messages": [{"from": "Shop"}]}"#);
+        then.status(200).body(r#"{"messages": []}"#);
+    });
+    let client = SmsClient::with_configuration(
+        get_test_configuration(&server.base_url()).with_default_sms_sender("InfoSMS"),
+    );
+
+    let request_body = get_sender_request_body(None);
+    client.send(request_body.clone()).await.unwrap();
+    assert_eq!(request_body.messages[0].from, None);
+    client
+        .send(get_sender_request_body(Some("Shop")))
+        .await
+        .unwrap();
+
+    default_mock.assert_hits_async(1).await;
+    explicit_mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_send_default_sender_keeps_validation_errors() {
+    let client = SmsClient::with_configuration(
+        get_test_configuration(DUMMY_BASE_URL).with_default_sms_sender("InfoSMS"),
+    );
+
+    let error = client.send(SendRequestBody::new(vec![])).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_prepare_send_bad_request() {
+    let client = SmsClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let request_body = SendRequestBody::new(vec![]);
+
+    let error = client.prepare_send(request_body).unwrap_err();
+
+    if let SdkError::Validation(validation_error) = error {
+        assert!(!validation_error.errors().is_empty());
+    } else {
+        panic!("not validation error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_single_valid() {
+    let expected_response = r#"
+    {
+      "bulkId": "2034072219640523073",
+      "messages": [
+        {
+          "messageId": "41793026727",
+          "status": {
+            "description": "Message sent to next instance",
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 26,
+            "name": "MESSAGE_ACCEPTED"
+          },
+          "to": "41793026727"
+        },
+        {
+          "messageId": "41793026834",
+          "status": {
+            "description": "Message sent to next instance",
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 26,
+            "name": "MESSAGE_ACCEPTED"
+          },
+          "to": "41793026834"
+        }
+      ]
+    }
+    "#;
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_SINGLE)
+            .json_body(serde_json::json!({
+                "from": "InfoSMS",
+                "to": ["41793026727", "41793026834"],
+                "text": DUMMY_TEXT
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(expected_response);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = SendSingleRequestBody::new(
+        vec!["41793026727".to_string(), "41793026834".to_string()],
+        DUMMY_TEXT,
+    );
+    request_body.from = Some("InfoSMS".to_string());
+
+    let response = client.send_single(request_body).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.messages.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_send_single_no_destinations() {
+    let client = SmsClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let request_body = SendSingleRequestBody::new(vec![], DUMMY_TEXT);
+
+    let error = client.send_single(request_body).await.unwrap_err();
+
+    if let SdkError::Validation(validation_error) = error {
+        assert!(!validation_error.errors().is_empty());
+    } else {
+        panic!("not validation error")
+    }
+}
+
+#[tokio::test]
+async fn test_send_once_rejects_duplicate() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+
+    let client = SmsClient::with_configuration(
+        get_test_configuration(&server.base_url()).with_send_once(10),
+    );
+
+    let build_request_body = |message_id: &str| {
+        let mut destination = Destination::new("41793026727");
+        destination.message_id = Some(message_id.to_string());
+        let mut message = Message::new(vec![destination]);
+        message.text = Some(DUMMY_TEXT.to_string());
+
+        SendRequestBody::new(vec![message])
+    };
+
+    client
+        .send_once(build_request_body("some-message-id"))
+        .await
+        .unwrap();
+    let error = client
+        .send_once(build_request_body("some-message-id"))
+        .await
+        .unwrap_err();
+
+    if let SdkError::DuplicateSend(_, message_id) = error {
+        assert_eq!(message_id.unwrap(), "some-message-id");
+    } else {
+        panic!("not duplicate send error")
+    }
+
+    client
+        .send_once(build_request_body("other-message-id"))
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(2).await;
+}
+
+fn sent_id(bulk_id: Option<&str>, message_id: Option<&str>) -> (Option<String>, Option<String>) {
+    (bulk_id.map(str::to_string), message_id.map(str::to_string))
+}
+
+#[test]
+fn test_sent_ids_evicts_least_recently_used() {
+    let mut sent_ids = SentIds::default();
+
+    sent_ids.record(vec![sent_id(None, Some("a"))], 2).unwrap();
+    sent_ids.record(vec![sent_id(None, Some("b"))], 2).unwrap();
+    assert!(sent_ids.record(vec![sent_id(None, Some("a"))], 2).is_err());
+    sent_ids.record(vec![sent_id(None, Some("c"))], 2).unwrap();
+
+    assert_eq!(sent_ids.len(), 2);
+    assert!(sent_ids.record(vec![sent_id(None, Some("a"))], 2).is_err());
+    assert!(sent_ids.record(vec![sent_id(None, Some("c"))], 2).is_err());
+    sent_ids.record(vec![sent_id(None, Some("b"))], 2).unwrap();
+}
+
+#[test]
+fn test_sent_ids_deduplicates_request_ids() {
+    let mut sent_ids = SentIds::default();
+    sent_ids
+        .record(vec![sent_id(Some("some-bulk-id"), Some("a"))], 2)
+        .unwrap();
+
+    let shared_bulk = vec![sent_id(Some("other-bulk-id"), None); 5];
+    sent_ids.record(shared_bulk, 2).unwrap();
+
+    assert_eq!(sent_ids.len(), 2);
+    assert!(sent_ids
+        .record(vec![sent_id(Some("some-bulk-id"), Some("a"))], 2)
+        .is_err());
+}
+
+#[test]
+fn test_sent_ids_sweeps_stale_uses() {
+    let mut sent_ids = SentIds::default();
+    sent_ids.record(vec![sent_id(None, Some("a"))], 4).unwrap();
+
+    for _ in 0..100 {
+        assert!(sent_ids.record(vec![sent_id(None, Some("a"))], 4).is_err());
+    }
+
+    assert!(sent_ids.uses.len() <= 2);
+}
+
+#[tokio::test]
+async fn test_send_with_api_version_override() {
+    let server = httpmock::MockServer::start_async().await;
+    let overridden = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/sms/3/text/advanced");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": []}"#);
+    });
+    let original = server.mock(|when, then| {
+        when.path(PATH_SEND);
+        then.status(404);
+    });
+
+    let configuration = get_test_configuration(&server.base_url())
+        .with_api_version_override(crate::configuration::ApiChannel::Sms, 3);
+    let client = SmsClient::with_configuration(configuration);
+
+    let message = Message::new(vec![Destination::new("123456789101")]);
+    let response = client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    overridden.assert_hits_async(1).await;
+    original.assert_hits_async(0).await;
+}
+
+#[tokio::test]
+async fn test_api_version_override_other_channel() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"bulkId": "some-bulk-id", "messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let configuration = get_test_configuration(&server.base_url())
+        .with_api_version_override(crate::configuration::ApiChannel::Email, 4);
+    let client = SmsClient::with_configuration(configuration);
+
+    let message = Message::new(vec![Destination::new("123456789101")]);
+    let response = client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_default_limit() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param(
+                "limit",
+                GetDeliveryReportsQueryParameters::DEFAULT_LIMIT.to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_reports_cursor_serializes_concurrent_batches() {
+    let delay = std::time::Duration::from_millis(300);
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", "2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(delay)
+            .body(r#"{"results": [{"messageId": "one"}, {"messageId": "two"}]}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+    let cursor = std::sync::Arc::new(client.reports_cursor());
+
+    let started = std::time::Instant::now();
+    let tasks: Vec<_> = (0..2)
+        .map(|_| {
+            let cursor = cursor.clone();
+            tokio::spawn(async move { cursor.next_batch(2).await })
+        })
+        .collect();
+    for task in tasks {
+        assert_eq!(task.await.unwrap().unwrap().len(), 2);
+    }
+
+    assert!(started.elapsed() >= delay * 2);
+    assert_eq!(cursor.fetched(), 4);
+    mock.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn test_reports_cursor_drain_all() {
+    let server = httpmock::MockServer::start_async().await;
+    let partial_batch = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", "5");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": [{"messageId": "one"}, {"messageId": "two"}, {"messageId": "three"}]}"#);
+    });
+    let full_batch = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", "2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": [{"messageId": "four"}, {"messageId": "five"}]}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+    let cursor = client.reports_cursor();
+
+    assert_eq!(cursor.drain_all(5).await.unwrap().len(), 3);
+    assert_eq!(cursor.drain_all(2).await.unwrap().len(), 2);
+
+    assert_eq!(cursor.fetched(), 5);
+    partial_batch.assert_hits_async(1).await;
+    full_batch.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_reports_cursor_drain_all_keeps_reports_on_failure() {
+    let max_limit = GetDeliveryReportsQueryParameters::MAX_LIMIT;
+    let results: Vec<_> = (0..max_limit)
+        .map(|index| serde_json::json!({ "messageId": index.to_string() }))
+        .collect();
+
+    let server = httpmock::MockServer::start_async().await;
+    let full_batch = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", max_limit.to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "results": results }));
+    });
+    let failed_batch = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("limit", "1");
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR", "text": "Something went wrong."}}}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+    let cursor = client.reports_cursor();
+
+    let error = cursor.drain_all(max_limit as usize + 1).await.unwrap_err();
+
+    assert_eq!(error.reports.len(), max_limit as usize);
+    assert_eq!(error.reports[0].message_id.as_deref(), Some("0"));
+    assert!(matches!(error.source, SdkError::ApiRequestError(_)));
+    assert_eq!(cursor.fetched(), max_limit as usize);
+    full_batch.assert_hits_async(1).await;
+    failed_batch.assert_hits_async(1).await;
+}
+
+#[test]
+fn test_format_validation_errors_nested_struct() {
+    let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
+    delivery_time_window.from = Some(DeliveryTime::new(24, 0));
+    delivery_time_window.to = Some(DeliveryTime::new(6, 0));
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.delivery_time_window = Some(delivery_time_window);
+
+    let errors = message.validate().unwrap_err();
+    let field_errors = crate::api::format_validation_errors(&errors);
+
+    assert_eq!(field_errors.len(), 1);
+    assert_eq!(field_errors[0].path, "deliveryTimeWindow.from.hour");
+    assert_eq!(field_errors[0].code, "range");
+}
+
+#[test]
+fn test_format_validation_errors_list_index() {
+    let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
+    delivery_time_window.from = Some(DeliveryTime::new(6, 0));
+    delivery_time_window.to = Some(DeliveryTime::new(8, 60));
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.delivery_time_window = Some(delivery_time_window);
+    let request_body = SendRequestBody::new(vec![
+        Message::new(vec![Destination::new("123456789012")]),
+        Message::new(vec![Destination::new("123456789012")]),
+        Message::new(vec![Destination::new("123456789012")]),
+        message,
+    ]);
+
+    let errors = request_body.validate().unwrap_err();
+    let paths: Vec<String> = crate::api::format_validation_errors(&errors)
+        .into_iter()
+        .map(|error| error.path)
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "messages[3].deliveryTimeWindow",
+            "messages[3].deliveryTimeWindow.to.minute"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_validation_error_display_has_field_paths() {
+    let client = SmsClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.callback_data = Some("a".repeat(4001));
+
+    let error = client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+    assert!(error.to_string().contains("messages[0].callbackData: "));
+}
+
+#[tokio::test]
+async fn test_export_logs_ndjson() {
+    let server = httpmock::MockServer::start_async().await;
+    let pages = [
+        ("2023-01-01T12:00:00.000+0000", vec!["11:05", "11:04"]),
+        ("2023-01-01T11:04:00.000+0000", vec!["11:04", "11:03"]),
+        ("2023-01-01T11:03:00.000+0000", vec!["11:03"]),
+    ];
+    let mocks: Vec<_> = pages
+        .iter()
+        .map(|(sent_until, times)| {
+            let results: Vec<String> = times
+                .iter()
+                .map(|time| {
+                    format!(
+                        r#"{{"messageId": "id-{}", "sentAt": "2023-01-01T{}:00.000+0000"}}"#,
+                        time, time
+                    )
+                })
+                .collect();
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path(PATH_GET_LOGS)
+                    .query_param("sentUntil", *sent_until);
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(r#"{{"results": [{}]}}"#, results.join(",")));
+            })
+        })
+        .collect();
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_until = Some("2023-01-01T12:00:00.000+0000".to_string());
+    query_parameters.limit = Some(2);
+
+    let mut buffer = Vec::new();
+    let progress = client
+        .export_logs(
+            query_parameters,
+            &mut buffer,
+            crate::api::export::ExportFormat::NdJson,
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+    for mock in mocks {
+        mock.assert_hits(1);
+    }
+    assert_eq!(progress.pages, 3);
+    let lines: Vec<&str> = std::str::from_utf8(&buffer).unwrap().lines().collect();
+    assert_eq!(lines.len(), 3);
+    let log: Log = serde_json::from_str(lines[2]).unwrap();
+    assert_eq!(log.message_id.as_deref(), Some("id-11:03"));
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_mismatched_body() {
+    let expected_response = r#"{"results": {"messageId": "some-message-id"}}"#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Deserialization(_, _)));
+    let raw_body = error.into_raw_body().unwrap();
+    assert_eq!(raw_body.text, expected_response);
+    assert!(!raw_body.is_truncated());
+
+    let value: serde_json::Value = replay_parse(&raw_body).unwrap();
+    assert_eq!(value["results"]["messageId"], "some-message-id");
+}
+
+#[tokio::test]
+async fn test_get_delivery_reports_mismatched_body_truncated() {
+    let expected_response = format!(r#"{{"results": "{}"}}"#, "é".repeat(40_000));
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        &expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let raw_body = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap_err()
+        .into_raw_body()
+        .unwrap();
+
+    assert!(raw_body.is_truncated());
+    assert_eq!(raw_body.len, expected_response.len());
+    assert!(raw_body.text.len() <= RAW_BODY_CAPTURE_LIMIT);
+    assert!(raw_body.text.len() > RAW_BODY_CAPTURE_LIMIT - 2);
+    assert!(expected_response.starts_with(&raw_body.text));
+    assert!(replay_parse::<serde_json::Value>(&raw_body).is_err());
+}
+
+fn get_chunked_request_body() -> SendRequestBody {
+    let destinations = (0..2500)
+        .map(|index| Destination::new(&format!("4179302{:04}", index)))
+        .collect();
+    let mut message = Message::new(destinations);
+    message.text = Some(DUMMY_TEXT.to_string());
+
+    SendRequestBody::new(vec![message])
+}
+
+#[tokio::test]
+async fn test_send_chunked() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": [{"to": "41793020000"}]}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = get_chunked_request_body();
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+
+    let result = client.send_chunked(request_body, 1000).await;
+
+    mock.assert_hits_async(3).await;
+    assert!(result.is_success());
+    assert_eq!(result.bulk_id, "some-bulk-id");
+    let sizes: Vec<usize> = result
+        .chunks
+        .iter()
+        .map(|chunk| {
+            chunk.request_body.messages[0]
+                .destinations
+                .as_ref()
+                .unwrap()
+                .len()
+        })
+        .collect();
+    assert_eq!(sizes, vec![1000, 1000, 500]);
+    assert_eq!(result.sent_messages().count(), 3);
+}
+
+#[tokio::test]
+async fn test_send_chunked_middle_chunk_fails() {
+    let server = httpmock::MockServer::start_async().await;
+    let failing_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("41793021000");
+        then.status(400)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"requestError": {"serviceException": {"messageId": "BAD_REQUEST", "text": "Bad request"}}}"#,
+            );
+    });
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"messages": [{"to": "41793020000"}]}"#);
+    });
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let result = client.send_chunked(get_chunked_request_body(), 1000).await;
+
+    failing_mock.assert_hits_async(1).await;
+    mock.assert_hits_async(2).await;
+    assert!(!result.is_success());
+    assert!(!result.bulk_id.is_empty());
+    assert!(result
+        .chunks
+        .iter()
+        .all(|chunk| chunk.request_body.bulk_id.as_ref() == Some(&result.bulk_id)));
+    let failed: Vec<usize> = result.failed_chunks().map(|chunk| chunk.index).collect();
+    assert_eq!(failed, vec![1]);
+    assert_eq!(result.sent_messages().count(), 2);
+}
+
+#[tokio::test]
+async fn get_tfa_application_opt_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/2fa/2/applications/some-application-id",
+        "",
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    assert!(client
+        .get_tfa_application_opt("some-application-id")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn get_tfa_message_template_opt_server_error() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/2fa/2/applications/some-application-id/messages/some-template-id",
+        r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR"}}}"#,
+        reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    assert!(client
+        .get_tfa_message_template_opt("some-application-id", "some-template-id")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn send_with_options_request_body() {
+    let harness = Harness::start().await;
+    let mock = harness
+        .expect(
+            ExpectedCall::new(httpmock::Method::POST, PATH_SEND)
+                .json_body(serde_json::json!({
+                    "bulkId": "some-bulk-id",
+                    "messages": [{"destinations": [{"to": "41793026727"}], "text": "Your code is 1234"}],
+                    "options": {
+                        "conversionTracking": {
+                            "useConversionTracking": true,
+                            "conversionTrackingName": "ONE_TIME_PIN"
+                        }
+                    }
+                }))
+                .responds(200, r#"{"bulkId": "some-bulk-id", "messages": []}"#),
+        )
+        .await;
+    let client = SmsClient::with_configuration(harness.configuration());
+
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some("Your code is 1234".to_string());
+    let mut request_body = SendRequestBody::new(vec![message]);
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+    request_body.options = Some(Options {
+        conversion_tracking: Some(ConversionTracking::enabled("ONE_TIME_PIN")),
+    });
+
+    client.send(request_body).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+async fn mock_report_for_message(body: &'static str) -> (httpmock::MockServer, SmsClient) {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("messageId", "some-message-id")
+            .query_param("limit", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_found() {
+    let (_server, client) =
+        mock_report_for_message(r#"{"results": [{"messageId": "some-message-id"}]}"#).await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("some-message-id"));
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_not_yet_available() {
+    let (_server, client) = mock_report_for_message(r#"{"results": []}"#).await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap();
+
+    assert!(report.is_none());
+}
+
+#[tokio::test]
+async fn test_get_report_for_message_takes_first() {
+    let (_server, client) = mock_report_for_message(
+        r#"{"results": [{"messageId": "some-message-id"}, {"messageId": "other-message-id"}]}"#,
+    )
+    .await;
+
+    let report = client
+        .get_report_for_message("some-message-id")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("some-message-id"));
+}
+
+#[tokio::test]
+async fn test_fetch_status_reads_logs() {
+    use crate::api::polling::StatusSource;
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("messageId", "some-message-id")
+            .query_param("limit", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": [{"messageId": "some-message-id", "status": {"groupId": 1, "groupName": "PENDING"}}]}"#);
+    });
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let status = client
+        .fetch_status("some-message-id")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(status.group_name.as_deref(), Some("PENDING"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_unauthorized_with_prefix_hint() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_PREVIEW,
+        r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#,
+        reqwest::StatusCode::UNAUTHORIZED,
+    )
+    .await;
+    let configuration = crate::configuration::Configuration::with_api_key(
+        server.base_url(),
+        crate::configuration::ApiKey {
+            key: "some-api-key".to_string(),
+            prefix: Some("Bearer".to_string()),
+        },
+    );
+    let client = SmsClient::with_configuration(configuration);
+
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+
+    match &error {
+        SdkError::Unauthorized {
+            error: Some(error),
+            hint,
+        } => {
+            assert_eq!(error.status, reqwest::StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                error
+                    .details
+                    .request_error
+                    .service_exception
+                    .text
+                    .as_deref(),
+                Some("Invalid login details")
+            );
+            assert!(hint.as_deref().unwrap().contains("\"Bearer\""));
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+    assert!(error.to_string().contains("\"App\" prefix"));
+    assert!(!error.to_string().contains("some-api-key"));
+}
+
+#[tokio::test]
+async fn test_forbidden_scope_error() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_PREVIEW,
+        r#"{"requestError": {"serviceException": {"messageId": "FORBIDDEN", "text": "Insufficient API key scope"}}}"#,
+        reqwest::StatusCode::FORBIDDEN,
+    )
+    .await;
+    let client = SmsClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::Forbidden {
+            error: Some(error),
+            hint,
+        } => {
+            assert_eq!(error.status, reqwest::StatusCode::FORBIDDEN);
+            assert_eq!(
+                error
+                    .details
+                    .request_error
+                    .service_exception
+                    .message_id
+                    .as_deref(),
+                Some("FORBIDDEN")
+            );
+            assert!(hint.unwrap().contains("scope"));
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_unauthorized_html_body() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(401)
+            .header("content-type", "text/html")
+            .body("<html><body><h1>401 Authorization Required</h1></body></html>");
+    });
+    let configuration = crate::configuration::Configuration::with_api_key(
+        server.base_url(),
+        crate::configuration::ApiKey {
+            key: "some-api-key".to_string(),
+            prefix: Some("Bearer".to_string()),
+        },
+    );
+    let client = SmsClient::with_configuration(configuration);
+
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::Unauthorized { error, hint } => {
+            assert!(error.is_none());
+            assert!(hint.unwrap().contains("\"Bearer\""));
+        }
+        _ => panic!("unexpected error: {:?}", error),
+    }
+}
@@ -0,0 +1,70 @@
+use crate::api::tests::{get_test_configuration, mock_json_endpoint};
+use crate::api::voice::*;
+use crate::api::SdkError;
+use crate::model::voice::*;
+
+const DUMMY_BASE_URL: &str = "https://some.url";
+
+#[tokio::test]
+async fn get_delivery_reports_valid() {
+    let expected_response = r#"
+    {
+      "results": [
+        {
+          "bulkId": "some-bulk-id",
+          "messageId": "some-message-id",
+          "to": "41793026727",
+          "voiceCall": {
+            "feature": "Text-to-Speech",
+            "duration": 10,
+            "dtmfCodes": "4,2"
+          },
+          "status": {
+            "groupId": 3,
+            "groupName": "DELIVERED",
+            "id": 5,
+            "name": "DELIVERED_TO_HANDSET"
+          }
+        }
+      ]
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_DELIVERY_REPORTS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = VoiceClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = client
+        .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    let report = &response.body.results.unwrap()[0];
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(report.call_status(), CallStatus::Delivered);
+    assert_eq!(
+        report.voice_call.as_ref().unwrap().dtmf_digits(),
+        vec!["4", "2"]
+    );
+}
+
+#[tokio::test]
+async fn get_delivery_reports_bad_limit() {
+    let client = VoiceClient::with_configuration(get_test_configuration(DUMMY_BASE_URL));
+
+    let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+    query_parameters.limit = Some(0);
+
+    let error = client
+        .get_delivery_reports(query_parameters)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use crate::api::webhook::*;
+use crate::model::sms::{InboundSmsReport, Report};
+
+const TTL: Duration = Duration::from_secs(60);
+
+fn report(message_id: &str, done_at: &str) -> Report {
+    Report {
+        message_id: Some(message_id.to_string()),
+        done_at: Some(done_at.to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_deduplicator_suppresses_duplicates() {
+    let deduplicator = WebhookDeduplicator::new(10, TTL);
+    let now = Instant::now();
+    let first = report("some-message-id", "2022-10-01T12:30:00.000+0000");
+
+    assert!(deduplicator.check_and_record_at(&first, now));
+    assert!(!deduplicator.check_and_record_at(&first.clone(), now + Duration::from_secs(1)));
+    assert!(!deduplicator.check_and_record_at(&first, now + Duration::from_secs(59)));
+    assert_eq!(deduplicator.len(), 1);
+}
+
+#[test]
+fn test_deduplicator_tells_events_apart() {
+    let deduplicator = WebhookDeduplicator::new(10, TTL);
+    let now = Instant::now();
+    let inbound = InboundSmsReport {
+        message_id: Some("some-message-id".to_string()),
+        received_at: Some("2022-10-01T12:30:00.000+0000".to_string()),
+        ..Default::default()
+    };
+
+    assert!(deduplicator.check_and_record_at(
+        &report("some-message-id", "2022-10-01T12:30:00.000+0000"),
+        now
+    ));
+    assert!(deduplicator.check_and_record_at(
+        &report("some-message-id", "2022-10-01T12:31:00.000+0000"),
+        now
+    ));
+    assert!(deduplicator.check_and_record_at(
+        &report("other-message-id", "2022-10-01T12:30:00.000+0000"),
+        now
+    ));
+    assert!(deduplicator.check_and_record_at(&inbound, now));
+    assert_eq!(deduplicator.len(), 4);
+}
+
+#[test]
+fn test_deduplicator_ignores_events_without_message_id() {
+    let deduplicator = WebhookDeduplicator::new(10, TTL);
+    let report = Report::default();
+
+    assert!(deduplicator.check_and_record(&report));
+    assert!(deduplicator.check_and_record(&report));
+    assert!(deduplicator.is_empty());
+}
+
+#[test]
+fn test_deduplicator_ttl_expiry() {
+    let deduplicator = WebhookDeduplicator::new(10, TTL);
+    let now = Instant::now();
+    let first = report("some-message-id", "2022-10-01T12:30:00.000+0000");
+    let second = report("other-message-id", "2022-10-01T12:30:00.000+0000");
+
+    assert!(deduplicator.check_and_record_at(&first, now));
+    assert!(deduplicator.check_and_record_at(&second, now + Duration::from_secs(30)));
+
+    assert!(deduplicator.check_and_record_at(&first, now + TTL));
+    assert!(!deduplicator.check_and_record_at(&second, now + TTL));
+    assert_eq!(deduplicator.len(), 2);
+}
+
+#[test]
+fn test_deduplicator_capacity_evicts_oldest() {
+    let deduplicator = WebhookDeduplicator::new(2, TTL);
+    let now = Instant::now();
+    let first = report("first-message-id", "2022-10-01T12:30:00.000+0000");
+    let second = report("second-message-id", "2022-10-01T12:30:00.000+0000");
+    let third = report("third-message-id", "2022-10-01T12:30:00.000+0000");
+
+    assert!(deduplicator.check_and_record_at(&first, now));
+    assert!(deduplicator.check_and_record_at(&second, now));
+    assert!(deduplicator.check_and_record_at(&third, now));
+    assert_eq!(deduplicator.len(), 2);
+
+    assert!(!deduplicator.check_and_record_at(&second, now));
+    assert!(!deduplicator.check_and_record_at(&third, now));
+    assert!(deduplicator.check_and_record_at(&first, now));
+    assert!(deduplicator.check_and_record_at(&second, now));
+}
+
+#[test]
+fn test_deduplicator_forget_after_failed_processing() {
+    let deduplicator = WebhookDeduplicator::new(10, TTL);
+    let now = Instant::now();
+    let failed = report("some-message-id", "2022-10-01T12:30:00.000+0000");
+    let other = report("other-message-id", "2022-10-01T12:30:00.000+0000");
+
+    assert!(deduplicator.check_and_record_at(&failed, now));
+    assert!(deduplicator.check_and_record_at(&other, now));
+
+    // Processing failed, the retried delivery must be processed again, once.
+    assert!(deduplicator.forget(&failed));
+    assert!(!deduplicator.forget(&failed));
+    assert_eq!(deduplicator.len(), 1);
+    assert!(deduplicator.check_and_record_at(&failed, now + Duration::from_secs(1)));
+    assert!(!deduplicator.check_and_record_at(&failed, now + Duration::from_secs(2)));
+    assert!(!deduplicator.check_and_record_at(&other, now + Duration::from_secs(2)));
+    assert_eq!(deduplicator.len(), 2);
+}
+
+#[test]
+fn test_deduplicator_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<WebhookDeduplicator>();
+}
@@ -1,6 +1,11 @@
+use chrono::{Duration, Utc};
+use httpmock::Method::{GET, POST};
+
+use crate::api::tests::harness::{ExpectedCall, Harness};
 use crate::api::tests::{get_test_configuration, mock_json_endpoint};
 use crate::api::whatsapp::*;
 use crate::api::SdkError::ApiRequestError;
+use crate::api::{PreparedBody, SdkError};
 use crate::model::whatsapp::*;
 
 fn get_dummy_send_text_request_body() -> SendTextRequestBody {
@@ -139,7 +144,10 @@ async fn send_text_api_error_401() {
         .unwrap();
 
     match sdk_error {
-        ApiRequestError(api_error) => {
+        SdkError::Unauthorized {
+            error: Some(api_error),
+            ..
+        } => {
             assert_eq!(api_error.status, reqwest::StatusCode::UNAUTHORIZED);
             assert_eq!(
                 api_error
@@ -796,7 +804,7 @@ async fn get_templates_valid() {
     "#;
 
     let sender = "441134960000";
-    let path = PATH_GET_TEMPLATES.replace("{sender}", &sender);
+    let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
 
     let server = mock_json_endpoint(
         httpmock::Method::GET,
@@ -886,7 +894,7 @@ async fn create_template_valid() {
     "#;
 
     let sender = "441134960000";
-    let path = PATH_CREATE_TEMPLATE.replace("{sender}", &sender);
+    let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
 
     let server = mock_json_endpoint(
         httpmock::Method::POST,
@@ -932,3 +940,401 @@ async fn delete_template_valid() {
 
     assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
 }
+
+#[tokio::test]
+async fn get_senders_valid() {
+    let expected_response = r#"
+        {
+          "senders": [
+            {
+              "sender": "441134960000",
+              "displayName": "Infobip",
+              "status": "CONNECTED",
+              "qualityRating": "GREEN",
+              "messagingLimit": "TIER_1K"
+            }
+          ]
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_SENDERS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = wa_client.get_senders().await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.senders.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn update_sender_webhook_valid() {
+    let expected_response = r#"
+        {
+          "url": "https://example.com/inbound",
+          "forwardInboundMessages": true,
+          "forwardDeliveryReports": false
+        }
+    "#;
+
+    let sender = "441134960000";
+    let path = PATH_UPDATE_SENDER_WEBHOOK.replace("{sender}", sender);
+
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        &path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let mut request_body = UpdateSenderWebhookRequestBody::new("https://example.com/inbound");
+    request_body.forward_inbound_messages = Some(true);
+    request_body.forward_delivery_reports = Some(false);
+
+    let response = wa_client
+        .update_sender_webhook(sender, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.url, "https://example.com/inbound");
+    assert_eq!(response.body.forward_inbound_messages, Some(true));
+}
+
+#[tokio::test]
+async fn update_sender_webhook_http_url() {
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration("https://some.url"));
+
+    let request_body = UpdateSenderWebhookRequestBody::new("http://example.com/inbound");
+
+    let error = wa_client
+        .update_sender_webhook("441134960000", request_body)
+        .await
+        .unwrap_err();
+
+    if let crate::api::SdkError::Validation(validation_error) = error {
+        assert!(!validation_error.errors().is_empty());
+    } else {
+        panic!("not validation error")
+    }
+}
+
+#[tokio::test]
+async fn send_text_within_conversation_window() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_TEXT,
+        r#"{"to": "55555555555", "messageCount": 1, "messageId": "some-message-id"}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let tracker = ConversationWindowTracker::new();
+    tracker.record_inbound(
+        "44444444444",
+        "55555555555",
+        Utc::now() - Duration::hours(23),
+    );
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()))
+        .with_window_tracker(tracker);
+
+    let response = wa_client
+        .send_text(get_dummy_send_text_request_body())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn send_text_conversation_window_closed() {
+    let tracker = ConversationWindowTracker::new();
+    tracker.record_inbound(
+        "44444444444",
+        "55555555555",
+        Utc::now() - Duration::hours(24) - Duration::minutes(1),
+    );
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration("https://some.url"))
+        .with_window_tracker(tracker);
+
+    let error = wa_client
+        .send_text(get_dummy_send_text_request_body())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        SdkError::ConversationWindowClosed(sender, to)
+            if sender == "44444444444" && to == "55555555555"
+    ));
+}
+
+#[test]
+fn conversation_window_from_inbound_message() {
+    let body = r#"
+        {
+          "results": [
+            {
+              "from": "385919998888",
+              "to": "447860099299",
+              "receivedAt": "2023-05-24T11:36:49.856+0000",
+              "message": {"type": "TEXT", "text": "Hello"}
+            }
+          ]
+        }
+    "#;
+    let messages = parse_whatsapp_inbound_messages(body.as_bytes()).unwrap();
+
+    let tracker = ConversationWindowTracker::new();
+    tracker.record_inbound_message(&messages[0]);
+
+    let received_at = "2023-05-24T11:36:49.856Z".parse().unwrap();
+    assert!(tracker.can_send_freeform_at(
+        "447860099299",
+        "385919998888",
+        received_at + Duration::hours(23)
+    ));
+    assert!(!tracker.can_send_freeform_at(
+        "447860099299",
+        "385919998888",
+        received_at + Duration::hours(24) + Duration::minutes(1)
+    ));
+    assert!(!tracker.can_send_freeform_at("447860099299", "385910000000", received_at));
+}
+
+const REMINDER_TEMPLATES: &str = r#"
+    {
+      "templates": [
+        {
+          "name": "appointment_reminder",
+          "language": "en",
+          "structure": {
+            "body": {"text": "Hi {{1}}, see you on {{2}}. Reply to {{1}}."},
+            "buttons": [
+              {"type": "URL", "text": "Details", "url": "https://www.example.com/{{1}}"},
+              {"type": "PHONE_NUMBER", "text": "Call", "phoneNumber": "41793026727"}
+            ]
+          }
+        }
+      ]
+    }"#;
+
+fn get_reminder_request_body(name: &str, placeholders: &[&str]) -> SendTemplateRequestBody {
+    let body =
+        TemplateBodyContent::new(placeholders.iter().map(|value| value.to_string()).collect());
+    let mut data = TemplateData::new(body);
+    data.buttons = Some(vec![TemplateButtonContent::new_url("booking-1")]);
+    let content = TemplateContent::new(name, data, "en");
+
+    SendTemplateRequestBody::new(vec![FailoverMessage::new(
+        "441134960000",
+        "441134960001",
+        content,
+    )])
+}
+
+#[tokio::test]
+async fn send_template_checked_match() {
+    let harness = Harness::start().await;
+    let templates_mock = harness
+        .expect(
+            ExpectedCall::new(GET, "/whatsapp/2/senders/441134960000/templates")
+                .responds(200, REMINDER_TEMPLATES),
+        )
+        .await;
+    let send_mock = harness
+        .expect(ExpectedCall::new(POST, PATH_SEND_TEMPLATE).responds(200, r#"{"messages": []}"#))
+        .await;
+    let wa_client = WhatsappClient::with_configuration(harness.configuration());
+
+    for _ in 0..2 {
+        wa_client
+            .send_template_checked(get_reminder_request_body(
+                "appointment_reminder",
+                &["John", "Monday"],
+            ))
+            .await
+            .unwrap();
+    }
+
+    templates_mock.assert_hits_async(1).await;
+    send_mock.assert_hits_async(2).await;
+}
+
+#[tokio::test]
+async fn send_template_checked_placeholder_mismatch() {
+    let harness = Harness::start().await;
+    let templates_mock = harness
+        .expect(
+            ExpectedCall::new(GET, "/whatsapp/2/senders/441134960000/templates")
+                .responds(200, REMINDER_TEMPLATES),
+        )
+        .await;
+    let send_mock = harness
+        .expect(ExpectedCall::new(POST, PATH_SEND_TEMPLATE).responds(200, r#"{"messages": []}"#))
+        .await;
+    let wa_client = WhatsappClient::with_configuration(harness.configuration());
+
+    let error = wa_client
+        .send_template_checked(get_reminder_request_body("appointment_reminder", &["John"]))
+        .await
+        .unwrap_err();
+
+    templates_mock.assert_async().await;
+    send_mock.assert_hits_async(0).await;
+    assert!(matches!(
+        &error,
+        SdkError::TemplateMismatch { template, language, problem }
+            if template == "appointment_reminder"
+                && language == "en"
+                && problem.contains("2 placeholders")
+    ));
+}
+
+#[tokio::test]
+async fn send_template_checked_refetches_stale_cache() {
+    let harness = Harness::start().await;
+    let templates_path = "/whatsapp/2/senders/441134960000/templates";
+    let send_mock = harness
+        .expect(ExpectedCall::new(POST, PATH_SEND_TEMPLATE).responds(200, r#"{"messages": []}"#))
+        .await;
+    let old_templates_mock = harness
+        .expect(ExpectedCall::new(GET, templates_path).responds(200, REMINDER_TEMPLATES))
+        .await;
+    let wa_client = WhatsappClient::with_configuration(harness.configuration());
+
+    wa_client
+        .send_template_checked(get_reminder_request_body(
+            "appointment_reminder",
+            &["John", "Monday"],
+        ))
+        .await
+        .unwrap();
+    old_templates_mock.assert_async().await;
+    old_templates_mock.delete_async().await;
+
+    let new_templates = REMINDER_TEMPLATES.replace("appointment_reminder", "booking_reminder");
+    let new_templates_mock = harness
+        .expect(ExpectedCall::new(GET, templates_path).responds(200, &new_templates))
+        .await;
+
+    wa_client
+        .send_template_checked(get_reminder_request_body(
+            "booking_reminder",
+            &["John", "Monday"],
+        ))
+        .await
+        .unwrap();
+    let error = wa_client
+        .send_template_checked(get_reminder_request_body(
+            "unknown_reminder",
+            &["John", "Monday"],
+        ))
+        .await
+        .unwrap_err();
+
+    new_templates_mock.assert_hits_async(2).await;
+    send_mock.assert_hits_async(2).await;
+    assert!(matches!(
+        error,
+        SdkError::TemplateNotFound { template, .. } if template == "unknown_reminder"
+    ));
+}
+
+#[tokio::test]
+async fn prepare_send_text_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_SEND_TEXT);
+        then.status(200);
+    });
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let prepared = wa_client
+        .prepare_send_text(get_dummy_send_text_request_body())
+        .unwrap();
+
+    assert_eq!(prepared.method, reqwest::Method::POST);
+    assert_eq!(
+        prepared.url,
+        format!("{}{}", server.base_url(), PATH_SEND_TEXT)
+    );
+    assert!(prepared
+        .headers
+        .contains(&("Authorization".to_string(), "App [REDACTED]".to_string())));
+    assert_eq!(
+        prepared.body,
+        PreparedBody::Json(
+            r#"{"from":"44444444444","to":"55555555555","content":{"text":"some text"}}"#
+                .to_string()
+        )
+    );
+    mock.assert_hits_async(0).await;
+}
+
+#[tokio::test]
+async fn prepare_send_template_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_SEND_TEMPLATE);
+        then.status(200);
+    });
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()));
+    let request_body = get_reminder_request_body("appointment_reminder", &["John"]);
+
+    let prepared = wa_client
+        .prepare_send_template(request_body.clone())
+        .unwrap();
+
+    assert_eq!(prepared.method, reqwest::Method::POST);
+    assert_eq!(
+        prepared.url,
+        format!("{}{}", server.base_url(), PATH_SEND_TEMPLATE)
+    );
+    assert_eq!(
+        prepared.body,
+        PreparedBody::Json(serde_json::to_string(&request_body).unwrap())
+    );
+    mock.assert_hits_async(0).await;
+}
+
+#[test]
+fn prepare_send_template_invalid() {
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration("https://some.url"));
+    let request_body = get_reminder_request_body("", &["John"]);
+
+    assert!(matches!(
+        wa_client.prepare_send_template(request_body),
+        Err(SdkError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn get_sender_webhook_encodes_sender() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        "/whatsapp/1/senders/44%2F..%3Fx%23y/webhook",
+        r#"{"url": "https://example.com/inbound"}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsappClient::with_configuration(get_test_configuration(&server.base_url()));
+
+    let response = wa_client.get_sender_webhook("44/..?x#y").await.unwrap();
+
+    assert_eq!(response.body.url, "https://example.com/inbound");
+}
@@ -0,0 +1,105 @@
+//! Module with client and endpoint functions for the Voice channel.
+
+use validator::Validate;
+
+use crate::api::{
+    build_api_error, build_http_client, connection_info, endpoints, parse_response,
+    read_response_text, send_no_body_request, RequestOptions, SdkError, SdkResponse,
+};
+use crate::configuration::Configuration;
+use crate::model::voice::{GetDeliveryReportsQueryParameters, GetDeliveryReportsResponseBody};
+use crate::model::ToQueryString;
+
+pub const PATH_GET_DELIVERY_REPORTS: &str = endpoints::voice::get_delivery_reports();
+
+/// Main asynchronous client for the Infobip Voice channel.
+#[derive(Clone, Debug)]
+pub struct VoiceClient {
+    pub configuration: Configuration,
+    pub http_client: reqwest::Client,
+}
+
+impl VoiceClient {
+    /// Builds and returns a new asynchronous `VoiceClient` with a specified configuration.
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        VoiceClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new asynchronous `VoiceClient` sending requests through `http_client`,
+    /// e.g. to share its connection pool with other clients.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
+        VoiceClient {
+            configuration,
+            http_client,
+        }
+    }
+
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        VoiceClient {
+            configuration: options.apply(&self.configuration),
+            ..self.clone()
+        }
+    }
+
+    /// Get one-time delivery reports of calls, including the voice-specific details like the
+    /// DTMF codes entered by the callee. Each report is returned only once.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::voice::VoiceClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::voice::GetDeliveryReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = VoiceClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .get_delivery_reports(GetDeliveryReportsQueryParameters::new())
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_delivery_reports(
+        &self,
+        query_parameters: GetDeliveryReportsQueryParameters,
+    ) -> Result<SdkResponse<GetDeliveryReportsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let query_string = query_parameters.to_query_string()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            &query_string,
+            reqwest::Method::GET,
+            PATH_GET_DELIVERY_REPORTS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+}
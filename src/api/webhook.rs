@@ -0,0 +1,308 @@
+//! Helpers for handling webhook deliveries, like delivery reports and inbound messages, shared by
+//! all channels.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderMap;
+
+#[cfg(all(feature = "axum", feature = "sms"))]
+pub mod axum;
+
+/// Default number of events remembered by a `WebhookDeduplicator`.
+pub const DEFAULT_DEDUPLICATOR_CAPACITY: usize = 10_000;
+
+/// Default time for which a `WebhookDeduplicator` remembers an event. Covers the retries of a
+/// webhook delivery that failed with a non-2xx response.
+pub const DEFAULT_DEDUPLICATOR_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Events received over webhooks, identified by their type, message ID and completion time.
+pub trait WebhookEvent {
+    /// Type of the event, so events of different kinds about the same message are told apart.
+    const EVENT_TYPE: &'static str;
+
+    /// Returns the ID of the message the event is about, if present.
+    fn message_id(&self) -> Option<&str>;
+
+    /// Returns the time the event happened, like `doneAt` of a report, if present.
+    fn done_at(&self) -> Option<&str>;
+}
+
+/// Checks that a webhook request comes from Infobip, e.g. by comparing a shared secret header or
+/// the source of the request. Implemented for closures taking the headers and the raw body.
+pub trait WebhookVerifier: Send + Sync {
+    /// Returns whether the request with these headers and body is accepted.
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> bool;
+}
+
+impl<F> WebhookVerifier for F
+where
+    F: Fn(&HeaderMap, &[u8]) -> bool + Send + Sync,
+{
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> bool {
+        self(headers, body)
+    }
+}
+
+type EventKey = (&'static str, String, Option<String>);
+
+#[derive(Debug, Default)]
+struct SeenEvents {
+    keys: HashSet<EventKey>,
+    // Keys in the order they were recorded, with the time they were recorded.
+    order: VecDeque<(Instant, EventKey)>,
+}
+
+/// Remembers recently received webhook events, so retried deliveries of the same event are only
+/// processed once. Memory is bounded: at most `capacity` events are kept, the oldest ones being
+/// evicted first, and events are forgotten after `ttl`. Eviction happens when recording, so no
+/// background task is needed.
+///
+/// Events are recorded when checked, before they are processed. When processing fails and the
+/// delivery should be retried, `forget` the event so the retried delivery is processed again.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use infobip_sdk::api::webhook::WebhookDeduplicator;
+/// # use infobip_sdk::model::sms::Report;
+/// let deduplicator = WebhookDeduplicator::new(1000, Duration::from_secs(3600));
+/// let report = Report {
+///     message_id: Some("some-message-id".to_string()),
+///     done_at: Some("2022-10-01T12:30:00.000+0000".to_string()),
+///     ..Default::default()
+/// };
+///
+/// assert!(deduplicator.check_and_record(&report));
+/// assert!(!deduplicator.check_and_record(&report));
+///
+/// // Processing failed, so the retried delivery must not be dropped as a duplicate.
+/// deduplicator.forget(&report);
+/// assert!(deduplicator.check_and_record(&report));
+/// ```
+#[derive(Debug)]
+pub struct WebhookDeduplicator {
+    capacity: usize,
+    ttl: Duration,
+    seen: Mutex<SeenEvents>,
+}
+
+impl WebhookDeduplicator {
+    /// Creates a deduplicator remembering at most `capacity` events, each for `ttl`.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        WebhookDeduplicator {
+            capacity,
+            ttl,
+            seen: Mutex::new(SeenEvents::default()),
+        }
+    }
+
+    /// Returns whether the event is seen for the first time, and records it. Returns `false` for
+    /// events recorded less than `ttl` ago and still remembered. Events without a message ID
+    /// cannot be told apart, so they are never reported as duplicates.
+    pub fn check_and_record<E: WebhookEvent>(&self, event: &E) -> bool {
+        self.check_and_record_at(event, Instant::now())
+    }
+
+    /// Forgets the event, so its next delivery is reported as seen for the first time. Returns
+    /// whether the event was remembered.
+    pub fn forget<E: WebhookEvent>(&self, event: &E) -> bool {
+        let key = match event_key(event) {
+            Some(key) => key,
+            None => return false,
+        };
+
+        let mut seen = self.lock();
+        if !seen.keys.remove(&key) {
+            return false;
+        }
+        seen.order.retain(|(_, recorded)| recorded != &key);
+
+        true
+    }
+
+    /// Returns the number of events currently remembered, including expired ones that were not
+    /// evicted yet.
+    pub fn len(&self) -> usize {
+        self.lock().keys.len()
+    }
+
+    /// Returns whether no events are remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn check_and_record_at<E: WebhookEvent>(&self, event: &E, now: Instant) -> bool {
+        let key = match event_key(event) {
+            Some(key) => key,
+            None => return true,
+        };
+
+        let mut seen = self.lock();
+        while let Some((recorded_at, _)) = seen.order.front() {
+            if now.saturating_duration_since(*recorded_at) < self.ttl {
+                break;
+            }
+            if let Some((_, expired)) = seen.order.pop_front() {
+                seen.keys.remove(&expired);
+            }
+        }
+
+        if seen.keys.contains(&key) {
+            return false;
+        }
+        if self.capacity == 0 {
+            return true;
+        }
+        while seen.order.len() >= self.capacity {
+            if let Some((_, oldest)) = seen.order.pop_front() {
+                seen.keys.remove(&oldest);
+            }
+        }
+        seen.keys.insert(key.clone());
+        seen.order.push_back((now, key));
+
+        true
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SeenEvents> {
+        self.seen
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// Returns the key identifying the event, or `None` for events without a message ID.
+fn event_key<E: WebhookEvent>(event: &E) -> Option<EventKey> {
+    let message_id = event.message_id()?;
+
+    Some((
+        E::EVENT_TYPE,
+        message_id.to_string(),
+        event.done_at().map(str::to_string),
+    ))
+}
+
+impl Default for WebhookDeduplicator {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEDUPLICATOR_CAPACITY, DEFAULT_DEDUPLICATOR_TTL)
+    }
+}
+
+#[cfg(feature = "sms")]
+impl WebhookEvent for crate::model::sms::Report {
+    const EVENT_TYPE: &'static str = "sms.report";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.done_at.as_deref()
+    }
+}
+
+#[cfg(feature = "sms")]
+impl<'a> WebhookEvent for crate::model::sms::ReportRef<'a> {
+    const EVENT_TYPE: &'static str = "sms.report";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.done_at.as_deref()
+    }
+}
+
+#[cfg(feature = "sms")]
+impl WebhookEvent for crate::model::sms::InboundSmsReport {
+    const EVENT_TYPE: &'static str = "sms.inbound";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.received_at.as_deref()
+    }
+}
+
+#[cfg(feature = "sms")]
+impl<'a> WebhookEvent for crate::model::sms::InboundSmsReportRef<'a> {
+    const EVENT_TYPE: &'static str = "sms.inbound";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.received_at.as_deref()
+    }
+}
+
+#[cfg(feature = "email")]
+impl WebhookEvent for crate::model::email::Report {
+    const EVENT_TYPE: &'static str = "email.report";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.done_at.as_deref()
+    }
+}
+
+#[cfg(feature = "email")]
+impl WebhookEvent for crate::model::email::InboundEmail {
+    const EVENT_TYPE: &'static str = "email.inbound";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.received_at.as_deref()
+    }
+}
+
+#[cfg(feature = "whatsapp")]
+impl WebhookEvent for crate::model::whatsapp::Report {
+    const EVENT_TYPE: &'static str = "whatsapp.report";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.done_at.as_deref()
+    }
+}
+
+#[cfg(feature = "whatsapp")]
+impl WebhookEvent for crate::model::whatsapp::InboundMessage {
+    const EVENT_TYPE: &'static str = "whatsapp.inbound";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.received_at.as_deref()
+    }
+}
+
+#[cfg(feature = "voice")]
+impl WebhookEvent for crate::model::voice::Report {
+    const EVENT_TYPE: &'static str = "voice.report";
+
+    fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    fn done_at(&self) -> Option<&str> {
+        self.done_at.as_deref()
+    }
+}
@@ -0,0 +1,259 @@
+//! Extractors for axum handlers receiving SMS webhooks. Each extractor reads the body, checks it
+//! with the `WebhookVerifier` of the `WebhookOptions` taken from the router state, parses it as
+//! JSON or, with the `xml` feature, as XML depending on its content type, and drops the events
+//! already seen by the `WebhookDeduplicator`. Failures are rejected with a `WebhookRejection`.
+//!
+//! # Example
+//! ```no_run
+//! # use axum::http::HeaderMap;
+//! # use axum::routing::post;
+//! # use axum::Router;
+//! # use infobip_sdk::api::webhook::axum::{InfobipDeliveryReports, WebhookOptions};
+//! # use infobip_sdk::api::webhook::WebhookDeduplicator;
+//! async fn delivery_reports(InfobipDeliveryReports(reports): InfobipDeliveryReports) {
+//!     for report in reports {
+//!         println!("{:?}: {:?}", report.message_id, report.status);
+//!     }
+//! }
+//!
+//! let options = WebhookOptions::new()
+//!     .with_verifier(|headers: &HeaderMap, _body: &[u8]| {
+//!         headers.get("x-webhook-secret").map_or(false, |value| value == "some-secret")
+//!     })
+//!     .with_deduplicator(WebhookDeduplicator::default());
+//!
+//! let app: Router = Router::new()
+//!     .route("/sms/reports", post(delivery_reports))
+//!     .with_state(options);
+//! ```
+
+use std::sync::Arc;
+
+use ::axum::async_trait;
+use ::axum::body::{Bytes, HttpBody};
+use ::axum::extract::{FromRef, FromRequest};
+use ::axum::http::{header, HeaderMap, Request, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use ::axum::BoxError;
+use thiserror::Error;
+
+use crate::api::webhook::{WebhookDeduplicator, WebhookEvent, WebhookVerifier};
+use crate::model::sms::{GetDeliveryReportsResponseBody, InboundMessagesWebhook, Report};
+
+/// Verifier and deduplicator used by the extractors, read from the router state. Routers
+/// without state use the default options, which accept every request and keep duplicates.
+#[derive(Clone, Default)]
+pub struct WebhookOptions {
+    verifier: Option<Arc<dyn WebhookVerifier>>,
+    deduplicator: Option<Arc<WebhookDeduplicator>>,
+}
+
+impl WebhookOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the verifier checking that requests come from Infobip. Requests it rejects are
+    /// answered with `401 Unauthorized`.
+    pub fn with_verifier<V: WebhookVerifier + 'static>(mut self, verifier: V) -> Self {
+        self.verifier = Some(Arc::new(verifier));
+        self
+    }
+
+    /// Sets the deduplicator dropping retried deliveries of already received events. Share one
+    /// deduplicator between routes receiving the same events.
+    pub fn with_deduplicator(mut self, deduplicator: WebhookDeduplicator) -> Self {
+        self.deduplicator = Some(Arc::new(deduplicator));
+        self
+    }
+
+    /// Returns the deduplicator, if set, e.g. to `forget` the events a handler failed to process
+    /// before answering with an error status so the retried delivery is processed again.
+    pub fn deduplicator(&self) -> Option<&WebhookDeduplicator> {
+        self.deduplicator.as_deref()
+    }
+
+    fn retain_new<E: WebhookEvent>(&self, events: Vec<E>) -> Vec<E> {
+        match &self.deduplicator {
+            Some(deduplicator) => events
+                .into_iter()
+                .filter(|event| deduplicator.check_and_record(event))
+                .collect(),
+            None => events,
+        }
+    }
+}
+
+impl std::fmt::Debug for WebhookOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookOptions")
+            .field("verifier", &self.verifier.is_some())
+            .field("deduplicator", &self.deduplicator)
+            .finish()
+    }
+}
+
+impl FromRef<()> for WebhookOptions {
+    fn from_ref(_state: &()) -> Self {
+        WebhookOptions::default()
+    }
+}
+
+/// Reason a webhook request was rejected by an extractor.
+#[derive(Debug, Error)]
+pub enum WebhookRejection {
+    /// The verifier rejected the request. Answered with `401 Unauthorized`.
+    #[error("webhook request could not be verified")]
+    Unauthorized,
+
+    /// The body is neither JSON nor, with the `xml` feature, XML. Answered with
+    /// `415 Unsupported Media Type`.
+    #[error("unsupported webhook content type: {0}")]
+    UnsupportedContentType(String),
+
+    /// The body could not be read or does not match the webhook model. Answered with
+    /// `400 Bad Request`.
+    #[error("invalid webhook body: {0}")]
+    InvalidBody(String),
+}
+
+impl WebhookRejection {
+    /// Returns the status code the request is answered with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            WebhookRejection::Unauthorized => StatusCode::UNAUTHORIZED,
+            WebhookRejection::UnsupportedContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            WebhookRejection::InvalidBody(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+/// SMS delivery reports of a webhook request, without the reports already received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfobipDeliveryReports(pub Vec<Report>);
+
+#[async_trait]
+impl<S, B> FromRequest<S, B> for InfobipDeliveryReports
+where
+    WebhookOptions: FromRef<S>,
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let options = WebhookOptions::from_ref(state);
+        let (format, body) = read_verified_body(request, state, &options).await?;
+
+        let response_body = match format {
+            BodyFormat::Json => serde_json::from_slice::<GetDeliveryReportsResponseBody>(&body)
+                .map_err(|error| WebhookRejection::InvalidBody(error.to_string()))?,
+            #[cfg(feature = "xml")]
+            BodyFormat::Xml => crate::model::sms::parse_delivery_reports_xml(&body)
+                .map_err(|error| WebhookRejection::InvalidBody(error.to_string()))?,
+        };
+
+        Ok(InfobipDeliveryReports(
+            options.retain_new(response_body.results.unwrap_or_default()),
+        ))
+    }
+}
+
+/// Inbound SMS webhook request, without the messages already received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfobipInboundSms(pub InboundMessagesWebhook);
+
+#[async_trait]
+impl<S, B> FromRequest<S, B> for InfobipInboundSms
+where
+    WebhookOptions: FromRef<S>,
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let options = WebhookOptions::from_ref(state);
+        let (format, body) = read_verified_body(request, state, &options).await?;
+
+        let mut webhook: InboundMessagesWebhook = match format {
+            BodyFormat::Json => serde_json::from_slice(&body)
+                .map_err(|error| WebhookRejection::InvalidBody(error.to_string()))?,
+            #[cfg(feature = "xml")]
+            BodyFormat::Xml => {
+                return Err(WebhookRejection::UnsupportedContentType(
+                    "inbound messages are only sent as JSON".to_string(),
+                ))
+            }
+        };
+        webhook.results = webhook.results.map(|results| options.retain_new(results));
+
+        Ok(InfobipInboundSms(webhook))
+    }
+}
+
+enum BodyFormat {
+    Json,
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+// Reads the body and checks it with the verifier before looking at its content type, so
+// unverified requests learn nothing about the accepted formats.
+async fn read_verified_body<S, B>(
+    request: Request<B>,
+    state: &S,
+    options: &WebhookOptions,
+) -> Result<(BodyFormat, Bytes), WebhookRejection>
+where
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    let headers = request.headers().clone();
+    let body = Bytes::from_request(request, state)
+        .await
+        .map_err(|rejection| WebhookRejection::InvalidBody(rejection.body_text()))?;
+
+    if let Some(verifier) = &options.verifier {
+        if !verifier.verify(&headers, &body) {
+            return Err(WebhookRejection::Unauthorized);
+        }
+    }
+
+    Ok((body_format(&headers)?, body))
+}
+
+// Requests without a content type are read as JSON, the default of `notifyContentType`.
+fn body_format(headers: &HeaderMap) -> Result<BodyFormat, WebhookRejection> {
+    let content_type = match headers.get(header::CONTENT_TYPE) {
+        Some(content_type) => content_type.to_str().unwrap_or_default(),
+        None => return Ok(BodyFormat::Json),
+    };
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    match essence.as_str() {
+        "application/json" => Ok(BodyFormat::Json),
+        #[cfg(feature = "xml")]
+        "application/xml" | "text/xml" => Ok(BodyFormat::Xml),
+        _ => Err(WebhookRejection::UnsupportedContentType(
+            content_type.to_string(),
+        )),
+    }
+}
@@ -1,18 +1,25 @@
 //! Module with client and endpoint functions for the WhatsApp channel.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
+use chrono::{DateTime, Duration, Utc};
 use reqwest::{Method, Response};
-use serde::Serialize;
 use validator::Validate;
 
 use crate::api::{
-    build_api_error, send_no_body_request, send_valid_json_request, SdkError, SdkResponse,
+    build_api_error, build_http_client, connection_info, endpoints, fill_path, parse_response,
+    prepare_json_request, read_response_text, send_no_body_request, send_valid_json_request,
+    PreparedRequest, RequestOptions, SdkError, SdkResponse, Tracked,
 };
 use crate::configuration::Configuration;
+use crate::model::common::parse_timestamp;
 use crate::model::whatsapp::{
-    CreateTemplateRequestBody, CreateTemplateResponseBody, GetTemplatesResponseBody,
-    SendAudioRequestBody, SendAudioResponseBody, SendContactRequestBody, SendContactResponseBody,
+    CreateTemplateRequestBody, CreateTemplateResponseBody, GetSenderWebhookResponseBody,
+    GetSendersResponseBody, GetTemplatesResponseBody, InboundMessage, SendAudioRequestBody,
+    SendAudioResponseBody, SendContactRequestBody, SendContactResponseBody,
     SendDocumentRequestBody, SendDocumentResponseBody, SendImageRequestBody, SendImageResponseBody,
     SendInteractiveButtonsRequestBody, SendInteractiveButtonsResponseBody,
     SendInteractiveListRequestBody, SendInteractiveListResponseBody,
@@ -20,55 +27,272 @@ use crate::model::whatsapp::{
     SendInteractiveProductRequestBody, SendInteractiveProductResponseBody, SendLocationRequestBody,
     SendLocationResponseBody, SendStickerRequestBody, SendStickerResponseBody,
     SendTemplateRequestBody, SendTemplateResponseBody, SendTextRequestBody, SendTextResponseBody,
-    SendVideoRequestBody, SendVideoResponseBody,
+    SendVideoRequestBody, SendVideoResponseBody, Template, UpdateSenderWebhookRequestBody,
+    UpdateSenderWebhookResponseBody,
 };
+use crate::model::ApiRequest;
 
-pub const PATH_CREATE_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates";
-pub const PATH_DELETE_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates/{templateName}";
-pub const PATH_GET_TEMPLATES: &str = "/whatsapp/2/senders/{sender}/templates";
-pub const PATH_SEND_AUDIO: &str = "/whatsapp/1/message/audio";
-pub const PATH_SEND_CONTACT: &str = "/whatsapp/1/message/contact";
-pub const PATH_SEND_DOCUMENT: &str = "/whatsapp/1/message/document";
-pub const PATH_SEND_IMAGE: &str = "/whatsapp/1/message/image";
-pub const PATH_SEND_INTERACTIVE_BUTTONS: &str = "/whatsapp/1/message/interactive/buttons";
-pub const PATH_SEND_INTERACTIVE_LIST: &str = "/whatsapp/1/message/interactive/list";
+pub const PATH_CREATE_TEMPLATE: &str = endpoints::whatsapp::create_template();
+pub const PATH_DELETE_TEMPLATE: &str = endpoints::whatsapp::delete_template();
+pub const PATH_GET_SENDERS: &str = endpoints::whatsapp::get_senders();
+pub const PATH_GET_SENDER_WEBHOOK: &str = endpoints::whatsapp::get_sender_webhook();
+pub const PATH_GET_TEMPLATES: &str = endpoints::whatsapp::get_templates();
+pub const PATH_SEND_AUDIO: &str = endpoints::whatsapp::send_audio();
+pub const PATH_SEND_CONTACT: &str = endpoints::whatsapp::send_contact();
+pub const PATH_SEND_DOCUMENT: &str = endpoints::whatsapp::send_document();
+pub const PATH_SEND_IMAGE: &str = endpoints::whatsapp::send_image();
+pub const PATH_SEND_INTERACTIVE_BUTTONS: &str = endpoints::whatsapp::send_interactive_buttons();
+pub const PATH_SEND_INTERACTIVE_LIST: &str = endpoints::whatsapp::send_interactive_list();
 pub const PATH_SEND_INTERACTIVE_MULTIPRODUCT: &str =
-    "/whatsapp/1/message/interactive/multi-product";
-pub const PATH_SEND_INTERACTIVE_PRODUCT: &str = "/whatsapp/1/message/interactive/product";
-pub const PATH_SEND_LOCATION: &str = "/whatsapp/1/message/location";
-pub const PATH_SEND_STICKER: &str = "/whatsapp/1/message/sticker";
-pub const PATH_SEND_TEMPLATE: &str = "/whatsapp/1/message/template";
-pub const PATH_SEND_TEXT: &str = "/whatsapp/1/message/text";
-pub const PATH_SEND_VIDEO: &str = "/whatsapp/1/message/video";
+    endpoints::whatsapp::send_interactive_multiproduct();
+pub const PATH_SEND_INTERACTIVE_PRODUCT: &str = endpoints::whatsapp::send_interactive_product();
+pub const PATH_SEND_LOCATION: &str = endpoints::whatsapp::send_location();
+pub const PATH_SEND_STICKER: &str = endpoints::whatsapp::send_sticker();
+pub const PATH_SEND_TEMPLATE: &str = endpoints::whatsapp::send_template();
+pub const PATH_SEND_TEXT: &str = endpoints::whatsapp::send_text();
+pub const PATH_SEND_VIDEO: &str = endpoints::whatsapp::send_video();
+pub const PATH_UPDATE_SENDER_WEBHOOK: &str = endpoints::whatsapp::update_sender_webhook();
+
+/// Hours after the latest inbound message of a user during which free-form messages can be sent
+/// to them. Outside this window, only template messages are delivered.
+pub const CONVERSATION_WINDOW_HOURS: i64 = 24;
+
+/// Storage of the time of the latest inbound message for each pair of sender and user.
+pub trait ConversationWindowStore: Send + Sync {
+    /// Returns the time of the latest inbound message sent by `to` to `sender`, if any.
+    fn last_inbound(&self, sender: &str, to: &str) -> Option<DateTime<Utc>>;
+
+    /// Stores the time of the latest inbound message sent by `to` to `sender`.
+    fn record_inbound(&self, sender: &str, to: &str, received_at: DateTime<Utc>);
+}
+
+/// In-memory `ConversationWindowStore`, used by default.
+#[derive(Debug, Default)]
+pub struct InMemoryWindowStore {
+    last_inbound: Mutex<HashMap<(String, String), DateTime<Utc>>>,
+}
+
+impl ConversationWindowStore for InMemoryWindowStore {
+    fn last_inbound(&self, sender: &str, to: &str) -> Option<DateTime<Utc>> {
+        self.last_inbound
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&(sender.to_string(), to.to_string()))
+            .copied()
+    }
+
+    fn record_inbound(&self, sender: &str, to: &str, received_at: DateTime<Utc>) {
+        self.last_inbound
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert((sender.to_string(), to.to_string()), received_at);
+    }
+}
+
+/// Tracks the customer care window of each conversation, opened for
+/// `CONVERSATION_WINDOW_HOURS` by every inbound message, so free-form messages sent outside of
+/// it can be rejected before reaching the API.
+#[derive(Clone)]
+pub struct ConversationWindowTracker {
+    store: Arc<dyn ConversationWindowStore>,
+}
+
+impl ConversationWindowTracker {
+    /// Builds a tracker with an `InMemoryWindowStore`.
+    pub fn new() -> Self {
+        ConversationWindowTracker::with_store(InMemoryWindowStore::default())
+    }
+
+    /// Builds a tracker with a custom store, e.g. one shared between instances.
+    pub fn with_store<S: ConversationWindowStore + 'static>(store: S) -> Self {
+        ConversationWindowTracker {
+            store: Arc::new(store),
+        }
+    }
+
+    /// Records an inbound message sent by `to` to `sender`. Older messages than the latest
+    /// recorded one are ignored.
+    pub fn record_inbound(&self, sender: &str, to: &str, received_at: DateTime<Utc>) {
+        if self
+            .store
+            .last_inbound(sender, to)
+            .map_or(true, |last| last < received_at)
+        {
+            self.store.record_inbound(sender, to, received_at);
+        }
+    }
+
+    /// Records a message parsed from the inbound message callback. Messages without a valid
+    /// `receivedAt` are recorded as received now.
+    pub fn record_inbound_message(&self, message: &InboundMessage) {
+        if let (Some(sender), Some(user)) = (&message.to, &message.from) {
+            let received_at = message
+                .received_at
+                .as_deref()
+                .and_then(parse_timestamp)
+                .map_or_else(Utc::now, |received_at| received_at.with_timezone(&Utc));
+
+            self.record_inbound(sender, user, received_at);
+        }
+    }
+
+    /// Returns whether a free-form message can be sent from `sender` to `to` now.
+    pub fn can_send_freeform(&self, sender: &str, to: &str) -> bool {
+        self.can_send_freeform_at(sender, to, Utc::now())
+    }
+
+    /// Returns whether a free-form message can be sent from `sender` to `to` at `now`.
+    pub fn can_send_freeform_at(&self, sender: &str, to: &str, now: DateTime<Utc>) -> bool {
+        self.store.last_inbound(sender, to).map_or(false, |last| {
+            now - last < Duration::hours(CONVERSATION_WINDOW_HOURS)
+        })
+    }
+}
+
+impl Default for ConversationWindowTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ConversationWindowTracker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ConversationWindowTracker")
+    }
+}
+
+/// How long `send_template_checked` reuses the templates fetched for a sender, unless changed with
+/// `WhatsappClient::with_template_cache_ttl`.
+pub const DEFAULT_TEMPLATE_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+// Templates of a sender with the time they were fetched.
+type CachedTemplates = (Instant, Vec<Template>);
+
+// Templates of each sender, fetched by `send_template_checked`, shared by the clones of a client.
+#[derive(Clone)]
+struct TemplateCache {
+    ttl: std::time::Duration,
+    entries: Arc<Mutex<HashMap<String, CachedTemplates>>>,
+}
+
+impl TemplateCache {
+    fn new(ttl: std::time::Duration) -> Self {
+        TemplateCache {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Returns the templates of the sender, if fetched less than `ttl` ago.
+    fn get(&self, sender: &str) -> Option<Vec<Template>> {
+        let entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries
+            .get(sender)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, templates)| templates.clone())
+    }
+
+    fn insert(&self, sender: &str, templates: Vec<Template>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(sender.to_string(), (Instant::now(), templates));
+    }
+}
+
+impl fmt::Debug for TemplateCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TemplateCache")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+fn find_template<'a>(
+    templates: &'a [Template],
+    name: &str,
+    language: &str,
+) -> Option<&'a Template> {
+    templates.iter().find(|template| {
+        template.name.as_deref() == Some(name)
+            && template.language.map_or(false, |template_language| {
+                template_language.to_string() == language
+            })
+    })
+}
 
 /// Main asynchronous client for the Infobip WhatsApp channel.
 #[derive(Clone, Debug)]
 pub struct WhatsappClient {
     pub configuration: Configuration,
     pub http_client: reqwest::Client,
+    window_tracker: Option<ConversationWindowTracker>,
+    template_cache: TemplateCache,
 }
 
 impl WhatsappClient {
     /// Builds and returns a new asynchronous `WhatsappClient` with a specified configuration.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        let http_client = build_http_client(&configuration);
+
+        WhatsappClient::with_http_client(configuration, http_client)
+    }
+
+    /// Builds and returns a new asynchronous `WhatsappClient` sending requests through `http_client`,
+    /// e.g. to share its connection pool with other clients.
+    pub fn with_http_client(configuration: Configuration, http_client: reqwest::Client) -> Self {
         WhatsappClient {
             configuration,
-            http_client: reqwest::Client::new(),
+            http_client,
+            window_tracker: None,
+            template_cache: TemplateCache::new(DEFAULT_TEMPLATE_CACHE_TTL),
+        }
+    }
+
+    /// Returns a copy of the client whose requests use `options`, like the API key of a tenant,
+    /// sharing the HTTP connection pool of this client. This client is not modified.
+    pub fn with_request_options(&self, options: &RequestOptions) -> Self {
+        WhatsappClient {
+            configuration: options.apply(&self.configuration),
+            ..self.clone()
         }
     }
 
-    async fn send_request<T: Validate + Serialize>(
+    /// Enables the conversation window guard: `send_text` fails with
+    /// `SdkError::ConversationWindowClosed`, before any network call, when the tracker has no
+    /// inbound message from the recipient in the last `CONVERSATION_WINDOW_HOURS`.
+    pub fn with_window_tracker(mut self, tracker: ConversationWindowTracker) -> Self {
+        self.window_tracker = Some(tracker);
+        self
+    }
+
+    /// Returns the conversation window tracker of the client, if the guard is enabled.
+    pub fn window_tracker(&self) -> Option<&ConversationWindowTracker> {
+        self.window_tracker.as_ref()
+    }
+
+    /// Sets how long `send_template_checked` reuses the templates fetched for a sender, starting
+    /// with an empty cache.
+    pub fn with_template_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.template_cache = TemplateCache::new(ttl);
+        self
+    }
+
+    async fn send_request<T: ApiRequest + Validate>(
         &self,
         request_body: T,
-        parameters: HashMap<String, String>,
+        query_string: &str,
         method: Method,
         path: &str,
-    ) -> Result<Response, SdkError> {
+    ) -> Result<Tracked<Response>, SdkError> {
         send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters,
+            query_string,
             method,
             path,
         )
@@ -77,7 +301,8 @@ impl WhatsappClient {
 
     /// Send a text message to a single recipient. Text messages can only be successfully delivered
     /// if the recipient has contacted the business within the last 24 hours, otherwise template
-    /// message should be used.
+    /// message should be used. With a window tracker set by `with_window_tracker`, messages
+    /// outside of that window are rejected before sending.
     ///
     /// # Example
     /// ```no_run
@@ -106,23 +331,75 @@ impl WhatsappClient {
         &self,
         request_body: SendTextRequestBody,
     ) -> Result<SdkResponse<SendTextResponseBody>, SdkError> {
+        if let Some(tracker) = &self.window_tracker {
+            if !tracker.can_send_freeform(&request_body.from, &request_body.to) {
+                return Err(SdkError::ConversationWindowClosed(
+                    request_body.from,
+                    request_body.to,
+                ));
+            }
+        }
+
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_TEXT)
+            .send_request(request_body, "", Method::POST, PATH_SEND_TEXT)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
+    /// Build the request that `send_text` would perform, without sending it. Credentials in the
+    /// returned headers are redacted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{SendTextRequestBody, TextContent};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_body = SendTextRequestBody::new(
+    ///     "44444444444",
+    ///     "55555555555",
+    ///     TextContent::new("Hello, Rustacean!")
+    /// );
+    ///
+    /// let prepared = wa_client.prepare_send_text(request_body)?;
+    ///
+    /// println!("{} {}", prepared.method, prepared.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_send_text(
+        &self,
+        request_body: SendTextRequestBody,
+    ) -> Result<PreparedRequest, SdkError> {
+        prepare_json_request(
+            &self.configuration,
+            &request_body,
+            "",
+            Method::POST,
+            PATH_SEND_TEXT,
+        )
+    }
+
     /// Send a document to a single recipient. Document messages can only be successfully delivered
     /// if the recipient has contacted the business within the last 24 hours, otherwise template
     /// message should be used.
@@ -155,24 +432,25 @@ impl WhatsappClient {
         request_body: SendDocumentRequestBody,
     ) -> Result<SdkResponse<SendDocumentResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_DOCUMENT,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_DOCUMENT)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -208,19 +486,25 @@ impl WhatsappClient {
         request_body: SendImageRequestBody,
     ) -> Result<SdkResponse<SendImageResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_IMAGE)
+            .send_request(request_body, "", Method::POST, PATH_SEND_IMAGE)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -256,19 +540,25 @@ impl WhatsappClient {
         request_body: SendAudioRequestBody,
     ) -> Result<SdkResponse<SendAudioResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_AUDIO)
+            .send_request(request_body, "", Method::POST, PATH_SEND_AUDIO)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -304,19 +594,25 @@ impl WhatsappClient {
         request_body: SendVideoRequestBody,
     ) -> Result<SdkResponse<SendVideoResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_VIDEO)
+            .send_request(request_body, "", Method::POST, PATH_SEND_VIDEO)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -352,24 +648,25 @@ impl WhatsappClient {
         request_body: SendStickerRequestBody,
     ) -> Result<SdkResponse<SendStickerResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_STICKER,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_STICKER)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -405,24 +702,25 @@ impl WhatsappClient {
         request_body: SendLocationRequestBody,
     ) -> Result<SdkResponse<SendLocationResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_LOCATION,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_LOCATION)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -459,24 +757,25 @@ impl WhatsappClient {
         request_body: SendContactRequestBody,
     ) -> Result<SdkResponse<SendContactResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_CONTACT,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_CONTACT)
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -523,21 +822,27 @@ impl WhatsappClient {
         let response = self
             .send_request(
                 request_body,
-                HashMap::new(),
+                "",
                 Method::POST,
                 PATH_SEND_INTERACTIVE_BUTTONS,
             )
             .await?;
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -584,23 +889,24 @@ impl WhatsappClient {
         request_body: SendInteractiveListRequestBody,
     ) -> Result<SdkResponse<SendInteractiveListResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_INTERACTIVE_LIST,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_INTERACTIVE_LIST)
             .await?;
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -641,21 +947,27 @@ impl WhatsappClient {
         let response = self
             .send_request(
                 request_body,
-                HashMap::new(),
+                "",
                 Method::POST,
                 PATH_SEND_INTERACTIVE_PRODUCT,
             )
             .await?;
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -704,21 +1016,27 @@ impl WhatsappClient {
         let response = self
             .send_request(
                 request_body,
-                HashMap::new(),
+                "",
                 Method::POST,
                 PATH_SEND_INTERACTIVE_MULTIPRODUCT,
             )
             .await?;
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -765,21 +1083,27 @@ impl WhatsappClient {
         sender: &str,
         request_body: CreateTemplateRequestBody,
     ) -> Result<SdkResponse<CreateTemplateResponseBody>, SdkError> {
-        let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
+        let path = fill_path(PATH_CREATE_TEMPLATE, &[("sender", sender)]);
 
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, path.as_str())
+            .send_request(request_body, "", Method::POST, path.as_str())
             .await?;
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -805,27 +1129,33 @@ impl WhatsappClient {
         &self,
         sender: &str,
     ) -> Result<SdkResponse<GetTemplatesResponseBody>, SdkError> {
-        let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
+        let path = fill_path(PATH_GET_TEMPLATES, &[("sender", sender)]);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             Method::GET,
             path.as_str(),
         )
         .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -864,14 +1194,15 @@ impl WhatsappClient {
         sender: &str,
         template_name: &str,
     ) -> Result<reqwest::StatusCode, SdkError> {
-        let path = PATH_DELETE_TEMPLATE
-            .replace("{sender}", sender)
-            .replace("{templateName}", template_name);
+        let path = fill_path(
+            PATH_DELETE_TEMPLATE,
+            &[("sender", sender), ("templateName", template_name)],
+        );
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            "",
             Method::DELETE,
             path.as_str(),
         )
@@ -882,8 +1213,8 @@ impl WhatsappClient {
         if status.is_success() {
             Ok(status)
         } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
+            let text = read_response_text(response, &self.configuration).await?;
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 
@@ -931,24 +1262,308 @@ impl WhatsappClient {
         request_body: SendTemplateRequestBody,
     ) -> Result<SdkResponse<SendTemplateResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_TEMPLATE,
-            )
+            .send_request(request_body, "", Method::POST, PATH_SEND_TEMPLATE)
+            .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Build the request that `send_template` would perform, without sending it. Credentials in
+    /// the returned headers are redacted. The template is not checked against the registered ones,
+    /// as `send_template_checked` does.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     FailoverMessage, SendTemplateRequestBody, TemplateBodyContent, TemplateContent,
+    /// #     TemplateData,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let body = TemplateBodyContent::new(vec!["John".to_string()]);
+    /// let content = TemplateContent::new("welcome", TemplateData::new(body), "en");
+    /// let message = FailoverMessage::new("1234567891011", "1234567891012", content);
+    ///
+    /// let prepared = wa_client.prepare_send_template(SendTemplateRequestBody::new(vec![message]))?;
+    ///
+    /// println!("{} {}", prepared.method, prepared.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_send_template(
+        &self,
+        request_body: SendTemplateRequestBody,
+    ) -> Result<PreparedRequest, SdkError> {
+        prepare_json_request(
+            &self.configuration,
+            &request_body,
+            "",
+            Method::POST,
+            PATH_SEND_TEMPLATE,
+        )
+    }
+
+    /// Same as `send_template`, but first checks every message against the registered template
+    /// of its sender, name and language, failing before sending with
+    /// `SdkError::TemplateMismatch` if the number of body placeholders, `quick reply` button
+    /// parameters or `dynamic URL` button parameters differs, or with
+    /// `SdkError::TemplateNotFound` if the template is not registered.
+    ///
+    /// Templates are fetched with `get_templates` and cached for each sender, see
+    /// `with_template_cache_ttl`. A template missing from the cache, e.g. registered after it was
+    /// filled, is fetched again once before failing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     FailoverMessage, SendTemplateRequestBody, TemplateBodyContent, TemplateContent,
+    /// #     TemplateData,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let body = TemplateBodyContent::new(vec!["John".to_string(), "Monday".to_string()]);
+    /// let content = TemplateContent::new("appointment_reminder", TemplateData::new(body), "en");
+    /// let message = FailoverMessage::new("1234567891011", "1234567891012", content);
+    ///
+    /// let response = wa_client
+    ///     .send_template_checked(SendTemplateRequestBody::new(vec![message]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_template_checked(
+        &self,
+        request_body: SendTemplateRequestBody,
+    ) -> Result<SdkResponse<SendTemplateResponseBody>, SdkError> {
+        request_body.validate()?;
+
+        for message in &request_body.messages {
+            let content = &message.content;
+            let template = self
+                .find_registered_template(&message.from, &content.template_name, &content.language)
+                .await?;
+
+            if let Some(structure) = &template.structure {
+                structure
+                    .check_data(&content.template_data)
+                    .map_err(|problem| SdkError::TemplateMismatch {
+                        template: content.template_name.clone(),
+                        language: content.language.clone(),
+                        problem,
+                    })?;
+            }
+        }
+
+        self.send_template(request_body).await
+    }
+
+    // Finds a template in the cache, fetching the templates of the sender if they are not cached,
+    // are stale, or do not have the template.
+    async fn find_registered_template(
+        &self,
+        sender: &str,
+        name: &str,
+        language: &str,
+    ) -> Result<Template, SdkError> {
+        if let Some(templates) = self.template_cache.get(sender) {
+            if let Some(template) = find_template(&templates, name, language) {
+                return Ok(template.clone());
+            }
+        }
+
+        let templates = self
+            .get_templates(sender)
+            .await?
+            .body
+            .templates
+            .unwrap_or_default();
+        let template = find_template(&templates, name, language).cloned();
+        self.template_cache.insert(sender, templates);
+
+        template.ok_or_else(|| SdkError::TemplateNotFound {
+            sender: sender.to_string(),
+            template: name.to_string(),
+            language: language.to_string(),
+        })
+    }
+
+    /// Get the WhatsApp senders registered for the account, with their status and quality
+    /// rating.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.get_senders().await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_senders(&self) -> Result<SdkResponse<GetSendersResponseBody>, SdkError> {
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            Method::GET,
+            PATH_GET_SENDERS,
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Get the webhook configuration of a sender, which tells where inbound messages and reports
+    /// are forwarded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.get_sender_webhook("44444444444").await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sender_webhook(
+        &self,
+        sender: &str,
+    ) -> Result<SdkResponse<GetSenderWebhookResponseBody>, SdkError> {
+        let path = fill_path(PATH_GET_SENDER_WEBHOOK, &[("sender", sender)]);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            "",
+            Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        let status = response.status();
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
+
+        if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
+            Ok(SdkResponse {
+                body,
+                status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
+            })
+        } else {
+            Err(build_api_error(status, &text, &self.configuration))
+        }
+    }
+
+    /// Update the webhook configuration of a sender. The webhook URL must use `https`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsappClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::UpdateSenderWebhookRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = UpdateSenderWebhookRequestBody::new("https://example.com/inbound");
+    /// request_body.forward_inbound_messages = Some(true);
+    ///
+    /// let response = wa_client
+    ///     .update_sender_webhook("44444444444", request_body)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_sender_webhook(
+        &self,
+        sender: &str,
+        request_body: UpdateSenderWebhookRequestBody,
+    ) -> Result<SdkResponse<UpdateSenderWebhookResponseBody>, SdkError> {
+        let path = fill_path(PATH_UPDATE_SENDER_WEBHOOK, &[("sender", sender)]);
+
+        let response = self
+            .send_request(request_body, "", Method::PUT, path.as_str())
             .await?;
 
         let status = response.status();
-        let text = response.text().await?;
+        let connection = connection_info(&response, &self.configuration);
+        let text = read_response_text(response, &self.configuration).await?;
 
         if status.is_success() {
+            let (body, warnings) = parse_response(&text)?;
+
             Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
+                body,
                 status,
+                sandbox: self.configuration.is_sandbox(),
+                warnings,
+                connection,
             })
         } else {
-            Err(build_api_error(status, &text))
+            Err(build_api_error(status, &text, &self.configuration))
         }
     }
 }
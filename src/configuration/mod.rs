@@ -1,72 +1,969 @@
 //! Configuration of the Infobip client
+use std::collections::HashMap;
 use std::env::{self, VarError};
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use regex::Regex;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use thiserror::Error;
+
+use crate::api::diagnostics::DiagnosticsBuffer;
+use crate::api::RequestTracker;
+
+lazy_static! {
+    static ref REGIONAL_HOST: Regex =
+        Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*\.api\.infobip\.com$").unwrap();
+}
+
+/// Attachment extensions rejected by the Infobip Email API.
+pub const DEFAULT_BLOCKED_ATTACHMENT_EXTENSIONS: &[&str] = &[
+    "ade",
+    "adp",
+    "apk",
+    "appx",
+    "appxbundle",
+    "bat",
+    "cab",
+    "chm",
+    "cmd",
+    "com",
+    "cpl",
+    "dll",
+    "dmg",
+    "exe",
+    "hta",
+    "ins",
+    "isp",
+    "iso",
+    "jar",
+    "js",
+    "jse",
+    "lib",
+    "lnk",
+    "mde",
+    "msc",
+    "msi",
+    "msix",
+    "msixbundle",
+    "msp",
+    "mst",
+    "nsh",
+    "pif",
+    "ps1",
+    "scr",
+    "sct",
+    "shb",
+    "sys",
+    "vb",
+    "vbe",
+    "vbs",
+    "vxd",
+    "wsc",
+    "wsf",
+    "wsh",
+];
+
+/// Maximum size in bytes of a multipart request body, like an email with attachments, accepted by
+/// the Infobip API unless the account has a raised limit.
+pub const DEFAULT_MAX_MULTIPART_BODY_SIZE: u64 = 20 * 1024 * 1024;
 
 /// Holds the necessary configuration URL and authentication details of an Infobip client.
-#[derive(Debug, Clone)]
+/// Cloning is cheap, since the settings are shared behind an `Arc` and only copied when a clone is
+/// modified. The `Debug` output redacts all credentials.
+#[derive(Clone)]
 pub struct Configuration {
+    inner: Arc<ConfigurationInner>,
+}
+
+#[derive(Clone)]
+struct ConfigurationInner {
     base_url: String,
     basic_auth: Option<BasicAuth>,
     bearer_access_token: Option<String>,
     api_key: Option<ApiKey>,
+    timeout: Option<Duration>,
+    blocked_attachment_extensions: Vec<String>,
+    attachment_inspector: Option<AttachmentInspector>,
+    max_multipart_body_size: u64,
+    max_response_body_size: Option<u64>,
+    send_once_capacity: Option<usize>,
+    api_version_overrides: HashMap<ApiChannel, u32>,
+    extra_headers: HeaderMap,
+    request_signer: Option<Arc<dyn RequestSigner>>,
+    sandbox: bool,
+    sandbox_base_url: Option<String>,
+    sandbox_headers: HeaderMap,
+    request_tracker: Arc<RequestTracker>,
+    send_policy: Option<SendPolicy>,
+    capture_connection_info: bool,
+    pinned_address: Option<IpAddr>,
+    diagnostics: Option<Arc<DiagnosticsBuffer>>,
+    default_email_from: Option<String>,
+    default_sms_sender: Option<String>,
+}
+
+impl ConfigurationInner {
+    fn new(base_url: String) -> ConfigurationInner {
+        ConfigurationInner {
+            base_url,
+            basic_auth: None,
+            bearer_access_token: None,
+            api_key: None,
+            timeout: None,
+            blocked_attachment_extensions: default_blocked_attachment_extensions(),
+            attachment_inspector: None,
+            max_multipart_body_size: DEFAULT_MAX_MULTIPART_BODY_SIZE,
+            max_response_body_size: None,
+            send_once_capacity: None,
+            api_version_overrides: HashMap::new(),
+            extra_headers: HeaderMap::new(),
+            request_signer: None,
+            sandbox: false,
+            sandbox_base_url: None,
+            sandbox_headers: HeaderMap::new(),
+            request_tracker: Arc::default(),
+            send_policy: None,
+            capture_connection_info: false,
+            pinned_address: None,
+            diagnostics: None,
+            default_email_from: None,
+            default_sms_sender: None,
+        }
+    }
 }
 
 impl Configuration {
     /// Reads API key details and IB_BASE_URL environment variable to build and return a
     /// `Configuration` instance.
     pub fn from_env_api_key() -> Result<Configuration, VarError> {
-        Ok(Configuration {
-            base_url: env::var("IB_BASE_URL")?,
-            api_key: Some(ApiKey::from_env()?),
-            basic_auth: None,
-            bearer_access_token: None,
-        })
+        Ok(Configuration::with_api_key(
+            env::var("IB_BASE_URL")?,
+            ApiKey::from_env()?,
+        ))
     }
 
     // Builds and returns a `Configuration` instance set with an API key.
     pub fn with_api_key(base_url: String, api_key: ApiKey) -> Configuration {
+        let mut inner = ConfigurationInner::new(base_url);
+        inner.api_key = Some(api_key);
+
         Configuration {
-            base_url,
-            api_key: Some(api_key),
-            basic_auth: None,
-            bearer_access_token: None,
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Returns a `ConfigurationBuilder`, which validates all settings at once.
+    pub fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder::new()
+    }
+
+    fn inner_mut(&mut self) -> &mut ConfigurationInner {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Builds a `Configuration` for the regional API host assigned to the account, e.g.
+    /// `xyz123.api.infobip.com`, failing if the host does not match the
+    /// `<subdomain>.api.infobip.com` pattern. Use `with_api_key` to target any other URL, like a
+    /// proxy.
+    pub fn for_host(host: &str, api_key: ApiKey) -> Result<Configuration, ConfigurationError> {
+        let host = host.trim().to_lowercase();
+        if !REGIONAL_HOST.is_match(&host) {
+            return Err(ConfigurationError::InvalidHost(host));
+        }
+
+        Ok(Configuration::with_api_key(
+            format!("https://{}", host),
+            api_key,
+        ))
+    }
+
+    /// Checks that the host of the base URL is one of `hosts`, e.g. to enforce data locality,
+    /// failing with the resolved host otherwise.
+    pub fn with_allowed_hosts(self, hosts: &[&str]) -> Result<Configuration, ConfigurationError> {
+        let host = self.host().unwrap_or_default();
+
+        if hosts
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(&host))
+        {
+            Ok(self)
+        } else {
+            Err(ConfigurationError::HostNotAllowed(host))
+        }
+    }
+
+    /// Returns the host of the base URL, if it can be parsed.
+    pub fn host(&self) -> Option<String> {
+        reqwest::Url::parse(self.base_url())
+            .ok()?
+            .host_str()
+            .map(str::to_lowercase)
+    }
+
+    /// Returns a hint about the likely cause of a 401 or 403 response, derived from the auth
+    /// method and the base URL. The secrets themselves are never inspected.
+    pub(crate) fn credential_hint(&self, status: reqwest::StatusCode) -> Option<String> {
+        if status == reqwest::StatusCode::FORBIDDEN {
+            return Some(
+                "the API key is missing a scope required by this endpoint, check its scopes in \
+                 the Infobip portal"
+                    .to_string(),
+            );
+        }
+        if status != reqwest::StatusCode::UNAUTHORIZED {
+            return None;
+        }
+
+        let host = self.host().unwrap_or_default();
+        if let Some(api_key) = self.api_key() {
+            match api_key.prefix.as_deref() {
+                Some(prefix) if prefix != "App" => Some(format!(
+                    "API keys are sent with the \"App\" prefix, but the configured prefix is \
+                     \"{}\"",
+                    prefix
+                )),
+                _ if host == "api.infobip.com" => Some(
+                    "use the base URL assigned to the account, like xyz123.api.infobip.com, \
+                     instead of api.infobip.com"
+                        .to_string(),
+                ),
+                _ if REGIONAL_HOST.is_match(&host) => Some(format!(
+                    "check that the API key belongs to the account of {}, API keys only work \
+                     with the base URL of their account",
+                    host
+                )),
+                _ => None,
+            }
+        } else if self.basic_auth().is_some() {
+            Some("check the username and password of the basic auth credentials".to_string())
+        } else if self.bearer_access_token().is_some() {
+            Some("the access token may have expired".to_string())
+        } else {
+            Some("no credentials are configured".to_string())
+        }
+    }
+
+    /// Replaces the list of blocked attachment extensions (without the leading dot) that are
+    /// rejected before sending an email. Pass an empty list to disable the check.
+    pub fn with_blocked_attachment_extensions(mut self, extensions: Vec<String>) -> Configuration {
+        self.inner_mut().blocked_attachment_extensions = extensions;
+        self
+    }
+
+    /// Sets a function that inspects every email attachment and inline image before sending.
+    /// Returning an error rejects the send with the given reason, before any network call.
+    pub fn with_attachment_inspector<F>(mut self, inspector: F) -> Configuration
+    where
+        F: Fn(&AttachmentMeta) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.inner_mut().attachment_inspector = Some(AttachmentInspector(Arc::new(inspector)));
+        self
+    }
+
+    /// Sets the maximum estimated size in bytes of a multipart request body, for accounts with a
+    /// raised limit. Larger requests fail with `SdkError::PayloadTooLarge` before being sent.
+    pub fn with_max_multipart_body_size(mut self, limit: u64) -> Configuration {
+        self.inner_mut().max_multipart_body_size = limit;
+        self
+    }
+
+    /// Sets the maximum size in bytes of a response body read into memory. Larger responses fail
+    /// with `SdkError::ResponseTooLarge`, without reading the rest of the body. Unlimited by
+    /// default.
+    pub fn with_max_response_body_size(mut self, limit: u64) -> Configuration {
+        self.inner_mut().max_response_body_size = Some(limit);
+        self
+    }
+
+    /// Replaces the credentials of the Configuration with `auth`.
+    pub fn with_auth(mut self, auth: AuthMethod) -> Configuration {
+        let inner = self.inner_mut();
+        inner.api_key = None;
+        inner.basic_auth = None;
+        inner.bearer_access_token = None;
+
+        match auth {
+            AuthMethod::ApiKey(api_key) => inner.api_key = Some(api_key),
+            AuthMethod::BasicAuth(basic_auth) => inner.basic_auth = Some(basic_auth),
+            AuthMethod::BearerAccessToken(token) => inner.bearer_access_token = Some(token),
+        }
+
+        self
+    }
+
+    /// Sets headers sent with every request, replacing the headers of the SDK with the same
+    /// name, like `Authorization`.
+    pub fn with_extra_headers(mut self, headers: HeaderMap) -> Configuration {
+        self.inner_mut().extra_headers = headers;
+        self
+    }
+
+    /// Sets a signer whose headers are added to every request, computed over the exact bytes
+    /// sent. See `RequestSigner` for what is signed.
+    pub fn with_request_signer<S>(mut self, signer: S) -> Configuration
+    where
+        S: RequestSigner + 'static,
+    {
+        self.inner_mut().request_signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Sets the base URL used instead of the configured one in sandbox mode, like the URL of a
+    /// test account or of a local simulator. When sandbox mode is already enabled, fails with
+    /// `ConfigurationError::ProductionHostInSandbox` if the URL points to an Infobip production
+    /// host, as `with_sandbox` does.
+    pub fn with_sandbox_base_url(
+        mut self,
+        base_url: &str,
+    ) -> Result<Configuration, ConfigurationError> {
+        self.inner_mut().sandbox_base_url = Some(base_url.to_string());
+
+        self.check_sandbox_host()
+    }
+
+    /// Sets headers sent with every request in sandbox mode, tagging it as test traffic, like a
+    /// header recognized by a simulator or a gateway in front of a test account. The Infobip API
+    /// has no test header of its own, so none is sent unless set here.
+    pub fn with_sandbox_headers(mut self, headers: HeaderMap) -> Configuration {
+        self.inner_mut().sandbox_headers = headers;
+        self
+    }
+
+    /// Enables or disables sandbox mode, for staging environments whose messages must not reach
+    /// real recipients. In sandbox mode:
+    /// - Requests go to the sandbox base URL, if one is set with `with_sandbox_base_url`.
+    /// - Every request has the headers set with `with_sandbox_headers`, if any.
+    /// - Responses have `SdkResponse::sandbox` set.
+    ///
+    /// Enabling sandbox mode fails with `ConfigurationError::ProductionHostInSandbox` if requests
+    /// would still go to an Infobip production host, like `xyz123.api.infobip.com`. This only
+    /// checks the host: the credentials are not inspected, so credentials of a production account
+    /// sent to a sandbox base URL are not detected.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::configuration::{ApiKey, Configuration};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let configuration = Configuration::with_api_key(
+    ///     "https://xyz123.api.infobip.com".to_string(),
+    ///     ApiKey::new("test-api-key".to_string()),
+    /// )
+    /// .with_sandbox_base_url("https://sandbox.company.com")?
+    /// .with_sandbox(true)?;
+    ///
+    /// assert_eq!(configuration.base_url(), "https://sandbox.company.com");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sandbox(mut self, enabled: bool) -> Result<Configuration, ConfigurationError> {
+        self.inner_mut().sandbox = enabled;
+
+        self.check_sandbox_host()
+    }
+
+    // Fails if sandbox mode is enabled and requests would go to an Infobip production host.
+    fn check_sandbox_host(self) -> Result<Configuration, ConfigurationError> {
+        if self.inner.sandbox {
+            if let Some(host) = self.host().filter(|host| is_production_host(host)) {
+                return Err(ConfigurationError::ProductionHostInSandbox(host));
+            }
         }
+
+        Ok(self)
+    }
+
+    /// Returns whether the Configuration is in sandbox mode.
+    pub fn is_sandbox(&self) -> bool {
+        self.inner.sandbox
     }
 
-    /// Returns the base URL of the Configuration.
+    /// Returns the base URL of the Configuration, which is the sandbox base URL in sandbox mode,
+    /// if one is set.
     pub fn base_url(&self) -> &String {
-        &self.base_url
+        match &self.inner.sandbox_base_url {
+            Some(sandbox_base_url) if self.inner.sandbox => sandbox_base_url,
+            _ => &self.inner.base_url,
+        }
     }
 
     /// Returns the API key of the Configuration.
     pub fn api_key(&self) -> Option<&ApiKey> {
-        self.api_key.as_ref()
+        self.inner.api_key.as_ref()
     }
 
     /// Returns the basic authentication of the Configuration.
     pub fn basic_auth(&self) -> Option<&BasicAuth> {
-        self.basic_auth.as_ref()
+        self.inner.basic_auth.as_ref()
     }
 
     /// Returns the bearer access token of the Configuration.
     pub fn bearer_access_token(&self) -> Option<&String> {
-        self.bearer_access_token.as_ref()
+        self.inner.bearer_access_token.as_ref()
+    }
+
+    /// Returns the timeout applied to every request, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
+    /// Sets the policy checked before sending every email, after the request body is validated.
+    pub fn with_send_policy(mut self, policy: SendPolicy) -> Configuration {
+        self.inner_mut().send_policy = Some(policy);
+        self
+    }
+
+    /// Returns the policy checked before sending every email, if any.
+    pub fn send_policy(&self) -> Option<&SendPolicy> {
+        self.inner.send_policy.as_ref()
+    }
+
+    /// Enables the duplicate send guard used by the `send_once` client methods, remembering up to
+    /// `capacity` of the most recently sent bulk and message ID pairs.
+    pub fn with_send_once(mut self, capacity: usize) -> Configuration {
+        self.inner_mut().send_once_capacity = Some(capacity);
+        self
+    }
+
+    /// Returns how many sent ID pairs the duplicate send guard remembers, or `None` when the
+    /// guard is disabled.
+    pub fn send_once_capacity(&self) -> Option<usize> {
+        self.inner.send_once_capacity
+    }
+
+    /// Replaces the API version of every endpoint of a channel, e.g. version 4 turns
+    /// `/email/3/send` into `/email/4/send`. Meant for accounts migrated by Infobip to a newer API
+    /// version before the SDK supports it; request and response models are not changed.
+    pub fn with_api_version_override(mut self, channel: ApiChannel, version: u32) -> Configuration {
+        self.inner_mut()
+            .api_version_overrides
+            .insert(channel, version);
+        self
+    }
+
+    /// Returns the API version override of a channel, if any.
+    pub fn api_version_override(&self, channel: ApiChannel) -> Option<u32> {
+        self.inner.api_version_overrides.get(&channel).copied()
     }
+
+    /// Returns the attachment extensions that are rejected before sending an email.
+    pub fn blocked_attachment_extensions(&self) -> &[String] {
+        &self.inner.blocked_attachment_extensions
+    }
+
+    /// Returns the attachment inspector of the Configuration.
+    pub fn attachment_inspector(&self) -> Option<&AttachmentInspector> {
+        self.inner.attachment_inspector.as_ref()
+    }
+
+    /// Returns the headers sent with every request.
+    pub fn extra_headers(&self) -> &HeaderMap {
+        &self.inner.extra_headers
+    }
+
+    /// Returns the headers sent with every request in sandbox mode.
+    pub fn sandbox_headers(&self) -> &HeaderMap {
+        &self.inner.sandbox_headers
+    }
+
+    /// Returns the request signer of the Configuration.
+    pub fn request_signer(&self) -> Option<&dyn RequestSigner> {
+        self.inner.request_signer.as_deref()
+    }
+
+    /// Returns the maximum estimated size in bytes of a multipart request body.
+    pub fn max_multipart_body_size(&self) -> u64 {
+        self.inner.max_multipart_body_size
+    }
+
+    /// Returns the maximum size in bytes of a response body read into memory, if any.
+    pub fn max_response_body_size(&self) -> Option<u64> {
+        self.inner.max_response_body_size
+    }
+
+    /// Captures the host and the remote address of every request into the `connection` of the
+    /// responses, e.g. to audit where API calls went. Disabled by default.
+    pub fn with_connection_info(mut self, enabled: bool) -> Configuration {
+        self.inner_mut().capture_connection_info = enabled;
+        self
+    }
+
+    /// Returns whether the connection info of every request is captured.
+    pub fn captures_connection_info(&self) -> bool {
+        self.inner.capture_connection_info
+    }
+
+    /// Resolves the host of the base URL to `address` instead of looking it up in DNS, for
+    /// environments with static egress rules. The port of the base URL is kept. Only applies to
+    /// the HTTP clients built by the SDK, not to the ones passed to `with_http_client`.
+    pub fn pin_host(mut self, address: IpAddr) -> Configuration {
+        self.inner_mut().pinned_address = Some(address);
+        self
+    }
+
+    /// Returns the address the host of the base URL is resolved to, if pinned.
+    pub fn pinned_address(&self) -> Option<IpAddr> {
+        self.inner.pinned_address
+    }
+
+    /// Sets the `from` used by the email clients for emails sent without one. An explicitly set
+    /// `from` always wins, and the request bodies passed by the caller are not modified.
+    pub fn with_default_email_from(mut self, from: &str) -> Configuration {
+        self.inner_mut().default_email_from = Some(from.to_string());
+        self
+    }
+
+    /// Returns the `from` used for emails sent without one, if any.
+    pub fn default_email_from(&self) -> Option<&str> {
+        self.inner.default_email_from.as_deref()
+    }
+
+    /// Sets the sender ID used by the SMS clients for messages sent without `from`. An explicitly
+    /// set `from` always wins, and the request bodies passed by the caller are not modified.
+    pub fn with_default_sms_sender(mut self, sender: &str) -> Configuration {
+        self.inner_mut().default_sms_sender = Some(sender.to_string());
+        self
+    }
+
+    /// Returns the sender ID used for SMS messages sent without `from`, if any.
+    pub fn default_sms_sender(&self) -> Option<&str> {
+        self.inner.default_sms_sender.as_deref()
+    }
+
+    /// Records the last `capacity` requests sent by the clients sharing this configuration, with
+    /// their responses, so they can be read back with the `diagnostics` method of a client. The
+    /// record is kept in memory, is shared by all clones of the configuration, and never holds
+    /// more than `capacity` entries, the oldest ones being evicted first. Disabled by default.
+    pub fn with_diagnostics(mut self, capacity: usize) -> Configuration {
+        self.inner_mut().diagnostics = Some(Arc::new(DiagnosticsBuffer::new(capacity)));
+        self
+    }
+
+    /// Returns how many requests the diagnostics record keeps, or `None` when it is disabled.
+    pub fn diagnostics_capacity(&self) -> Option<usize> {
+        self.inner
+            .diagnostics
+            .as_ref()
+            .map(|diagnostics| diagnostics.capacity())
+    }
+
+    // Diagnostics record, shared by all clones of the Configuration, even after they are
+    // modified.
+    pub(crate) fn diagnostics(&self) -> Option<&Arc<DiagnosticsBuffer>> {
+        self.inner.diagnostics.as_ref()
+    }
+
+    // Tracker of the requests in flight, shared by all clones of the Configuration, even after
+    // they are modified.
+    pub(crate) fn request_tracker(&self) -> &Arc<RequestTracker> {
+        &self.inner.request_tracker
+    }
+}
+
+impl fmt::Debug for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Configuration")
+            .field("base_url", &self.inner.base_url)
+            .field("api_key", &self.inner.api_key)
+            .field("basic_auth", &self.inner.basic_auth)
+            .field(
+                "bearer_access_token",
+                &self.inner.bearer_access_token.as_ref().map(|_| Redacted),
+            )
+            .field("timeout", &self.inner.timeout)
+            .field(
+                "blocked_attachment_extensions",
+                &self.inner.blocked_attachment_extensions,
+            )
+            .field("attachment_inspector", &self.inner.attachment_inspector)
+            .field(
+                "max_multipart_body_size",
+                &self.inner.max_multipart_body_size,
+            )
+            .field("max_response_body_size", &self.inner.max_response_body_size)
+            .field("send_once_capacity", &self.inner.send_once_capacity)
+            .field("api_version_overrides", &self.inner.api_version_overrides)
+            .field(
+                "extra_headers",
+                &self.inner.extra_headers.keys().collect::<Vec<_>>(),
+            )
+            .field(
+                "request_signer",
+                &self.inner.request_signer.as_ref().map(|_| "RequestSigner"),
+            )
+            .field("send_policy", &self.inner.send_policy)
+            .field("sandbox", &self.inner.sandbox)
+            .field("sandbox_base_url", &self.inner.sandbox_base_url)
+            .field(
+                "sandbox_headers",
+                &self.inner.sandbox_headers.keys().collect::<Vec<_>>(),
+            )
+            .field(
+                "capture_connection_info",
+                &self.inner.capture_connection_info,
+            )
+            .field("pinned_address", &self.inner.pinned_address)
+            .field("diagnostics_capacity", &self.diagnostics_capacity())
+            .field("default_email_from", &self.inner.default_email_from)
+            .field("default_sms_sender", &self.inner.default_sms_sender)
+            .finish()
+    }
+}
+
+// Placeholder printed instead of a secret.
+struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("****")
+    }
+}
+
+/// Longest request timeout accepted by `ConfigurationBuilder`.
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Builds a `Configuration`, checking all settings at once when calling `build`.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigurationBuilder {
+    base_url: Option<String>,
+    api_key: Option<ApiKey>,
+    basic_auth: Option<BasicAuth>,
+    bearer_access_token: Option<String>,
+    timeout: Option<Duration>,
 }
 
-/// Holds the details for authentication based on username and password.
-#[derive(Debug, Clone)]
+impl ConfigurationBuilder {
+    /// Creates an empty `ConfigurationBuilder`.
+    pub fn new() -> ConfigurationBuilder {
+        ConfigurationBuilder::default()
+    }
+
+    /// Sets the base URL, e.g. `https://xyz123.api.infobip.com`.
+    pub fn base_url(mut self, base_url: &str) -> ConfigurationBuilder {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Sets the API key used to authenticate requests.
+    pub fn api_key(mut self, api_key: ApiKey) -> ConfigurationBuilder {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Sets the username and password used to authenticate requests.
+    pub fn basic_auth(mut self, basic_auth: BasicAuth) -> ConfigurationBuilder {
+        self.basic_auth = Some(basic_auth);
+        self
+    }
+
+    /// Sets the bearer access token used to authenticate requests.
+    pub fn bearer_access_token(mut self, token: &str) -> ConfigurationBuilder {
+        self.bearer_access_token = Some(token.to_string());
+        self
+    }
+
+    /// Sets the timeout of every request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> ConfigurationBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Checks the settings and builds the `Configuration`, failing with every problem found.
+    pub fn build(self) -> Result<Configuration, ConfigurationError> {
+        let mut problems = Vec::new();
+
+        match &self.base_url {
+            None => problems.push(ConfigurationProblem::MissingBaseUrl),
+            Some(base_url) => match reqwest::Url::parse(base_url) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") && url.host_str().is_some() => {
+                }
+                _ => problems.push(ConfigurationProblem::InvalidBaseUrl(base_url.clone())),
+            },
+        }
+
+        if self.api_key.is_none() && self.basic_auth.is_none() && self.bearer_access_token.is_none()
+        {
+            problems.push(ConfigurationProblem::MissingCredentials);
+        }
+        if matches!(&self.api_key, Some(api_key) if api_key.key.trim().is_empty()) {
+            problems.push(ConfigurationProblem::EmptyApiKey);
+        }
+        if matches!(&self.basic_auth, Some(basic_auth) if basic_auth.username.trim().is_empty()) {
+            problems.push(ConfigurationProblem::EmptyUsername);
+        }
+        if matches!(&self.bearer_access_token, Some(token) if token.trim().is_empty()) {
+            problems.push(ConfigurationProblem::EmptyBearerAccessToken);
+        }
+
+        if let Some(timeout) = self.timeout {
+            if timeout.is_zero() || timeout > MAX_TIMEOUT {
+                problems.push(ConfigurationProblem::InvalidTimeout(timeout));
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(ConfigurationError::Invalid(problems));
+        }
+
+        let mut inner = ConfigurationInner::new(self.base_url.unwrap_or_default());
+        inner.api_key = self.api_key;
+        inner.basic_auth = self.basic_auth;
+        inner.bearer_access_token = self.bearer_access_token;
+        inner.timeout = self.timeout;
+
+        Ok(Configuration {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+/// API channels whose endpoint version can be overridden, named after the first segment of
+/// their endpoint paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiChannel {
+    Email,
+    Numbers,
+    Sms,
+    TwoFactorAuthentication,
+    Voice,
+    WhatsApp,
+}
+
+impl ApiChannel {
+    /// Returns the first segment of the endpoint paths of the channel.
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            ApiChannel::Email => "email",
+            ApiChannel::Numbers => "numbers",
+            ApiChannel::Sms => "sms",
+            ApiChannel::TwoFactorAuthentication => "2fa",
+            ApiChannel::Voice => "tts",
+            ApiChannel::WhatsApp => "whatsapp",
+        }
+    }
+
+    pub(crate) fn from_path_segment(segment: &str) -> Option<ApiChannel> {
+        [
+            ApiChannel::Email,
+            ApiChannel::Numbers,
+            ApiChannel::Sms,
+            ApiChannel::TwoFactorAuthentication,
+            ApiChannel::Voice,
+            ApiChannel::WhatsApp,
+        ]
+        .into_iter()
+        .find(|channel| channel.path_segment() == segment)
+    }
+}
+
+// Whether the host serves the production Infobip API.
+fn is_production_host(host: &str) -> bool {
+    host == "api.infobip.com" || REGIONAL_HOST.is_match(host)
+}
+
+fn default_blocked_attachment_extensions() -> Vec<String> {
+    DEFAULT_BLOCKED_ATTACHMENT_EXTENSIONS
+        .iter()
+        .map(|extension| extension.to_string())
+        .collect()
+}
+
+/// Holds the errors that can happen when building a `Configuration`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigurationError {
+    #[error("host '{0}' does not match <subdomain>.api.infobip.com")]
+    InvalidHost(String),
+
+    #[error("host '{0}' is not one of the allowed hosts")]
+    HostNotAllowed(String),
+
+    #[error("sandbox mode would send requests to the production host '{0}'")]
+    ProductionHostInSandbox(String),
+
+    #[error("invalid configuration: {}", display_problems(.0))]
+    Invalid(Vec<ConfigurationProblem>),
+}
+
+/// Holds a problem found by `ConfigurationBuilder::build`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigurationProblem {
+    #[error("base URL is missing")]
+    MissingBaseUrl,
+
+    #[error("base URL '{0}' is not a valid http or https URL")]
+    InvalidBaseUrl(String),
+
+    #[error("no API key, basic authentication or bearer access token is set")]
+    MissingCredentials,
+
+    #[error("API key is empty")]
+    EmptyApiKey,
+
+    #[error("basic authentication username is empty")]
+    EmptyUsername,
+
+    #[error("bearer access token is empty")]
+    EmptyBearerAccessToken,
+
+    #[error("timeout of {0:?} is not between zero and {MAX_TIMEOUT:?}")]
+    InvalidTimeout(Duration),
+}
+
+fn display_problems(problems: &[ConfigurationProblem]) -> String {
+    problems
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Rules checked before sending an email, on top of the validation of the request body, e.g. to
+/// enforce compliance rules. A breached rule fails the send with `SdkError::PolicyViolation`
+/// before any network call. Rules are disabled by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SendPolicy {
+    /// Rejects emails with `cc` or `bcc` recipients.
+    pub forbid_cc_bcc: bool,
+
+    /// Rejects emails without a `templateId`.
+    pub require_template: bool,
+
+    /// Domains the `from` address must belong to, compared case-insensitively. Any domain is
+    /// allowed when empty.
+    pub allowed_from_domains: Vec<String>,
+}
+
+impl SendPolicy {
+    pub fn new() -> Self {
+        SendPolicy::default()
+    }
+}
+
+/// Holds the details of an email attachment passed to the attachment inspector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentMeta {
+    /// Path of the file, as given in the request body.
+    pub file_name: String,
+
+    /// Lowercase file extension, without the leading dot.
+    pub extension: Option<String>,
+
+    /// Size of the file in bytes.
+    pub size: u64,
+
+    /// Whether the file is sent as an inline image instead of an attachment.
+    pub inline: bool,
+}
+
+type InspectorFn = dyn Fn(&AttachmentMeta) -> Result<(), String> + Send + Sync;
+
+/// Holds a user-provided function that inspects email attachments before sending.
+#[derive(Clone)]
+pub struct AttachmentInspector(Arc<InspectorFn>);
+
+impl AttachmentInspector {
+    /// Runs the inspector over an attachment.
+    pub fn inspect(&self, attachment: &AttachmentMeta) -> Result<(), String> {
+        (self.0)(attachment)
+    }
+}
+
+impl fmt::Debug for AttachmentInspector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AttachmentInspector")
+    }
+}
+
+/// Error returned by a `RequestSigner`, aborting the request.
+pub type SigningError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Computes headers added to every request, like the HMAC signature required by an egress
+/// gateway. The signer runs after the body is serialized and right before the request is sent,
+/// so it sees exactly what goes over the wire:
+///
+/// - `method` is the HTTP method.
+/// - `path` is the path of the URL with its query string, if any, e.g. `/sms/1/reports?limit=50`.
+/// - `body` is the body bytes: empty for requests without a body, and the serialized JSON for
+///   JSON bodies. Multipart bodies, like emails, are encoded in full before signing, with their
+///   boundary in the `Content-Type` header, so `body` holds every part with its framing.
+///
+/// Signed headers replace the headers of the SDK with the same name. An error aborts the request
+/// with `SdkError::RequestSigning`.
+pub trait RequestSigner: Send + Sync {
+    /// Returns the headers to add to the request.
+    fn sign(
+        &self,
+        method: &reqwest::Method,
+        path: &str,
+        body: &[u8],
+    ) -> Result<Vec<(HeaderName, HeaderValue)>, SigningError>;
+}
+
+/// Credentials used to authenticate requests. The `Debug` output redacts all secrets.
+#[derive(Clone)]
+pub enum AuthMethod {
+    ApiKey(ApiKey),
+    BasicAuth(BasicAuth),
+    BearerAccessToken(String),
+}
+
+impl fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthMethod::ApiKey(api_key) => f.debug_tuple("ApiKey").field(api_key).finish(),
+            AuthMethod::BasicAuth(basic_auth) => {
+                f.debug_tuple("BasicAuth").field(basic_auth).finish()
+            }
+            AuthMethod::BearerAccessToken(_) => {
+                f.debug_tuple("BearerAccessToken").field(&Redacted).finish()
+            }
+        }
+    }
+}
+
+/// Holds the details for authentication based on username and password. The `Debug` output
+/// redacts the password.
+#[derive(Clone)]
 pub struct BasicAuth {
     pub username: String,
     pub password: Option<String>,
 }
 
-/// Holds the details for API key authentication.
-#[derive(Debug, Clone)]
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| Redacted))
+            .finish()
+    }
+}
+
+/// Holds the details for API key authentication. The `Debug` output only shows the prefix and the
+/// last 4 characters of the key, e.g. `App ****1234`.
+#[derive(Clone)]
 pub struct ApiKey {
     pub prefix: Option<String>,
     pub key: String,
 }
 
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chars = self.key.chars().count();
+        let last = if chars > 4 {
+            self.key.chars().skip(chars - 4).collect()
+        } else {
+            String::new()
+        };
+
+        match &self.prefix {
+            Some(prefix) => write!(f, "{} ****{}", prefix, last),
+            None => write!(f, "****{}", last),
+        }
+    }
+}
+
 impl ApiKey {
     /// Creates a new `ApiKey`.
     pub fn new(key: String) -> ApiKey {
@@ -84,3 +981,6 @@ impl ApiKey {
         })
     }
 }
+
+#[cfg(test)]
+mod tests;
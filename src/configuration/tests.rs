@@ -0,0 +1,347 @@
+use std::time::Duration;
+
+use crate::configuration::{
+    ApiKey, AuthMethod, BasicAuth, Configuration, ConfigurationError, ConfigurationProblem,
+};
+
+fn get_test_api_key() -> ApiKey {
+    ApiKey::new("some-api-key".to_string())
+}
+
+#[test]
+fn for_host_regional_host() {
+    let configuration =
+        Configuration::for_host("XYZ123.api.infobip.com", get_test_api_key()).unwrap();
+
+    assert_eq!(configuration.base_url(), "https://xyz123.api.infobip.com");
+    assert_eq!(configuration.host().unwrap(), "xyz123.api.infobip.com");
+}
+
+#[test]
+fn for_host_invalid_host() {
+    let error = Configuration::for_host("api.infobip.com", get_test_api_key()).unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::InvalidHost("api.infobip.com".to_string())
+    );
+    assert!(error.to_string().contains("api.infobip.com"));
+
+    assert!(
+        Configuration::for_host("xyz123.api.infobip.com.evil.com", get_test_api_key()).is_err()
+    );
+    assert!(Configuration::for_host("https://xyz123.api.infobip.com", get_test_api_key()).is_err());
+}
+
+#[test]
+fn with_allowed_hosts_allowed() {
+    let configuration = Configuration::for_host("xyz123.api.infobip.com", get_test_api_key())
+        .unwrap()
+        .with_allowed_hosts(&["abc456.api.infobip.com", "xyz123.api.infobip.com"]);
+
+    assert!(configuration.is_ok());
+}
+
+#[test]
+fn with_allowed_hosts_unexpected_host() {
+    let error =
+        Configuration::with_api_key("https://api.infobip.com".to_string(), get_test_api_key())
+            .with_allowed_hosts(&["xyz123.api.infobip.com"])
+            .unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::HostNotAllowed("api.infobip.com".to_string())
+    );
+    assert!(error.to_string().contains("'api.infobip.com'"));
+}
+
+#[test]
+fn with_api_key_explicit_override() {
+    let configuration =
+        Configuration::with_api_key("http://localhost:8080".to_string(), get_test_api_key())
+            .with_allowed_hosts(&["localhost"])
+            .unwrap();
+
+    assert_eq!(configuration.base_url(), "http://localhost:8080");
+    assert_eq!(configuration.host().unwrap(), "localhost");
+}
+
+#[test]
+fn builder_valid() {
+    let configuration = Configuration::builder()
+        .base_url("https://xyz123.api.infobip.com")
+        .api_key(get_test_api_key())
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap();
+
+    assert_eq!(configuration.base_url(), "https://xyz123.api.infobip.com");
+    assert_eq!(configuration.api_key().unwrap().key, "some-api-key");
+    assert_eq!(configuration.timeout(), Some(Duration::from_secs(30)));
+}
+
+#[test]
+fn builder_aggregates_errors() {
+    let error = Configuration::builder()
+        .base_url("xyz123.api.infobip.com")
+        .basic_auth(BasicAuth {
+            username: " ".to_string(),
+            password: None,
+        })
+        .timeout(Duration::ZERO)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::Invalid(vec![
+            ConfigurationProblem::InvalidBaseUrl("xyz123.api.infobip.com".to_string()),
+            ConfigurationProblem::EmptyUsername,
+            ConfigurationProblem::InvalidTimeout(Duration::ZERO),
+        ])
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid configuration: base URL 'xyz123.api.infobip.com' is not a valid http or https \
+         URL; basic authentication username is empty; timeout of 0ns is not between zero and 600s"
+    );
+}
+
+#[test]
+fn builder_missing_settings() {
+    let error = Configuration::builder()
+        .timeout(Duration::from_secs(3600))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::Invalid(vec![
+            ConfigurationProblem::MissingBaseUrl,
+            ConfigurationProblem::MissingCredentials,
+            ConfigurationProblem::InvalidTimeout(Duration::from_secs(3600)),
+        ])
+    );
+}
+
+#[test]
+fn builder_empty_credentials() {
+    let error = Configuration::builder()
+        .base_url("https://xyz123.api.infobip.com")
+        .api_key(ApiKey::new("".to_string()))
+        .bearer_access_token("")
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::Invalid(vec![
+            ConfigurationProblem::EmptyApiKey,
+            ConfigurationProblem::EmptyBearerAccessToken,
+        ])
+    );
+}
+
+#[test]
+fn debug_redacts_api_key() {
+    let configuration = Configuration::with_api_key(
+        "https://xyz123.api.infobip.com".to_string(),
+        ApiKey::new("secret-api-key-1234".to_string()),
+    );
+
+    let output = format!("{:?}", configuration);
+
+    assert!(output.contains("api_key: Some(App ****1234)"));
+    assert!(!output.contains("secret-api-key"));
+    assert_eq!(format!("{:?}", ApiKey::new("1234".to_string())), "App ****");
+}
+
+#[test]
+fn debug_redacts_basic_auth_and_token() {
+    let configuration = Configuration::builder()
+        .base_url("https://xyz123.api.infobip.com")
+        .basic_auth(BasicAuth {
+            username: "user".to_string(),
+            password: Some("secret-password".to_string()),
+        })
+        .bearer_access_token("secret-token")
+        .build()
+        .unwrap();
+
+    let output = format!("{:?}", configuration);
+
+    assert!(output.contains("username: \"user\""));
+    assert!(!output.contains("secret-password"));
+    assert!(!output.contains("secret-token"));
+}
+
+#[test]
+fn clone_shares_settings() {
+    let configuration = Configuration::with_api_key(
+        "https://xyz123.api.infobip.com".to_string(),
+        get_test_api_key(),
+    );
+    let modified = configuration.clone().with_send_once(10);
+
+    assert_eq!(configuration.send_once_capacity(), None);
+    assert_eq!(modified.send_once_capacity(), Some(10));
+    assert_eq!(modified.base_url(), configuration.base_url());
+}
+
+#[test]
+fn with_auth_replaces_credentials() {
+    let configuration = Configuration::with_api_key("https://some.url".into(), get_test_api_key());
+    let overridden = configuration
+        .clone()
+        .with_auth(AuthMethod::BearerAccessToken("some-token".to_string()));
+
+    assert!(overridden.api_key().is_none());
+    assert_eq!(overridden.bearer_access_token().unwrap(), "some-token");
+    assert!(configuration.api_key().is_some());
+    assert!(configuration.bearer_access_token().is_none());
+    assert_eq!(
+        format!(
+            "{:?}",
+            AuthMethod::BearerAccessToken("some-token".to_string())
+        ),
+        "BearerAccessToken(****)"
+    );
+}
+
+#[test]
+fn test_max_response_body_size() {
+    let configuration = Configuration::with_api_key(
+        "https://some.url".to_string(),
+        ApiKey::new("some-api-key".to_string()),
+    );
+    assert_eq!(configuration.max_response_body_size(), None);
+
+    let configuration = configuration.with_max_response_body_size(1024);
+    assert_eq!(configuration.max_response_body_size(), Some(1024));
+}
+
+#[test]
+fn test_default_senders() {
+    let configuration = Configuration::with_api_key(
+        "https://some.url".to_string(),
+        ApiKey::new("some-api-key".to_string()),
+    );
+    assert_eq!(configuration.default_email_from(), None);
+    assert_eq!(configuration.default_sms_sender(), None);
+
+    let configuration = configuration
+        .with_default_email_from("noreply@company.com")
+        .with_default_sms_sender("InfoSMS");
+    assert_eq!(
+        configuration.default_email_from(),
+        Some("noreply@company.com")
+    );
+    assert_eq!(configuration.default_sms_sender(), Some("InfoSMS"));
+}
+
+#[test]
+fn with_sandbox_uses_sandbox_base_url() {
+    let configuration = Configuration::for_host("xyz123.api.infobip.com", get_test_api_key())
+        .unwrap()
+        .with_sandbox_base_url("https://sandbox.company.com")
+        .unwrap();
+
+    assert_eq!(configuration.base_url(), "https://xyz123.api.infobip.com");
+    assert!(!configuration.is_sandbox());
+
+    let sandbox = configuration.with_sandbox(true).unwrap();
+    assert_eq!(sandbox.base_url(), "https://sandbox.company.com");
+    assert_eq!(sandbox.host().unwrap(), "sandbox.company.com");
+    assert!(sandbox.is_sandbox());
+
+    let production = sandbox.with_sandbox(false).unwrap();
+    assert_eq!(production.base_url(), "https://xyz123.api.infobip.com");
+}
+
+#[test]
+fn with_sandbox_refuses_production_host() {
+    let error = Configuration::for_host("xyz123.api.infobip.com", get_test_api_key())
+        .unwrap()
+        .with_sandbox(true)
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        ConfigurationError::ProductionHostInSandbox("xyz123.api.infobip.com".to_string())
+    );
+
+    let error =
+        Configuration::with_api_key("https://api.infobip.com".to_string(), get_test_api_key())
+            .with_sandbox(true)
+            .unwrap_err();
+    assert!(matches!(
+        error,
+        ConfigurationError::ProductionHostInSandbox(_)
+    ));
+
+    assert!(
+        Configuration::with_api_key("http://localhost:8080".to_string(), get_test_api_key())
+            .with_sandbox(true)
+            .is_ok()
+    );
+}
+
+#[test]
+fn with_sandbox_base_url_refuses_production_host_in_sandbox() {
+    let sandbox =
+        Configuration::with_api_key("http://localhost:8080".to_string(), get_test_api_key())
+            .with_sandbox(true)
+            .unwrap();
+
+    let error = sandbox
+        .clone()
+        .with_sandbox_base_url("https://xyz123.api.infobip.com")
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ConfigurationError::ProductionHostInSandbox("xyz123.api.infobip.com".to_string())
+    );
+
+    assert!(sandbox
+        .with_sandbox(false)
+        .unwrap()
+        .with_sandbox_base_url("https://xyz123.api.infobip.com")
+        .is_ok());
+}
+
+#[test]
+fn credential_hint_unauthorized() {
+    let unauthorized = reqwest::StatusCode::UNAUTHORIZED;
+
+    let generic_host =
+        Configuration::with_api_key("https://api.infobip.com".to_string(), get_test_api_key());
+    assert!(generic_host
+        .credential_hint(unauthorized)
+        .unwrap()
+        .contains("xyz123.api.infobip.com"));
+
+    let regional_host =
+        Configuration::for_host("xyz123.api.infobip.com", get_test_api_key()).unwrap();
+    assert!(regional_host
+        .credential_hint(unauthorized)
+        .unwrap()
+        .contains("account of xyz123.api.infobip.com"));
+
+    let proxy =
+        Configuration::with_api_key("https://proxy.example.com".to_string(), get_test_api_key());
+    assert_eq!(proxy.credential_hint(unauthorized), None);
+    assert_eq!(
+        proxy.credential_hint(reqwest::StatusCode::INTERNAL_SERVER_ERROR),
+        None
+    );
+
+    let basic_auth = proxy.with_auth(AuthMethod::BasicAuth(BasicAuth {
+        username: "user".to_string(),
+        password: Some("password".to_string()),
+    }));
+    assert!(basic_auth
+        .credential_hint(unauthorized)
+        .unwrap()
+        .contains("username and password"));
+}
@@ -32,12 +32,11 @@
 //! Replace `<version>` with the latest (or desired) release of the library. For example `0.5.0`.
 //!
 //! ## Usage
-//! To use the library, import the client and channel-specific models. Then create a client and
-//! call the associated functions. For example, to send an SMS, you can do this:
+//! To use the library, import the prelude, which has the clients and the common models under
+//! channel-prefixed names. Then create a client and call the associated functions. For example, to
+//! send an SMS, you can do this:
 //! ```no_run
-//! use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
-//! use infobip_sdk::api::sms::SmsClient;
-//! use infobip_sdk::configuration::Configuration;
+//! use infobip_sdk::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() {
@@ -48,14 +47,14 @@
 //!     );
 //!
 //!     // Create a message.
-//!     let message = Message {
-//!         destinations: Some(vec![Destination::new("491702384590")]),
+//!     let message = SmsMessage {
+//!         destinations: Some(vec![SmsDestination::new("491702384590")]),
 //!         text: Some("Foobar!".to_string()),
 //!         ..Default::default()
 //!     };
 //!
-//!     // Create the SendRequestBody instance.
-//!     let request_body = SendRequestBody::new(vec![message]);
+//!     // Create the SmsSendRequestBody instance.
+//!     let request_body = SmsSendRequestBody::new(vec![message]);
 //!
 //!     // Send the SMS.
 //!     let response = sms_client.send(request_body).await.unwrap();
@@ -77,18 +76,18 @@
 //! ### Building payload models
 //! Structs that represent the models have public fields, so you can either build them with the
 //! provided `new()` functions, with `serde_json::from_str()`, or with the true constructor.
-//! For example, to build a `Message` instance, you can do this:
+//! For example, to build an `SmsMessage` instance, you can do this:
 //! ```rust
-//! # use infobip_sdk::model::sms::{Destination, Message};
-//! let mut message = Message::new(
-//!    vec![Destination::new("123456789012")]
+//! # use infobip_sdk::prelude::*;
+//! let mut message = SmsMessage::new(
+//!    vec![SmsDestination::new("123456789012")]
 //! );
 //! message.text = Some("Your message text".to_string());
 //! ```
 //! or this:
 //! ```rust
-//! # use infobip_sdk::model::sms::{Destination, Message};
-//! let message: Message = serde_json::from_str(
+//! # use infobip_sdk::prelude::*;
+//! let message: SmsMessage = serde_json::from_str(
 //!     r#"
 //!         {
 //!           "destinations": [
@@ -104,12 +103,14 @@
 //! ```
 //! or this:
 //! ```rust
-//! # use infobip_sdk::model::sms::{Destination, Message};
-//! let destination = Destination {
+//! # use infobip_sdk::prelude::*;
+//! let destination = SmsDestination {
 //!     message_id: None,
-//!     to: "41793026727".to_string()
+//!     network_id: None,
+//!     to: "41793026727".to_string(),
+//!     placeholders: None,
 //! };
-//! let message = Message {
+//! let message = SmsMessage {
 //!     callback_data: None,
 //!     delivery_time_window: None,
 //!     destinations: Some(vec![destination]),
@@ -117,6 +118,7 @@
 //!     from: None,
 //!     intermediate_report: None,
 //!     language: None,
+//!     max_parts: None,
 //!     notify_content_type: None,
 //!     notify_url: None,
 //!     regional: None,
@@ -133,14 +135,23 @@
 //! is done automatically when calling an endpoint, or you can call the `.validate()` method of the
 //! model.
 //!
+//! ### Empty response fields
+//! The API sends some unset fields of reports, logs, statuses and domains as empty strings, like
+//! the `bulkId` of a message sent on its own. These are read as `None`, like omitted fields, and
+//! are therefore skipped when the models are serialized again.
+//!
 //! ### Using features
 //! You can speed up compile time by turning only the needed channels as library features.
 //! For example, to only build SMS, add the dependency like this:
 //! ```toml
-//! infobip_sdk = { version = "0.5", features = ["sms"] }
+//! infobip_sdk = { version = "0.5", default-features = false, features = ["sms"] }
 //! ```
 //! You can see the complete list of features in the Cargo.toml of the project. Feature names
-//! follow channel names.
+//! follow channel names. The optional `xml` feature adds parsing of delivery report callbacks sent
+//! with `notifyContentType` set to `application/xml`. The optional `stub` feature adds a stub of
+//! the API serving canned responses, to run examples and tests offline.
+//! The optional `axum` feature adds extractors for axum handlers receiving SMS delivery report
+//! and inbound message webhooks, which verify, parse and deduplicate them in one step.
 
 #[macro_use]
 extern crate lazy_static;
@@ -148,3 +159,7 @@ extern crate lazy_static;
 pub mod api;
 pub mod configuration;
 pub mod model;
+pub mod prelude;
+
+#[cfg(feature = "stub")]
+pub mod stub;
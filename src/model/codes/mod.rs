@@ -0,0 +1,66 @@
+//! Status and error codes returned in delivery reports and logs of all channels, with their
+//! names, groups and descriptions.
+//!
+//! The tables are generated by `scripts/generate_codes.py` from the CSV files in `scripts/codes`,
+//! which are transcribed from the status and error code documentation of Infobip.
+
+mod tables;
+
+pub use tables::{ERROR_CODES, STATUS_CODES};
+
+/// Details of a status code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StatusCodeInfo {
+    /// Status ID.
+    pub id: i32,
+
+    /// Status name, e.g. `DELIVERED_TO_HANDSET`.
+    pub name: &'static str,
+
+    /// Status group ID.
+    pub group_id: i32,
+
+    /// Status group name, e.g. `DELIVERED`.
+    pub group_name: &'static str,
+
+    /// Human-readable description of the status.
+    pub description: &'static str,
+}
+
+/// Details of an error code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ErrorCodeInfo {
+    /// Error ID.
+    pub id: i32,
+
+    /// Error name, e.g. `EC_ABSENT_SUBSCRIBER`.
+    pub name: &'static str,
+
+    /// Error group ID.
+    pub group_id: i32,
+
+    /// Error group name, e.g. `HANDSET_ERRORS`.
+    pub group_name: &'static str,
+
+    /// Whether the error is permanent, so sending the message again will not help.
+    pub permanent: bool,
+
+    /// Human-readable description of the error.
+    pub description: &'static str,
+}
+
+/// Returns the details of a status code, if known.
+pub fn status_by_id(id: i32) -> Option<&'static StatusCodeInfo> {
+    STATUS_CODES
+        .binary_search_by_key(&id, |status| status.id)
+        .ok()
+        .map(|index| &STATUS_CODES[index])
+}
+
+/// Returns the details of an error code, if known.
+pub fn error_by_id(id: i32) -> Option<&'static ErrorCodeInfo> {
+    ERROR_CODES
+        .binary_search_by_key(&id, |error| error.id)
+        .ok()
+        .map(|index| &ERROR_CODES[index])
+}
@@ -0,0 +1,369 @@
+// Generated by scripts/generate_codes.py from scripts/codes/*.csv. Do not edit.
+
+use super::{ErrorCodeInfo, StatusCodeInfo};
+
+/// Known status codes, sorted by ID.
+pub const STATUS_CODES: &[StatusCodeInfo] = &[
+    StatusCodeInfo {
+        id: 2,
+        name: "DELIVERED_TO_OPERATOR",
+        group_id: 3,
+        group_name: "DELIVERED",
+        description: "Message delivered to operator",
+    },
+    StatusCodeInfo {
+        id: 3,
+        name: "PENDING_WAITING_DELIVERY",
+        group_id: 1,
+        group_name: "PENDING",
+        description: "Message sent and waiting for a delivery report",
+    },
+    StatusCodeInfo {
+        id: 4,
+        name: "UNDELIVERABLE_REJECTED_OPERATOR",
+        group_id: 2,
+        group_name: "UNDELIVERABLE",
+        description: "Message rejected by the operator",
+    },
+    StatusCodeInfo {
+        id: 5,
+        name: "DELIVERED_TO_HANDSET",
+        group_id: 3,
+        group_name: "DELIVERED",
+        description: "Message delivered to handset",
+    },
+    StatusCodeInfo {
+        id: 6,
+        name: "REJECTED_NETWORK",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Network is forbidden",
+    },
+    StatusCodeInfo {
+        id: 7,
+        name: "PENDING_ENROUTE",
+        group_id: 1,
+        group_name: "PENDING",
+        description: "Message sent to next instance",
+    },
+    StatusCodeInfo {
+        id: 8,
+        name: "REJECTED_PREFIX_MISSING",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Number prefix missing",
+    },
+    StatusCodeInfo {
+        id: 9,
+        name: "UNDELIVERABLE_NOT_DELIVERED",
+        group_id: 2,
+        group_name: "UNDELIVERABLE",
+        description: "Message sent but not delivered",
+    },
+    StatusCodeInfo {
+        id: 10,
+        name: "REJECTED_DND",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Destination is on the do-not-disturb list",
+    },
+    StatusCodeInfo {
+        id: 11,
+        name: "REJECTED_SOURCE",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Invalid sender",
+    },
+    StatusCodeInfo {
+        id: 12,
+        name: "REJECTED_NOT_ENOUGH_CREDITS",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Not enough credits",
+    },
+    StatusCodeInfo {
+        id: 13,
+        name: "REJECTED_SENDER",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Sender is on the blocklist",
+    },
+    StatusCodeInfo {
+        id: 14,
+        name: "REJECTED_DESTINATION",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Destination is on the blocklist",
+    },
+    StatusCodeInfo {
+        id: 15,
+        name: "EXPIRED_EXPIRED",
+        group_id: 4,
+        group_name: "EXPIRED",
+        description: "Message expired",
+    },
+    StatusCodeInfo {
+        id: 17,
+        name: "REJECTED_PREPAID_PACKAGE_EXPIRED",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Prepaid package expired",
+    },
+    StatusCodeInfo {
+        id: 18,
+        name: "REJECTED_DESTINATION_NOT_REGISTERED",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Destination is not registered",
+    },
+    StatusCodeInfo {
+        id: 19,
+        name: "REJECTED_ROUTE_NOT_AVAILABLE",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Route is not available",
+    },
+    StatusCodeInfo {
+        id: 20,
+        name: "REJECTED_FLOODING_FILTER",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Rejected by the flooding filter",
+    },
+    StatusCodeInfo {
+        id: 21,
+        name: "REJECTED_SYSTEM_ERROR",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "System error",
+    },
+    StatusCodeInfo {
+        id: 23,
+        name: "REJECTED_DUPLICATE_MESSAGE_ID",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Duplicate message ID",
+    },
+    StatusCodeInfo {
+        id: 24,
+        name: "REJECTED_INVALID_UDH",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Invalid user data header",
+    },
+    StatusCodeInfo {
+        id: 25,
+        name: "REJECTED_MESSAGE_TOO_LONG",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Message is too long",
+    },
+    StatusCodeInfo {
+        id: 26,
+        name: "PENDING_ACCEPTED",
+        group_id: 1,
+        group_name: "PENDING",
+        description: "Message accepted and waiting to be sent",
+    },
+    StatusCodeInfo {
+        id: 29,
+        name: "EXPIRED_DLR_UNKNOWN",
+        group_id: 4,
+        group_name: "EXPIRED",
+        description: "Message expired with an unknown delivery status",
+    },
+    StatusCodeInfo {
+        id: 51,
+        name: "MISSING_TO",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Missing destination",
+    },
+    StatusCodeInfo {
+        id: 52,
+        name: "REJECTED_INVALID_DESTINATION",
+        group_id: 5,
+        group_name: "REJECTED",
+        description: "Invalid destination address",
+    },
+];
+
+/// Known error codes, sorted by ID.
+pub const ERROR_CODES: &[ErrorCodeInfo] = &[
+    ErrorCodeInfo {
+        id: 0,
+        name: "NO_ERROR",
+        group_id: 0,
+        group_name: "OK",
+        permanent: false,
+        description: "No error",
+    },
+    ErrorCodeInfo {
+        id: 1,
+        name: "EC_UNKNOWN_SUBSCRIBER",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Unknown subscriber",
+    },
+    ErrorCodeInfo {
+        id: 5,
+        name: "EC_UNIDENTIFIED_SUBSCRIBER",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Unidentified subscriber",
+    },
+    ErrorCodeInfo {
+        id: 6,
+        name: "EC_ABSENT_SUBSCRIBER_SM",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Absent subscriber",
+    },
+    ErrorCodeInfo {
+        id: 7,
+        name: "EC_UNKNOWN_EQUIPMENT",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Unknown equipment",
+    },
+    ErrorCodeInfo {
+        id: 8,
+        name: "EC_ROAMING_NOT_ALLOWED",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Roaming not allowed",
+    },
+    ErrorCodeInfo {
+        id: 9,
+        name: "EC_ILLEGAL_SUBSCRIBER",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Illegal subscriber",
+    },
+    ErrorCodeInfo {
+        id: 10,
+        name: "EC_BEARER_SERVICE_NOT_PROVISIONED",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Bearer service not provisioned",
+    },
+    ErrorCodeInfo {
+        id: 11,
+        name: "EC_TELESERVICE_NOT_PROVISIONED",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Teleservice not provisioned",
+    },
+    ErrorCodeInfo {
+        id: 12,
+        name: "EC_ILLEGAL_EQUIPMENT",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "Illegal equipment",
+    },
+    ErrorCodeInfo {
+        id: 13,
+        name: "EC_CALL_BARRED",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Call barred",
+    },
+    ErrorCodeInfo {
+        id: 21,
+        name: "EC_FACILITY_NOT_SUPPORTED",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Facility not supported",
+    },
+    ErrorCodeInfo {
+        id: 27,
+        name: "EC_ABSENT_SUBSCRIBER",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Absent subscriber",
+    },
+    ErrorCodeInfo {
+        id: 31,
+        name: "EC_SUBSCRIBER_BUSY_FOR_MT_SMS",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Subscriber busy",
+    },
+    ErrorCodeInfo {
+        id: 32,
+        name: "EC_SM_DELIVERY_FAILURE",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Delivery failure",
+    },
+    ErrorCodeInfo {
+        id: 33,
+        name: "EC_MESSAGE_WAITING_LIST_FULL",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: false,
+        description: "Message waiting list full",
+    },
+    ErrorCodeInfo {
+        id: 34,
+        name: "EC_SYSTEM_FAILURE",
+        group_id: 3,
+        group_name: "OPERATOR_ERRORS",
+        permanent: false,
+        description: "System failure",
+    },
+    ErrorCodeInfo {
+        id: 35,
+        name: "EC_DATA_MISSING",
+        group_id: 3,
+        group_name: "OPERATOR_ERRORS",
+        permanent: false,
+        description: "Data missing",
+    },
+    ErrorCodeInfo {
+        id: 36,
+        name: "EC_UNEXPECTED_DATA_VALUE",
+        group_id: 3,
+        group_name: "OPERATOR_ERRORS",
+        permanent: false,
+        description: "Unexpected data value",
+    },
+    ErrorCodeInfo {
+        id: 2050,
+        name: "EC_SPAM_COMPLAINT",
+        group_id: 2,
+        group_name: "USER_ERRORS",
+        permanent: true,
+        description: "Recipient marked the email as spam",
+    },
+    ErrorCodeInfo {
+        id: 5480,
+        name: "EC_VOICE_NO_ANSWER",
+        group_id: 2,
+        group_name: "USER_ERRORS",
+        permanent: true,
+        description: "User was notified, but did not answer call",
+    },
+    ErrorCodeInfo {
+        id: 7003,
+        name: "EC_UNREGISTERED_USER",
+        group_id: 1,
+        group_name: "HANDSET_ERRORS",
+        permanent: true,
+        description: "The destination is not a WhatsApp user",
+    },
+];
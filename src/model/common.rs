@@ -0,0 +1,566 @@
+//! Models shared by more than one channel.
+
+use std::borrow::Cow;
+
+#[cfg(any(feature = "sms", feature = "email"))]
+use chrono::Duration;
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+lazy_static! {
+    static ref SMS_NUMBER: Regex = Regex::new(r"^\+?[0-9]{3,15}$").unwrap();
+    static ref WHATSAPP_NUMBER: Regex = Regex::new(r"^[1-9][0-9]{6,14}$").unwrap();
+}
+
+/// Format of the timestamps used by the API, e.g. `2022-10-01T12:30:00.000+0000`.
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f%z";
+
+/// Number of hours for which logs are retained and can be queried.
+pub const LOGS_RETENTION_HOURS: i64 = 48;
+
+/// Pagination details returned by paged endpoints.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Paging {
+    /// Requested page number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+
+    /// Requested page size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+
+    /// Total number of pages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<i32>,
+
+    /// Total number of results across all pages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_results: Option<i32>,
+}
+
+/// Content type of the delivery reports sent to `notifyUrl`, shared by SMS and email requests.
+#[cfg(any(feature = "sms", feature = "email"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum NotifyContentType {
+    #[serde(rename = "application/json")]
+    Json,
+    #[serde(rename = "application/xml")]
+    Xml,
+}
+
+#[cfg(any(feature = "sms", feature = "email"))]
+impl NotifyContentType {
+    /// Returns the content type as sent to the API, e.g. `application/json`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifyContentType::Json => "application/json",
+            NotifyContentType::Xml => "application/xml",
+        }
+    }
+}
+
+/// Amount of money, like the price of a message. A `f64` by default, or a
+/// `rust_decimal::Decimal` with the `decimal` feature, so prices can be reconciled exactly
+/// against invoices. Either way it is read from and written to JSON as a number.
+#[cfg(not(feature = "decimal"))]
+pub type Money = f64;
+
+/// Amount of money, like the price of a message. A `f64` by default, or a
+/// `rust_decimal::Decimal` with the `decimal` feature, so prices can be reconciled exactly
+/// against invoices. Either way it is read from and written to JSON as a number.
+#[cfg(feature = "decimal")]
+pub type Money = rust_decimal::Decimal;
+
+/// Returns an amount of money as a `f64`, regardless of the `decimal` feature.
+#[cfg(not(feature = "decimal"))]
+pub fn money_to_f64(amount: Money) -> f64 {
+    amount
+}
+
+/// Returns an amount of money as a `f64`, regardless of the `decimal` feature.
+#[cfg(feature = "decimal")]
+pub fn money_to_f64(amount: Money) -> f64 {
+    rust_decimal::prelude::ToPrimitive::to_f64(&amount).unwrap_or(f64::NAN)
+}
+
+// Deserializes an optional amount of money. The value is always read as a `f64`, so formats that
+// only hand out numbers as text, like XML callbacks, work with the `decimal` feature too; the
+// decimal is then built from the shortest representation of the float, which keeps the digits
+// sent by the API.
+#[cfg(any(
+    feature = "sms",
+    feature = "email",
+    feature = "whatsapp",
+    feature = "voice"
+))]
+pub(crate) fn deserialize_money<'de, D>(deserializer: D) -> Result<Option<Money>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let amount = <Option<f64> as serde::Deserialize>::deserialize(deserializer)?;
+
+    #[cfg(feature = "decimal")]
+    let amount = amount
+        .map(|amount| amount.to_string().parse().map_err(serde::de::Error::custom))
+        .transpose()?;
+
+    Ok(amount)
+}
+
+// Deserializes an optional timestamp in milliseconds since the Unix epoch. Depending on the API
+// version the value comes as a number, as a number in a string, or as an ISO timestamp string.
+#[cfg(feature = "email")]
+pub(crate) fn deserialize_epoch_millis<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum EpochMillis {
+        Number(u64),
+        Text(String),
+    }
+
+    let value = <Option<EpochMillis> as serde::Deserialize>::deserialize(deserializer)?;
+
+    match value {
+        None => Ok(None),
+        Some(EpochMillis::Number(millis)) => Ok(Some(millis)),
+        Some(EpochMillis::Text(text)) => {
+            if let Ok(millis) = text.trim().parse::<u64>() {
+                return Ok(Some(millis));
+            }
+            parse_timestamp(text.trim())
+                .and_then(|timestamp| u64::try_from(timestamp.timestamp_millis()).ok())
+                .map(Some)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "'{}' is neither epoch milliseconds nor a timestamp",
+                        text
+                    ))
+                })
+        }
+    }
+}
+
+// Checks that a `limit` query parameter is between 1 and `max`, with a message naming the range.
+#[cfg(any(feature = "sms", feature = "email"))]
+pub(crate) fn validate_limit(limit: i32, max: i32) -> Result<(), ValidationError> {
+    if (1..=max).contains(&limit) {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("range");
+    error.message = Some(format!("limit must be between 1 and {}", max).into());
+    error.add_param("min".into(), &1);
+    error.add_param("max".into(), &max);
+
+    Err(error)
+}
+
+// Deserializes an optional string, reading an empty string as `None`. The API sends some unset
+// response fields, like the `bulkId` of a single message, as `""` instead of omitting them.
+#[cfg(any(feature = "sms", feature = "email"))]
+pub(crate) fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = <Option<String> as serde::Deserialize>::deserialize(deserializer)?;
+
+    Ok(value.filter(|value| !value.is_empty()))
+}
+
+// Wraps a `Cow` so that serde borrows it from the input when the string has no escape sequences.
+// Serde only does this for `Cow` fields directly, not for a `Cow` inside an `Option`.
+#[cfg(feature = "sms")]
+#[derive(Deserialize)]
+struct BorrowedStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+// Deserializes an optional string, borrowing it from the input when possible.
+#[cfg(feature = "sms")]
+pub(crate) fn borrowed_str<'de, D>(deserializer: D) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = <Option<BorrowedStr> as serde::Deserialize>::deserialize(deserializer)?;
+
+    Ok(value.map(|value| value.0))
+}
+
+// Like `empty_string_as_none`, but borrows the string from the input when possible.
+#[cfg(feature = "sms")]
+pub(crate) fn borrowed_empty_string_as_none<'de, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(borrowed_str(deserializer)?.filter(|value| !value.is_empty()))
+}
+
+// Deserializes a value sent either as a string or as a number, like a `sendAt` that can be a
+// timestamp or epoch milliseconds, into its text.
+#[cfg(feature = "sms")]
+pub(crate) fn deserialize_string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        Number(i64),
+        Text(String),
+    }
+
+    match <StringOrNumber as serde::Deserialize>::deserialize(deserializer)? {
+        StringOrNumber::Number(number) => Ok(number.to_string()),
+        StringOrNumber::Text(text) => Ok(text),
+    }
+}
+
+/// Implemented by responses of paged endpoints, so they can be driven by
+/// [`fetch_all_pages`](crate::api::fetch_all_pages).
+pub trait Paged {
+    /// Returns the pagination details of the response, if present.
+    fn paging(&self) -> Option<&Paging>;
+
+    /// Returns the number of items contained in this page.
+    fn items_len(&self) -> usize;
+}
+
+/// SMS message sent if a message on another channel, like WhatsApp or Viber, could not be
+/// delivered. Serialized under the `smsFailover` key of the message it belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SmsFailover {
+    /// SMS sender number. Must be in international format.
+    #[validate(length(min = 1, max = 24))]
+    pub from: String,
+
+    /// Content of the SMS that will be sent.
+    #[validate(length(min = 1, max = 4096))]
+    pub text: String,
+
+    /// Message validity period in minutes. When the period expires, the SMS will not be sent.
+    /// Default value is 2880 minutes (48 hours).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2880))]
+    pub validity_period: Option<i32>,
+}
+
+impl SmsFailover {
+    pub fn new(from: &str, text: &str) -> Self {
+        SmsFailover {
+            from: from.into(),
+            text: text.into(),
+            validity_period: None,
+        }
+    }
+}
+
+/// Recipient of a message on any channel, so application code can hold one type and convert it
+/// to the wire type of the channel at send time. Each channel accepts different recipients, see
+/// the `validate_for_*` methods.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "sms", feature = "email"))]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use infobip_sdk::model::common::Recipient;
+/// # use infobip_sdk::model::sms::Destination;
+/// let recipient = Recipient::Msisdn("41793026727".to_string());
+///
+/// assert!(recipient.validate_for_whatsapp().is_ok());
+/// assert!(recipient.validate_for_email().is_err());
+///
+/// let destination = Destination::try_from(recipient)?;
+/// assert_eq!(destination.to, "41793026727");
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(feature = "sms", feature = "email")))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Recipient {
+    /// Phone number in international format, or a short code for SMS.
+    Msisdn(String),
+
+    /// Email address, like `john@company.com`.
+    Email(String),
+}
+
+impl Recipient {
+    /// Checks that the recipient can receive SMS: a phone number of 3 to 15 digits, optionally
+    /// prefixed by `+`, so short codes are accepted.
+    pub fn validate_for_sms(&self) -> Result<(), ValidationError> {
+        match self {
+            Recipient::Msisdn(number) if SMS_NUMBER.is_match(number) => Ok(()),
+            Recipient::Msisdn(_) => Err(validation_error(
+                "recipient_number",
+                "SMS recipient must be a number of 3 to 15 digits, optionally prefixed by +",
+            )),
+            Recipient::Email(_) => Err(validation_error(
+                "recipient_channel",
+                "SMS recipient must be a phone number, not an email address",
+            )),
+        }
+    }
+
+    /// Checks that the recipient can receive WhatsApp messages: a number in E.164 format without
+    /// the leading `+`.
+    pub fn validate_for_whatsapp(&self) -> Result<(), ValidationError> {
+        match self {
+            Recipient::Msisdn(number) if WHATSAPP_NUMBER.is_match(number) => Ok(()),
+            Recipient::Msisdn(_) => Err(validation_error(
+                "recipient_number",
+                "WhatsApp recipient must be a number in E.164 format without the leading +",
+            )),
+            Recipient::Email(_) => Err(validation_error(
+                "recipient_channel",
+                "WhatsApp recipient must be a phone number, not an email address",
+            )),
+        }
+    }
+
+    /// Checks that the recipient can receive emails: exactly one email address.
+    #[cfg(feature = "email")]
+    pub fn validate_for_email(&self) -> Result<(), ValidationError> {
+        match self {
+            Recipient::Email(address) => {
+                match crate::model::email::address::parse_address_list(address) {
+                    Ok(addresses) if addresses.len() == 1 => Ok(()),
+                    _ => Err(validation_error(
+                        "recipient_address",
+                        "email recipient must be exactly one valid email address",
+                    )),
+                }
+            }
+            Recipient::Msisdn(_) => Err(validation_error(
+                "recipient_channel",
+                "email recipient must be an email address, not a phone number",
+            )),
+        }
+    }
+
+    /// Returns the number to use as the `to` of a WhatsApp message, which has no destination
+    /// type of its own, after checking it with `validate_for_whatsapp`.
+    pub fn to_whatsapp_number(&self) -> Result<&str, ValidationError> {
+        self.validate_for_whatsapp()?;
+
+        Ok(self.value())
+    }
+
+    /// Returns the phone number or email address.
+    pub fn value(&self) -> &str {
+        match self {
+            Recipient::Msisdn(value) | Recipient::Email(value) => value,
+        }
+    }
+}
+
+/// Default delay of `RetryPolicy::short_delay`, for errors that clear within minutes.
+pub const DEFAULT_SHORT_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+/// Default delay of `RetryPolicy::long_delay`, for errors that clear within hours.
+pub const DEFAULT_LONG_RETRY_DELAY: std::time::Duration =
+    std::time::Duration::from_secs(4 * 60 * 60);
+
+/// How soon a failed message is worth sending again, as returned by the `retry_advice` methods of
+/// delivery report errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RetryAdvice {
+    /// The failure is transient; send the message again right away.
+    RetryNow,
+    /// The recipient or the network cannot take the message for now; send it again after the
+    /// delay.
+    RetryLater(std::time::Duration),
+    /// Sending the message again will not help, or the error is not known to be transient.
+    DoNotRetry,
+}
+
+/// Delay classes of `RETRY_CLASSIFICATION`, turned into durations by a `RetryPolicy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RetryDelay {
+    Immediate,
+    Short,
+    Long,
+}
+
+/// Transient error names mapped to how soon a failed message is worth sending again, used by
+/// `RetryPolicy::advise`. Exposed so the mapping can be audited against the error code tables of
+/// the Infobip documentation.
+pub const RETRY_CLASSIFICATION: &[(&str, RetryDelay)] = &[
+    ("EC_SYSTEM_FAILURE", RetryDelay::Immediate),
+    ("EC_THROTTLING", RetryDelay::Short),
+    ("EC_SUBSCRIBER_BUSY_FOR_MT_SMS", RetryDelay::Short),
+    ("EC_SM_DELIVERY_FAILURE", RetryDelay::Short),
+    ("EC_ABSENT_SUBSCRIBER_SM", RetryDelay::Long),
+    ("EC_ABSENT_SUBSCRIBER", RetryDelay::Long),
+    ("EC_MESSAGE_WAITING_LIST_FULL", RetryDelay::Long),
+];
+
+/// Delays used by the retry advice of delivery report errors, to match the schedule of a retry
+/// engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+    /// Delay of errors that clear within minutes, like throttling.
+    pub short_delay: std::time::Duration,
+
+    /// Delay of errors that clear within hours, like an absent subscriber.
+    pub long_delay: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            short_delay: DEFAULT_SHORT_RETRY_DELAY,
+            long_delay: DEFAULT_LONG_RETRY_DELAY,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the advice for an error with the given name and permanent flag. Permanent errors
+    /// are never retried, and neither are errors missing from `RETRY_CLASSIFICATION`.
+    pub fn advise(&self, name: Option<&str>, permanent: Option<bool>) -> RetryAdvice {
+        if permanent == Some(true) {
+            return RetryAdvice::DoNotRetry;
+        }
+
+        let delay = name.and_then(|name| {
+            RETRY_CLASSIFICATION
+                .iter()
+                .find(|(error_name, _)| *error_name == name)
+                .map(|(_, delay)| *delay)
+        });
+
+        match delay {
+            Some(RetryDelay::Immediate) => RetryAdvice::RetryNow,
+            Some(RetryDelay::Short) => RetryAdvice::RetryLater(self.short_delay),
+            Some(RetryDelay::Long) => RetryAdvice::RetryLater(self.long_delay),
+            None => RetryAdvice::DoNotRetry,
+        }
+    }
+}
+
+/// Parses a timestamp in the API format, also accepting RFC 3339 timestamps.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, TIMESTAMP_FORMAT)
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+}
+
+/// Formats a date and time as a UTC timestamp in the API format, e.g.
+/// `2022-10-01T07:00:00.000+0000` for `2022-10-01T12:30:00+05:30`.
+pub fn format_timestamp<Tz: TimeZone>(time: &DateTime<Tz>) -> String {
+    time.with_timezone(&Utc)
+        .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+        .to_string()
+}
+
+/// Parses a `sendAt` value returned by the API, either milliseconds since the Unix epoch or a
+/// timestamp, into a UTC date and time.
+pub fn parse_send_at(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    match value.parse::<i64>() {
+        Ok(millis) => Utc.timestamp_millis_opt(millis).single(),
+        Err(_) => parse_timestamp(value).map(|time| time.with_timezone(&Utc)),
+    }
+}
+
+#[cfg(any(feature = "sms", feature = "email"))]
+fn range_error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(Cow::from(message));
+
+    error
+}
+
+// Checks that a `sentSince`/`sentUntil` pair is a coherent range: both bounds parse, none is in
+// the future, since comes before until, and the range is not wider than the retention window.
+// Either bound can be omitted. Without until, the range runs up to now, so since alone cannot be
+// further back than the retention window.
+#[cfg(any(feature = "sms", feature = "email"))]
+pub(crate) fn validate_time_range(
+    since: Option<&str>,
+    until: Option<&str>,
+    retention_hours: i64,
+) -> Result<(), ValidationError> {
+    let window = format!(
+        "sentSince and sentUntil must be in the past, in order, and at most {} hours apart",
+        retention_hours
+    );
+    let parse = |value: &str| {
+        parse_timestamp(value).ok_or_else(|| {
+            range_error(
+                "timestamp_format",
+                format!(
+                    "'{}' is not a valid timestamp, expected yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+                    value
+                ),
+            )
+        })
+    };
+    let since = since.map(parse).transpose()?;
+    let until = until.map(parse).transpose()?;
+
+    let now = Utc::now();
+    if since.iter().chain(until.iter()).any(|time| *time > now) {
+        return Err(range_error("time_range_future", window));
+    }
+    if let Some(since) = since {
+        if until.map_or(false, |until| since > until) {
+            return Err(range_error("time_range_inverted", window));
+        }
+        let until = until.map_or(now, |until| until.with_timezone(&Utc));
+        if until - since.with_timezone(&Utc) > Duration::hours(retention_hours) {
+            return Err(range_error("time_range_too_wide", window));
+        }
+    }
+
+    Ok(())
+}
+
+// Checks that a `sendAt` value is a timestamp in the API format that is not further in the past
+// than `tolerance`, compared to the clock of this machine. Past times are accepted by the API,
+// which then sends right away, so this is only reported when asked for.
+#[cfg(any(feature = "sms", feature = "email"))]
+pub(crate) fn validate_send_at(send_at: &str, tolerance: Duration) -> Result<(), ValidationError> {
+    let time = parse_timestamp(send_at).ok_or_else(|| {
+        range_error(
+            "send_at_format",
+            format!(
+                "'{}' is not a valid timestamp, expected yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+                send_at
+            ),
+        )
+    })?;
+
+    if time < Utc::now() - tolerance {
+        return Err(range_error(
+            "send_at_in_past",
+            format!("send_at {} is in the past", send_at),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Builds a validation error with the given code and message.
+pub(crate) fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(Cow::Borrowed(message));
+
+    error
+}
+
+/// Returns whether the value contains control characters, which the API does not accept in
+/// `callbackData`.
+#[cfg(any(feature = "sms", feature = "email"))]
+pub(crate) fn has_control_characters(value: &str) -> bool {
+    value.chars().any(char::is_control)
+}
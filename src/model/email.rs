@@ -1,26 +1,130 @@
 //! Models for calling Email endpoints.
 
+use std::collections::HashMap;
+
+use base64::Engine;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use regex::{Captures, Regex};
+use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
+use thiserror::Error;
 use validator::Validate;
 
+use crate::api::SdkError;
+use crate::model::codes::{error_by_id, status_by_id, ErrorCodeInfo, StatusCodeInfo};
+pub use crate::model::common::Paging;
+use crate::model::common::{
+    deserialize_epoch_millis, deserialize_money, empty_string_as_none, format_timestamp,
+    has_control_characters, money_to_f64, validate_limit, validate_send_at, validate_time_range,
+    validation_error, Money, NotifyContentType, Paged, Recipient, RetryAdvice, RetryPolicy,
+    LOGS_RETENTION_HOURS,
+};
+use crate::model::{
+    ApiRequest, ApiResponse, DeliveryStatus, SendOutcome, ToQueryString, ValidateStrict, WireNames,
+};
+
+pub mod address;
+
+lazy_static! {
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{\{\s*([^{}\s]+)\s*\}\}").unwrap();
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailRecipient {
+    /// Email address of the recipient.
+    #[validate(length(min = 1))]
+    pub to: String,
+
+    /// Values for the template placeholders of this recipient only.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub placeholders: HashMap<String, String>,
+
+    /// ID of the message sent to this recipient, so its delivery reports can be told apart from
+    /// those of the other recipients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Client data sent in the delivery reports of this recipient only, in place of the
+    /// `callbackData` of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 4000))]
+    pub callback_data: Option<String>,
+}
+
+/// Converts a recipient checked with `Recipient::validate_for_email`.
+impl TryFrom<Recipient> for EmailRecipient {
+    type Error = validator::ValidationError;
+
+    fn try_from(recipient: Recipient) -> Result<Self, Self::Error> {
+        recipient.validate_for_email()?;
+
+        Ok(EmailRecipient::new(recipient.value()))
+    }
+}
+
+impl EmailRecipient {
+    pub fn new(to: &str) -> Self {
+        EmailRecipient {
+            to: to.into(),
+            placeholders: HashMap::new(),
+            message_id: None,
+            callback_data: None,
+        }
+    }
+}
+
+/// Priority of an email in the sending queue, relative to the other emails of the account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ClientPriority {
+    #[serde(rename = "HIGH")]
+    High,
+    #[serde(rename = "MEDIUM")]
+    Medium,
+    #[serde(rename = "LOW")]
+    Low,
+}
+
+impl ClientPriority {
+    /// Returns the priority as sent to the API, e.g. `HIGH`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientPriority::High => "HIGH",
+            ClientPriority::Medium => "MEDIUM",
+            ClientPriority::Low => "LOW",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_send_recipients"))]
 pub struct SendRequestBody {
     /// Email address with optional sender name. This field is required if `templateId` is not
     /// present.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_sender_address")]
     pub from: Option<String>,
 
-    /// Email address of the recipient.
-    #[validate(length(min = 1))]
+    /// Email address of the recipient, or a list of addresses. Can be left empty if
+    /// `recipients` is not.
+    #[validate(custom = "validate_address_list")]
     pub to: String,
 
+    /// Recipients with their own placeholder values, message ID or callback data, sent in
+    /// addition to `to`. Placeholders can only be used together with `templateId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub recipients: Option<Vec<EmailRecipient>>,
+
     /// CC recipient email address.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_address_list")]
     pub cc: Option<String>,
 
     /// BCC recipient email address.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_address_list")]
     pub bcc: Option<String>,
 
     /// Message subject. This field is required if `templateId` is not present.
@@ -65,9 +169,9 @@ pub struct SendRequestBody {
     #[validate(url)]
     pub notify_url: Option<String>,
 
-    /// Preferred Delivery report content type. Can be `application/json` or `application/xml`.
+    /// Preferred Delivery report content type, `application/json` or `application/xml`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notify_content_type: Option<String>,
+    pub notify_content_type: Option<NotifyContentType>,
 
     /// Additional client data that will be sent on the notifyUrl.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -107,6 +211,7 @@ pub struct SendRequestBody {
 
     /// Email address to which recipients of the email can reply.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_address_list")]
     pub reply_to: Option<String>,
 
     /// General placeholder, given in a form of json example:
@@ -124,7 +229,7 @@ pub struct SendRequestBody {
     pub preserve_recipients: Option<bool>,
 
     /// To schedule message at a given time in future. Time provided should be in UTC in the
-    /// following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    /// following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`. See `schedule_at`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub send_at: Option<String>,
 
@@ -135,9 +240,56 @@ pub struct SendRequestBody {
 
     /// Opt out landing page which will be used and displayed once end user clicks the unsubscribe
     /// link. If not present default opt out landing page will be displayed. Create a landing page
-    /// on IB’s portal and use the last 6 digits from URL to use that opt out page.
+    /// on IB’s portal and use the last 6 digits from URL to use that opt out page, see
+    /// `landing_page_id_from_url`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub landing_page_id: Option<String>,
+
+    /// Priority of the email in the sending queue, e.g. `High` for password resets that should
+    /// not wait behind a newsletter of the same account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_priority: Option<ClientPriority>,
+
+    /// Additional email headers, by name. Sent as a JSON object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+impl WireNames for SendRequestBody {
+    fn wire_names() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("from", "from"),
+            ("to", "to"),
+            ("recipients", "recipients"),
+            ("cc", "cc"),
+            ("bcc", "bcc"),
+            ("subject", "subject"),
+            ("text", "text"),
+            ("html", "html"),
+            ("amp_html", "ampHtml"),
+            ("template_id", "templateId"),
+            ("attachments", "attachments"),
+            ("inline_images", "inlineImages"),
+            ("intermediate_report", "intermediateReport"),
+            ("notify_url", "notifyUrl"),
+            ("notify_content_type", "notifyContentType"),
+            ("callback_data", "callbackData"),
+            ("track", "track"),
+            ("track_clicks", "trackClicks"),
+            ("track_opens", "trackOpens"),
+            ("tracking_url", "trackingUrl"),
+            ("bulk_id", "bulkId"),
+            ("message_id", "messageId"),
+            ("reply_to", "replyTo"),
+            ("default_placeholders", "defaultPlaceholders"),
+            ("preserve_recipients", "preserveRecipients"),
+            ("send_at", "sendAt"),
+            ("landing_page_placeholders", "landingPagePlaceholders"),
+            ("landing_page_id", "landingPageId"),
+            ("client_priority", "clientPriority"),
+            ("headers", "headers"),
+        ]
+    }
 }
 
 impl SendRequestBody {
@@ -145,6 +297,7 @@ impl SendRequestBody {
         SendRequestBody {
             from: None,
             to: to.into(),
+            recipients: None,
             cc: None,
             bcc: None,
             subject: None,
@@ -170,12 +323,204 @@ impl SendRequestBody {
             send_at: None,
             landing_page_placeholders: None,
             landing_page_id: None,
+            client_priority: None,
+            headers: None,
+        }
+    }
+
+    /// Schedules the email at `send_at`, given in any time zone, setting `send_at` to the
+    /// matching UTC timestamp in the API format.
+    ///
+    /// # Example
+    /// ```
+    /// # use chrono::DateTime;
+    /// # use infobip_sdk::model::email::SendRequestBody;
+    /// let mut request_body = SendRequestBody::new("someone@company.com");
+    /// request_body.schedule_at(DateTime::parse_from_rfc3339("2030-03-01T09:00:00+05:30").unwrap());
+    ///
+    /// assert_eq!(request_body.send_at.as_deref(), Some("2030-03-01T03:30:00.000+0000"));
+    /// ```
+    pub fn schedule_at<Tz: TimeZone>(&mut self, send_at: DateTime<Tz>) {
+        self.send_at = Some(format_timestamp(&send_at));
+    }
+
+    /// Checks that `send_at`, if set, is a timestamp in the API format and is not more than
+    /// `tolerance` in the past according to the clock of this machine. The API sends emails
+    /// scheduled in the past right away, so this is not part of `validate`.
+    pub fn check_send_at(&self, tolerance: Duration) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+
+        if let Some(send_at) = &self.send_at {
+            if let Err(error) = validate_send_at(send_at, tolerance) {
+                errors.add("send_at", error);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Adds the `List-Unsubscribe` and `List-Unsubscribe-Post` headers required for one-click
+    /// unsubscribe (RFC 8058) by bulk sender rules of mailbox providers. `mailto` is the address
+    /// receiving unsubscribe emails, with or without the `mailto:` scheme, and `url` the HTTPS
+    /// endpoint receiving the one-click POST request.
+    ///
+    /// Fails if the address or URL are not valid, or if `landing_page_id` is set, since
+    /// unsubscribes would then be handled by Infobip's landing page too. Pass `force` to add the
+    /// headers anyway.
+    pub fn with_one_click_unsubscribe(
+        mut self,
+        mailto: &str,
+        url: &str,
+        force: bool,
+    ) -> Result<Self, SdkError> {
+        let address = mailto.strip_prefix("mailto:").unwrap_or(mailto);
+        let mut errors = validator::ValidationErrors::new();
+
+        if !validator::validate_email(address) {
+            errors.add(
+                "headers",
+                validation_error(
+                    "unsubscribe_mailto",
+                    "unsubscribe mailto must be an email address",
+                ),
+            );
+        }
+        if !url.starts_with("https://") || !validator::validate_url(url) {
+            errors.add(
+                "headers",
+                validation_error("unsubscribe_url", "unsubscribe URL must be an HTTPS URL"),
+            );
+        }
+        if self.landing_page_id.is_some() && !force {
+            errors.add(
+                "headers",
+                validation_error(
+                    "unsubscribe_landing_page",
+                    "one-click unsubscribe conflicts with landing_page_id",
+                ),
+            );
+        }
+        if !errors.is_empty() {
+            return Err(SdkError::Validation(errors));
+        }
+
+        let headers = self.headers.get_or_insert_with(HashMap::new);
+        headers.insert(
+            "List-Unsubscribe".to_string(),
+            format!("<mailto:{}>, <{}>", address, url),
+        );
+        headers.insert(
+            "List-Unsubscribe-Post".to_string(),
+            "List-Unsubscribe=One-Click".to_string(),
+        );
+
+        Ok(self)
+    }
+}
+
+fn address_error(error: address::ParseError) -> validator::ValidationError {
+    let mut validation_error = validator::ValidationError::new("address_list");
+    validation_error.message = Some(error.to_string().into());
+
+    validation_error
+}
+
+fn validate_address_list(value: &str) -> Result<(), validator::ValidationError> {
+    address::parse_address_list(value)
+        .map(|_| ())
+        .map_err(address_error)
+}
+
+fn validate_sender_address(value: &str) -> Result<(), validator::ValidationError> {
+    if address::parse_address_list(value)
+        .map_err(address_error)?
+        .len()
+        != 1
+    {
+        return Err(validation_error(
+            "sender_address",
+            "from must contain exactly one address",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_send_recipients(
+    request_body: &SendRequestBody,
+) -> Result<(), validator::ValidationError> {
+    let recipients = request_body.recipients.as_deref().unwrap_or_default();
+
+    if request_body.to.is_empty() && recipients.is_empty() {
+        return Err(validator::ValidationError::new("missing_recipient"));
+    }
+    if request_body.template_id.is_none()
+        && recipients
+            .iter()
+            .any(|recipient| !recipient.placeholders.is_empty())
+    {
+        let mut error = validator::ValidationError::new("recipient_placeholders_without_template");
+        error.message = Some("recipient placeholders can only be used with template_id".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Checks, on top of `validate`, these limits of the Email API:
+/// - `from` is at most 256 characters long.
+/// - `callbackData` has no control characters.
+impl ValidateStrict for SendRequestBody {
+    fn validate_strict(&self) -> Result<(), validator::ValidationErrors> {
+        self.validate()?;
+
+        let mut errors = validator::ValidationErrors::new();
+
+        if self
+            .from
+            .as_ref()
+            .map_or(false, |from| from.chars().count() > 256)
+        {
+            errors.add(
+                "from",
+                validation_error("strict_from_length", "from must be at most 256 characters"),
+            );
+        }
+        let recipients = self.recipients.as_deref().unwrap_or_default();
+        if self
+            .callback_data
+            .as_deref()
+            .map_or(false, has_control_characters)
+            || recipients.iter().any(|recipient| {
+                recipient
+                    .callback_data
+                    .as_deref()
+                    .map_or(false, has_control_characters)
+            })
+        {
+            errors.add(
+                "callback_data",
+                validation_error(
+                    "strict_callback_data_charset",
+                    "callback_data must not contain control characters",
+                ),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SentMessageDetails {
     /// The destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,7 +535,7 @@ pub struct SentMessageDetails {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct ReportError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<i32>,
@@ -211,15 +556,103 @@ pub struct ReportError {
     pub permanent: Option<bool>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+/// How a failed email delivery should be handled, as returned by `ReportError::classify`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BounceClass {
+    /// The address does not exist or cannot receive email; stop sending to it.
+    HardBounce,
+    /// Temporary failure, like a full mailbox or an unavailable server; retry later.
+    SoftBounce,
+    /// The recipient marked the email as spam; stop sending to it.
+    Complaint,
+    /// Delivery was refused by the receiving side or blocked by a policy.
+    Blocked,
+    /// The error could not be classified.
+    Unknown,
+}
+
+/// Error IDs and names mapped to their bounce class, used by `ReportError::classify`. Exposed so
+/// the mapping can be audited against the error code tables of the Infobip documentation.
+pub const BOUNCE_CLASSIFICATION: &[(i32, &str, BounceClass)] = &[
+    (1, "EC_UNKNOWN_SUBSCRIBER", BounceClass::HardBounce),
+    (5, "EC_UNIDENTIFIED_SUBSCRIBER", BounceClass::HardBounce),
+    (9, "EC_ILLEGAL_SUBSCRIBER", BounceClass::HardBounce),
+    (6, "EC_ABSENT_SUBSCRIBER_SM", BounceClass::SoftBounce),
+    (27, "EC_ABSENT_SUBSCRIBER", BounceClass::SoftBounce),
+    (31, "EC_SUBSCRIBER_BUSY_FOR_MT_SMS", BounceClass::SoftBounce),
+    (32, "EC_SM_DELIVERY_FAILURE", BounceClass::SoftBounce),
+    (33, "EC_MESSAGE_WAITING_LIST_FULL", BounceClass::SoftBounce),
+    (13, "EC_CALL_BARRED", BounceClass::Blocked),
+    (2050, "EC_SPAM_COMPLAINT", BounceClass::Complaint),
+];
+
+impl ReportError {
+    /// Returns the details of the error code from `codes::ERROR_CODES`, if known.
+    pub fn info(&self) -> Option<&'static ErrorCodeInfo> {
+        self.id.and_then(error_by_id)
+    }
+
+    /// Classifies the error with `BOUNCE_CLASSIFICATION`. Errors missing from the table are
+    /// classified by `permanent`, or by the permanent flag of the known error code when not set:
+    /// hard bounce when permanent, soft bounce when not, and unknown otherwise.
+    pub fn classify(&self) -> BounceClass {
+        let mapped = self.id.and_then(|id| {
+            BOUNCE_CLASSIFICATION
+                .iter()
+                .find(|(error_id, _, _)| *error_id == id)
+                .map(|(_, _, class)| *class)
+        });
+
+        let permanent = self
+            .permanent
+            .or_else(|| self.info().map(|info| info.permanent));
+
+        match (mapped, permanent) {
+            (Some(class), _) => class,
+            (None, Some(true)) => BounceClass::HardBounce,
+            (None, Some(false)) => BounceClass::SoftBounce,
+            (None, None) => BounceClass::Unknown,
+        }
+    }
+
+    /// Returns how soon the email is worth sending again, with the delays of the default
+    /// `RetryPolicy`.
+    pub fn retry_advice(&self) -> RetryAdvice {
+        self.retry_advice_with(&RetryPolicy::default())
+    }
+
+    /// Returns how soon the email is worth sending again, with the delays of `policy`. Errors
+    /// missing from `RETRY_CLASSIFICATION` are retried after the long delay when they classify
+    /// as a soft bounce, and not retried otherwise.
+    pub fn retry_advice_with(&self, policy: &RetryPolicy) -> RetryAdvice {
+        let info = self.info();
+        let name = self.name.as_deref().or_else(|| info.map(|info| info.name));
+        let permanent = self.permanent.or_else(|| info.map(|info| info.permanent));
+
+        match policy.advise(name, permanent) {
+            RetryAdvice::DoNotRetry
+                if permanent != Some(true) && self.classify() == BounceClass::SoftBounce =>
+            {
+                RetryAdvice::RetryLater(policy.long_delay)
+            }
+            advice => advice,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Status {
     /// Status group ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<i32>,
 
     /// Status group name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub group_name: Option<String>,
 
     /// Status ID.
@@ -227,20 +660,45 @@ pub struct Status {
     pub id: Option<i32>,
 
     /// Status name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub name: Option<String>,
 
     /// Status description.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub description: Option<String>,
 
     /// Action name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Status {
+    /// Returns the details of the status code from `codes::STATUS_CODES`, if known.
+    pub fn info(&self) -> Option<&'static StatusCodeInfo> {
+        self.id.and_then(status_by_id)
+    }
+}
+
+impl DeliveryStatus for Status {
+    fn group_id(&self) -> Option<i32> {
+        self.group_id
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SendResponseBody {
     /// The ID that uniquely identifies a list of message responses.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,22 +709,91 @@ pub struct SendResponseBody {
     pub messages: Option<Vec<SentMessageDetails>>,
 }
 
+impl ApiResponse for SendResponseBody {}
+
+impl SendResponseBody {
+    /// Pairs each of the given recipients with the details of the message sent to it, matching
+    /// addresses case-insensitively. Recipients without a matching message are paired with
+    /// `None`.
+    pub fn details_for<'a>(
+        &'a self,
+        recipients: &'a [EmailRecipient],
+    ) -> Vec<(&'a EmailRecipient, Option<&'a SentMessageDetails>)> {
+        let messages = self.messages.as_deref().unwrap_or_default();
+
+        recipients
+            .iter()
+            .map(|recipient| {
+                let details = messages.iter().find(|message| {
+                    message.to.as_deref().map_or(false, |to| {
+                        to.trim().eq_ignore_ascii_case(recipient.to.trim())
+                    })
+                });
+                (recipient, details)
+            })
+            .collect()
+    }
+}
+
+impl SendOutcome for SendResponseBody {
+    type Details = SentMessageDetails;
+
+    fn sent_messages(&self) -> &[SentMessageDetails] {
+        self.messages.as_deref().unwrap_or_default()
+    }
+
+    fn status_group_id(details: &SentMessageDetails) -> Option<i32> {
+        details.status.as_ref().and_then(|status| status.group_id)
+    }
+}
+
+/// Identifies a scheduled bulk either by the bulk ID assigned on send or by an external bulk ID.
+/// Exactly one of them must be set.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_bulk_reference"))]
 pub struct GetBulksQueryParameters {
     #[validate(length(min = 1))]
-    pub bulk_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_bulk_id: Option<String>,
 }
 
+impl ToQueryString for GetBulksQueryParameters {}
+
 impl GetBulksQueryParameters {
     pub fn new(bulk_id: &str) -> Self {
         GetBulksQueryParameters {
-            bulk_id: bulk_id.into(),
+            bulk_id: Some(bulk_id.into()),
+            external_bulk_id: None,
+        }
+    }
+
+    pub fn with_external_bulk_id(external_bulk_id: &str) -> Self {
+        GetBulksQueryParameters {
+            bulk_id: None,
+            external_bulk_id: Some(external_bulk_id.into()),
         }
     }
 }
 
+fn validate_bulk_reference(
+    query_parameters: &GetBulksQueryParameters,
+) -> Result<(), validator::ValidationError> {
+    if query_parameters.bulk_id.is_some() == query_parameters.external_bulk_id.is_some() {
+        let mut error = validator::ValidationError::new("bulk_reference");
+        error.message = Some("exactly one of bulkId and externalBulkId must be set".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetBulksResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_bulk_id: Option<String>,
@@ -275,25 +802,53 @@ pub struct GetBulksResponseBody {
     pub bulks: Option<Vec<BulkInfo>>,
 }
 
+impl ApiResponse for GetBulksResponseBody {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct BulkInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Time the bulk is scheduled for, in milliseconds since the Unix epoch (UTC). Read from
+    /// either a number or a string, as the API has returned both.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_epoch_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub send_at: Option<u64>,
 }
 
+impl ApiResponse for BulkInfo {}
+
+impl BulkInfo {
+    /// Returns `send_at` as a UTC date and time.
+    pub fn send_at_datetime(&self) -> Option<DateTime<Utc>> {
+        let millis = i64::try_from(self.send_at?).ok()?;
+
+        Utc.timestamp_millis_opt(millis).single()
+    }
+
+    /// Returns `send_at` as an ISO 8601 timestamp in the format used by the API, e.g.
+    /// `2022-10-01T12:30:00.000+0000`.
+    pub fn send_at_iso(&self) -> Option<String> {
+        self.send_at_datetime()
+            .map(|send_at| send_at.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string())
+    }
+}
+
 pub type RescheduleQueryParameters = GetBulksQueryParameters;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct RescheduleRequestBody {
     #[validate(length(min = 1))]
     pub send_at: String,
 }
 
+impl ApiRequest for RescheduleRequestBody {}
+
 impl RescheduleRequestBody {
     pub fn new(send_at: &str) -> Self {
         RescheduleRequestBody {
@@ -307,6 +862,7 @@ pub type RescheduleResponseBody = BulkInfo;
 pub type GetScheduledStatusQueryParameters = GetBulksQueryParameters;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum BulkStatus {
     PENDING,
     PAUSED,
@@ -314,9 +870,31 @@ pub enum BulkStatus {
     CANCELED,
     FINISHED,
     FAILED,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl BulkStatus {
+    /// Returns whether the status is final, so polling the bulk can stop.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            BulkStatus::CANCELED | BulkStatus::FINISHED | BulkStatus::FAILED
+        )
+    }
+
+    /// Returns whether a scheduled bulk can be moved to this status: paused, resumed with
+    /// `PENDING`, or canceled.
+    pub fn is_valid_target(&self) -> bool {
+        matches!(
+            self,
+            BulkStatus::PENDING | BulkStatus::PAUSED | BulkStatus::CANCELED
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BulkStatusInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
@@ -325,8 +903,10 @@ pub struct BulkStatusInfo {
     pub status: Option<BulkStatus>,
 }
 
+impl ApiResponse for BulkStatusInfo {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetScheduledStatusResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_bulk_id: Option<String>,
@@ -335,14 +915,33 @@ pub struct GetScheduledStatusResponseBody {
     pub bulks: Option<Vec<BulkStatusInfo>>,
 }
 
+impl ApiResponse for GetScheduledStatusResponseBody {}
+
 pub type UpdateScheduledStatusQueryParameters = GetBulksQueryParameters;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_scheduled_status_target"))]
 pub struct UpdateScheduledStatusRequestBody {
+    /// New status of the bulk, one of `PENDING`, `PAUSED` or `CANCELED`.
     pub status: BulkStatus,
 }
 
+impl ApiRequest for UpdateScheduledStatusRequestBody {}
+
+fn validate_scheduled_status_target(
+    request_body: &UpdateScheduledStatusRequestBody,
+) -> Result<(), validator::ValidationError> {
+    if !request_body.status.is_valid_target() {
+        return Err(validation_error(
+            "status_target",
+            "status can only be updated to PENDING, PAUSED or CANCELED",
+        ));
+    }
+
+    Ok(())
+}
+
 impl UpdateScheduledStatusRequestBody {
     pub fn new(status: BulkStatus) -> Self {
         UpdateScheduledStatusRequestBody { status }
@@ -351,8 +950,107 @@ impl UpdateScheduledStatusRequestBody {
 
 pub type UpdateScheduledStatusResponseBody = BulkStatusInfo;
 
+/// Lists the messages of a scheduled bulk, one page at a time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GetScheduledMessagesQueryParameters {
+    /// ID of the scheduled bulk.
+    #[validate(length(min = 1))]
+    pub bulk_id: String,
+
+    /// Page number to fetch, starting at 0. Default is 0.
+    #[validate(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+
+    /// Maximum number of messages per page. Default is 100, with a maximum of
+    /// `MAX_SCHEDULED_MESSAGES_PAGE_SIZE`.
+    #[validate(range(min = 1, max = "MAX_SCHEDULED_MESSAGES_PAGE_SIZE"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+}
+
+/// Largest page size accepted when listing the messages of a scheduled bulk.
+pub const MAX_SCHEDULED_MESSAGES_PAGE_SIZE: i32 = 1000;
+
+impl ToQueryString for GetScheduledMessagesQueryParameters {}
+
+impl GetScheduledMessagesQueryParameters {
+    pub fn new(bulk_id: &str) -> Self {
+        GetScheduledMessagesQueryParameters {
+            bulk_id: bulk_id.into(),
+            page: None,
+            size: None,
+        }
+    }
+}
+
+/// Message of a scheduled bulk.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScheduledMessage {
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Email address of the recipient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Time the message is scheduled for, in milliseconds since the Unix epoch (UTC). Read from
+    /// either a number or a string, like `BulkInfo::send_at`.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_epoch_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub send_at: Option<u64>,
+
+    /// Status of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+impl ScheduledMessage {
+    /// Returns `send_at` as a UTC date and time.
+    pub fn send_at_datetime(&self) -> Option<DateTime<Utc>> {
+        let millis = i64::try_from(self.send_at?).ok()?;
+
+        Utc.timestamp_millis_opt(millis).single()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetScheduledMessagesResponseBody {
+    /// ID of the scheduled bulk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// Pagination details like page number, page size, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
+
+    /// Messages of the bulk on this page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<ScheduledMessage>>,
+}
+
+impl ApiResponse for GetScheduledMessagesResponseBody {}
+
+impl Paged for GetScheduledMessagesResponseBody {
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn items_len(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_delivery_reports_time_range"))]
 pub struct GetDeliveryReportsQueryParameters {
     /// Bulk ID for which report is requested.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -362,21 +1060,72 @@ pub struct GetDeliveryReportsQueryParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
-    /// Maximum number of reports.
+    /// Maximum number of delivery reports to be returned, between 1 and `MAX_LIMIT`. If not set,
+    /// the client requests `DEFAULT_LIMIT` records.
+    #[validate(custom = "validate_delivery_reports_limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+
+    /// Campaign reference ID the emails were sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Application ID the emails were sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Entity ID the emails were sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Only return reports of emails sent after this time. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_since: Option<String>,
+
+    /// Only return reports of emails sent before this time. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_until: Option<String>,
+}
+
+impl ToQueryString for GetDeliveryReportsQueryParameters {}
+
+fn validate_delivery_reports_time_range(
+    query_parameters: &GetDeliveryReportsQueryParameters,
+) -> Result<(), validator::ValidationError> {
+    validate_time_range(
+        query_parameters.sent_since.as_deref(),
+        query_parameters.sent_until.as_deref(),
+        LOGS_RETENTION_HOURS,
+    )
 }
 
 impl GetDeliveryReportsQueryParameters {
+    /// Number of delivery reports requested when `limit` is not set.
+    pub const DEFAULT_LIMIT: i32 = 50;
+
+    /// Maximum number of delivery reports that can be requested at once.
+    pub const MAX_LIMIT: i32 = 1000;
+
     pub fn new() -> Self {
         GetDeliveryReportsQueryParameters {
             bulk_id: None,
             message_id: None,
             limit: None,
+            campaign_reference_id: None,
+            application_id: None,
+            entity_id: None,
+            sent_since: None,
+            sent_until: None,
         }
     }
 }
 
+fn validate_delivery_reports_limit(limit: i32) -> Result<(), validator::ValidationError> {
+    validate_limit(limit, GetDeliveryReportsQueryParameters::MAX_LIMIT)
+}
+
 impl Default for GetDeliveryReportsQueryParameters {
     fn default() -> Self {
         Self::new()
@@ -384,38 +1133,69 @@ impl Default for GetDeliveryReportsQueryParameters {
 }
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct Price {
     /// Price per one email request.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub price_per_message: Option<f32>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_message: Option<Money>,
 
     /// The currency in which the price is expressed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Price {
+    /// Returns the price per message as a `f64`, regardless of the `decimal` feature.
+    pub fn price_per_message_f64(&self) -> Option<f64> {
+        self.price_per_message.map(money_to_f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Report {
     /// The ID that uniquely identifies bulks of request.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub bulk_id: Option<String>,
 
     /// The ID that uniquely identifies the sent email request.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub message_id: Option<String>,
 
     /// The recipient email address.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub to: Option<String>,
 
     /// Tells when the email was initiated. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sent_at: Option<String>,
 
     /// Tells when the email request was processed by Infobip
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub done_at: Option<String>,
 
     /// Email request count.
@@ -433,17 +1213,63 @@ pub struct Report {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ReportError>,
+
+    /// Client data sent with the email, of its recipient when set per recipient.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub callback_data: Option<String>,
+
+    /// Campaign reference ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub campaign_reference_id: Option<String>,
+
+    /// Application ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub application_id: Option<String>,
+
+    /// Entity ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_id: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetDeliveryReportsResponseBody {
     #[serde(rename = "results", skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Report>>,
 }
 
+impl ApiResponse for GetDeliveryReportsResponseBody {}
+
+/// Parses a delivery report callback sent as XML, when `notifyContentType` is
+/// `application/xml`, into the same structure used for JSON reports.
+#[cfg(feature = "xml")]
+pub fn parse_delivery_reports_xml(
+    xml: &[u8],
+) -> Result<GetDeliveryReportsResponseBody, crate::api::SdkError> {
+    Ok(GetDeliveryReportsResponseBody {
+        results: Some(crate::model::xml::parse_reports(xml)?),
+    })
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_logs_time_range"))]
 pub struct GetLogsQueryParameters {
     /// The ID that uniquely identifies the sent email.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -478,6 +1304,37 @@ pub struct GetLogsQueryParameters {
     /// Maximum number of logs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+
+    /// Whether to return the subject and HTML content of the emails with the logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_content: Option<bool>,
+
+    /// Application ID the emails were sent with.
+    #[validate(length(min = 1, max = 255))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Entity ID the emails were sent with.
+    #[validate(length(min = 1, max = 255))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Campaign reference ID the emails were sent with.
+    #[validate(length(min = 1, max = 255))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+}
+
+impl ToQueryString for GetLogsQueryParameters {}
+
+fn validate_logs_time_range(
+    query_parameters: &GetLogsQueryParameters,
+) -> Result<(), validator::ValidationError> {
+    validate_time_range(
+        query_parameters.sent_since.as_deref(),
+        query_parameters.sent_until.as_deref(),
+        LOGS_RETENTION_HOURS,
+    )
 }
 
 impl GetLogsQueryParameters {
@@ -491,6 +1348,20 @@ impl GetLogsQueryParameters {
             sent_since: None,
             sent_until: None,
             limit: None,
+            include_content: None,
+            application_id: None,
+            entity_id: None,
+            campaign_reference_id: None,
+        }
+    }
+
+    /// Returns query parameters for the log of a single email, including its subject and HTML
+    /// content.
+    pub fn message_content(message_id: &str) -> Self {
+        GetLogsQueryParameters {
+            message_id: Some(message_id.to_string()),
+            include_content: Some(true),
+            ..Self::new()
         }
     }
 }
@@ -501,31 +1372,59 @@ impl Default for GetLogsQueryParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Log {
     /// The ID that uniquely identifies the sent email request.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub message_id: Option<String>,
 
     /// The recipient email address.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub to: Option<String>,
 
     /// From email address.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub from: Option<String>,
 
-    /// The text from email body.
+    /// The text from email body, possibly truncated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
-    /// Tells when the email was initiated. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    /// Subject of the email. Only returned when `include_content` is requested.
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// HTML body of the email. Only returned when `include_content` is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Tells when the email was initiated. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sent_at: Option<String>,
 
     /// Tells when the email request was processed by Infobip
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub done_at: Option<String>,
 
     /// Email request count.
@@ -542,18 +1441,62 @@ pub struct Log {
     pub status: Option<Status>,
 
     /// The ID that uniquely identifies the request.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub bulk_id: Option<String>,
+
+    /// Application ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub application_id: Option<String>,
+
+    /// Entity ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_id: Option<String>,
+
+    /// Campaign reference ID the email was sent with.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub campaign_reference_id: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetLogsResponseBody {
     #[serde(rename = "results", skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Log>>,
+
+    /// Pagination details like page number, page size, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+impl ApiResponse for GetLogsResponseBody {}
+
+impl Paged for GetLogsResponseBody {
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn items_len(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidateAddressRequestBody {
     /// Email address of the recipient.
@@ -561,6 +1504,8 @@ pub struct ValidateAddressRequestBody {
     to: String,
 }
 
+impl ApiRequest for ValidateAddressRequestBody {}
+
 impl ValidateAddressRequestBody {
     pub fn new(to: &str) -> Self {
         ValidateAddressRequestBody { to: to.into() }
@@ -568,7 +1513,7 @@ impl ValidateAddressRequestBody {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct ValidateAddressResponseBody {
     /// Email address of the recipient.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,28 +1543,114 @@ pub struct ValidateAddressResponseBody {
 
     /// Reason is provided when validMailbox status is unknown.
     /// 1. INBOX_FULL - The user quota exceeded / The user inbox is full / The user doesn't accept
-    /// any more requests.
+    ///    any more requests.
     /// 2. UNEXPECTED_FAILURE - The mail Server returned a temporary error.
     /// 3. THROTTLED - The mail server is not allowing us momentarily because of too many requests.
     /// 4. TIMED_OUT - The Mail Server took a longer time to respond / there was a delay in the
-    /// network.
+    ///    network.
     /// 5. TEMP_REJECTION - Mail server temporarily rejected.
     /// 6. UNABLE_TO_CONNECT - Unable to connect to the Mail Server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
 }
 
+impl ApiResponse for ValidateAddressResponseBody {}
+
+impl ValidateAddressResponseBody {
+    /// Combines the validation results into a single decision under `policy`:
+    /// 1. Invalid syntax, a mailbox reported as invalid, or a disposable address not allowed by
+    ///    the policy is rejected.
+    /// 2. A mailbox with unknown or missing status, like when the mail server `TIMED_OUT` or
+    ///    `THROTTLED` the check, needs review, since a later check may succeed.
+    /// 3. A role-based or catch-all address not allowed by the policy needs review.
+    /// 4. Anything else is accepted.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::model::email::{RiskAssessment, ValidateAddressResponseBody, ValidationPolicy};
+    /// let response: ValidateAddressResponseBody = serde_json::from_str(
+    ///     r#"{"to": "info@company.com", "validMailbox": "true", "validSyntax": true, "roleBased": true}"#,
+    /// )?;
+    ///
+    /// assert_eq!(response.assess(&ValidationPolicy::new()), RiskAssessment::Review);
+    ///
+    /// let mut policy = ValidationPolicy::new();
+    /// policy.allow_role_based = true;
+    /// assert_eq!(response.assess(&policy), RiskAssessment::Accept);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn assess(&self, policy: &ValidationPolicy) -> RiskAssessment {
+        let mailbox = self
+            .valid_mailbox
+            .as_deref()
+            .map(|mailbox| mailbox.trim().to_ascii_lowercase());
+
+        if self.valid_syntax == Some(false)
+            || mailbox.as_deref() == Some("false")
+            || (self.disposable == Some(true) && !policy.allow_disposable)
+        {
+            return RiskAssessment::Reject;
+        }
+        if mailbox.as_deref() != Some("true")
+            || (self.role_based == Some(true) && !policy.allow_role_based)
+            || (self.catch_all == Some(true) && !policy.allow_catch_all)
+        {
+            return RiskAssessment::Review;
+        }
+
+        RiskAssessment::Accept
+    }
+}
+
+/// Decision on whether to send to a validated address, see `ValidateAddressResponseBody::assess`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RiskAssessment {
+    /// The address can be sent to.
+    Accept,
+    /// The address may be valid, but should be checked again or by a person before sending.
+    Review,
+    /// The address should not be sent to.
+    Reject,
+}
+
+/// Kinds of addresses that `ValidateAddressResponseBody::assess` accepts. By default none of them
+/// are, following the recommendation of Infobip to avoid disposable, role-based and catch-all
+/// addresses to protect the sender reputation.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ValidationPolicy {
+    /// Accept disposable addresses, rejected otherwise.
+    pub allow_disposable: bool,
+
+    /// Accept role-based addresses, like `info@company.com`, reviewed otherwise.
+    pub allow_role_based: bool,
+
+    /// Accept addresses of catch-all domains, whose mailboxes can not be verified, reviewed
+    /// otherwise.
+    pub allow_catch_all: bool,
+}
+
+impl ValidationPolicy {
+    pub fn new() -> Self {
+        ValidationPolicy::default()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct GetDomainsQueryParameters {
     /// Maximum number of domains to be viewed per page. Default value is 10 with a maximum of 20 records per page.
     #[validate(range(min = 1, max = 20))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<i32>,
 
     /// Page number you want to see. Default is 0.
     #[validate(range(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<i32>,
 }
 
+impl ToQueryString for GetDomainsQueryParameters {}
+
 impl GetDomainsQueryParameters {
     pub fn new() -> Self {
         GetDomainsQueryParameters {
@@ -636,7 +1667,7 @@ impl Default for GetDomainsQueryParameters {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct Tracking {
     /// Indicates whether tracking of clicks is enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -649,10 +1680,19 @@ pub struct Tracking {
     /// Indicates whether tracking of unsubscribes is enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unsubscribe: Option<bool>,
+
+    /// Custom tracking domain used in tracked links and the open pixel, a subdomain of the
+    /// domain like `track.example.com`. Not set when the default Infobip tracking domain is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_domain: Option<String>,
+
+    /// URL of the image used as the open tracking pixel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct DnsRecord {
     /// Type of the record.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -671,15 +1711,21 @@ pub struct DnsRecord {
     pub verified: Option<bool>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl ApiResponse for DnsRecord {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Domain {
     /// Id of the domain.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain_id: Option<i64>,
 
     /// Name of the domain.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub domain_name: Option<String>,
 
     /// Activation status of the domain.
@@ -699,28 +1745,27 @@ pub struct Domain {
     pub blocked: Option<bool>,
 
     /// Date the domain was created. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub created_at: Option<String>,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Paging {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<i32>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<i32>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_pages: Option<i32>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_results: Option<i32>,
+    /// Length of the current DKIM key of the domain.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_dkim_key_length",
+        serialize_with = "serialize_dkim_key_length",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dkim_key_length: Option<DkimKeyLength>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl ApiResponse for Domain {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetDomainsResponseBody {
     /// Pagination details like page number, page size, etc.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -731,24 +1776,42 @@ pub struct GetDomainsResponseBody {
     pub results: Option<Vec<Domain>>,
 }
 
+impl ApiResponse for GetDomainsResponseBody {}
+
+impl Paged for GetDomainsResponseBody {
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn items_len(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum DkimKeyLength {
     #[serde(rename = "1024")]
     L1024 = 1024,
     #[serde(rename = "2048")]
     L2048 = 2048,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AddDomainRequestBody {
     #[validate(length(min = 1))]
     pub domain_name: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_dkim_key_length")]
     pub dkim_key_length: Option<DkimKeyLength>,
 }
 
+impl ApiRequest for AddDomainRequestBody {}
+
 impl AddDomainRequestBody {
     pub fn new(domain_name: &str) -> Self {
         AddDomainRequestBody {
@@ -758,11 +1821,60 @@ impl AddDomainRequestBody {
     }
 }
 
+// Rejects `DkimKeyLength::Unknown`, which is only read from responses.
+fn validate_dkim_key_length(key_length: &DkimKeyLength) -> Result<(), validator::ValidationError> {
+    if *key_length == DkimKeyLength::Unknown {
+        return Err(validation_error(
+            "unknown_dkim_key_length",
+            "dkim_key_length must be 1024 or 2048",
+        ));
+    }
+
+    Ok(())
+}
+
+// Serializes an optional DKIM key length as the number returned by the API for domains. An
+// unknown length is written as `null`.
+fn serialize_dkim_key_length<S>(
+    key_length: &Option<DkimKeyLength>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match key_length {
+        Some(DkimKeyLength::Unknown) | None => serializer.serialize_none(),
+        Some(key_length) => serializer.serialize_u32(*key_length as u32),
+    }
+}
+
+// Deserializes an optional DKIM key length, which the API returns as a number for domains, while
+// requests carry it as a string.
+fn deserialize_dkim_key_length<'de, D>(deserializer: D) -> Result<Option<DkimKeyLength>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum KeyLength {
+        Number(u32),
+        Typed(DkimKeyLength),
+    }
+
+    match <Option<KeyLength> as serde::Deserialize>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(KeyLength::Typed(key_length)) => Ok(Some(key_length)),
+        Some(KeyLength::Number(1024)) => Ok(Some(DkimKeyLength::L1024)),
+        Some(KeyLength::Number(2048)) => Ok(Some(DkimKeyLength::L2048)),
+        Some(KeyLength::Number(_)) => Ok(Some(DkimKeyLength::Unknown)),
+    }
+}
+
 pub type AddDomainResponseBody = Domain;
 
 pub type GetDomainResponseBody = Domain;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTrackingRequestBody {
     #[serde(rename = "open", skip_serializing_if = "Option::is_none")]
@@ -775,6 +1887,8 @@ pub struct UpdateTrackingRequestBody {
     pub unsubscribe: Option<bool>,
 }
 
+impl ApiRequest for UpdateTrackingRequestBody {}
+
 impl UpdateTrackingRequestBody {
     pub fn new() -> Self {
         UpdateTrackingRequestBody {
@@ -785,6 +1899,16 @@ impl UpdateTrackingRequestBody {
     }
 }
 
+impl WireNames for UpdateTrackingRequestBody {
+    fn wire_names() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("opens", "open"),
+            ("clicks", "clicks"),
+            ("unsubscribe", "unsubscribe"),
+        ]
+    }
+}
+
 impl Default for UpdateTrackingRequestBody {
     fn default() -> Self {
         Self::new()
@@ -792,3 +1916,566 @@ impl Default for UpdateTrackingRequestBody {
 }
 
 pub type UpdateTrackingResponseBody = Domain;
+
+/// Custom tracking domain of a sender domain. The API reference has no endpoint to set it, so the
+/// SDK does not send this body; use `validate_for_domain` to check a tracking domain before
+/// configuring it in the Infobip portal.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTrackingDomainRequestBody {
+    /// Custom tracking domain, a subdomain of the sender domain like `track.example.com`. Its
+    /// CNAME record must point to the tracking host shown in the Infobip portal.
+    #[validate(length(min = 1, max = 253), custom = "validate_hostname")]
+    pub custom_domain: String,
+}
+
+impl UpdateTrackingDomainRequestBody {
+    pub fn new(custom_domain: &str) -> Self {
+        UpdateTrackingDomainRequestBody {
+            custom_domain: custom_domain.into(),
+        }
+    }
+
+    /// Checks that `custom_domain` is a subdomain of `domain_name`, so tracked links stay on the
+    /// domain of the sender.
+    pub fn validate_for_domain(
+        &self,
+        domain_name: &str,
+    ) -> Result<(), validator::ValidationErrors> {
+        self.validate()?;
+
+        let custom_domain = self.custom_domain.trim_end_matches('.').to_lowercase();
+        let domain_name = domain_name.trim_end_matches('.').to_lowercase();
+
+        if !custom_domain.ends_with(&format!(".{}", domain_name)) {
+            let mut errors = validator::ValidationErrors::new();
+            errors.add(
+                "custom_domain",
+                validation_error(
+                    "tracking_subdomain",
+                    "custom_domain must be a subdomain of the sender domain",
+                ),
+            );
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_hostname(hostname: &str) -> Result<(), validator::ValidationError> {
+    let valid = hostname.trim_end_matches('.').split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '-')
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(validation_error(
+            "hostname",
+            "custom_domain must be a host name like track.example.com",
+        ))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_create_template_content"))]
+pub struct CreateTemplateRequestBody {
+    /// Name of the template.
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+
+    /// Subject of the emails sent with the template.
+    #[validate(length(min = 1, max = 150))]
+    pub subject: String,
+
+    /// Email address with optional sender name, used when the send request has no `from`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Text body of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// HTML body of the template. If `html` and `text` fields are present, the `text` field will
+    /// be ignored and `html` will be delivered as a message body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Email address to which recipients of the email can reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+}
+
+impl ApiRequest for CreateTemplateRequestBody {}
+
+impl CreateTemplateRequestBody {
+    pub fn new(name: &str, subject: &str) -> Self {
+        CreateTemplateRequestBody {
+            name: name.into(),
+            subject: subject.into(),
+            from: None,
+            text: None,
+            html: None,
+            reply_to: None,
+        }
+    }
+}
+
+fn validate_create_template_content(
+    request_body: &CreateTemplateRequestBody,
+) -> Result<(), validator::ValidationError> {
+    let is_set = |content: &Option<String>| content.as_ref().map_or(false, |c| !c.is_empty());
+
+    if !is_set(&request_body.text) && !is_set(&request_body.html) {
+        return Err(validation_error(
+            "missing_template_content",
+            "template needs a text or html body",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Changes to an existing template. Only the fields that are set are updated.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTemplateRequestBody {
+    /// Name of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+
+    /// Subject of the emails sent with the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 150))]
+    pub subject: Option<String>,
+
+    /// Email address with optional sender name, used when the send request has no `from`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Text body of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// HTML body of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Email address to which recipients of the email can reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+}
+
+impl ApiRequest for UpdateTemplateRequestBody {}
+
+impl UpdateTemplateRequestBody {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Template {
+    /// ID of the template, to be used as `templateId` when sending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Name of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Subject of the emails sent with the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// Date the template was created. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Date the template was last updated. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl ApiResponse for Template {}
+
+pub type CreateTemplateResponseBody = Template;
+
+pub type UpdateTemplateResponseBody = Template;
+
+/// Number of characters in a landing page ID, as expected by `SendRequestBody::landing_page_id`.
+pub const LANDING_PAGE_ID_LENGTH: usize = 6;
+
+/// Extracts the landing page ID from a landing page URL copied from IB's portal, that is, the
+/// last 6 characters of its final path segment. Returns `None` if the segment is too short or
+/// is not alphanumeric.
+pub fn landing_page_id_from_url(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next()?;
+    let segment = path.trim_end_matches('/').rsplit('/').next()?;
+
+    if segment.len() < LANDING_PAGE_ID_LENGTH || !segment.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+
+    Some(segment[segment.len() - LANDING_PAGE_ID_LENGTH..].to_string())
+}
+
+/// Type of a suppressed address, that is, why emails are no longer sent to it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SuppressionType {
+    BOUNCE,
+    COMPLAINT,
+    UNSUBSCRIBE,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuppressionsQueryParameters {
+    /// Name of the domain to get suppressed addresses for.
+    #[validate(length(min = 1))]
+    pub domain_name: String,
+
+    /// Type of the suppressed addresses, e.g. `UNSUBSCRIBE` for addresses that unsubscribed
+    /// through an opt out landing page.
+    #[serde(rename = "type")]
+    pub suppression_type: SuppressionType,
+
+    /// Suppressed email address to look for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+
+    /// Domain of the suppressed addresses, e.g. `example.com`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_domain: Option<String>,
+
+    /// Start of the period when the addresses were suppressed. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_from: Option<String>,
+
+    /// End of the period when the addresses were suppressed. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_to: Option<String>,
+
+    /// Page number you want to see. Default is 0.
+    #[validate(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+
+    /// Maximum number of addresses to be viewed per page. Default value is 100 with a maximum of
+    /// 1000 records per page.
+    #[validate(range(min = 1, max = 1000))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+}
+
+impl ToQueryString for GetSuppressionsQueryParameters {}
+
+impl GetSuppressionsQueryParameters {
+    pub fn new(domain_name: &str, suppression_type: SuppressionType) -> Self {
+        GetSuppressionsQueryParameters {
+            domain_name: domain_name.into(),
+            suppression_type,
+            email_address: None,
+            recipient_domain: None,
+            created_date_from: None,
+            created_date_to: None,
+            page: None,
+            size: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Suppression {
+    /// Name of the domain the address is suppressed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+
+    /// Suppressed email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+
+    /// Type of the suppression.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub suppression_type: Option<SuppressionType>,
+
+    /// Date and time when the address was suppressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<String>,
+
+    /// Reason of the suppression, e.g. the bounce message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetSuppressionsResponseBody {
+    /// List of suppressed addresses of the domain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Suppression>>,
+
+    /// Pagination details like page number, page size, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
+}
+
+impl ApiResponse for GetSuppressionsResponseBody {}
+
+impl Paged for GetSuppressionsResponseBody {
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn items_len(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
+}
+
+/// Event sent to the callback URL when a recipient unsubscribes through the opt-out landing page.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsubscribeEvent {
+    /// Email address which unsubscribed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+
+    /// Name of the domain the address unsubscribed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+
+    /// The ID of the bulk of the email the recipient unsubscribed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID of the email the recipient unsubscribed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Campaign reference ID of the email, if set when sending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// ID of the landing page used to unsubscribe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_page_id: Option<String>,
+
+    /// Date and time when the address unsubscribed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unsubscribed_at: Option<String>,
+}
+
+/// Parses the body of an unsubscribe callback, sent either as a single event, an array of
+/// events, or a batch with the events under `results`. Unknown fields are ignored.
+pub fn parse_unsubscribe_events(body: &[u8]) -> Result<Vec<UnsubscribeEvent>, SdkError> {
+    parse_callback_items(body)
+}
+
+// Parses a callback body holding a single item, an array of items, or a batch with the items
+// under `results`.
+fn parse_callback_items<T: DeserializeOwned>(body: &[u8]) -> Result<Vec<T>, SdkError> {
+    let mut value: serde_json::Value = serde_json::from_slice(body)?;
+
+    let items = if let Some(results) = value.get_mut("results") {
+        serde_json::from_value(results.take())?
+    } else if value.is_array() {
+        serde_json::from_value(value)?
+    } else {
+        vec![serde_json::from_value(value)?]
+    };
+
+    Ok(items)
+}
+
+/// Email received at one of your domains, as forwarded by the inbound email callback.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundEmail {
+    /// The ID that uniquely identifies the received email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Sender email address with optional name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Recipient email addresses, separated by commas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// CC recipient email addresses, separated by commas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc: Option<String>,
+
+    /// Subject of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// Text body of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// HTML body of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Date and time when the email was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<String>,
+
+    /// Files attached to the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<InboundEmailAttachment>>,
+}
+
+/// File attached to a sent email, see `EmailClient::download_attachment`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MessageAttachment {
+    /// The ID that uniquely identifies the attachment within its message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+
+    /// Name of the file.
+    #[serde(rename = "filename", skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// MIME type of the file, e.g. `application/pdf`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Size of the file in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetAttachmentsResponseBody {
+    /// The ID that uniquely identifies the sent email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Attachments of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<MessageAttachment>>,
+}
+
+impl ApiResponse for GetAttachmentsResponseBody {}
+
+/// File attached to an inbound email, with its content either inline as base64 or behind a URL.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundEmailAttachment {
+    /// Name of the file.
+    #[serde(rename = "filename", skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// MIME type of the file, e.g. `application/pdf`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Size of the file in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Content of the file encoded as base64, see `decode_content`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// URL to download the file from, when its content is not inline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl InboundEmailAttachment {
+    /// Decodes the base64 `content` of the attachment. Fails if the attachment has no inline
+    /// content, e.g. because it has to be downloaded from `url`, or if the content is not valid
+    /// base64. Line breaks in the content are ignored.
+    pub fn decode_content(&self) -> Result<Vec<u8>, AttachmentContentError> {
+        let content = self
+            .content
+            .as_ref()
+            .ok_or(AttachmentContentError::NotInline)?;
+        let content: String = content
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+
+        base64::engine::general_purpose::STANDARD
+            .decode(content)
+            .map_err(|error| AttachmentContentError::InvalidBase64(error.to_string()))
+    }
+}
+
+/// Errors that can happen when decoding the content of an inbound email attachment.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AttachmentContentError {
+    #[error("attachment has no inline content")]
+    NotInline,
+
+    #[error("attachment content is not valid base64: {0}")]
+    InvalidBase64(String),
+}
+
+/// Parses the body of an inbound email callback, sent either as a single email, an array of
+/// emails, or a batch with the emails under `results`. Unknown fields are ignored.
+pub fn parse_inbound_email(body: &[u8]) -> Result<Vec<InboundEmail>, SdkError> {
+    parse_callback_items(body)
+}
+
+/// Errors that can happen when rendering placeholders locally.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RenderError {
+    #[error("missing values for placeholders: {}", .0.join(", "))]
+    MissingPlaceholders(Vec<String>),
+}
+
+/// Renders email content locally by replacing `{{key}}` tokens with their values, as done by
+/// the API when sending with `defaultPlaceholders` or recipient placeholders. Whitespace around
+/// the key is ignored, substituted values are not rendered again, and braces which are not part
+/// of a token are kept as they are, so `{{{name}}}` renders as `{John}`. Fails listing every
+/// token without a value.
+pub fn render_placeholders(
+    content: &str,
+    placeholders: &HashMap<String, String>,
+) -> Result<String, RenderError> {
+    let mut missing: Vec<String> = Vec::new();
+
+    let rendered = PLACEHOLDER.replace_all(content, |captures: &Captures| {
+        let key = &captures[1];
+        match placeholders.get(key) {
+            Some(value) => value.clone(),
+            None => {
+                if !missing.iter().any(|missing_key| missing_key == key) {
+                    missing.push(key.to_string());
+                }
+                captures[0].to_string()
+            }
+        }
+    });
+
+    if missing.is_empty() {
+        Ok(rendered.into_owned())
+    } else {
+        Err(RenderError::MissingPlaceholders(missing))
+    }
+}
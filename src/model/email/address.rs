@@ -0,0 +1,297 @@
+//! Parsing and formatting of the address lists used by the `from`, `to`, `cc`, `bcc` and
+//! `replyTo` fields of emails, like `"Doe, John" <john@company.com>, jane@company.com`.
+//!
+//! Addresses are separated by commas or semicolons. Display names can be quoted, in which case
+//! they may contain separators and backslash escaped quotes. Empty entries, like the one left by
+//! a trailing separator, are ignored.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use thiserror::Error;
+
+/// Email address with an optional display name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmailAddress {
+    /// Display name, unquoted, like `Doe, John`.
+    pub display_name: Option<String>,
+
+    /// Address, like `john@company.com`.
+    pub address: String,
+}
+
+impl EmailAddress {
+    pub fn new(address: &str) -> Self {
+        EmailAddress {
+            display_name: None,
+            address: address.into(),
+        }
+    }
+
+    pub fn with_display_name(display_name: &str, address: &str) -> Self {
+        EmailAddress {
+            display_name: Some(display_name.into()),
+            address: address.into(),
+        }
+    }
+}
+
+/// Formats the address as `Name <address>`, quoting the name if needed, or as the bare address
+/// if there is no display name.
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.display_name.as_deref() {
+            Some(name) if !name.is_empty() => {
+                if name.chars().all(is_phrase_char) {
+                    write!(f, "{} <{}>", name, self.address)
+                } else {
+                    let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+                    write!(f, "\"{}\" <{}>", escaped, self.address)
+                }
+            }
+            _ => f.write_str(&self.address),
+        }
+    }
+}
+
+/// Errors that can happen when parsing an address list. Positions are byte offsets into the
+/// parsed text.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("quoted string starting at position {0} is not terminated")]
+    UnterminatedQuote(usize),
+
+    #[error("angle bracket at position {0} is not closed")]
+    UnterminatedAngleBracket(usize),
+
+    #[error("unexpected '{character}' at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+
+    #[error("'{0}' is not an email address")]
+    InvalidAddress(String),
+}
+
+/// Parses a list of addresses separated by commas or semicolons, keeping their order.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::model::email::address::{parse_address_list, EmailAddress};
+/// let addresses = parse_address_list(r#""Doe, John" <john@company.com>, jane@company.com,"#)?;
+///
+/// assert_eq!(
+///     addresses,
+///     vec![
+///         EmailAddress::with_display_name("Doe, John", "john@company.com"),
+///         EmailAddress::new("jane@company.com"),
+///     ]
+/// );
+/// # Ok::<(), infobip_sdk::model::email::address::ParseError>(())
+/// ```
+pub fn parse_address_list(input: &str) -> Result<Vec<EmailAddress>, ParseError> {
+    let mut addresses = Vec::new();
+
+    for (offset, entry) in split_entries(input)? {
+        let trimmed = entry.trim_start();
+        let offset = offset + entry.len() - trimmed.len();
+        let trimmed = trimmed.trim_end();
+        if !trimmed.is_empty() {
+            addresses.push(parse_entry(trimmed, offset)?);
+        }
+    }
+
+    Ok(addresses)
+}
+
+/// Formats addresses as a comma separated list that `parse_address_list` reads back.
+pub fn format_address_list(addresses: &[EmailAddress]) -> String {
+    addresses
+        .iter()
+        .map(EmailAddress::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses an address list and formats it back without the addresses that are repeated, compared
+/// case-insensitively. The first occurrence of an address, with its display name, is kept.
+pub fn normalize_address_list(input: &str) -> Result<String, ParseError> {
+    let mut seen = HashSet::new();
+    let addresses: Vec<EmailAddress> = parse_address_list(input)?
+        .into_iter()
+        .filter(|address| seen.insert(address.address.to_lowercase()))
+        .collect();
+
+    Ok(format_address_list(&addresses))
+}
+
+// Splits the input on separators outside of quotes and angle brackets, returning every entry
+// with its offset.
+fn split_entries(input: &str) -> Result<Vec<(usize, &str)>, ParseError> {
+    let mut entries = Vec::new();
+    let mut start = 0;
+    let mut quote: Option<usize> = None;
+    let mut angle: Option<usize> = None;
+    let mut escaped = false;
+
+    for (position, character) in input.char_indices() {
+        if quote.is_some() {
+            if escaped {
+                escaped = false;
+            } else if character == '\\' {
+                escaped = true;
+            } else if character == '"' {
+                quote = None;
+            }
+            continue;
+        }
+
+        match character {
+            '"' => quote = Some(position),
+            '<' if angle.is_none() => angle = Some(position),
+            '>' if angle.is_some() => angle = None,
+            '<' | '>' => {
+                return Err(ParseError::UnexpectedCharacter {
+                    character,
+                    position,
+                })
+            }
+            ',' | ';' if angle.is_none() => {
+                entries.push((start, &input[start..position]));
+                start = position + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(position) = quote {
+        return Err(ParseError::UnterminatedQuote(position));
+    }
+    if let Some(position) = angle {
+        return Err(ParseError::UnterminatedAngleBracket(position));
+    }
+    entries.push((start, &input[start..]));
+
+    Ok(entries)
+}
+
+// Parses a single trimmed entry, either `address`, `<address>` or `name <address>`.
+fn parse_entry(entry: &str, offset: usize) -> Result<EmailAddress, ParseError> {
+    let opening = match find_unquoted(entry, '<') {
+        Some(opening) => opening,
+        None => return parse_address(entry).map(|address| EmailAddress::new(&address)),
+    };
+    // Angle brackets are balanced, as checked when splitting.
+    let closing = opening + entry[opening..].find('>').unwrap_or(entry.len() - opening);
+
+    if let Some((position, character)) = entry[closing + 1..]
+        .char_indices()
+        .find(|(_, character)| !character.is_whitespace())
+    {
+        return Err(ParseError::UnexpectedCharacter {
+            character,
+            position: offset + closing + 1 + position,
+        });
+    }
+
+    let address = parse_address(entry[opening + 1..closing].trim())?;
+    let display_name = unquote_phrase(&entry[..opening]);
+
+    Ok(EmailAddress {
+        display_name: if display_name.is_empty() {
+            None
+        } else {
+            Some(display_name)
+        },
+        address,
+    })
+}
+
+fn find_unquoted(entry: &str, target: char) -> Option<usize> {
+    let mut in_quote = false;
+    let mut escaped = false;
+
+    for (position, character) in entry.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if in_quote && character == '\\' {
+            escaped = true;
+        } else if character == '"' {
+            in_quote = !in_quote;
+        } else if !in_quote && character == target {
+            return Some(position);
+        }
+    }
+
+    None
+}
+
+// Removes the quotes and escapes of a display name, collapsing whitespace outside of quotes.
+fn unquote_phrase(phrase: &str) -> String {
+    let mut name = String::new();
+    let mut in_quote = false;
+    let mut escaped = false;
+
+    for character in phrase.trim().chars() {
+        if escaped {
+            name.push(character);
+            escaped = false;
+        } else if in_quote && character == '\\' {
+            escaped = true;
+        } else if character == '"' {
+            in_quote = !in_quote;
+        } else if !in_quote && character.is_whitespace() {
+            if !name.ends_with(' ') {
+                name.push(' ');
+            }
+        } else {
+            name.push(character);
+        }
+    }
+
+    name.trim().to_string()
+}
+
+fn parse_address(address: &str) -> Result<String, ParseError> {
+    if is_address(address) {
+        Ok(address.to_string())
+    } else {
+        Err(ParseError::InvalidAddress(address.to_string()))
+    }
+}
+
+// Checks the shape of an address: a local part made of atoms or a single quoted string, and a
+// domain of dot separated labels. Length limits are left to the API.
+fn is_address(address: &str) -> bool {
+    let (local, domain) = match address.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let quoted_local = local.len() >= 2
+        && local.starts_with('"')
+        && local.ends_with('"')
+        && !local[1..local.len() - 1].contains('"');
+    let local_valid = quoted_local
+        || (!local.is_empty()
+            && local.chars().all(is_atom_char)
+            && local.split('.').all(|atom| !atom.is_empty()));
+    let domain_valid = !domain.is_empty()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|character| character.is_alphanumeric() || character == '-')
+        });
+
+    local_valid && domain_valid
+}
+
+fn is_atom_char(character: char) -> bool {
+    character.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(character)
+}
+
+// Characters that can appear in a display name without quoting it.
+fn is_phrase_char(character: char) -> bool {
+    character == ' ' || (is_atom_char(character) && character != '.')
+}
@@ -1,15 +1,290 @@
 //! Module that contains the modules with models to create payloads and query parameters to call
 //! endpoints and get responses from them with convenient, validated structs. There is one
 //! submodule for each channel.
+//!
+//! Enums read from responses or webhooks, like `email::BulkStatus`, are `#[non_exhaustive]` and
+//! have an `Unknown` variant, so values added to the API later do not fail deserialization.
+//! `Unknown` is only produced when reading and is not meant to be sent. Enums only sent in
+//! requests stay exhaustive.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use validator::{Validate, ValidationErrors};
+
+use crate::api::SdkError;
+use crate::model::query::{QueryValue, PRESENCE_ONLY};
+
+pub mod codes;
+
+pub mod common;
 
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(feature = "sms")]
+pub mod numbers;
+
+mod query;
+
 #[cfg(feature = "sms")]
 pub mod sms;
 
+#[cfg(feature = "voice")]
+pub mod voice;
+
 #[cfg(feature = "whatsapp")]
 pub mod whatsapp;
 
+#[cfg(all(feature = "xml", any(feature = "sms", feature = "email")))]
+mod xml;
+
+/// Serializes query parameter structs into URL-encoded query strings. Keys are taken from the
+/// serde field names, `None` fields are skipped, and sequences are encoded as repeated keys.
+/// Keys are sorted so the resulting query string is stable.
+///
+/// Plain `bool` fields are written as `true` or `false`, the format expected by the endpoints of
+/// the SDK. Fields of endpoints expecting another format use `QueryBool`.
+pub trait ToQueryString: Serialize {
+    /// Returns the URL-encoded query string, without the leading `?`. An empty string is returned
+    /// when no parameters are set.
+    fn to_query_string(&self) -> Result<String, SdkError> {
+        let mut pairs: Vec<(String, Option<String>)> = Vec::new();
+
+        for (key, value) in query::to_fields(self)? {
+            match value {
+                QueryValue::Seq(items) => {
+                    for item in items {
+                        if let Some(item) = query_value(item) {
+                            pairs.push((key.clone(), item));
+                        }
+                    }
+                }
+                value => {
+                    if let Some(value) = query_value(value) {
+                        pairs.push((key, value));
+                    }
+                }
+            }
+        }
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut encoded = Vec::with_capacity(pairs.len());
+        for (key, value) in pairs {
+            match value {
+                Some(value) => encoded.push(serde_urlencoded::to_string([(key, value)])?),
+                None => {
+                    let pair = serde_urlencoded::to_string([(key, "")])?;
+                    encoded.push(pair.trim_end_matches('=').to_string());
+                }
+            }
+        }
+
+        Ok(encoded.join("&"))
+    }
+}
+
+/// Format of a boolean query parameter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BoolFormat {
+    /// `true` or `false`, the format of plain `bool` fields.
+    #[default]
+    Lowercase,
+    /// `True` or `False`.
+    Capitalized,
+    /// The key alone when `true`, e.g. `?flash`, and no key when `false`.
+    PresenceOnly,
+}
+
+/// Boolean query parameter written in a given format by `ToQueryString`, for endpoints that do
+/// not accept `true` or `false`. Leave the field `None` to send no key at all.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::model::{BoolFormat, QueryBool, ToQueryString};
+/// #[derive(serde::Serialize)]
+/// struct Filters {
+///     archived: Option<QueryBool>,
+///     flash: Option<QueryBool>,
+/// }
+///
+/// let filters = Filters {
+///     archived: Some(QueryBool::new(false, BoolFormat::Capitalized)),
+///     flash: Some(QueryBool::new(true, BoolFormat::PresenceOnly)),
+/// };
+/// # impl ToQueryString for Filters {}
+///
+/// assert_eq!(filters.to_query_string()?, "archived=False&flash");
+/// # Ok::<(), infobip_sdk::api::SdkError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct QueryBool {
+    pub value: bool,
+    pub format: BoolFormat,
+}
+
+impl QueryBool {
+    pub fn new(value: bool, format: BoolFormat) -> Self {
+        QueryBool { value, format }
+    }
+}
+
+impl From<bool> for QueryBool {
+    fn from(value: bool) -> Self {
+        QueryBool::new(value, BoolFormat::default())
+    }
+}
+
+impl Serialize for QueryBool {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.format, self.value) {
+            (BoolFormat::Lowercase, value) => serializer.serialize_bool(value),
+            (BoolFormat::Capitalized, true) => serializer.serialize_str("True"),
+            (BoolFormat::Capitalized, false) => serializer.serialize_str("False"),
+            (BoolFormat::PresenceOnly, true) => serializer.serialize_unit_struct(PRESENCE_ONLY),
+            (BoolFormat::PresenceOnly, false) => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Marks the bodies sent to the API. Clients only send types implementing this trait, so a
+/// response body cannot be posted back by mistake.
+pub trait ApiRequest: Serialize {}
+
+/// Marks the bodies read from API responses. Clients only parse responses into types implementing
+/// this trait, so a response is never read into a request body.
+pub trait ApiResponse: DeserializeOwned {}
+
+impl<T: ApiResponse> ApiResponse for Vec<T> {}
+
+/// Opt-in validation of documented API limits that `validate` does not check, so requests the
+/// API would reject can fail early. Each added check is documented on the implementing type.
+pub trait ValidateStrict: Validate {
+    /// Runs `validate` and, if it passes, the additional strict checks.
+    fn validate_strict(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Partitions the messages of a send response by their status group. Messages in the `ACCEPTED`,
+/// `PENDING` or `DELIVERED` groups are accepted. Messages in any other group, or without a status
+/// or group ID, are rejected.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::model::sms::SendResponseBody;
+/// # use infobip_sdk::model::SendOutcome;
+/// let response: SendResponseBody = serde_json::from_str(
+///     r#"{"messages": [
+///         {"to": "41793026727", "status": {"groupId": 1, "groupName": "PENDING"}},
+///         {"to": "41793026728", "status": {"groupId": 5, "groupName": "REJECTED"}}
+///     ]}"#,
+/// )?;
+///
+/// assert_eq!(response.accepted().len(), 1);
+/// assert_eq!(response.rejected()[0].to.as_deref(), Some("41793026728"));
+/// assert!(!response.all_accepted());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub trait SendOutcome {
+    /// Details of a single sent message.
+    type Details;
+
+    /// Returns the details of all sent messages, empty if the response has none.
+    fn sent_messages(&self) -> &[Self::Details];
+
+    /// Returns the status group ID of a sent message, if present.
+    fn status_group_id(details: &Self::Details) -> Option<i32>;
+
+    /// Returns the messages accepted for delivery, in response order.
+    fn accepted(&self) -> Vec<&Self::Details> {
+        self.sent_messages()
+            .iter()
+            .filter(|details| is_accepted_group(Self::status_group_id(details)))
+            .collect()
+    }
+
+    /// Returns the messages that were not accepted, in response order.
+    fn rejected(&self) -> Vec<&Self::Details> {
+        self.sent_messages()
+            .iter()
+            .filter(|details| !is_accepted_group(Self::status_group_id(details)))
+            .collect()
+    }
+
+    /// Returns whether the response has messages and all of them were accepted.
+    fn all_accepted(&self) -> bool {
+        let messages = self.sent_messages();
+        !messages.is_empty()
+            && messages
+                .iter()
+                .all(|details| is_accepted_group(Self::status_group_id(details)))
+    }
+}
+
+/// Status of a sent message, classified by its status group.
+pub trait DeliveryStatus {
+    /// Returns the status group ID, if present.
+    fn group_id(&self) -> Option<i32>;
+
+    /// Returns whether the message was delivered, i.e. is in the `DELIVERED` group.
+    fn is_delivered(&self) -> bool {
+        self.group_id() == Some(3)
+    }
+
+    /// Returns whether the message can no longer be delivered, i.e. is in the `UNDELIVERABLE`,
+    /// `EXPIRED` or `REJECTED` group.
+    fn is_failed(&self) -> bool {
+        matches!(self.group_id(), Some(2) | Some(4) | Some(5))
+    }
+
+    /// Returns whether the status is final, so polling the message can stop.
+    fn is_terminal(&self) -> bool {
+        self.is_delivered() || self.is_failed()
+    }
+}
+
+/// Maps the Rust field names of a request struct to the names they have on the wire, as written
+/// by serde. Used to label the parts of prepared requests, and checked against the serde output
+/// in tests so renames like `opens` → `open` cannot drift.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "email")]
+/// # fn main() {
+/// # use infobip_sdk::model::email::UpdateTrackingRequestBody;
+/// # use infobip_sdk::model::WireNames;
+/// assert_eq!(UpdateTrackingRequestBody::wire_name("opens"), Some("open"));
+/// assert_eq!(UpdateTrackingRequestBody::wire_name("missing"), None);
+/// # }
+/// # #[cfg(not(feature = "email"))]
+/// # fn main() {}
+/// ```
+pub trait WireNames {
+    /// Returns the `(rust, wire)` name pairs of all fields, in declaration order.
+    fn wire_names() -> &'static [(&'static str, &'static str)];
+
+    /// Returns the wire name of a field, or `None` if the struct has no such field.
+    fn wire_name(field: &str) -> Option<&'static str> {
+        Self::wire_names()
+            .iter()
+            .find(|(rust, _)| *rust == field)
+            .map(|(_, wire)| *wire)
+    }
+}
+
+// Status groups ACCEPTED, PENDING and DELIVERED.
+fn is_accepted_group(group_id: Option<i32>) -> bool {
+    matches!(group_id, Some(0) | Some(1) | Some(3))
+}
+
+// Returns `None` for absent values, and `Some(None)` for keys written without a value.
+fn query_value(value: QueryValue) -> Option<Option<String>> {
+    match value {
+        QueryValue::Presence => Some(None),
+        QueryValue::Value(Value::Null) => None,
+        QueryValue::Value(Value::String(value)) => Some(Some(value)),
+        value => Some(Some(value.into_json().to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests;
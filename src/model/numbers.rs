@@ -0,0 +1,282 @@
+//! Models for calling the Numbers endpoints, used to list purchased numbers and configure how
+//! inbound SMS messages sent to them are handled.
+
+use serde_derive::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::model::common::{deserialize_money, Money, Paged, Paging};
+use crate::model::{ApiRequest, ApiResponse, ToQueryString};
+
+/// Type of a purchased number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum NumberType {
+    #[serde(rename = "VIRTUAL_LONG_NUMBER")]
+    VirtualLongNumber,
+    #[serde(rename = "SHORT_CODE")]
+    ShortCode,
+    #[serde(rename = "TOLL_FREE")]
+    TollFree,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// Channel a purchased number can be used on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum NumberCapability {
+    #[serde(rename = "SMS")]
+    Sms,
+    #[serde(rename = "VOICE")]
+    Voice,
+    #[serde(rename = "MMS")]
+    Mms,
+    #[serde(rename = "WHATSAPP")]
+    WhatsApp,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// Recurring and one-time prices of a purchased number.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NumberPrice {
+    /// Monthly price of the number.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_month: Option<Money>,
+
+    /// One-time price charged when the number is purchased.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub setup_price: Option<Money>,
+
+    /// Price charged for the first month.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub initial_month_price: Option<Money>,
+
+    /// Currency of the prices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+/// Number purchased in the account.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PurchasedNumber {
+    /// Unique key of the number, used to reference it in other Numbers endpoints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_key: Option<String>,
+
+    /// The number, in international format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+
+    /// Two-letter code of the country of the number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Name of the country of the number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+
+    /// Type of the number.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub number_type: Option<NumberType>,
+
+    /// Channels the number can be used on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<NumberCapability>>,
+
+    /// Whether the number is shared with other accounts, in which case inbound messages are
+    /// matched by keyword.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+
+    /// Prices of the number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<NumberPrice>,
+
+    /// Network of the number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl ApiResponse for PurchasedNumber {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNumbersQueryParameters {
+    /// Maximum number of numbers to be returned per page. Default value is 50 with a maximum of
+    /// 1000 records per page.
+    #[validate(range(min = 1, max = 1000))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+
+    /// Page number you want to see. Default is 0.
+    #[validate(range(min = 0))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+
+    /// Filter numbers containing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+}
+
+impl ToQueryString for GetNumbersQueryParameters {}
+
+impl GetNumbersQueryParameters {
+    pub fn new() -> Self {
+        GetNumbersQueryParameters {
+            limit: None,
+            page: None,
+            number: None,
+        }
+    }
+}
+
+impl Default for GetNumbersQueryParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetNumbersResponseBody {
+    /// Numbers purchased in the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numbers: Option<Vec<PurchasedNumber>>,
+
+    /// Total number of numbers purchased in the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_count: Option<i32>,
+
+    /// Pagination details like page number, page size, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
+}
+
+impl ApiResponse for GetNumbersResponseBody {}
+
+impl Paged for GetNumbersResponseBody {
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn items_len(&self) -> usize {
+        self.numbers.as_ref().map_or(0, Vec::len)
+    }
+}
+
+pub type GetNumberResponseBody = PurchasedNumber;
+
+/// How inbound SMS messages are forwarded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SmsForwardType {
+    #[serde(rename = "HTTP_FORWARD_POST")]
+    HttpForwardPost,
+    #[serde(rename = "HTTP_FORWARD_GET")]
+    HttpForwardGet,
+    #[serde(rename = "PULL")]
+    Pull,
+    #[serde(rename = "NO_ACTION")]
+    NoAction,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl SmsForwardType {
+    /// Returns whether the forward type requires a URL to send the messages to.
+    pub fn is_http(&self) -> bool {
+        matches!(
+            self,
+            SmsForwardType::HttpForwardPost | SmsForwardType::HttpForwardGet
+        )
+    }
+}
+
+/// Forwarding of inbound SMS messages received on a number.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_sms_forwarding"))]
+pub struct SmsForwarding {
+    /// How inbound messages are forwarded.
+    #[serde(rename = "type")]
+    pub forward_type: SmsForwardType,
+
+    /// URL inbound messages are forwarded to. Required for HTTP forward types.
+    #[validate(url)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SmsForwarding {
+    /// Builds a forwarding that posts inbound messages to the given URL.
+    pub fn http_post(url: &str) -> Self {
+        SmsForwarding {
+            forward_type: SmsForwardType::HttpForwardPost,
+            url: Some(url.into()),
+        }
+    }
+}
+
+fn validate_sms_forwarding(forwarding: &SmsForwarding) -> Result<(), ValidationError> {
+    if forwarding.forward_type.is_http() && forwarding.url.is_none() {
+        let mut error = ValidationError::new("missing_url");
+        error.message = Some("HTTP forward types require a url".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// SMS configuration of a number: the keyword inbound messages are matched by, and how they are
+/// forwarded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NumberSmsConfiguration {
+    /// Keyword inbound messages must start with to be handled by this configuration. Required
+    /// on shared numbers.
+    #[validate(length(min = 1, max = 50))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+
+    /// How inbound messages are forwarded.
+    #[validate]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarding: Option<SmsForwarding>,
+}
+
+impl ApiRequest for NumberSmsConfiguration {}
+impl ApiResponse for NumberSmsConfiguration {}
+
+impl NumberSmsConfiguration {
+    pub fn new() -> Self {
+        NumberSmsConfiguration {
+            keyword: None,
+            forwarding: None,
+        }
+    }
+}
+
+impl Default for NumberSmsConfiguration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub type UpdateNumberSmsConfigurationRequestBody = NumberSmsConfiguration;
+
+pub type UpdateNumberSmsConfigurationResponseBody = NumberSmsConfiguration;
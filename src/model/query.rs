@@ -0,0 +1,525 @@
+//! Serializer collecting the fields of query parameter structs for `ToQueryString`. Values are
+//! converted with `serde_json`, except `QueryBool` values in the `PresenceOnly` format, which
+//! serialize as a unit struct with a dedicated name and become `QueryValue::Presence`, so no
+//! parameter value can be mistaken for them.
+
+use serde::ser::{
+    Error as _, Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple,
+    Serializer,
+};
+use serde_json::{Error, Map, Value};
+
+/// Name of the unit struct written by a `QueryBool` in the `PresenceOnly` format.
+pub(crate) const PRESENCE_ONLY: &str = "infobip_sdk::model::QueryBool::PresenceOnly";
+
+/// Value of a query parameter field.
+pub(crate) enum QueryValue {
+    /// A single value, `Value::Null` for absent ones.
+    Value(Value),
+    /// The key alone, without a value.
+    Presence,
+    /// A sequence, encoded as repeated keys.
+    Seq(Vec<QueryValue>),
+}
+
+impl QueryValue {
+    /// Returns the JSON form of the value, used for values that cannot be repeated keys.
+    pub(crate) fn into_json(self) -> Value {
+        match self {
+            QueryValue::Value(value) => value,
+            QueryValue::Presence => Value::Bool(true),
+            QueryValue::Seq(items) => {
+                Value::Array(items.into_iter().map(QueryValue::into_json).collect())
+            }
+        }
+    }
+}
+
+/// Returns the fields of a struct or map as `(key, value)` pairs, in serialization order.
+pub(crate) fn to_fields<T: Serialize + ?Sized>(
+    value: &T,
+) -> Result<Vec<(String, QueryValue)>, Error> {
+    value.serialize(FieldsSerializer)
+}
+
+fn unsupported<T>() -> Result<T, Error> {
+    Err(Error::custom("query parameters must be a struct or a map"))
+}
+
+struct FieldsSerializer;
+
+struct Fields {
+    fields: Vec<(String, QueryValue)>,
+    key: Option<String>,
+}
+
+impl Serializer for FieldsSerializer {
+    type Ok = Vec<(String, QueryValue)>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = Fields;
+    type SerializeStruct = Fields;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        Ok(Vec::new())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        Ok(Vec::new())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        Ok(Vec::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Error> {
+        unsupported()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        unsupported()
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        unsupported()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        unsupported()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        unsupported()
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(Fields {
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        unsupported()
+    }
+}
+
+impl SerializeStruct for Fields {
+    type Ok = Vec<(String, QueryValue)>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.fields
+            .push((key.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeMap for Fields {
+    type Ok = Vec<(String, QueryValue)>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(map_key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("map value serialized before its key"))?;
+        self.fields.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.fields)
+    }
+}
+
+struct ValueSerializer;
+
+struct Items(Vec<QueryValue>);
+
+// Nested struct or map, written as JSON.
+struct Object {
+    object: Map<String, Value>,
+    key: Option<String>,
+}
+
+// Converts a value with `serde_json`.
+fn json<T: Serialize + ?Sized>(value: &T) -> Result<QueryValue, Error> {
+    serde_json::to_value(value).map(QueryValue::Value)
+}
+
+impl Serializer for ValueSerializer {
+    type Ok = QueryValue;
+    type Error = Error;
+    type SerializeSeq = Items;
+    type SerializeTuple = Items;
+    type SerializeTupleStruct = Impossible<QueryValue, Error>;
+    type SerializeTupleVariant = Impossible<QueryValue, Error>;
+    type SerializeMap = Object;
+    type SerializeStruct = Object;
+    type SerializeStructVariant = Impossible<QueryValue, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<QueryValue, Error> {
+        json(&v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<QueryValue, Error> {
+        json(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<QueryValue, Error> {
+        json(v)
+    }
+
+    fn serialize_none(self) -> Result<QueryValue, Error> {
+        Ok(QueryValue::Value(Value::Null))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<QueryValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<QueryValue, Error> {
+        Ok(QueryValue::Value(Value::Null))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<QueryValue, Error> {
+        if name == PRESENCE_ONLY {
+            Ok(QueryValue::Presence)
+        } else {
+            Ok(QueryValue::Value(Value::Null))
+        }
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<QueryValue, Error> {
+        json(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<QueryValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<QueryValue, Error> {
+        let mut object = Map::new();
+        object.insert(variant.to_string(), serde_json::to_value(value)?);
+        Ok(QueryValue::Value(Value::Object(object)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Items, Error> {
+        Ok(Items(Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Items, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(Error::custom("unsupported query parameter value"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(Error::custom("unsupported query parameter value"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Object, Error> {
+        Ok(Object {
+            object: Map::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Object, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(Error::custom("unsupported query parameter value"))
+    }
+}
+
+impl SerializeSeq for Items {
+    type Ok = QueryValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let item = match value.serialize(ValueSerializer)? {
+            QueryValue::Seq(items) => QueryValue::Value(QueryValue::Seq(items).into_json()),
+            item => item,
+        };
+        self.0.push(item);
+        Ok(())
+    }
+
+    fn end(self) -> Result<QueryValue, Error> {
+        Ok(QueryValue::Seq(self.0))
+    }
+}
+
+impl SerializeTuple for Items {
+    type Ok = QueryValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<QueryValue, Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeStruct for Object {
+    type Ok = QueryValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.object
+            .insert(key.to_string(), serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<QueryValue, Error> {
+        Ok(QueryValue::Value(Value::Object(self.object)))
+    }
+}
+
+impl SerializeMap for Object {
+    type Ok = QueryValue;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(map_key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("map value serialized before its key"))?;
+        self.object.insert(key, serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<QueryValue, Error> {
+        Ok(QueryValue::Value(Value::Object(self.object)))
+    }
+}
+
+fn map_key<T: Serialize + ?Sized>(key: &T) -> Result<String, Error> {
+    match serde_json::to_value(key)? {
+        Value::String(key) => Ok(key),
+        key => Ok(key.to_string()),
+    }
+}
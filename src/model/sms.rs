@@ -1,25 +1,41 @@
 //! Models for calling SMS endpoints.
 
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use regex::Regex;
+use serde::Serializer;
 use serde_derive::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use validator::Validate;
 
+use crate::model::codes::{error_by_id, status_by_id, ErrorCodeInfo, StatusCodeInfo};
+use crate::model::common::{
+    borrowed_empty_string_as_none, borrowed_str, deserialize_money, deserialize_string_or_number,
+    empty_string_as_none, format_timestamp, has_control_characters, money_to_f64, parse_send_at,
+    validate_limit, validate_send_at, validate_time_range, validation_error, Money,
+    NotifyContentType, Recipient, RetryAdvice, RetryPolicy, LOGS_RETENTION_HOURS,
+};
+use crate::model::{
+    ApiRequest, ApiResponse, DeliveryStatus, SendOutcome, ToQueryString, ValidateStrict, WireNames,
+};
+
 lazy_static! {
-    static ref LANGUAGE_CODES: Regex = Regex::new(r"^(TR|ES|PT|AUTODETECT)$").unwrap();
     static ref TRANSLITERATIONS: Regex = Regex::new(
         r"^(TURKISH|GREEK|CYRILLIC|SERBIAN_CYRILLIC|CENTRAL_EUROPEAN|BALTIC|NON_UNICODE)$"
     )
     .unwrap();
-    static ref CONTENT_TYPES: Regex = Regex::new(r"^(application/json|application/xml)$").unwrap();
     static ref TURKEY_RECIPIENT_TYPES: Regex = Regex::new(r"^(TACIR|BIREYSEL)$").unwrap();
+    static ref E164_NUMBER: Regex = Regex::new(r"^\+?[1-9][0-9]{1,14}$").unwrap();
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{\{\s*([^{}\s]+)\s*\}\}").unwrap();
+    static ref NUMERIC_SENDER: Regex = Regex::new(r"^\+?[0-9]{1,16}$").unwrap();
+    static ref ALPHANUMERIC_SENDER: Regex = Regex::new(r"^[A-Za-z0-9 ]{1,11}$").unwrap();
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct PreviewRequestBody {
-    /// Code for language character set of a message text.
-    #[validate(regex = "LANGUAGE_CODES")]
+    /// Code for language character set of a message text, one of the `LanguageCode` values.
+    #[validate(custom = "validate_language_code")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 
@@ -32,6 +48,8 @@ pub struct PreviewRequestBody {
     pub transliteration: Option<String>,
 }
 
+impl ApiRequest for PreviewRequestBody {}
+
 impl PreviewRequestBody {
     pub fn new(text: &str) -> PreviewRequestBody {
         PreviewRequestBody {
@@ -42,25 +60,74 @@ impl PreviewRequestBody {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+/// Language whose national character set is used to encode a message, so its special
+/// characters take less space than with Unicode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum LanguageCode {
+    TR,
+    ES,
+    PT,
+    AUTODETECT,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl LanguageCode {
+    /// Returns the code as sent to the API, e.g. `TR`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageCode::TR => "TR",
+            LanguageCode::ES => "ES",
+            LanguageCode::PT => "PT",
+            LanguageCode::AUTODETECT => "AUTODETECT",
+            LanguageCode::Unknown => "UNKNOWN",
+        }
+    }
+
+    /// Returns the language with the given code, if supported.
+    pub fn parse(code: &str) -> Option<LanguageCode> {
+        [
+            LanguageCode::TR,
+            LanguageCode::ES,
+            LanguageCode::PT,
+            LanguageCode::AUTODETECT,
+        ]
+        .into_iter()
+        .find(|language_code| language_code.as_str() == code)
+    }
+}
+
+// Checks the language codes given as text, like in previews, against `LanguageCode`.
+fn validate_language_code(language_code: &str) -> Result<(), validator::ValidationError> {
+    match LanguageCode::parse(language_code) {
+        Some(_) => Ok(()),
+        None => Err(validation_error(
+            "language_code",
+            "language code must be one of TR, ES, PT or AUTODETECT",
+        )),
+    }
+}
+
+/// Language settings of a message, sent as `"language": {"languageCode": "TR"}`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Language {
     /// Language code for the correct character set.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate(regex = "LANGUAGE_CODES")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
 }
 
 impl Language {
-    pub fn new(language_code: &str) -> Language {
+    pub fn new(language_code: LanguageCode) -> Language {
         Language {
-            language_code: Some(language_code.into()),
+            language_code: Some(language_code),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreviewLanguageConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<Language>,
@@ -70,8 +137,14 @@ pub struct PreviewLanguageConfiguration {
     pub transliteration: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl From<&PreviewLanguageConfiguration> for Language {
+    fn from(configuration: &PreviewLanguageConfiguration) -> Self {
+        configuration.language.clone().unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Preview {
     /// Number of remaining characters in the last SMS part.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,8 +163,8 @@ pub struct Preview {
     pub text_preview: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreviewResponseBody {
     /// Text supplied in the request.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,6 +175,8 @@ pub struct PreviewResponseBody {
     pub previews: Option<Vec<Preview>>,
 }
 
+impl ApiResponse for PreviewResponseBody {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDeliveryReportsQueryParameters {
@@ -114,14 +189,22 @@ pub struct GetDeliveryReportsQueryParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
-    /// Maximum number of delivery reports to be returned. If not set, the latest 50 records are
-    /// returned.
-    #[validate(range(max = 1000))]
+    /// Maximum number of delivery reports to be returned, between 1 and `MAX_LIMIT`. If not set,
+    /// the client requests `DEFAULT_LIMIT` records.
+    #[validate(custom = "validate_delivery_reports_limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
 }
 
+impl ToQueryString for GetDeliveryReportsQueryParameters {}
+
 impl GetDeliveryReportsQueryParameters {
+    /// Number of delivery reports requested when `limit` is not set.
+    pub const DEFAULT_LIMIT: i32 = 50;
+
+    /// Maximum number of delivery reports that can be requested at once.
+    pub const MAX_LIMIT: i32 = 1000;
+
     pub fn new() -> GetDeliveryReportsQueryParameters {
         GetDeliveryReportsQueryParameters {
             bulk_id: None,
@@ -131,53 +214,101 @@ impl GetDeliveryReportsQueryParameters {
     }
 }
 
+fn validate_delivery_reports_limit(limit: i32) -> Result<(), validator::ValidationError> {
+    validate_limit(limit, GetDeliveryReportsQueryParameters::MAX_LIMIT)
+}
+
 impl Default for GetDeliveryReportsQueryParameters {
     fn default() -> Self {
         Self::new()
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Status {
     /// Action that should be taken to eliminate the error.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action: Option<String>,
 
     /// Human-readable description of the status.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub description: Option<String>,
 
     /// Status group ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<i32>,
     /// Status group name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub group_name: Option<String>,
     /// Status ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
     /// Status name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Status {
+    /// Returns the details of the status code from `codes::STATUS_CODES`, if known.
+    pub fn info(&self) -> Option<&'static StatusCodeInfo> {
+        self.id.and_then(status_by_id)
+    }
+}
+
+impl DeliveryStatus for Status {
+    fn group_id(&self) -> Option<i32> {
+        self.group_id
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Price {
     /// The currency in which the price is expressed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
     /// Price per one SMS.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub price_per_message: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_message: Option<Money>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Price {
+    /// Returns the price per message as a `f64`, regardless of the `decimal` feature.
+    pub fn price_per_message_f64(&self) -> Option<f64> {
+        self.price_per_message.map(money_to_f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Error {
     /// Human-readable description of the error.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub description: Option<String>,
 
     /// Error group ID.
@@ -185,7 +316,11 @@ pub struct Error {
     pub group_id: Option<i32>,
 
     /// Error group name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub group_name: Option<String>,
 
     /// Error ID.
@@ -193,7 +328,11 @@ pub struct Error {
     pub id: Option<i32>,
 
     /// Error name.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub name: Option<String>,
 
     /// Tells if the error is permanent.
@@ -201,45 +340,135 @@ pub struct Error {
     pub permanent: Option<bool>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Error {
+    /// Returns the details of the error code from `codes::ERROR_CODES`, if known.
+    pub fn info(&self) -> Option<&'static ErrorCodeInfo> {
+        self.id.and_then(error_by_id)
+    }
+
+    /// Returns how soon the message is worth sending again, with the delays of the default
+    /// `RetryPolicy`.
+    pub fn retry_advice(&self) -> RetryAdvice {
+        self.retry_advice_with(&RetryPolicy::default())
+    }
+
+    /// Returns how soon the message is worth sending again, with the delays of `policy`. The
+    /// error name and permanent flag are taken from the known error code when not set.
+    pub fn retry_advice_with(&self, policy: &RetryPolicy) -> RetryAdvice {
+        let info = self.info();
+        let name = self.name.as_deref().or_else(|| info.map(|info| info.name));
+        let permanent = self.permanent.or_else(|| info.map(|info| info.permanent));
+
+        policy.advise(name, permanent)
+    }
+}
+
+/// Splits a combined mobile country and network code, like `21910` or `310260`, into the
+/// 3-digit country code and the 2 or 3-digit network code. Returns `None` for other values.
+pub fn split_mcc_mnc(mcc_mnc: &str) -> Option<(&str, &str)> {
+    if matches!(mcc_mnc.len(), 5 | 6) && mcc_mnc.bytes().all(|byte| byte.is_ascii_digit()) {
+        Some(mcc_mnc.split_at(3))
+    } else {
+        None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Report {
+    /// Application the message was sent for, as set with `applicationId` when sending.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub application_id: Option<String>,
+
     /// Bulk ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub bulk_id: Option<String>,
 
     /// Callback data sent through `callbackData` field in fully featured SMS message.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub callback_data: Option<String>,
 
     /// Tells when the SMS was finished processing by Infobip (i.e., delivered to the destination,
     /// delivered to the destination network, etc.). Has the following format:
     /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub done_at: Option<String>,
 
+    /// Entity the message was sent for, as set with `entityId` when sending.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_id: Option<String>,
+
     /// Indicates whether the error occurred during the query execution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<Error>,
 
     /// Sender ID that can be alphanumeric or numeric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub from: Option<String>,
 
     /// Mobile country and network codes.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mcc_mnc: Option<String>,
 
     /// Message ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub message_id: Option<String>,
 
+    /// Name of the network of the destination, on accounts where it is available.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub network_name: Option<String>,
+
+    /// Tells if the destination number was ported to another network, on accounts where it is
+    /// available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ported: Option<bool>,
+
     /// Sent SMS price.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Price>,
 
     /// Tells when the SMS was sent. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sent_at: Option<String>,
 
     /// The number of parts the sent SMS was split into.
@@ -252,19 +481,174 @@ pub struct Report {
     pub status: Option<Status>,
 
     /// Destination address.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub to: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Report {
+    /// Returns the mobile country code, the first 3 digits of `mcc_mnc`.
+    pub fn mcc(&self) -> Option<&str> {
+        self.mcc_mnc
+            .as_deref()
+            .and_then(split_mcc_mnc)
+            .map(|(mcc, _)| mcc)
+    }
+
+    /// Returns the mobile network code, the 2 or 3 digits of `mcc_mnc` after the country code.
+    pub fn mnc(&self) -> Option<&str> {
+        self.mcc_mnc
+            .as_deref()
+            .and_then(split_mcc_mnc)
+            .map(|(_, mnc)| mnc)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetDeliveryReportsResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Report>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+impl ApiResponse for GetDeliveryReportsResponseBody {}
+
+/// Parses a delivery report callback sent as XML, when `notifyContentType` is
+/// `application/xml`, into the same structure used for JSON reports.
+#[cfg(feature = "xml")]
+pub fn parse_delivery_reports_xml(
+    xml: &[u8],
+) -> Result<GetDeliveryReportsResponseBody, crate::api::SdkError> {
+    Ok(GetDeliveryReportsResponseBody {
+        results: Some(crate::model::xml::parse_reports(xml)?),
+    })
+}
+
+/// Borrowed variant of `Report`, for parsing delivery report webhooks without allocating a
+/// `String` for every field. Strings with escape sequences are still allocated.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct ReportRef<'a> {
+    /// Application the message was sent for.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub application_id: Option<Cow<'a, str>>,
+
+    /// Bulk ID.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub bulk_id: Option<Cow<'a, str>>,
+
+    /// Callback data sent through `callbackData` field in fully featured SMS message.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub callback_data: Option<Cow<'a, str>>,
+
+    /// Tells when the SMS was finished processing by Infobip.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub done_at: Option<Cow<'a, str>>,
+
+    /// Entity the message was sent for.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub entity_id: Option<Cow<'a, str>>,
+
+    /// Indicates whether the error occurred during the query execution.
+    pub error: Option<Error>,
+
+    /// Sender ID that can be alphanumeric or numeric.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub from: Option<Cow<'a, str>>,
+
+    /// Mobile country and network codes.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub mcc_mnc: Option<Cow<'a, str>>,
+
+    /// Message ID.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub message_id: Option<Cow<'a, str>>,
+
+    /// Name of the network of the destination, on accounts where it is available.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub network_name: Option<Cow<'a, str>>,
+
+    /// Tells if the destination number was ported to another network.
+    pub ported: Option<bool>,
+
+    /// Sent SMS price.
+    pub price: Option<Price>,
+
+    /// Tells when the SMS was sent.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub sent_at: Option<Cow<'a, str>>,
+
+    /// The number of parts the sent SMS was split into.
+    pub sms_count: Option<i32>,
+
+    /// Status of the message.
+    pub status: Option<Status>,
+
+    /// Destination address.
+    #[serde(borrow, default, deserialize_with = "borrowed_empty_string_as_none")]
+    pub to: Option<Cow<'a, str>>,
+}
+
+impl<'a> ReportRef<'a> {
+    /// Returns an owned copy of the report.
+    pub fn to_owned(&self) -> Report {
+        Report {
+            application_id: to_owned_string(&self.application_id),
+            bulk_id: to_owned_string(&self.bulk_id),
+            callback_data: to_owned_string(&self.callback_data),
+            done_at: to_owned_string(&self.done_at),
+            entity_id: to_owned_string(&self.entity_id),
+            error: self.error.clone(),
+            from: to_owned_string(&self.from),
+            mcc_mnc: to_owned_string(&self.mcc_mnc),
+            message_id: to_owned_string(&self.message_id),
+            network_name: to_owned_string(&self.network_name),
+            ported: self.ported,
+            price: self.price.clone(),
+            sent_at: to_owned_string(&self.sent_at),
+            sms_count: self.sms_count,
+            status: self.status.clone(),
+            to: to_owned_string(&self.to),
+        }
+    }
+}
+
+/// Borrowed variant of `GetDeliveryReportsResponseBody`, the body of a delivery report webhook.
+#[derive(Clone, Debug, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryReportsWebhookRef<'a> {
+    #[serde(borrow)]
+    pub results: Option<Vec<ReportRef<'a>>>,
+}
+
+impl<'a> DeliveryReportsWebhookRef<'a> {
+    /// Returns an owned copy of the webhook body.
+    pub fn to_owned(&self) -> GetDeliveryReportsResponseBody {
+        GetDeliveryReportsResponseBody {
+            results: self
+                .results
+                .as_ref()
+                .map(|results| results.iter().map(ReportRef::to_owned).collect()),
+        }
+    }
+}
+
+/// Parses the body of a delivery report webhook, borrowing its strings from `body`.
+pub fn parse_delivery_reports_borrowed(
+    body: &[u8],
+) -> Result<DeliveryReportsWebhookRef<'_>, crate::api::SdkError> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+fn to_owned_string(value: &Option<Cow<str>>) -> Option<String> {
+    value.as_ref().map(|value| value.to_string())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Tracking {
     /// Custom base url used for shortening links from SMS text in `URL` Conversion rate tracking
     /// use-case.
@@ -310,6 +694,17 @@ pub enum TimeUnit {
     DAY,
 }
 
+impl TimeUnit {
+    /// Returns the length of the time unit.
+    pub fn duration(&self) -> std::time::Duration {
+        match self {
+            TimeUnit::MINUTE => std::time::Duration::from_secs(60),
+            TimeUnit::HOUR => std::time::Duration::from_secs(60 * 60),
+            TimeUnit::DAY => std::time::Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 pub struct DeliveryTime {
     /// Hour when the time window opens when used in from property or closes when used into the
@@ -318,7 +713,7 @@ pub struct DeliveryTime {
     pub hour: i32,
 
     /// Minute when the time window opens when used in from property or closes when used into the
-    /// property.
+    /// property. Inside a `DeliveryTimeWindow` it must be one of `DELIVERY_TIME_MINUTES`.
     #[validate(range(min = 0, max = 59))]
     pub minute: i32,
 }
@@ -329,27 +724,147 @@ impl DeliveryTime {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_speed_limit"))]
 pub struct SpeedLimit {
     /// The number of messages to be sent per timeUnit. By default, the system sends messages as
     /// fast as the infrastructure allows. Use this parameter to adapt sending capacity to your
     /// needs. The system is only able to work against its maximum capacity for ambitious message
-    /// batches.
+    /// batches. Must be between 1 and `SpeedLimit::MAX_AMOUNT` when `time_unit` is set; `0` is
+    /// only accepted without a time unit, see `SpeedLimit::unlimited`.
+    #[validate(range(min = 0, max = "SpeedLimit::MAX_AMOUNT"))]
     pub amount: i32,
 
-    /// The time unit in which the defined message amount will be sent. The default value is `MINUTE`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The time unit in which the defined message amount will be sent. The default value is
+    /// `MINUTE`, which is sent explicitly when only a non-zero `amount` is set.
     pub time_unit: Option<TimeUnit>,
 }
 
+// Wire format of `SpeedLimit`, with the default time unit filled in.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpeedLimitWire {
+    amount: i32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_unit: Option<TimeUnit>,
+}
+
+impl serde::Serialize for SpeedLimit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let time_unit = match self.amount {
+            0 => self.time_unit,
+            _ => Some(self.time_unit.unwrap_or(SpeedLimit::DEFAULT_TIME_UNIT)),
+        };
+
+        let wire = SpeedLimitWire {
+            amount: self.amount,
+            time_unit,
+        };
+
+        serde::Serialize::serialize(&wire, serializer)
+    }
+}
+
 impl SpeedLimit {
+    /// Largest accepted amount, well above what any account can send per minute.
+    pub const MAX_AMOUNT: i32 = 1_000_000;
+
+    /// Time unit applied by the API when none is set.
+    pub const DEFAULT_TIME_UNIT: TimeUnit = TimeUnit::MINUTE;
+
     pub fn new(amount: i32) -> SpeedLimit {
         SpeedLimit {
             amount,
             time_unit: None,
         }
     }
+
+    /// Returns a speed limit with an amount of `0`, which the API treats as no limit, so messages
+    /// are sent as fast as the infrastructure allows.
+    pub fn unlimited() -> SpeedLimit {
+        SpeedLimit::new(0)
+    }
+
+    /// Returns a speed limit of `amount` messages per minute.
+    pub fn per_minute(amount: i32) -> SpeedLimit {
+        SpeedLimit {
+            amount,
+            time_unit: Some(TimeUnit::MINUTE),
+        }
+    }
+
+    /// Returns a speed limit of `amount` messages per hour.
+    pub fn per_hour(amount: i32) -> SpeedLimit {
+        SpeedLimit {
+            amount,
+            time_unit: Some(TimeUnit::HOUR),
+        }
+    }
+
+    /// Returns a speed limit of `amount` messages per day.
+    pub fn per_day(amount: i32) -> SpeedLimit {
+        SpeedLimit {
+            amount,
+            time_unit: Some(TimeUnit::DAY),
+        }
+    }
+
+    /// Returns the number of messages sent per minute under this limit, taking a missing time
+    /// unit as `MINUTE`, or `None` if the amount is `0`, which means no limit.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::model::sms::SpeedLimit;
+    /// assert_eq!(SpeedLimit::per_hour(120).effective_rate_per_minute(), Some(2.0));
+    /// assert_eq!(SpeedLimit::new(30).effective_rate_per_minute(), Some(30.0));
+    /// assert_eq!(SpeedLimit::unlimited().effective_rate_per_minute(), None);
+    /// ```
+    pub fn effective_rate_per_minute(&self) -> Option<f64> {
+        if self.amount == 0 {
+            return None;
+        }
+        let minutes = self
+            .time_unit
+            .unwrap_or(SpeedLimit::DEFAULT_TIME_UNIT)
+            .duration()
+            .as_secs_f64()
+            / 60.0;
+
+        Some(f64::from(self.amount) / minutes)
+    }
+
+    /// Checks that the rate implied by this limit is at most `max_rate_per_minute` messages per
+    /// minute. A limit with an amount of `0` sends as fast as possible, so it is rejected too.
+    pub fn check_rate(&self, max_rate_per_minute: f64) -> Result<(), validator::ValidationError> {
+        match self.effective_rate_per_minute() {
+            Some(rate) if rate <= max_rate_per_minute => Ok(()),
+            rate => {
+                let mut error = validation_error(
+                    "speed_limit_rate",
+                    "sending speed limit is above the allowed messages per minute",
+                );
+                if let Some(rate) = rate {
+                    error.add_param("rate_per_minute".into(), &rate);
+                }
+                error.add_param("max_rate_per_minute".into(), &max_rate_per_minute);
+
+                Err(error)
+            }
+        }
+    }
+}
+
+fn validate_speed_limit(speed_limit: &SpeedLimit) -> Result<(), validator::ValidationError> {
+    if speed_limit.time_unit.is_some() && speed_limit.amount < 1 {
+        return Err(validation_error(
+            "speed_limit_amount",
+            "amount must be at least 1 when a time unit is set",
+        ));
+    }
+
+    Ok(())
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -376,6 +891,66 @@ pub struct UrlOptions {
     pub custom_domain: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    /// Sets up conversion tracking of the messages of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub conversion_tracking: Option<ConversionTracking>,
+}
+
+impl Options {
+    pub fn new() -> Options {
+        Options::default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_conversion_tracking"))]
+pub struct ConversionTracking {
+    /// Indicates if the messages are tracked for conversion rates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_conversion_tracking: Option<bool>,
+
+    /// Name of the conversion tracking process, required when `use_conversion_tracking` is
+    /// `true`.
+    #[serde(
+        rename = "conversionTrackingName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tracking_name: Option<String>,
+}
+
+impl ConversionTracking {
+    /// Returns conversion tracking enabled with the process named `tracking_name`.
+    pub fn enabled(tracking_name: &str) -> ConversionTracking {
+        ConversionTracking {
+            use_conversion_tracking: Some(true),
+            tracking_name: Some(tracking_name.into()),
+        }
+    }
+}
+
+fn validate_conversion_tracking(
+    tracking: &ConversionTracking,
+) -> Result<(), validator::ValidationError> {
+    let has_name = tracking
+        .tracking_name
+        .as_deref()
+        .map_or(false, |name| !name.trim().is_empty());
+
+    if tracking.use_conversion_tracking == Some(true) && !has_name {
+        return Err(validation_error(
+            "conversion_tracking_name",
+            "conversionTrackingName must be set when conversion tracking is enabled",
+        ));
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum DeliveryDay {
     MONDAY,
@@ -387,7 +962,11 @@ pub enum DeliveryDay {
     SUNDAY,
 }
 
+/// Minute values accepted by the API for `DeliveryTime` inside a `DeliveryTimeWindow`.
+pub const DELIVERY_TIME_MINUTES: [i32; 4] = [0, 15, 30, 45];
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_delivery_time_window_minutes"))]
 pub struct DeliveryTimeWindow {
     /// Days which are included in the delivery time window. Values are: `MONDAY`, `TUESDAY`,
     /// `WEDNESDAY`, `THURSDAY`, `FRIDAY`, `SATURDAY`, `SUNDAY`. At least one day must be stated.
@@ -405,6 +984,11 @@ pub struct DeliveryTimeWindow {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate]
     pub to: Option<DeliveryTime>,
+
+    /// Skip the local check that `from` and `to` minutes are one of `DELIVERY_TIME_MINUTES`.
+    /// Not sent to the API; only useful if the API starts accepting other minute values.
+    #[serde(skip)]
+    pub allow_any_minute: bool,
 }
 
 impl DeliveryTimeWindow {
@@ -413,28 +997,116 @@ impl DeliveryTimeWindow {
             days,
             from: None,
             to: None,
+            allow_any_minute: false,
         }
     }
 }
 
+fn validate_delivery_time_window_minutes(
+    delivery_time_window: &DeliveryTimeWindow,
+) -> Result<(), validator::ValidationError> {
+    if delivery_time_window.allow_any_minute {
+        return Ok(());
+    }
+
+    let times = [&delivery_time_window.from, &delivery_time_window.to];
+    if times
+        .iter()
+        .flat_map(|time| time.as_ref())
+        .all(|time| DELIVERY_TIME_MINUTES.contains(&time.minute))
+    {
+        return Ok(());
+    }
+
+    let mut error = validator::ValidationError::new("delivery_time_minute");
+    error.message = Some("delivery time minute must be one of 0, 15, 30 or 45".into());
+
+    Err(error)
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Destination {
-    /// The ID that uniquely identifies the message sent.
+    /// The ID that uniquely identifies the message sent. Setting a distinct ID for every
+    /// destination allows matching reports to destinations.
+    #[validate(length(max = 200))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
+    /// ID of the network to route the message through, overriding the network the destination
+    /// belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<i32>,
+
     /// Message destination address. Addresses must be in international format (Example:
     /// `41793026727`).
     #[validate(length(min = 1, max = 50))]
     pub to: String,
+
+    /// Values of the `{{key}}` placeholders of the message text for this destination only. If
+    /// any destination of a message has placeholders, every destination must have a value for
+    /// each placeholder used in the text.
+    ///
+    /// Placeholders are filled in locally by `Message::expand_placeholders` and never sent, as
+    /// the advanced text endpoint does not document per-destination placeholders.
+    #[serde(skip)]
+    pub placeholders: Option<HashMap<String, String>>,
+}
+
+/// Converts a recipient checked with `Recipient::validate_for_sms`.
+impl TryFrom<Recipient> for Destination {
+    type Error = validator::ValidationError;
+
+    fn try_from(recipient: Recipient) -> Result<Self, Self::Error> {
+        recipient.validate_for_sms()?;
+
+        Ok(Destination::new(recipient.value()))
+    }
 }
 
 impl Destination {
     pub fn new(to: &str) -> Destination {
         Destination {
             message_id: None,
+            network_id: None,
             to: to.into(),
+            placeholders: None,
+        }
+    }
+
+    /// Creates a `Destination` with the ID of the message sent to it.
+    pub fn with_message_id(to: &str, message_id: &str) -> Destination {
+        Destination {
+            message_id: Some(message_id.into()),
+            ..Destination::new(to)
+        }
+    }
+
+    /// Creates a `Destination` with its own values for the placeholders of the message text.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use infobip_sdk::model::sms::{Destination, Message};
+    /// # use validator::Validate;
+    /// let mut message = Message::new(vec![
+    ///     Destination::with_placeholders(
+    ///         "41793026727",
+    ///         HashMap::from([("name".to_string(), "John".to_string())]),
+    ///     ),
+    ///     Destination::with_placeholders(
+    ///         "41793026728",
+    ///         HashMap::from([("name".to_string(), "Jane".to_string())]),
+    ///     ),
+    /// ]);
+    /// message.text = Some("Hello {{name}}!".to_string());
+    ///
+    /// assert!(message.validate().is_ok());
+    /// ```
+    pub fn with_placeholders(to: &str, placeholders: HashMap<String, String>) -> Destination {
+        Destination {
+            placeholders: Some(placeholders),
+            ..Destination::new(to)
         }
     }
 }
@@ -516,6 +1188,8 @@ impl Default for RegionalOptions {
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_max_parts"))]
+#[validate(schema(function = "validate_destination_placeholders"))]
 pub struct Message {
     /// Additional data that can be used for identifying, managing, or monitoring a message.
     /// Data included here will also be automatically included in the message Delivery Report.
@@ -554,14 +1228,20 @@ pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub intermediate_report: Option<bool>,
 
-    /// Sets the language parameters for the message being sent.
+    /// Sets the language parameters for the message being sent, selecting the national character
+    /// set used to encode the text together with `transliteration`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<Language>,
 
-    /// Preferred Delivery report content type. Can be `application/json` or `application/xml`.
+    /// Largest number of parts the text may be split into, checked by `validate` against the
+    /// local `estimate_parts` estimate, so long texts fail before being sent and billed per part.
+    /// Not sent to the API; no cap is checked if not set.
+    #[serde(skip)]
+    pub max_parts: Option<usize>,
+
+    /// Preferred Delivery report content type, `application/json` or `application/xml`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate(regex = "CONTENT_TYPES")]
-    pub notify_content_type: Option<String>,
+    pub notify_content_type: Option<NotifyContentType>,
 
     /// The URL on your call back server on to which a delivery report will be sent. The retry
     /// cycle for when your URL becomes unavailable uses the following formula:
@@ -612,6 +1292,7 @@ impl Message {
             from: None,
             intermediate_report: None,
             language: None,
+            max_parts: None,
             notify_content_type: None,
             notify_url: None,
             regional: None,
@@ -621,6 +1302,299 @@ impl Message {
             validity_period: None,
         }
     }
+
+    /// Returns the local estimate of the parts needed to send `text`, taking `language` and
+    /// `transliteration` into account, or `None` if there is no text. See `estimate_parts`.
+    pub fn estimate_parts(&self) -> Option<PartsEstimate> {
+        let language_code = self
+            .language
+            .as_ref()
+            .and_then(|language| language.language_code);
+
+        self.text
+            .as_deref()
+            .map(|text| estimate_parts(text, language_code, self.transliteration.as_deref()))
+    }
+
+    /// Returns the messages to send for this one, with the placeholders of each destination
+    /// filled in the text. A message with several destinations having placeholders becomes one
+    /// message per destination; a message without placeholders is returned unchanged. Unknown
+    /// placeholders are left as they are, `validate` reports them.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use infobip_sdk::model::sms::{Destination, Message};
+    /// let mut message = Message::new(vec![
+    ///     Destination::with_placeholders(
+    ///         "41793026727",
+    ///         HashMap::from([("name".to_string(), "John".to_string())]),
+    ///     ),
+    ///     Destination::with_placeholders(
+    ///         "41793026728",
+    ///         HashMap::from([("name".to_string(), "Jane".to_string())]),
+    ///     ),
+    /// ]);
+    /// message.text = Some("Hello {{name}}!".to_string());
+    ///
+    /// let messages = message.expand_placeholders();
+    ///
+    /// assert_eq!(messages[0].text.as_deref(), Some("Hello John!"));
+    /// assert_eq!(messages[1].text.as_deref(), Some("Hello Jane!"));
+    /// ```
+    pub fn expand_placeholders(&self) -> Vec<Message> {
+        let destinations = self.destinations.as_deref().unwrap_or_default();
+        if destinations
+            .iter()
+            .all(|destination| destination.placeholders.is_none())
+        {
+            return vec![self.clone()];
+        }
+
+        destinations
+            .iter()
+            .map(|destination| {
+                let text = self.text.as_deref().map(|text| {
+                    PLACEHOLDER
+                        .replace_all(text, |captures: &regex::Captures| {
+                            destination
+                                .placeholders
+                                .as_ref()
+                                .and_then(|placeholders| placeholders.get(&captures[1]))
+                                .cloned()
+                                .unwrap_or_else(|| captures[0].to_string())
+                        })
+                        .into_owned()
+                });
+
+                Message {
+                    destinations: Some(vec![destination.clone()]),
+                    text,
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Sets `language` and `transliteration` to the ones of a preview configuration, so the
+    /// message is sent as previewed.
+    pub fn apply_preview_configuration(&mut self, configuration: &PreviewLanguageConfiguration) {
+        self.language = configuration.language.clone();
+        self.transliteration = configuration.transliteration.clone();
+    }
+
+    /// Like `apply_preview_configuration`, with the configuration of `preview`, but first checks
+    /// that the preview was made for the text of this message, by comparing the length of `text`
+    /// with the length of the `original_text` of the response. The check is skipped if either
+    /// text is missing.
+    pub fn apply_preview(
+        &mut self,
+        response: &PreviewResponseBody,
+        preview: &Preview,
+    ) -> Result<(), validator::ValidationError> {
+        if let (Some(text), Some(original_text)) = (&self.text, &response.original_text) {
+            if text.chars().count() != original_text.chars().count() {
+                return Err(validation_error(
+                    "preview_text",
+                    "preview was made for a text of a different length than the message text",
+                ));
+            }
+        }
+
+        if let Some(configuration) = &preview.configuration {
+            self.apply_preview_configuration(configuration);
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_max_parts(message: &Message) -> Result<(), validator::ValidationError> {
+    let (max_parts, estimate) = match (message.max_parts, message.estimate_parts()) {
+        (Some(max_parts), Some(estimate)) => (max_parts, estimate),
+        _ => return Ok(()),
+    };
+
+    if estimate.parts > max_parts {
+        let mut error = validator::ValidationError::new("max_parts");
+        error.message = Some(
+            format!(
+                "text needs an estimated {} {} parts for {} characters, more than max_parts {}",
+                estimate.parts,
+                estimate.encoding.as_str(),
+                estimate.length,
+                max_parts
+            )
+            .into(),
+        );
+        error.add_param("parts".into(), &estimate.parts);
+        error.add_param("max_parts".into(), &max_parts);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+// Checks that, if any destination has placeholders, every destination has a value for each
+// placeholder used in the text. Reports the first destination missing one.
+fn validate_destination_placeholders(message: &Message) -> Result<(), validator::ValidationError> {
+    let destinations = message.destinations.as_deref().unwrap_or_default();
+    if destinations
+        .iter()
+        .all(|destination| destination.placeholders.is_none())
+    {
+        return Ok(());
+    }
+    let text = message.text.as_deref().unwrap_or_default();
+
+    for (index, destination) in destinations.iter().enumerate() {
+        let missing = PLACEHOLDER.captures_iter(text).find(|captures| {
+            destination
+                .placeholders
+                .as_ref()
+                .map_or(true, |placeholders| {
+                    !placeholders.contains_key(&captures[1])
+                })
+        });
+        if let Some(captures) = missing {
+            let key = &captures[1];
+            let mut error = validator::ValidationError::new("destination_placeholder");
+            error.message = Some(
+                format!(
+                    "destination {} has no value for placeholder '{}'",
+                    index, key
+                )
+                .into(),
+            );
+            error.add_param("index".into(), &index);
+            error.add_param("key".into(), &key);
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?¡\
+    ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+const GSM7_EXTENSION: &str = "\u{c}^{}\\[~]|€";
+
+/// Character encoding of an SMS text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SmsEncoding {
+    /// The 7-bit GSM alphabet, optionally extended by a national language shift table.
+    Gsm7,
+    /// UCS-2, used when the text has characters outside of the GSM alphabet.
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// Returns the usual name of the encoding, e.g. `GSM-7`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SmsEncoding::Gsm7 => "GSM-7",
+            SmsEncoding::Ucs2 => "UCS-2",
+        }
+    }
+}
+
+/// Local estimate of how an SMS text is split into parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PartsEstimate {
+    /// Encoding the text is sent with.
+    pub encoding: SmsEncoding,
+
+    /// Length of the text in units of the encoding: septets for GSM-7, where extension characters
+    /// take two, or UTF-16 code units for UCS-2.
+    pub length: usize,
+
+    /// Number of parts the text is split into.
+    pub parts: usize,
+}
+
+/// Estimates the encoding and number of parts of an SMS text, without calling the API. A GSM-7
+/// text fits 160 characters in one part and 153 per part when concatenated, 155 and 149 with the
+/// single shift table of a language, and a UCS-2 text 70 and 67.
+///
+/// With `language_code`, the characters of its single shift table are encoded in GSM-7;
+/// `AUTODETECT` picks the first language covering the text. With `transliteration`, characters
+/// outside of the GSM alphabet are assumed to be replaced by one GSM character each. The API
+/// decides the final count, which `SmsClient::preview` returns exactly.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::model::sms::{estimate_parts, SmsEncoding};
+/// let estimate = estimate_parts(&"a".repeat(161), None, None);
+///
+/// assert_eq!(estimate.encoding, SmsEncoding::Gsm7);
+/// assert_eq!(estimate.parts, 2);
+/// ```
+pub fn estimate_parts(
+    text: &str,
+    language_code: Option<LanguageCode>,
+    transliteration: Option<&str>,
+) -> PartsEstimate {
+    let languages: &[Option<LanguageCode>] = match language_code {
+        None | Some(LanguageCode::Unknown) => &[None],
+        Some(LanguageCode::AUTODETECT) => &[
+            None,
+            Some(LanguageCode::TR),
+            Some(LanguageCode::ES),
+            Some(LanguageCode::PT),
+        ],
+        Some(LanguageCode::TR) => &[Some(LanguageCode::TR)],
+        Some(LanguageCode::ES) => &[Some(LanguageCode::ES)],
+        Some(LanguageCode::PT) => &[Some(LanguageCode::PT)],
+    };
+
+    let gsm7 = languages.iter().find_map(|language| {
+        gsm7_length(text, *language, transliteration.is_some())
+            .map(|length| (length, language.is_some()))
+    });
+
+    let (encoding, length, single, multi) = match gsm7 {
+        Some((length, false)) => (SmsEncoding::Gsm7, length, 160, 153),
+        Some((length, true)) => (SmsEncoding::Gsm7, length, 155, 149),
+        None => (SmsEncoding::Ucs2, text.encode_utf16().count(), 70, 67),
+    };
+    let parts = if length <= single {
+        1
+    } else {
+        (length + multi - 1) / multi
+    };
+
+    PartsEstimate {
+        encoding,
+        length,
+        parts,
+    }
+}
+
+// Returns the length in septets of the text in GSM-7 with the single shift table of the language,
+// or `None` if a character cannot be encoded.
+fn gsm7_length(
+    text: &str,
+    language_code: Option<LanguageCode>,
+    transliterated: bool,
+) -> Option<usize> {
+    let shift_table = match language_code {
+        Some(LanguageCode::TR) => "ĞİŞçğış",
+        Some(LanguageCode::ES) => "çÁÍÓÚáíóú",
+        Some(LanguageCode::PT) => "êçÔôÁÊÀÍÓÚÃÕÂáíóúãõâ",
+        _ => "",
+    };
+
+    text.chars().try_fold(0, |length, character| {
+        if GSM7_BASIC.contains(character) {
+            Some(length + 1)
+        } else if GSM7_EXTENSION.contains(character) || shift_table.contains(character) {
+            Some(length + 2)
+        } else if transliterated {
+            Some(length + 1)
+        } else {
+            None
+        }
+    })
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
@@ -694,10 +1668,9 @@ pub struct BinaryMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub intermediate_report: Option<bool>,
 
-    /// Preferred Delivery report content type. Can be `application/json` or `application/xml`.
+    /// Preferred Delivery report content type, `application/json` or `application/xml`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate(regex = "CONTENT_TYPES")]
-    pub notify_content_type: Option<String>,
+    pub notify_content_type: Option<NotifyContentType>,
 
     /// The URL on your call back server on which the Delivery report will be sent.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -742,7 +1715,7 @@ impl BinaryMessage {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct SendRequestBody {
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
@@ -762,6 +1735,7 @@ pub struct SendRequestBody {
     /// call-to-action option from a message you sent. Not setting a send speed limit can
     /// overwhelm your resources with incoming traffic.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub sending_speed_limit: Option<SpeedLimit>,
 
     /// Sets up URL shortening and tracking feature. Not compatible with old tracking feature.
@@ -771,6 +1745,26 @@ pub struct SendRequestBody {
     /// Sets up tracking parameters to track conversion metrics and type.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tracking: Option<Tracking>,
+
+    /// Options applied to all messages of the request, like conversion tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub options: Option<Options>,
+}
+
+impl ApiRequest for SendRequestBody {}
+
+impl WireNames for SendRequestBody {
+    fn wire_names() -> &'static [(&'static str, &'static str)] {
+        &[
+            ("bulk_id", "bulkId"),
+            ("messages", "messages"),
+            ("sending_speed_limit", "sendingSpeedLimit"),
+            ("url_options", "urlOptions"),
+            ("tracking", "tracking"),
+            ("options", "options"),
+        ]
+    }
 }
 
 impl SendRequestBody {
@@ -781,11 +1775,226 @@ impl SendRequestBody {
             url_options: None,
             bulk_id: None,
             tracking: None,
+            options: None,
+        }
+    }
+
+    /// Schedules every message at `send_at`, given in any time zone, setting their `send_at` to
+    /// the matching UTC timestamp in the API format.
+    ///
+    /// # Example
+    /// ```
+    /// # use chrono::DateTime;
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+    /// let mut request_body = SendRequestBody::new(vec![Message::new(vec![Destination::new(
+    ///     "41793026727",
+    /// )])]);
+    /// request_body.schedule_at(DateTime::parse_from_rfc3339("2030-03-01T09:00:00+05:30").unwrap());
+    ///
+    /// assert_eq!(
+    ///     request_body.messages[0].send_at.as_deref(),
+    ///     Some("2030-03-01T03:30:00.000+0000")
+    /// );
+    /// ```
+    pub fn schedule_at<Tz: TimeZone>(&mut self, send_at: DateTime<Tz>) {
+        let send_at = format_timestamp(&send_at);
+
+        for message in &mut self.messages {
+            message.send_at = Some(send_at.clone());
+        }
+    }
+
+    /// Checks that the `send_at` of every message, if set, is a timestamp in the API format and is
+    /// not more than `tolerance` in the past according to the clock of this machine. The API
+    /// sends messages scheduled in the past right away, so this is not part of `validate`.
+    ///
+    /// Errors are reported on `messages`, with the position of the message in the `index` param.
+    pub fn check_send_at(&self, tolerance: Duration) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+
+        for (index, message) in self.messages.iter().enumerate() {
+            if let Some(send_at) = &message.send_at {
+                if let Err(mut error) = validate_send_at(send_at, tolerance) {
+                    error.add_param("index".into(), &index);
+                    errors.add("messages", error);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks that `sending_speed_limit`, if set, implies at most `max_rate_per_minute` messages
+    /// per minute, see `SpeedLimit::check_rate`. The ceiling depends on the account, so this is
+    /// not part of `validate`.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody, SpeedLimit};
+    /// let mut request_body = SendRequestBody::new(vec![Message::new(vec![Destination::new(
+    ///     "41793026727",
+    /// )])]);
+    /// request_body.sending_speed_limit = Some(SpeedLimit::per_day(100_000));
+    ///
+    /// assert!(request_body.check_sending_speed_limit(100.0).is_ok());
+    /// assert!(request_body.check_sending_speed_limit(50.0).is_err());
+    /// ```
+    pub fn check_sending_speed_limit(
+        &self,
+        max_rate_per_minute: f64,
+    ) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+
+        if let Some(speed_limit) = &self.sending_speed_limit {
+            if let Err(error) = speed_limit.check_rate(max_rate_per_minute) {
+                errors.add("sending_speed_limit", error);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Splits the request into requests of at most `max_destinations` destinations each, keeping
+    /// the order of messages and destinations. Messages with more destinations than fit in the
+    /// current chunk are split into copies holding part of the destinations. All chunks keep the
+    /// bulk ID and the other request-level settings.
+    pub fn split_into_chunks(&self, max_destinations: usize) -> Vec<SendRequestBody> {
+        let max_destinations = max_destinations.max(1);
+        let mut chunks = Vec::new();
+        let mut messages: Vec<Message> = Vec::new();
+        let mut size = 0;
+
+        for message in &self.messages {
+            let destinations = match &message.destinations {
+                Some(destinations) if !destinations.is_empty() => destinations.as_slice(),
+                _ => {
+                    if size == max_destinations {
+                        chunks.push(self.with_messages(std::mem::take(&mut messages)));
+                        size = 0;
+                    }
+                    messages.push(message.clone());
+                    size += 1;
+                    continue;
+                }
+            };
+
+            let mut remaining = destinations;
+            while !remaining.is_empty() {
+                if size == max_destinations {
+                    chunks.push(self.with_messages(std::mem::take(&mut messages)));
+                    size = 0;
+                }
+
+                let (part, rest) = remaining.split_at(remaining.len().min(max_destinations - size));
+                let mut part_message = message.clone();
+                part_message.destinations = Some(part.to_vec());
+                messages.push(part_message);
+                size += part.len();
+                remaining = rest;
+            }
+        }
+
+        if !messages.is_empty() {
+            chunks.push(self.with_messages(messages));
+        }
+
+        chunks
+    }
+
+    fn with_messages(&self, messages: Vec<Message>) -> SendRequestBody {
+        SendRequestBody {
+            bulk_id: self.bulk_id.clone(),
+            messages,
+            sending_speed_limit: self.sending_speed_limit.clone(),
+            url_options: self.url_options.clone(),
+            tracking: self.tracking.clone(),
+            options: self.options.clone(),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+/// Longest validity period accepted by the SMS API, in minutes (48 hours).
+pub const MAX_VALIDITY_PERIOD_MINUTES: i64 = 2880;
+
+/// Checks, on top of `validate`, these limits of the SMS API for every message:
+/// - `from` is numeric with at most 16 digits, or alphanumeric with at most 11 characters.
+/// - `callbackData` has no control characters.
+/// - Destination `to` addresses are numbers in international format, with at most 15 digits.
+/// - `validityPeriod` is at most `MAX_VALIDITY_PERIOD_MINUTES`.
+///
+/// Errors are reported on `messages`, with the position of the message in the `index` param.
+impl ValidateStrict for SendRequestBody {
+    fn validate_strict(&self) -> Result<(), validator::ValidationErrors> {
+        self.validate()?;
+
+        let mut errors = validator::ValidationErrors::new();
+        let mut add_error = |index: usize, code: &'static str, message: &'static str| {
+            let mut error = validation_error(code, message);
+            error.add_param("index".into(), &index);
+            errors.add("messages", error);
+        };
+
+        for (index, message) in self.messages.iter().enumerate() {
+            if let Some(from) = &message.from {
+                if !NUMERIC_SENDER.is_match(from) && !ALPHANUMERIC_SENDER.is_match(from) {
+                    add_error(
+                        index,
+                        "strict_sender",
+                        "from must be numeric with at most 16 digits or alphanumeric with at most 11 characters",
+                    );
+                }
+            }
+            if message
+                .callback_data
+                .as_deref()
+                .map_or(false, has_control_characters)
+            {
+                add_error(
+                    index,
+                    "strict_callback_data_charset",
+                    "callback_data must not contain control characters",
+                );
+            }
+            let destinations = message.destinations.as_deref().unwrap_or_default();
+            if destinations
+                .iter()
+                .any(|destination| !E164_NUMBER.is_match(&destination.to))
+            {
+                add_error(
+                    index,
+                    "strict_destination",
+                    "destination must be a number in international format with at most 15 digits",
+                );
+            }
+            if message
+                .validity_period
+                .map_or(false, |period| period > MAX_VALIDITY_PERIOD_MINUTES)
+            {
+                add_error(
+                    index,
+                    "strict_validity_period",
+                    "validity_period must be at most 2880 minutes",
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct SendBinaryRequestBody {
     /// The ID which uniquely identifies the request. Bulk ID will be received only when you send a
@@ -805,9 +2014,12 @@ pub struct SendBinaryRequestBody {
     /// period, allowing your systems or agents to handle incoming traffic in real-time,
     /// resulting in better customer satisfaction.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub sending_speed_limit: Option<SpeedLimit>,
 }
 
+impl ApiRequest for SendBinaryRequestBody {}
+
 impl SendBinaryRequestBody {
     pub fn new(messages: Vec<BinaryMessage>) -> SendBinaryRequestBody {
         SendBinaryRequestBody {
@@ -818,8 +2030,8 @@ impl SendBinaryRequestBody {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SentMessageDetails {
     /// The ID that uniquely identifies the message sent.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -835,8 +2047,8 @@ pub struct SentMessageDetails {
     pub to: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SendResponseBody {
     /// The ID that uniquely identifies the request. Bulk ID will be received only when you send a
     /// message to more than one destination address.
@@ -848,14 +2060,73 @@ pub struct SendResponseBody {
     pub messages: Option<Vec<SentMessageDetails>>,
 }
 
+impl ApiResponse for SendResponseBody {}
+
 pub type SendBinaryResponseBody = SendResponseBody;
 
+impl SendOutcome for SendResponseBody {
+    type Details = SentMessageDetails;
+
+    fn sent_messages(&self) -> &[SentMessageDetails] {
+        self.messages.as_deref().unwrap_or_default()
+    }
+
+    fn status_group_id(details: &SentMessageDetails) -> Option<i32> {
+        details.status.as_ref().and_then(|status| status.group_id)
+    }
+}
+
+fn validate_e164_destinations(destinations: &[String]) -> Result<(), validator::ValidationError> {
+    if destinations
+        .iter()
+        .all(|destination| E164_NUMBER.is_match(destination))
+    {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("e164"))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleTextRequestBody {
+    /// The sender ID which can be alphanumeric or numeric (e.g., `CompanyName`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Message destination addresses in E.164 format, e.g. `41793026727`.
+    #[validate(length(min = 1), custom = "validate_e164_destinations")]
+    pub to: Vec<String>,
+
+    /// Content of the message being sent.
+    pub text: String,
+}
+
+impl ApiRequest for SingleTextRequestBody {}
+
+impl SingleTextRequestBody {
+    pub fn new(to: Vec<String>, text: &str) -> SingleTextRequestBody {
+        SingleTextRequestBody {
+            from: None,
+            to,
+            text: text.into(),
+        }
+    }
+}
+
+pub type SendSingleRequestBody = SingleTextRequestBody;
+
+pub type SendSingleResponseBody = SendResponseBody;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct GetScheduledQueryParameters {
     #[validate(length(min = 1))]
     pub bulk_id: String,
 }
 
+impl ToQueryString for GetScheduledQueryParameters {}
+
 impl GetScheduledQueryParameters {
     pub fn new(bulk_id: &str) -> GetScheduledQueryParameters {
         GetScheduledQueryParameters {
@@ -869,11 +2140,25 @@ impl GetScheduledQueryParameters {
 pub struct GetScheduledResponseBody {
     pub bulk_id: String,
 
+    /// Time the bulk is scheduled for, as returned by the API: a timestamp or milliseconds since
+    /// the Unix epoch. See `send_at_datetime`.
+    #[serde(deserialize_with = "deserialize_string_or_number")]
     pub send_at: String,
 }
 
+impl ApiResponse for GetScheduledResponseBody {}
+
+impl GetScheduledResponseBody {
+    /// Returns `send_at` as a UTC date and time, whether it was returned as a timestamp or as
+    /// milliseconds since the Unix epoch.
+    pub fn send_at_datetime(&self) -> Option<DateTime<Utc>> {
+        parse_send_at(&self.send_at)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_logs_time_range"))]
 pub struct GetLogsQueryParameters {
     /// The sender ID which can be alphanumeric or numeric.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -925,6 +2210,18 @@ pub struct GetLogsQueryParameters {
     pub mnc: Option<String>,
 }
 
+impl ToQueryString for GetLogsQueryParameters {}
+
+fn validate_logs_time_range(
+    query_parameters: &GetLogsQueryParameters,
+) -> Result<(), validator::ValidationError> {
+    validate_time_range(
+        query_parameters.sent_since.as_deref(),
+        query_parameters.sent_until.as_deref(),
+        LOGS_RETENTION_HOURS,
+    )
+}
+
 impl GetLogsQueryParameters {
     pub fn new() -> GetLogsQueryParameters {
         GetLogsQueryParameters {
@@ -948,41 +2245,78 @@ impl Default for GetLogsQueryParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Log {
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
     /// messages via a single API request.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub bulk_id: Option<String>,
 
     /// Date and time when the Infobip services finished processing the message (i.e. delivered
     /// to the destination, delivered to the destination network, etc.). Has the following
     /// format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ.`
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub done_at: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<Error>,
 
     /// Sender ID that can be alphanumeric or numeric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub from: Option<String>,
 
     /// Mobile country and network codes.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mcc_mnc: Option<String>,
 
     /// Unique message ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub message_id: Option<String>,
 
+    /// Name of the network of the destination, on accounts where it is available.
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub network_name: Option<String>,
+
+    /// Tells if the destination number was ported to another network, on accounts where it is
+    /// available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ported: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Price>,
 
     /// Date and time when the message was scheduled to be sent. Has the following format:
     /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sent_at: Option<String>,
 
     /// The number of parts the message content was split into.
@@ -997,24 +2331,52 @@ pub struct Log {
     pub text: Option<String>,
 
     /// The destination address of the message.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        deserialize_with = "empty_string_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub to: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Log {
+    /// Returns the mobile country code, the first 3 digits of `mcc_mnc`.
+    pub fn mcc(&self) -> Option<&str> {
+        self.mcc_mnc
+            .as_deref()
+            .and_then(split_mcc_mnc)
+            .map(|(mcc, _)| mcc)
+    }
+
+    /// Returns the mobile network code, the 2 or 3 digits of `mcc_mnc` after the country code.
+    pub fn mnc(&self) -> Option<&str> {
+        self.mcc_mnc
+            .as_deref()
+            .and_then(split_mcc_mnc)
+            .map(|(_, mnc)| mnc)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetLogsResponseBody {
     /// Collection of logs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Log>>,
 }
 
+impl ApiResponse for GetLogsResponseBody {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct GetInboundReportsQueryParameters {
     #[validate(range(max = 1000))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
 }
 
+impl ToQueryString for GetInboundReportsQueryParameters {}
+
 impl GetInboundReportsQueryParameters {
     pub fn new() -> GetInboundReportsQueryParameters {
         GetInboundReportsQueryParameters { limit: None }
@@ -1027,8 +2389,8 @@ impl Default for GetInboundReportsQueryParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetInboundReportsResponseBody {
     /// The number of messages returned in the `results` array.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1043,8 +2405,13 @@ pub struct GetInboundReportsResponseBody {
     pub results: Option<Vec<InboundSmsReport>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl ApiResponse for GetInboundReportsResponseBody {}
+
+/// Body of an inbound SMS webhook, which has the same structure as the inbound reports response.
+pub type InboundMessagesWebhook = GetInboundReportsResponseBody;
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct InboundSmsReport {
     /// Custom callback data sent over the notifyUrl.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1088,7 +2455,114 @@ pub struct InboundSmsReport {
     pub to: Option<String>,
 }
 
+/// Borrowed variant of `InboundSmsReport`, for parsing inbound SMS webhooks without allocating a
+/// `String` for every field. Strings with escape sequences are still allocated.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundSmsReportRef<'a> {
+    /// Custom callback data sent over the notifyUrl.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub callback_data: Option<Cow<'a, str>>,
+
+    /// Content of the message without a keyword (if a keyword was sent).
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub clean_text: Option<Cow<'a, str>>,
+
+    /// Sender ID that can be alphanumeric or numeric.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub from: Option<Cow<'a, str>>,
+
+    /// Keyword extracted from the message content.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub keyword: Option<Cow<'a, str>>,
+
+    /// Unique message ID.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub message_id: Option<Cow<'a, str>>,
+
+    /// A price object showing currency and a price per each message.
+    pub price: Option<Price>,
+
+    /// Indicates when the Infobip platform received the message.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub received_at: Option<Cow<'a, str>>,
+
+    /// The number of characters within a message
+    pub sms_count: Option<i32>,
+
+    /// Full content of the message.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub text: Option<Cow<'a, str>>,
+
+    /// The destination address of the message.
+    #[serde(borrow, default, deserialize_with = "borrowed_str")]
+    pub to: Option<Cow<'a, str>>,
+}
+
+impl<'a> InboundSmsReportRef<'a> {
+    /// Returns an owned copy of the message.
+    pub fn to_owned(&self) -> InboundSmsReport {
+        InboundSmsReport {
+            callback_data: to_owned_string(&self.callback_data),
+            clean_text: to_owned_string(&self.clean_text),
+            from: to_owned_string(&self.from),
+            keyword: to_owned_string(&self.keyword),
+            message_id: to_owned_string(&self.message_id),
+            price: self.price.clone(),
+            received_at: to_owned_string(&self.received_at),
+            sms_count: self.sms_count,
+            text: to_owned_string(&self.text),
+            to: to_owned_string(&self.to),
+        }
+    }
+}
+
+/// Borrowed variant of `GetInboundReportsResponseBody`, the body of an inbound SMS webhook.
+#[derive(Clone, Debug, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessagesWebhookRef<'a> {
+    /// The number of messages returned in the `results` array.
+    pub message_count: Option<i32>,
+
+    /// The number of messages that have not been pulled in.
+    pub pending_message_count: Option<i32>,
+
+    /// An array of result objects.
+    #[serde(borrow)]
+    pub results: Option<Vec<InboundSmsReportRef<'a>>>,
+}
+
+impl<'a> InboundMessagesWebhookRef<'a> {
+    /// Returns an owned copy of the webhook body.
+    pub fn to_owned(&self) -> GetInboundReportsResponseBody {
+        GetInboundReportsResponseBody {
+            message_count: self.message_count,
+            pending_message_count: self.pending_message_count,
+            results: self
+                .results
+                .as_ref()
+                .map(|results| results.iter().map(InboundSmsReportRef::to_owned).collect()),
+        }
+    }
+}
+
+/// Parses the body of an inbound SMS webhook, borrowing its strings from `body`.
+pub fn parse_inbound_sms_borrowed(
+    body: &[u8],
+) -> Result<InboundMessagesWebhookRef<'_>, crate::api::SdkError> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+// The query string send endpoint expects all recipients in a single comma-separated `to` value.
+fn serialize_comma_separated<S: Serializer>(
+    values: &[String],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&values.join(","))
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct SendOverQueryParametersQueryParameters {
     /// Username for authentication.
     pub username: String,
@@ -1098,65 +2572,87 @@ pub struct SendOverQueryParametersQueryParameters {
 
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
     /// messages via a single API request.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
 
     /// The sender ID which can be alphanumeric or numeric (e.g., `CompanyName`).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<String>,
 
     /// List of message recipients.
+    #[serde(serialize_with = "serialize_comma_separated")]
     pub to: Vec<String>,
 
     /// Content of the message being sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// Sends a flash SMS if set to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub flash: Option<bool>,
 
     /// Conversion of a message text from one script to another.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transliteration: Option<String>,
 
-    /// Code for language character set of a message content.
+    /// Code for language character set of a message content, one of the `LanguageCode` values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_language_code")]
     pub language_code: Option<String>,
 
     /// Use a real-time intermediate delivery report that will be sent on your callback server.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub intermediate_report: Option<bool>,
 
     /// The URL on your call back server on to which a delivery report will be sent.
     #[validate(url)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notify_url: Option<String>,
 
     /// Preferred delivery report content type, `application/json` or `application/xml`.
-    #[validate(regex = "CONTENT_TYPES")]
-    pub notify_content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_content_type: Option<NotifyContentType>,
 
-    /// Additional client data to be sent over the notifyUrl.
+    /// Additional client data to be sent over the notifyUrl. The maximum value is 4000
+    /// characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 4000))]
     pub callback_data: Option<String>,
 
     /// The message validity period in minutes. When the period expires, it will not be allowed for
     /// the message to be sent. Validity period longer than 48h is not supported. Any bigger value
     /// will automatically default back to 2880.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub validity_period: Option<i32>,
 
     /// Date and time when the message is to be sent. Used for scheduled SMS. Has the following
     /// format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`. Must be sooner than 180 days from now.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub send_at: Option<String>,
 
     /// Sets the conversion element to be tracked.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub track: Option<String>,
 
     /// The process key which uniquely identifies conversion tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub process_key: Option<String>,
 
     /// Sets a custom conversion type naming convention, e.g. ONE_TIME_PIN, SOCIAL_INVITES, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tracking_type: Option<String>,
 
     /// The ID of your registered DLT (Distributed Ledger Technology) content template.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub india_dlt_content_template_id: Option<String>,
 
     /// Your DLT (Distributed Ledger Technology) entity id.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub india_dlt_principal_entity_id: Option<String>,
 }
 
+impl ToQueryString for SendOverQueryParametersQueryParameters {}
+
 impl SendOverQueryParametersQueryParameters {
     pub fn new(
         username: &str,
@@ -1192,7 +2688,7 @@ pub type SendOverQueryParametersResponseBody = SendResponseBody;
 
 pub type RescheduleQueryParameters = GetScheduledQueryParameters;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct RescheduleRequestBody {
     /// Date and time when the message is to be sent. Used for scheduled SMS (see Scheduled SMS
@@ -1202,6 +2698,8 @@ pub struct RescheduleRequestBody {
     pub send_at: String,
 }
 
+impl ApiRequest for RescheduleRequestBody {}
+
 impl RescheduleRequestBody {
     pub fn new(send_at: &str) -> RescheduleRequestBody {
         RescheduleRequestBody {
@@ -1213,6 +2711,7 @@ impl RescheduleRequestBody {
 pub type RescheduleResponseBody = GetScheduledResponseBody;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ScheduledStatus {
     PENDING,
     PAUSED,
@@ -1220,12 +2719,33 @@ pub enum ScheduledStatus {
     CANCELED,
     FINISHED,
     FAILED,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl ScheduledStatus {
+    /// Returns whether the status is final, so polling the bulk can stop.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ScheduledStatus::CANCELED | ScheduledStatus::FINISHED | ScheduledStatus::FAILED
+        )
+    }
+
+    /// Returns whether a scheduled bulk can be moved to this status: paused, resumed with
+    /// `PENDING`, or canceled.
+    pub fn is_valid_target(&self) -> bool {
+        matches!(
+            self,
+            ScheduledStatus::PENDING | ScheduledStatus::PAUSED | ScheduledStatus::CANCELED
+        )
+    }
 }
 
 pub type GetScheduledStatusQueryParameters = GetScheduledQueryParameters;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetScheduledStatusResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
@@ -1234,14 +2754,33 @@ pub struct GetScheduledStatusResponseBody {
     pub status: Option<ScheduledStatus>,
 }
 
+impl ApiResponse for GetScheduledStatusResponseBody {}
+
 pub type UpdateScheduledStatusQueryParameters = RescheduleQueryParameters;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_scheduled_status_target"))]
 pub struct UpdateScheduledStatusRequestBody {
+    /// New status of the bulk, one of `PENDING`, `PAUSED` or `CANCELED`.
     pub status: ScheduledStatus,
 }
 
+impl ApiRequest for UpdateScheduledStatusRequestBody {}
+
+fn validate_scheduled_status_target(
+    request_body: &UpdateScheduledStatusRequestBody,
+) -> Result<(), validator::ValidationError> {
+    if !request_body.status.is_valid_target() {
+        return Err(validation_error(
+            "status_target",
+            "status can only be updated to PENDING, PAUSED or CANCELED",
+        ));
+    }
+
+    Ok(())
+}
+
 impl UpdateScheduledStatusRequestBody {
     pub fn new(status: ScheduledStatus) -> UpdateScheduledStatusRequestBody {
         UpdateScheduledStatusRequestBody { status }
@@ -1250,8 +2789,8 @@ impl UpdateScheduledStatusRequestBody {
 
 pub type UpdateScheduledStatusResponseBody = GetScheduledStatusResponseBody;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct TfaApplicationConfiguration {
     /// Indicates whether multiple PIN verification is allowed.
     #[serde(
@@ -1307,6 +2846,9 @@ pub struct TfaApplication {
     pub name: String,
 }
 
+impl ApiRequest for TfaApplication {}
+impl ApiResponse for TfaApplication {}
+
 pub type GetTfaApplicationsResponseBody = Vec<TfaApplication>;
 
 pub type CreateTfaApplicationRequestBody = TfaApplication;
@@ -1331,6 +2873,7 @@ pub type UpdateTfaApplicationRequestBody = TfaApplication;
 pub type UpdateTfaApplicationResponseBody = TfaApplication;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TfaLanguage {
     #[serde(rename = "en")]
     En,
@@ -1376,9 +2919,12 @@ pub enum TfaLanguage {
     ZhCn,
     #[serde(rename = "zh-tw")]
     ZhTw,
+    #[serde(rename = "unknown", other)]
+    Unknown,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum PinType {
     #[serde(rename = "NUMERIC")]
     Numeric,
@@ -1388,10 +2934,12 @@ pub enum PinType {
     Hex,
     #[serde(rename = "ALPHANUMERIC")]
     Alphanumeric,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct TfaRegional {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate]
@@ -1446,6 +2994,9 @@ pub struct TfaMessageTemplate {
     pub speech_rate: Option<f64>,
 }
 
+impl ApiRequest for TfaMessageTemplate {}
+impl ApiResponse for TfaMessageTemplate {}
+
 impl TfaMessageTemplate {
     pub fn new(message_text: &str, pin_type: PinType, pin_length: i32) -> TfaMessageTemplate {
         TfaMessageTemplate {
@@ -1479,9 +3030,12 @@ pub type UpdateTfaMessageTemplateResponseBody = TfaMessageTemplate;
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct SendPinOverSmsQueryParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nc_needed: Option<bool>,
 }
 
+impl ToQueryString for SendPinOverSmsQueryParameters {}
+
 impl SendPinOverSmsQueryParameters {
     pub fn new() -> Self {
         Self { nc_needed: None }
@@ -1494,7 +3048,7 @@ impl Default for SendPinOverSmsQueryParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct SendPinOverSmsRequestBody {
     /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
@@ -1518,6 +3072,8 @@ pub struct SendPinOverSmsRequestBody {
     pub to: String,
 }
 
+impl ApiRequest for SendPinOverSmsRequestBody {}
+
 impl SendPinOverSmsRequestBody {
     pub fn new(application_id: &str, message_id: &str, to: &str) -> Self {
         Self {
@@ -1530,8 +3086,8 @@ impl SendPinOverSmsRequestBody {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SendPinResponseBody {
     /// Call status, e.g. `PENDING_ACCEPTED`.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1554,9 +3110,11 @@ pub struct SendPinResponseBody {
     pub to: Option<String>,
 }
 
+impl ApiResponse for SendPinResponseBody {}
+
 pub type SendPinOverSmsResponseBody = SendPinResponseBody;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct ResendPinRequestBody {
     /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a pin placeholder. Valid example: "placeholders":{"firstName":"John"}
@@ -1564,6 +3122,8 @@ pub struct ResendPinRequestBody {
     pub placeholders: Option<HashMap<String, String>>,
 }
 
+impl ApiRequest for ResendPinRequestBody {}
+
 impl ResendPinRequestBody {
     pub fn new() -> Self {
         Self { placeholders: None }
@@ -1588,21 +3148,23 @@ pub type ResendPinOverVoiceRequestBody = ResendPinRequestBody;
 
 pub type ResendPinOverVoiceResponseBody = SendPinResponseBody;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Validate)]
 pub struct VerifyPhoneNumberRequestBody {
     /// ID of the pin code that has to be verified.
     #[validate(length(min = 1))]
     pub pin: String,
 }
 
+impl ApiRequest for VerifyPhoneNumberRequestBody {}
+
 impl VerifyPhoneNumberRequestBody {
     pub fn new(pin: &str) -> Self {
         Self { pin: pin.into() }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct VerifyPhoneNumberResponseBody {
     /// Number of remaining PIN attempts.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1621,7 +3183,10 @@ pub struct VerifyPhoneNumberResponseBody {
     pub verified: Option<bool>,
 }
 
+impl ApiResponse for VerifyPhoneNumberResponseBody {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct GetTfaVerificationStatusQueryParameters {
     /// Filter by msisdn (phone number) for which verification status is checked.
     pub msisdn: String,
@@ -1635,6 +3200,8 @@ pub struct GetTfaVerificationStatusQueryParameters {
     pub sent: Option<bool>,
 }
 
+impl ToQueryString for GetTfaVerificationStatusQueryParameters {}
+
 impl GetTfaVerificationStatusQueryParameters {
     pub fn new(msisdn: &str) -> Self {
         Self {
@@ -1645,8 +3212,8 @@ impl GetTfaVerificationStatusQueryParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct TfaVerification {
     /// Phone number (MSISDN) for which verification status is checked.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1665,10 +3232,12 @@ pub struct TfaVerification {
     pub verified_at: Option<i64>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetTfaVerificationStatusResponseBody {
     /// Collection of verifications
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verifications: Option<Vec<TfaVerification>>,
 }
+
+impl ApiResponse for GetTfaVerificationStatusResponseBody {}
@@ -0,0 +1,67 @@
+use crate::model::codes::*;
+
+#[test]
+fn status_by_id_known_codes() {
+    let cases = [
+        (2, "DELIVERED_TO_OPERATOR", "DELIVERED"),
+        (5, "DELIVERED_TO_HANDSET", "DELIVERED"),
+        (7, "PENDING_ENROUTE", "PENDING"),
+        (9, "UNDELIVERABLE_NOT_DELIVERED", "UNDELIVERABLE"),
+        (12, "REJECTED_NOT_ENOUGH_CREDITS", "REJECTED"),
+        (15, "EXPIRED_EXPIRED", "EXPIRED"),
+        (26, "PENDING_ACCEPTED", "PENDING"),
+        (51, "MISSING_TO", "REJECTED"),
+    ];
+
+    for (id, name, group_name) in cases {
+        let status = status_by_id(id).unwrap();
+        assert_eq!(status.name, name);
+        assert_eq!(status.group_name, group_name);
+    }
+}
+
+#[test]
+fn error_by_id_known_codes() {
+    let cases = [
+        (0, "NO_ERROR", "OK", false),
+        (1, "EC_UNKNOWN_SUBSCRIBER", "HANDSET_ERRORS", true),
+        (6, "EC_ABSENT_SUBSCRIBER_SM", "HANDSET_ERRORS", false),
+        (13, "EC_CALL_BARRED", "HANDSET_ERRORS", false),
+        (34, "EC_SYSTEM_FAILURE", "OPERATOR_ERRORS", false),
+        (2050, "EC_SPAM_COMPLAINT", "USER_ERRORS", true),
+        (5480, "EC_VOICE_NO_ANSWER", "USER_ERRORS", true),
+        (7003, "EC_UNREGISTERED_USER", "HANDSET_ERRORS", true),
+    ];
+
+    for (id, name, group_name, permanent) in cases {
+        let error = error_by_id(id).unwrap();
+        assert_eq!(error.name, name);
+        assert_eq!(error.group_name, group_name);
+        assert_eq!(error.permanent, permanent);
+    }
+}
+
+#[test]
+fn unknown_codes() {
+    assert!(status_by_id(9999).is_none());
+    assert!(error_by_id(-1).is_none());
+}
+
+#[test]
+fn tables_sorted_by_id() {
+    assert!(STATUS_CODES.windows(2).all(|pair| pair[0].id < pair[1].id));
+    assert!(ERROR_CODES.windows(2).all(|pair| pair[0].id < pair[1].id));
+}
+
+#[cfg(feature = "sms")]
+#[test]
+fn sms_status_and_error_info() {
+    use crate::model::sms::{Error, Status};
+
+    let status: Status =
+        serde_json::from_str(r#"{"groupId": 3, "id": 5, "name": "DELIVERED_TO_HANDSET"}"#).unwrap();
+    let error: Error = serde_json::from_str(r#"{"id": 27}"#).unwrap();
+
+    assert_eq!(status.info().unwrap().group_name, "DELIVERED");
+    assert_eq!(error.info().unwrap().name, "EC_ABSENT_SUBSCRIBER");
+}
@@ -0,0 +1,118 @@
+use crate::model::common::Recipient;
+use crate::model::email::EmailRecipient;
+use crate::model::sms::Destination;
+
+#[test]
+fn recipient_validated_for_each_channel() {
+    // (recipient, valid for SMS, valid for WhatsApp, valid for email)
+    let cases = [
+        (
+            Recipient::Msisdn("41793026727".to_string()),
+            true,
+            true,
+            false,
+        ),
+        (
+            Recipient::Msisdn("+41793026727".to_string()),
+            true,
+            false,
+            false,
+        ),
+        (Recipient::Msisdn("1234".to_string()), true, false, false),
+        (
+            Recipient::Msisdn("0041793026727".to_string()),
+            true,
+            false,
+            false,
+        ),
+        (
+            Recipient::Msisdn("4179302672700000".to_string()),
+            false,
+            false,
+            false,
+        ),
+        (
+            Recipient::Msisdn("41 79 302 67 27".to_string()),
+            false,
+            false,
+            false,
+        ),
+        (
+            Recipient::Email("john@company.com".to_string()),
+            false,
+            false,
+            true,
+        ),
+        (
+            Recipient::Email("John <john@company.com>".to_string()),
+            false,
+            false,
+            true,
+        ),
+        (
+            Recipient::Email("john@company.com, jane@company.com".to_string()),
+            false,
+            false,
+            false,
+        ),
+        (Recipient::Email("john".to_string()), false, false, false),
+    ];
+
+    for (recipient, sms, whatsapp, email) in cases {
+        assert_eq!(recipient.validate_for_sms().is_ok(), sms, "{:?}", recipient);
+        assert_eq!(
+            recipient.validate_for_whatsapp().is_ok(),
+            whatsapp,
+            "{:?}",
+            recipient
+        );
+        assert_eq!(
+            recipient.validate_for_email().is_ok(),
+            email,
+            "{:?}",
+            recipient
+        );
+    }
+}
+
+#[test]
+fn recipient_wrong_channel_error_code() {
+    let number = Recipient::Msisdn("41793026727".to_string());
+    let address = Recipient::Email("john@company.com".to_string());
+
+    assert_eq!(
+        number.validate_for_email().unwrap_err().code,
+        "recipient_channel"
+    );
+    assert_eq!(
+        address.validate_for_sms().unwrap_err().code,
+        "recipient_channel"
+    );
+    assert_eq!(
+        Recipient::Msisdn("+41793026727".to_string())
+            .validate_for_whatsapp()
+            .unwrap_err()
+            .code,
+        "recipient_number"
+    );
+}
+
+#[test]
+fn recipient_conversions() {
+    let number = Recipient::Msisdn("41793026727".to_string());
+    let address = Recipient::Email("john@company.com".to_string());
+
+    assert_eq!(
+        Destination::try_from(number.clone()).unwrap(),
+        Destination::new("41793026727")
+    );
+    assert_eq!(number.to_whatsapp_number().unwrap(), "41793026727");
+    assert_eq!(
+        EmailRecipient::try_from(address.clone()).unwrap(),
+        EmailRecipient::new("john@company.com")
+    );
+
+    assert!(Destination::try_from(address.clone()).is_err());
+    assert!(address.to_whatsapp_number().is_err());
+    assert!(EmailRecipient::try_from(number).is_err());
+}
@@ -1,6 +1,11 @@
 use validator::Validate;
 
+use crate::model::common::{NotifyContentType, Paged, RetryAdvice, DEFAULT_LONG_RETRY_DELAY};
 use crate::model::email::*;
+use crate::model::tests::round_trip::{
+    assert_deserializes_empty, assert_round_trip, assert_wire_names, load_fixture, read_fixture,
+};
+use crate::model::{SendOutcome, ToQueryString, ValidateStrict, WireNames};
 
 pub fn get_dummy_send_email_request_body() -> SendRequestBody {
     let mut request = SendRequestBody::new("some@company.com");
@@ -16,7 +21,7 @@ pub fn get_dummy_send_email_request_body() -> SendRequestBody {
     request.inline_images = Some(vec!["../../../tests/image.png".to_string()]);
     request.notify_url = Some("https://some.url".to_string());
     request.intermediate_report = Some(true);
-    request.notify_content_type = Some("application/json".to_string());
+    request.notify_content_type = Some(NotifyContentType::Json);
     request.callback_data = Some("some data".to_string());
     request.track = Some(true);
     request.track_clicks = Some(true);
@@ -31,6 +36,7 @@ pub fn get_dummy_send_email_request_body() -> SendRequestBody {
     request.landing_page_placeholders =
         Some(r#"landingPagePlaceholders={"ph1": "Success"}"#.to_string());
     request.landing_page_id = Some("some-landing-page-id".to_string());
+    request.client_priority = Some(ClientPriority::High);
 
     request
 }
@@ -42,6 +48,30 @@ fn test_send_request_valid() {
     assert!(request_body.validate().is_ok());
 }
 
+#[test]
+fn test_send_request_client_priority_serialization() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+
+    assert!(serde_json::to_value(&request_body)
+        .unwrap()
+        .get("clientPriority")
+        .is_none());
+
+    for (priority, wire) in [
+        (ClientPriority::High, "HIGH"),
+        (ClientPriority::Medium, "MEDIUM"),
+        (ClientPriority::Low, "LOW"),
+    ] {
+        request_body.client_priority = Some(priority);
+
+        assert_eq!(
+            serde_json::to_value(&request_body).unwrap()["clientPriority"],
+            wire
+        );
+        assert_eq!(priority.as_str(), wire);
+    }
+}
+
 #[test]
 fn test_send_request_valid_full() {
     let request_body = get_dummy_send_email_request_body();
@@ -157,3 +187,1604 @@ fn test_add_domain_request_body_no_domain() {
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn test_get_bulks_query_parameters_query_string() {
+    let query_params = GetBulksQueryParameters::new("some-bulk-id");
+
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "bulkId=some-bulk-id"
+    );
+}
+
+#[test]
+fn test_get_bulks_query_parameters_external_bulk_id_query_string() {
+    let query_params = GetBulksQueryParameters::with_external_bulk_id("some-external-id");
+
+    assert!(query_params.validate().is_ok());
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "externalBulkId=some-external-id"
+    );
+}
+
+#[test]
+fn test_get_bulks_query_parameters_both_ids() {
+    let mut query_params = GetBulksQueryParameters::new("some-bulk-id");
+    query_params.external_bulk_id = Some("some-external-id".to_string());
+
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_bulks_query_parameters_no_ids() {
+    let mut query_params = GetBulksQueryParameters::new("some-bulk-id");
+    query_params.bulk_id = None;
+
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_delivery_reports_query_parameters_query_string() {
+    let mut query_params = GetDeliveryReportsQueryParameters::new();
+    query_params.bulk_id = Some("some-bulk-id".to_string());
+    query_params.message_id = Some("some message/id".to_string());
+    query_params.limit = Some(10);
+
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "bulkId=some-bulk-id&limit=10&messageId=some+message%2Fid"
+    );
+}
+
+#[test]
+fn test_get_delivery_reports_query_parameters_all_filters_query_string() {
+    let mut query_params = GetDeliveryReportsQueryParameters::new();
+    query_params.bulk_id = Some("some-bulk-id".to_string());
+    query_params.message_id = Some("some-message-id".to_string());
+    query_params.limit = Some(10);
+    query_params.campaign_reference_id = Some("summer-sale".to_string());
+    query_params.application_id = Some("some-application".to_string());
+    query_params.entity_id = Some("some-entity".to_string());
+    query_params.sent_since = Some("2022-10-01T00:00:00.000+0000".to_string());
+    query_params.sent_until = Some("2022-10-02T00:00:00.000+0000".to_string());
+
+    assert!(query_params.validate().is_ok());
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "applicationId=some-application&bulkId=some-bulk-id&campaignReferenceId=summer-sale\
+         &entityId=some-entity&limit=10&messageId=some-message-id\
+         &sentSince=2022-10-01T00%3A00%3A00.000%2B0000&sentUntil=2022-10-02T00%3A00%3A00.000%2B0000"
+    );
+}
+
+#[test]
+fn test_get_delivery_reports_query_parameters_inverted_time_range() {
+    let mut query_params = GetDeliveryReportsQueryParameters::new();
+    query_params.sent_since = Some("2022-10-02T00:00:00.000+0000".to_string());
+    query_params.sent_until = Some("2022-10-01T00:00:00.000+0000".to_string());
+
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_delivery_reports_filtered_response_body() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>(
+        "email/get_delivery_reports_filtered_response.json",
+    );
+
+    let response_body: GetDeliveryReportsResponseBody = serde_json::from_value(load_fixture(
+        "email/get_delivery_reports_filtered_response.json",
+    ))
+    .unwrap();
+    let report = &response_body.results.unwrap()[0];
+
+    assert_eq!(report.campaign_reference_id.as_deref(), Some("summer-sale"));
+    assert_eq!(
+        report.application_id.as_deref(),
+        Some("marketing-automation-application")
+    );
+    assert_eq!(report.entity_id.as_deref(), Some("promotional-traffic"));
+}
+
+#[test]
+fn test_get_delivery_reports_query_parameters_empty_query_string() {
+    let query_params = GetDeliveryReportsQueryParameters::new();
+
+    assert_eq!(query_params.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn test_get_logs_query_parameters_query_string() {
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.from = Some("john@company.com".to_string());
+    query_params.general_status = Some("DELIVERED".to_string());
+    query_params.sent_since = Some("2022-10-01T00:00:00.000+0000".to_string());
+
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "from=john%40company.com&generalStatus=DELIVERED&sentSince=2022-10-01T00%3A00%3A00.000%2B0000"
+    );
+}
+
+#[test]
+fn test_get_logs_query_parameters_tenant_filters_query_string() {
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.application_id = Some("marketing automation".to_string());
+    query_params.entity_id = Some("tenant-42".to_string());
+    query_params.campaign_reference_id = Some("spring&sale".to_string());
+
+    assert!(query_params.validate().is_ok());
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "applicationId=marketing+automation&campaignReferenceId=spring%26sale&entityId=tenant-42"
+    );
+}
+
+#[test]
+fn test_get_logs_query_parameters_tenant_filters_length() {
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.entity_id = Some(String::new());
+    assert!(query_params.validate().is_err());
+
+    query_params.entity_id = Some("x".repeat(255));
+    assert!(query_params.validate().is_ok());
+
+    query_params.application_id = Some("x".repeat(256));
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_logs_query_parameters_empty_query_string() {
+    let query_params = GetLogsQueryParameters::new();
+
+    assert_eq!(query_params.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn test_get_domains_query_parameters_query_string() {
+    let mut query_params = GetDomainsQueryParameters::new();
+    query_params.size = Some(5);
+    query_params.page = Some(2);
+
+    assert_eq!(query_params.to_query_string().unwrap(), "page=2&size=5");
+}
+
+#[test]
+fn test_get_domains_query_parameters_empty_query_string() {
+    let query_params = GetDomainsQueryParameters::new();
+
+    assert_eq!(query_params.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn test_send_response_body_round_trip() {
+    assert_round_trip::<SendResponseBody>("email/send_response.json");
+}
+
+#[test]
+fn test_get_delivery_reports_response_body_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("email/get_delivery_reports_response.json");
+}
+
+#[test]
+fn test_get_logs_response_body_round_trip() {
+    assert_round_trip::<GetLogsResponseBody>("email/get_logs_response.json");
+}
+
+#[test]
+fn test_get_logs_response_body_tenant_fields() {
+    assert_round_trip::<GetLogsResponseBody>("email/get_logs_tenant_response.json");
+
+    let response: GetLogsResponseBody =
+        serde_json::from_value(load_fixture("email/get_logs_tenant_response.json")).unwrap();
+    let log = &response.results.unwrap()[0];
+    assert_eq!(log.application_id.as_deref(), Some("marketing-automation"));
+    assert_eq!(log.entity_id.as_deref(), Some("tenant-42"));
+    assert_eq!(log.campaign_reference_id.as_deref(), Some("spring-sale"));
+
+    let response: GetLogsResponseBody =
+        serde_json::from_value(load_fixture("email/get_logs_response.json")).unwrap();
+    let log = &response.results.unwrap()[0];
+    assert_eq!(log.application_id, None);
+    assert_eq!(log.entity_id, None);
+    assert_eq!(log.campaign_reference_id, None);
+}
+
+#[test]
+fn test_get_domains_response_body_round_trip() {
+    assert_round_trip::<GetDomainsResponseBody>("email/get_domains_response.json");
+}
+
+#[test]
+fn test_get_scheduled_messages_response_body_round_trip() {
+    assert_round_trip::<GetScheduledMessagesResponseBody>(
+        "email/get_scheduled_messages_response.json",
+    );
+}
+
+#[test]
+fn test_get_scheduled_messages_response_body() {
+    let response: GetScheduledMessagesResponseBody =
+        serde_json::from_value(load_fixture("email/get_scheduled_messages_response.json")).unwrap();
+    let message = &response.results.as_ref().unwrap()[0];
+
+    assert_eq!(response.items_len(), 2);
+    assert_eq!(response.paging().unwrap().total_results, Some(2));
+    assert_eq!(message.message_id.as_deref(), Some("some-message-id"));
+    assert_eq!(message.to.as_deref(), Some("john.smith@somedomain.com"));
+    assert_eq!(
+        message.send_at_datetime().unwrap().to_rfc3339(),
+        "2022-10-01T12:30:00+00:00"
+    );
+    assert_eq!(message.status.as_ref().unwrap().group_id, Some(1));
+
+    let message: ScheduledMessage = serde_json::from_str(r#"{"sendAt": "1664627400000"}"#).unwrap();
+    assert_eq!(message.send_at, Some(1664627400000));
+}
+
+#[test]
+fn test_get_scheduled_messages_query_parameters() {
+    let mut query_params = GetScheduledMessagesQueryParameters::new("some bulk&id");
+    query_params.page = Some(0);
+    query_params.size = Some(MAX_SCHEDULED_MESSAGES_PAGE_SIZE);
+
+    assert!(query_params.validate().is_ok());
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "bulkId=some+bulk%26id&page=0&size=1000"
+    );
+
+    query_params.size = Some(MAX_SCHEDULED_MESSAGES_PAGE_SIZE + 1);
+    assert!(query_params.validate().is_err());
+    query_params.size = Some(0);
+    assert!(query_params.validate().is_err());
+    query_params.size = None;
+    query_params.page = Some(-1);
+    assert!(query_params.validate().is_err());
+    assert!(GetScheduledMessagesQueryParameters::new("")
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn test_domain_dkim_key_length() {
+    let response: GetDomainsResponseBody =
+        serde_json::from_slice(&read_fixture("email/get_domains_response.json")).unwrap();
+    let domain = &response.results.unwrap()[0];
+    assert_eq!(domain.dkim_key_length, Some(DkimKeyLength::L2048));
+
+    let domain: Domain = serde_json::from_str(r#"{"dkimKeyLength": "1024"}"#).unwrap();
+    assert_eq!(domain.dkim_key_length, Some(DkimKeyLength::L1024));
+
+    let domain: Domain = serde_json::from_str(r#"{"dkimKeyLength": 4096}"#).unwrap();
+    assert_eq!(domain.dkim_key_length, Some(DkimKeyLength::Unknown));
+    assert_eq!(
+        serde_json::to_value(&domain).unwrap(),
+        serde_json::json!({"dkimKeyLength": null})
+    );
+}
+
+#[test]
+fn test_dkim_key_length_unknown_rejected_in_requests() {
+    let mut request_body = AddDomainRequestBody::new("example.com");
+    request_body.dkim_key_length = Some(DkimKeyLength::Unknown);
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_get_logs_query_parameters_range_at_retention_boundary() {
+    let until = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.sent_since = Some((until - chrono::Duration::hours(48)).to_rfc3339());
+    query_params.sent_until = Some(until.to_rfc3339());
+
+    assert!(query_params.validate().is_ok());
+}
+
+#[test]
+fn test_get_logs_query_parameters_range_over_retention() {
+    let until = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.sent_since = Some((until - chrono::Duration::hours(49)).to_rfc3339());
+    query_params.sent_until = Some(until.to_rfc3339());
+
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_logs_query_parameters_since_only_over_retention() {
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.sent_since = Some((chrono::Utc::now() - chrono::Duration::hours(47)).to_rfc3339());
+    assert!(query_params.validate().is_ok());
+
+    query_params.sent_since = Some((chrono::Utc::now() - chrono::Duration::hours(49)).to_rfc3339());
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_logs_query_parameters_until_only() {
+    let mut query_params = GetLogsQueryParameters::new();
+    query_params.sent_until = Some((chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339());
+
+    assert!(query_params.validate().is_ok());
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn test_parse_delivery_reports_xml_matches_json() {
+    use crate::model::tests::round_trip::{load_fixture, read_fixture};
+
+    let from_xml = parse_delivery_reports_xml(&read_fixture("email/delivery_reports.xml")).unwrap();
+    let from_json: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture("email/get_delivery_reports_response.json")).unwrap();
+
+    assert_eq!(from_xml, from_json);
+}
+
+#[test]
+fn test_landing_page_id_from_url() {
+    let landing_page_id =
+        landing_page_id_from_url("https://portal.infobip.com/email/landing-pages/8F2A1C9D4E7B/")
+            .unwrap();
+
+    assert_eq!(landing_page_id, "9D4E7B");
+    assert_eq!(landing_page_id.len(), LANDING_PAGE_ID_LENGTH);
+
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.landing_page_id = Some(landing_page_id);
+    assert!(request_body.validate().is_ok());
+
+    let serialized = serde_json::to_value(&request_body).unwrap();
+    assert_eq!(serialized["landingPageId"], "9D4E7B");
+}
+
+#[test]
+fn test_landing_page_id_from_url_invalid() {
+    assert!(landing_page_id_from_url("https://portal.infobip.com/email/landing-pages/").is_none());
+    assert!(landing_page_id_from_url("https://portal.infobip.com/a1b2").is_none());
+    assert!(landing_page_id_from_url("https://portal.infobip.com/abc-123456").is_none());
+}
+
+#[test]
+fn test_get_suppressions_query_parameters_query_string() {
+    let mut query_params =
+        GetSuppressionsQueryParameters::new("example.com", SuppressionType::UNSUBSCRIBE);
+    query_params.page = Some(0);
+    query_params.size = Some(500);
+
+    assert!(query_params.validate().is_ok());
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "domainName=example.com&page=0&size=500&type=UNSUBSCRIBE"
+    );
+}
+
+#[test]
+fn test_get_suppressions_query_parameters_no_domain() {
+    let query_params = GetSuppressionsQueryParameters::new("", SuppressionType::BOUNCE);
+
+    assert!(query_params.validate().is_err());
+}
+
+#[test]
+fn test_get_suppressions_response_body() {
+    let response_body: GetSuppressionsResponseBody = serde_json::from_str(
+        r#"{
+          "results": [
+            {
+              "domainName": "example.com",
+              "emailAddress": "jane.smith@somecompany.com",
+              "type": "BOUNCE",
+              "createdDate": "2024-08-14T14:02:17.366",
+              "reason": "550 5.1.1 mailbox does not exist"
+            },
+            {"emailAddress": "john@somecompany.com", "type": "SOMETHING_NEW"}
+          ],
+          "paging": {"page": 0, "size": 100}
+        }"#,
+    )
+    .unwrap();
+
+    let results = response_body.results.unwrap();
+    assert_eq!(results[0].suppression_type, Some(SuppressionType::BOUNCE));
+    assert_eq!(results[1].suppression_type, Some(SuppressionType::Unknown));
+}
+
+fn get_dummy_recipients() -> Vec<EmailRecipient> {
+    let mut john = EmailRecipient::new("john@company.com");
+    john.placeholders
+        .insert("name".to_string(), "John".to_string());
+    let mut jane = EmailRecipient::new("jane@company.com");
+    jane.placeholders
+        .insert("name".to_string(), "Jane".to_string());
+
+    vec![john, jane]
+}
+
+#[test]
+fn test_send_request_recipients_with_template_valid() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.template_id = Some(2);
+    request_body.recipients = Some(get_dummy_recipients());
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn test_send_request_recipients_placeholders_without_template() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.recipients = Some(get_dummy_recipients());
+
+    let errors = request_body.validate().unwrap_err();
+    assert!(errors.to_string().contains("template_id"));
+}
+
+#[test]
+fn test_send_request_recipients_without_placeholders_no_template() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.recipients = Some(vec![EmailRecipient::new("john@company.com")]);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn test_send_request_no_recipient() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.recipients = Some(vec![]);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_send_response_details_for_recipients() {
+    let recipients = get_dummy_recipients();
+    let response_body: SendResponseBody = serde_json::from_str(
+        r#"{
+          "bulkId": "some-bulk-id",
+          "messages": [
+            {"to": "Jane@Company.com", "messageId": "jane-message-id"},
+            {"to": "john@company.com", "messageId": "john-message-id"}
+          ]
+        }"#,
+    )
+    .unwrap();
+
+    let details = response_body.details_for(&recipients);
+
+    assert_eq!(details.len(), 2);
+    assert_eq!(details[0].0.to, "john@company.com");
+    assert_eq!(
+        details[0].1.unwrap().message_id.as_deref(),
+        Some("john-message-id")
+    );
+    assert_eq!(details[1].0.to, "jane@company.com");
+    assert_eq!(
+        details[1].1.unwrap().message_id.as_deref(),
+        Some("jane-message-id")
+    );
+}
+
+fn get_dummy_tenant_recipients() -> Vec<EmailRecipient> {
+    let mut john = EmailRecipient::new("john@company.com");
+    john.message_id = Some("john-message-id".to_string());
+    john.callback_data = Some("tenant-a".to_string());
+    let mut jane = EmailRecipient::new("jane@company.com");
+    jane.message_id = Some("jane-message-id".to_string());
+    jane.callback_data = Some("tenant-b".to_string());
+
+    vec![john, jane]
+}
+
+#[test]
+fn test_send_request_recipients_callback_data_valid() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.recipients = Some(get_dummy_tenant_recipients());
+
+    assert!(request_body.validate().is_ok());
+    assert!(request_body.validate_strict().is_ok());
+}
+
+#[test]
+fn test_email_recipient_callback_data_serialization() {
+    let recipients = get_dummy_tenant_recipients();
+
+    assert_eq!(
+        serde_json::to_value(&recipients).unwrap(),
+        serde_json::json!([
+            {
+                "to": "john@company.com",
+                "messageId": "john-message-id",
+                "callbackData": "tenant-a"
+            },
+            {
+                "to": "jane@company.com",
+                "messageId": "jane-message-id",
+                "callbackData": "tenant-b"
+            }
+        ])
+    );
+    assert_eq!(
+        serde_json::to_value(EmailRecipient::new("john@company.com")).unwrap(),
+        serde_json::json!({"to": "john@company.com"})
+    );
+}
+
+#[test]
+fn test_send_request_recipient_callback_data_too_long() {
+    let mut recipients = get_dummy_tenant_recipients();
+    recipients[1].callback_data = Some("a".repeat(4001));
+    let mut request_body = SendRequestBody::new("");
+    request_body.recipients = Some(recipients);
+
+    assert!(request_body.validate().is_err());
+
+    request_body.recipients.as_mut().unwrap()[1].callback_data = Some("a".repeat(4000));
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn test_send_request_strict_recipient_callback_data_control_characters() {
+    let mut recipients = get_dummy_tenant_recipients();
+    recipients[0].callback_data = Some("tenant\na".to_string());
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.recipients = Some(recipients);
+
+    assert_strict_error(&request_body, "callback_data");
+}
+
+#[test]
+fn test_report_callback_data() {
+    let report: Report = serde_json::from_str(
+        r#"{"messageId": "jane-message-id", "to": "jane@company.com", "callbackData": "tenant-b"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(report.callback_data.as_deref(), Some("tenant-b"));
+    assert_eq!(
+        serde_json::to_value(&report).unwrap()["callbackData"],
+        "tenant-b"
+    );
+    assert_eq!(
+        serde_json::from_str::<Report>(r#"{"callbackData": ""}"#)
+            .unwrap()
+            .callback_data,
+        None
+    );
+}
+
+#[test]
+fn test_get_delivery_reports_query_parameters_limit_bounds() {
+    let mut query_params = GetDeliveryReportsQueryParameters::new();
+
+    query_params.limit = Some(0);
+    assert!(query_params.validate().is_err());
+    query_params.limit = Some(1);
+    assert!(query_params.validate().is_ok());
+    query_params.limit = Some(GetDeliveryReportsQueryParameters::MAX_LIMIT);
+    assert!(query_params.validate().is_ok());
+    query_params.limit = Some(GetDeliveryReportsQueryParameters::MAX_LIMIT + 1);
+    let errors = query_params.validate().unwrap_err();
+    assert!(errors.to_string().contains(&format!(
+        "between 1 and {}",
+        GetDeliveryReportsQueryParameters::MAX_LIMIT
+    )));
+}
+
+fn get_dummy_placeholders() -> std::collections::HashMap<String, String> {
+    [("name", "John"), ("company", "Acme")]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_render_placeholders() {
+    let rendered = render_placeholders(
+        "<p>Hi {{name}}, welcome to {{ company }}!</p>",
+        &get_dummy_placeholders(),
+    )
+    .unwrap();
+
+    assert_eq!(rendered, "<p>Hi John, welcome to Acme!</p>");
+}
+
+#[test]
+fn test_render_placeholders_nested_braces() {
+    let placeholders = get_dummy_placeholders();
+
+    assert_eq!(
+        render_placeholders("{{{name}}}", &placeholders).unwrap(),
+        "{John}"
+    );
+    assert_eq!(
+        render_placeholders("{ {{company}} } {x}", &placeholders).unwrap(),
+        "{ Acme } {x}"
+    );
+    assert_eq!(
+        render_placeholders("{{name{{company}}}}", &placeholders).unwrap(),
+        "{{nameAcme}}"
+    );
+}
+
+#[test]
+fn test_render_placeholders_value_not_rendered_again() {
+    let mut placeholders = get_dummy_placeholders();
+    placeholders.insert("name".to_string(), "{{company}}".to_string());
+
+    assert_eq!(
+        render_placeholders("Hi {{name}}", &placeholders).unwrap(),
+        "Hi {{company}}"
+    );
+}
+
+#[test]
+fn test_render_placeholders_missing_keys() {
+    let error = render_placeholders(
+        "{{name}} {{surname}} {{title}} {{surname}}",
+        &get_dummy_placeholders(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        RenderError::MissingPlaceholders(vec!["surname".to_string(), "title".to_string()])
+    );
+    assert_eq!(
+        error.to_string(),
+        "missing values for placeholders: surname, title"
+    );
+}
+
+fn assert_strict_error(request_body: &SendRequestBody, field: &str) {
+    assert!(request_body.validate().is_ok());
+
+    let errors = request_body.validate_strict().unwrap_err();
+    assert!(errors.field_errors().contains_key(field));
+}
+
+#[test]
+fn test_send_request_strict_valid() {
+    let request_body = get_dummy_send_email_request_body();
+
+    assert!(request_body.validate_strict().is_ok());
+}
+
+#[test]
+fn test_send_request_strict_long_from() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.from = Some(format!("{}@company.com", "j".repeat(250)));
+
+    assert_strict_error(&request_body, "from");
+}
+
+#[test]
+fn test_send_request_strict_callback_data_control_characters() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.callback_data = Some("some\ndata".to_string());
+
+    assert_strict_error(&request_body, "callback_data");
+}
+
+#[test]
+fn test_send_request_notify_content_type_unsupported() {
+    let result = serde_json::from_str::<SendRequestBody>(
+        r#"{"to": "some@company.com", "notifyContentType": "text/plain"}"#,
+    );
+
+    assert!(result.is_err());
+}
+
+fn get_dummy_report_error(id: i32, permanent: Option<bool>) -> ReportError {
+    ReportError {
+        id: Some(id),
+        permanent,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_report_error_classify_table() {
+    let cases = [
+        (1, BounceClass::HardBounce),
+        (27, BounceClass::SoftBounce),
+        (2050, BounceClass::Complaint),
+        (13, BounceClass::Blocked),
+    ];
+
+    for (id, class) in cases {
+        assert_eq!(get_dummy_report_error(id, None).classify(), class);
+    }
+}
+
+#[test]
+fn test_report_error_classify_table_over_permanent() {
+    assert_eq!(
+        get_dummy_report_error(27, Some(true)).classify(),
+        BounceClass::SoftBounce
+    );
+}
+
+#[test]
+fn test_report_error_classify_permanent_fallback() {
+    assert_eq!(
+        get_dummy_report_error(9999, Some(true)).classify(),
+        BounceClass::HardBounce
+    );
+    assert_eq!(
+        get_dummy_report_error(9999, Some(false)).classify(),
+        BounceClass::SoftBounce
+    );
+}
+
+#[test]
+fn test_report_error_classify_unknown() {
+    assert_eq!(
+        get_dummy_report_error(9999, None).classify(),
+        BounceClass::Unknown
+    );
+    assert_eq!(ReportError::default().classify(), BounceClass::Unknown);
+}
+
+#[test]
+fn test_report_error_retry_advice_soft_bounce() {
+    assert_eq!(
+        get_dummy_report_error(27, None).retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_LONG_RETRY_DELAY)
+    );
+    assert_eq!(
+        get_dummy_report_error(9999, Some(false)).retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_LONG_RETRY_DELAY)
+    );
+}
+
+#[test]
+fn test_report_error_retry_advice_permanent() {
+    assert_eq!(
+        get_dummy_report_error(1, None).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+    assert_eq!(
+        get_dummy_report_error(27, Some(true)).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+}
+
+#[test]
+fn test_report_error_retry_advice_unrecognized() {
+    assert_eq!(
+        get_dummy_report_error(9999, None).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+    assert_eq!(
+        ReportError::default().retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+}
+
+#[test]
+fn test_send_request_one_click_unsubscribe() {
+    let request_body = SendRequestBody::new("someone@company.com")
+        .with_one_click_unsubscribe(
+            "unsubscribe@company.com",
+            "https://company.com/unsubscribe",
+            false,
+        )
+        .unwrap();
+
+    let headers = request_body.headers.unwrap();
+    assert_eq!(
+        headers["List-Unsubscribe"],
+        "<mailto:unsubscribe@company.com>, <https://company.com/unsubscribe>"
+    );
+    assert_eq!(
+        headers["List-Unsubscribe-Post"],
+        "List-Unsubscribe=One-Click"
+    );
+}
+
+#[test]
+fn test_send_request_one_click_unsubscribe_invalid() {
+    let request_body = SendRequestBody::new("someone@company.com");
+
+    assert!(request_body
+        .clone()
+        .with_one_click_unsubscribe(
+            "mailto:not-an-address",
+            "https://company.com/unsubscribe",
+            false
+        )
+        .is_err());
+    assert!(request_body
+        .with_one_click_unsubscribe(
+            "unsubscribe@company.com",
+            "http://company.com/unsubscribe",
+            false
+        )
+        .is_err());
+}
+
+#[test]
+fn test_send_request_one_click_unsubscribe_landing_page() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.landing_page_id = Some("9D4E7B".to_string());
+
+    assert!(request_body
+        .clone()
+        .with_one_click_unsubscribe(
+            "unsubscribe@company.com",
+            "https://company.com/unsubscribe",
+            false
+        )
+        .is_err());
+
+    let request_body = request_body
+        .with_one_click_unsubscribe(
+            "mailto:unsubscribe@company.com",
+            "https://company.com/unsubscribe",
+            true,
+        )
+        .unwrap();
+    assert_eq!(
+        request_body.headers.unwrap()["List-Unsubscribe"],
+        "<mailto:unsubscribe@company.com>, <https://company.com/unsubscribe>"
+    );
+}
+
+#[test]
+fn test_price_no_precision_loss() {
+    let json = r#"{"pricePerMessage":0.0051,"currency":"EUR"}"#;
+
+    let price: Price = serde_json::from_str(json).unwrap();
+
+    assert_eq!(price.price_per_message_f64(), Some(0.0051));
+    assert_eq!(serde_json::to_string(&price).unwrap(), json);
+}
+
+#[test]
+fn test_price_equality() {
+    let price: Price = serde_json::from_str(r#"{"pricePerMessage":0.0051}"#).unwrap();
+    let same: Price = serde_json::from_str(r#"{"pricePerMessage":0.00510}"#).unwrap();
+    let other: Price = serde_json::from_str(r#"{"pricePerMessage":0.0052}"#).unwrap();
+
+    assert_eq!(price, same);
+    assert_ne!(price, other);
+}
+
+#[test]
+fn test_report_error_classify_uses_known_permanent_flag() {
+    assert_eq!(
+        get_dummy_report_error(7, None).classify(),
+        BounceClass::SoftBounce
+    );
+    assert_eq!(
+        get_dummy_report_error(12, None).classify(),
+        BounceClass::HardBounce
+    );
+}
+
+#[test]
+fn test_parse_unsubscribe_events_batch() {
+    let events = parse_unsubscribe_events(&read_fixture("email/unsubscribe_events.json")).unwrap();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[0].email_address.as_deref(),
+        Some("jane.doe@example.com")
+    );
+    assert_eq!(
+        events[0].domain_name.as_deref(),
+        Some("newsletter.example.com")
+    );
+    assert_eq!(events[0].bulk_id.as_deref(), Some("snxemd8u52byvsvn4x6r"));
+    assert_eq!(
+        events[0].campaign_reference_id.as_deref(),
+        Some("spring-sale-2023")
+    );
+    assert_eq!(
+        events[0].landing_page_id.as_deref(),
+        Some("6B2E6E5E3C6C0A1B")
+    );
+    assert_eq!(
+        events[0].unsubscribed_at.as_deref(),
+        Some("2023-04-12T09:21:05.000+0000")
+    );
+    assert!(events[1].campaign_reference_id.is_none());
+}
+
+#[test]
+fn test_parse_unsubscribe_events_list_and_single() {
+    let list =
+        br#"[{"emailAddress": "jane.doe@example.com"}, {"emailAddress": "john.doe@example.com"}]"#;
+    let single = br#"{"emailAddress": "jane.doe@example.com", "somethingNew": 1}"#;
+
+    assert_eq!(parse_unsubscribe_events(list).unwrap().len(), 2);
+    assert_eq!(
+        parse_unsubscribe_events(single).unwrap()[0]
+            .email_address
+            .as_deref(),
+        Some("jane.doe@example.com")
+    );
+}
+
+#[test]
+fn test_parse_unsubscribe_events_malformed() {
+    let error = parse_unsubscribe_events(br#"{"results": [{"emailAddress": 42}]}"#).unwrap_err();
+
+    assert!(matches!(error, crate::api::SdkError::Serde(_)));
+    assert!(parse_unsubscribe_events(b"not json").is_err());
+}
+
+#[test]
+fn test_bulk_info_send_at_number_and_string() {
+    let number: BulkInfo =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "sendAt": 1681291265000}"#).unwrap();
+    let numeric_string: BulkInfo =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "sendAt": "1681291265000"}"#).unwrap();
+    let iso_string: BulkInfo = serde_json::from_str(
+        r#"{"bulkId": "some-bulk-id", "sendAt": "2023-04-12T09:21:05.000+0000"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(number.send_at, Some(1681291265000));
+    assert_eq!(numeric_string, number);
+    assert_eq!(iso_string, number);
+    assert_eq!(
+        number.send_at_datetime(),
+        chrono::DateTime::parse_from_rfc3339("2023-04-12T09:21:05Z")
+            .ok()
+            .map(|send_at| send_at.with_timezone(&chrono::Utc))
+    );
+    assert_eq!(
+        iso_string.send_at_iso().as_deref(),
+        Some("2023-04-12T09:21:05.000+0000")
+    );
+}
+
+#[test]
+fn test_bulk_info_send_at_missing_or_invalid() {
+    let missing: BulkInfo = serde_json::from_str(r#"{"bulkId": "some-bulk-id"}"#).unwrap();
+
+    assert!(missing.send_at.is_none());
+    assert!(missing.send_at_datetime().is_none());
+    assert!(missing.send_at_iso().is_none());
+    assert!(serde_json::from_str::<BulkInfo>(r#"{"sendAt": "tomorrow"}"#).is_err());
+    assert_eq!(
+        serde_json::to_string(&BulkInfo {
+            send_at: Some(1681291265000),
+            ..Default::default()
+        })
+        .unwrap(),
+        r#"{"sendAt":1681291265000}"#
+    );
+}
+
+#[test]
+fn test_update_scheduled_status_request_body_targets() {
+    for status in [
+        BulkStatus::PENDING,
+        BulkStatus::PAUSED,
+        BulkStatus::CANCELED,
+    ] {
+        assert!(UpdateScheduledStatusRequestBody::new(status)
+            .validate()
+            .is_ok());
+    }
+    for status in [
+        BulkStatus::PROCESSING,
+        BulkStatus::FINISHED,
+        BulkStatus::FAILED,
+        BulkStatus::Unknown,
+    ] {
+        assert!(UpdateScheduledStatusRequestBody::new(status)
+            .validate()
+            .is_err());
+    }
+}
+
+#[test]
+fn test_bulk_status_is_terminal() {
+    let response: BulkStatusInfo =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "status": "FINISHED"}"#).unwrap();
+
+    assert!(response.status.unwrap().is_terminal());
+    assert!(BulkStatus::CANCELED.is_terminal());
+    assert!(BulkStatus::FAILED.is_terminal());
+    assert!(!BulkStatus::PENDING.is_terminal());
+    assert!(!BulkStatus::PAUSED.is_terminal());
+    assert!(!BulkStatus::PROCESSING.is_terminal());
+}
+
+#[test]
+fn test_bulk_status_unknown() {
+    let response: BulkStatusInfo =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "status": "ARCHIVED"}"#).unwrap();
+
+    assert_eq!(response.status, Some(BulkStatus::Unknown));
+    assert!(!BulkStatus::Unknown.is_terminal());
+}
+
+#[test]
+fn test_get_logs_with_content_response_body_round_trip() {
+    assert_round_trip::<GetLogsResponseBody>("email/get_logs_with_content_response.json");
+
+    let response: GetLogsResponseBody =
+        serde_json::from_slice(&read_fixture("email/get_logs_with_content_response.json")).unwrap();
+    let log = &response.results.unwrap()[0];
+
+    assert_eq!(log.subject.as_deref(), Some("Order \"#1234\" & more"));
+    assert_eq!(
+        log.html.as_deref(),
+        Some(
+            "<p class=\"greeting\">Dear John,</p>\n\
+             <p>Your order <b>#1234</b> is on its way &amp; \u{e9}t\u{e9} \\o/</p>"
+        )
+    );
+}
+
+#[test]
+fn test_get_logs_query_parameters_message_content() {
+    let query_parameters = GetLogsQueryParameters::message_content("MSG/TEST 123&x");
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "includeContent=true&messageId=MSG%2FTEST+123%26x"
+    );
+}
+
+#[test]
+fn test_create_template_request_body_content() {
+    let mut request_body = CreateTemplateRequestBody::new("welcome", "Welcome!");
+
+    assert!(request_body.validate().is_err());
+
+    request_body.html = Some("<p>Hello</p>".to_string());
+    assert!(request_body.validate().is_ok());
+
+    request_body.subject = "a".repeat(151);
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_parse_inbound_email_with_attachments() {
+    let emails =
+        parse_inbound_email(&read_fixture("email/inbound_email_with_attachments.json")).unwrap();
+
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].subject.as_deref(), Some("Re: Your order #1234"));
+
+    let attachments = emails[0].attachments.as_ref().unwrap();
+    assert_eq!(attachments.len(), 2);
+    assert_eq!(attachments[0].file_name.as_deref(), Some("notes.txt"));
+    assert_eq!(attachments[0].size, Some(14));
+    assert_eq!(
+        attachments[0].decode_content().unwrap(),
+        b"Hello, world!\n".to_vec()
+    );
+    assert_eq!(
+        attachments[1].content_type.as_deref(),
+        Some("application/pdf")
+    );
+    assert!(attachments[1].url.is_some());
+    assert_eq!(
+        attachments[1].decode_content(),
+        Err(AttachmentContentError::NotInline)
+    );
+}
+
+#[test]
+fn test_parse_inbound_email_without_attachments() {
+    let emails = parse_inbound_email(&read_fixture(
+        "email/inbound_email_without_attachments.json",
+    ))
+    .unwrap();
+
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].text.as_deref(), Some("Got it, thanks."));
+    assert!(emails[0].attachments.is_none());
+    assert_eq!(
+        serde_json::to_value(&emails[0]).unwrap(),
+        load_fixture("email/inbound_email_without_attachments.json")
+    );
+}
+
+#[test]
+fn test_inbound_email_attachment_invalid_base64() {
+    let attachment: InboundEmailAttachment =
+        serde_json::from_str(r#"{"filename": "broken.bin", "content": "not base64!"}"#).unwrap();
+
+    assert!(matches!(
+        attachment.decode_content(),
+        Err(AttachmentContentError::InvalidBase64(_))
+    ));
+}
+
+#[test]
+fn test_parse_address_list_table() {
+    use crate::model::email::address::{parse_address_list, EmailAddress};
+
+    let cases = vec![
+        ("", vec![]),
+        (" , ;", vec![]),
+        (
+            "john@company.com",
+            vec![EmailAddress::new("john@company.com")],
+        ),
+        (
+            "john@company.com, jane@company.com,",
+            vec![
+                EmailAddress::new("john@company.com"),
+                EmailAddress::new("jane@company.com"),
+            ],
+        ),
+        (
+            "john@company.com;jane@company.com;;",
+            vec![
+                EmailAddress::new("john@company.com"),
+                EmailAddress::new("jane@company.com"),
+            ],
+        ),
+        (
+            "<john@company.com>",
+            vec![EmailAddress::new("john@company.com")],
+        ),
+        (
+            "  < john@company.com >  ",
+            vec![EmailAddress::new("john@company.com")],
+        ),
+        (
+            "John Doe <john@company.com>",
+            vec![EmailAddress::with_display_name(
+                "John Doe",
+                "john@company.com",
+            )],
+        ),
+        (
+            "John   Doe<john@company.com>",
+            vec![EmailAddress::with_display_name(
+                "John Doe",
+                "john@company.com",
+            )],
+        ),
+        (
+            r#""Doe, John" <john@company.com>, "Smith; Jane" <jane@company.com>"#,
+            vec![
+                EmailAddress::with_display_name("Doe, John", "john@company.com"),
+                EmailAddress::with_display_name("Smith; Jane", "jane@company.com"),
+            ],
+        ),
+        (
+            r#""John \"JD\" Doe" <john@company.com>"#,
+            vec![EmailAddress::with_display_name(
+                r#"John "JD" Doe"#,
+                "john@company.com",
+            )],
+        ),
+        (
+            r#""<admin>" <admin@company.com>"#,
+            vec![EmailAddress::with_display_name(
+                "<admin>",
+                "admin@company.com",
+            )],
+        ),
+        (
+            r#""" <john@company.com>"#,
+            vec![EmailAddress::new("john@company.com")],
+        ),
+        (
+            "first.last+tag@mail.company.com",
+            vec![EmailAddress::new("first.last+tag@mail.company.com")],
+        ),
+        (
+            r#"<"john doe"@company.com>"#,
+            vec![EmailAddress::new(r#""john doe"@company.com"#)],
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_address_list(input).unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_address_list_errors_table() {
+    use crate::model::email::address::{parse_address_list, ParseError};
+
+    let cases = vec![
+        (
+            r#""Doe, John <john@company.com>"#,
+            ParseError::UnterminatedQuote(0),
+        ),
+        (
+            "jane@company.com, John <john@company.com",
+            ParseError::UnterminatedAngleBracket(23),
+        ),
+        (
+            "John <<john@company.com>>",
+            ParseError::UnexpectedCharacter {
+                character: '<',
+                position: 6,
+            },
+        ),
+        (
+            "john@company.com>",
+            ParseError::UnexpectedCharacter {
+                character: '>',
+                position: 16,
+            },
+        ),
+        (
+            "<john@company.com> Doe",
+            ParseError::UnexpectedCharacter {
+                character: 'D',
+                position: 19,
+            },
+        ),
+        (
+            "John Doe john@company.com",
+            ParseError::InvalidAddress("John Doe john@company.com".to_string()),
+        ),
+        ("john", ParseError::InvalidAddress("john".to_string())),
+        ("John <>", ParseError::InvalidAddress("".to_string())),
+        (
+            "john..doe@company.com",
+            ParseError::InvalidAddress("john..doe@company.com".to_string()),
+        ),
+        (
+            "john@-company.com",
+            ParseError::InvalidAddress("john@-company.com".to_string()),
+        ),
+        (
+            "john@company.com, @company.com",
+            ParseError::InvalidAddress("@company.com".to_string()),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_address_list(input).unwrap_err(),
+            expected,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_format_address_list() {
+    use crate::model::email::address::{format_address_list, parse_address_list, EmailAddress};
+
+    let addresses = vec![
+        EmailAddress::with_display_name("Doe, John", "john@company.com"),
+        EmailAddress::with_display_name(r#"Jane "JS" Smith"#, "jane@company.com"),
+        EmailAddress::with_display_name("Support Team", "support@company.com"),
+        EmailAddress::with_display_name("J. R. Bob", "bob@company.com"),
+        EmailAddress::new("noreply@company.com"),
+    ];
+
+    let formatted = format_address_list(&addresses);
+
+    assert_eq!(
+        formatted,
+        r#""Doe, John" <john@company.com>, "Jane \"JS\" Smith" <jane@company.com>, Support Team <support@company.com>, "J. R. Bob" <bob@company.com>, noreply@company.com"#
+    );
+    assert_eq!(parse_address_list(&formatted).unwrap(), addresses);
+}
+
+#[test]
+fn test_normalize_address_list() {
+    use crate::model::email::address::normalize_address_list;
+
+    assert_eq!(
+        normalize_address_list(" John <john@company.com> ; JOHN@company.com, jane@company.com,")
+            .unwrap(),
+        "John <john@company.com>, jane@company.com"
+    );
+}
+
+#[test]
+fn test_send_request_address_lists_validation() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.to = r#""Doe, John" <john@company.com>, jane@company.com,"#.to_string();
+    request_body.reply_to = Some("Support <support@company.com>".to_string());
+    assert!(request_body.validate().is_ok());
+
+    request_body.cc = Some("one@company.com, <two@company.com".to_string());
+    request_body.bcc = Some("three@company.com four@company.com".to_string());
+    let errors = request_body.validate().unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors["cc"][0].code, "address_list");
+    assert_eq!(
+        field_errors["bcc"][0].message.as_deref(),
+        Some("'three@company.com four@company.com' is not an email address")
+    );
+}
+
+#[test]
+fn test_send_request_from_single_address() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.from = Some("John <john@company.com>, jane@company.com".to_string());
+
+    let errors = request_body.validate().unwrap_err();
+
+    assert_eq!(errors.field_errors()["from"][0].code, "sender_address");
+}
+
+#[test]
+fn test_send_request_schedule_at_converts_to_utc() {
+    let mut request_body = get_dummy_send_email_request_body();
+
+    request_body
+        .schedule_at(chrono::DateTime::parse_from_rfc3339("2099-06-15T10:00:00+05:30").unwrap());
+
+    assert_eq!(
+        request_body.send_at.as_deref(),
+        Some("2099-06-15T04:30:00.000+0000")
+    );
+    assert!(request_body.validate().is_ok());
+    assert!(request_body
+        .check_send_at(chrono::Duration::minutes(1))
+        .is_ok());
+}
+
+#[test]
+fn test_send_request_check_send_at_in_past() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.schedule_at(chrono::Utc::now() - chrono::Duration::minutes(10));
+
+    let errors = request_body
+        .check_send_at(chrono::Duration::minutes(1))
+        .unwrap_err();
+    assert_eq!(errors.field_errors()["send_at"][0].code, "send_at_in_past");
+
+    assert!(request_body
+        .check_send_at(chrono::Duration::minutes(15))
+        .is_ok());
+}
+
+#[test]
+fn test_domain_tracking_domain_fields() {
+    let response: GetDomainsResponseBody =
+        serde_json::from_slice(&read_fixture("email/get_domains_response.json")).unwrap();
+    let tracking = response.results.unwrap()[0].tracking.clone().unwrap();
+
+    assert_eq!(tracking.custom_domain.as_deref(), Some("track.example.com"));
+    assert_eq!(
+        tracking.image_url.as_deref(),
+        Some("https://track.example.com/pixel.gif")
+    );
+    assert_eq!(
+        serde_json::to_string(&Tracking::default()).unwrap(),
+        "{}".to_string()
+    );
+}
+
+#[test]
+fn test_update_tracking_domain_request_body() {
+    let request_body = UpdateTrackingDomainRequestBody::new("track.example.com");
+
+    assert_eq!(
+        serde_json::to_string(&request_body).unwrap(),
+        r#"{"customDomain":"track.example.com"}"#
+    );
+}
+
+#[test]
+fn test_update_tracking_domain_subdomain_validation() {
+    let cases = vec![
+        ("track.example.com", "example.com", true),
+        ("Track.Example.com", "example.COM", true),
+        ("links.mail.example.com", "example.com", true),
+        ("track.example.com.", "example.com", true),
+        ("example.com", "example.com", false),
+        ("trackexample.com", "example.com", false),
+        ("track.example.org", "example.com", false),
+        ("track..example.com", "example.com", false),
+        ("-track.example.com", "example.com", false),
+        ("track_1.example.com", "example.com", false),
+        ("", "example.com", false),
+    ];
+
+    for (custom_domain, domain_name, valid) in cases {
+        let request_body = UpdateTrackingDomainRequestBody::new(custom_domain);
+
+        assert_eq!(
+            request_body.validate_for_domain(domain_name).is_ok(),
+            valid,
+            "{} for {}",
+            custom_domain,
+            domain_name
+        );
+    }
+}
+
+#[test]
+fn test_delivery_reports_empty_strings_as_none() {
+    let fixture = "email/get_delivery_reports_empty_strings_response.json";
+    let response: GetDeliveryReportsResponseBody =
+        serde_json::from_slice(&read_fixture(fixture)).unwrap();
+    let report = &response.results.unwrap()[0];
+
+    assert_eq!(report.bulk_id, None);
+    assert_eq!(report.campaign_reference_id, None);
+    assert_eq!(report.application_id, None);
+    assert_eq!(report.entity_id, None);
+    assert_eq!(report.status.as_ref().unwrap().action, None);
+    assert_eq!(report.message_id.as_deref(), Some("MESSAGE-ID-123-xyz"));
+
+    let serialized = serde_json::to_value(report).unwrap();
+    for key in ["bulkId", "campaignReferenceId", "applicationId", "entityId"] {
+        assert!(serialized.get(key).is_none(), "{}", key);
+    }
+    assert!(serialized["status"].get("action").is_none());
+}
+
+#[test]
+fn test_log_and_domain_empty_strings_as_none() {
+    let log: Log = serde_json::from_str(r#"{"bulkId": "", "messageId": "some-id"}"#).unwrap();
+    assert_eq!(log.bulk_id, None);
+    assert_eq!(
+        serde_json::to_string(&log).unwrap(),
+        r#"{"messageId":"some-id"}"#
+    );
+
+    let domain: Domain = serde_json::from_str(r#"{"domainId": 1, "createdAt": ""}"#).unwrap();
+    assert_eq!(domain.created_at, None);
+}
+
+#[test]
+fn test_send_response_partitions_statuses() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"{
+            "bulkId": "some-bulk-id",
+            "messages": [
+                {"to": "one@company.com", "status": {"groupId": 1, "groupName": "PENDING"}},
+                {"to": "two@company.com", "status": {"groupId": 5, "groupName": "REJECTED"}},
+                {"to": "three@company.com"}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.accepted()[0].to.as_deref(),
+        Some("one@company.com")
+    );
+    assert_eq!(response.rejected().len(), 2);
+    assert!(!response.all_accepted());
+}
+
+#[test]
+fn test_validate_address_response_assess() {
+    let default_policy = ValidationPolicy::new();
+    let permissive_policy = ValidationPolicy {
+        allow_disposable: true,
+        allow_role_based: true,
+        allow_catch_all: true,
+    };
+
+    let cases = [
+        (
+            r#"{"validMailbox": "true", "validSyntax": true}"#,
+            RiskAssessment::Accept,
+            RiskAssessment::Accept,
+        ),
+        (
+            r#"{"validMailbox": "true", "validSyntax": false}"#,
+            RiskAssessment::Reject,
+            RiskAssessment::Reject,
+        ),
+        (
+            r#"{"validMailbox": "false", "validSyntax": true}"#,
+            RiskAssessment::Reject,
+            RiskAssessment::Reject,
+        ),
+        (
+            r#"{"validMailbox": "true", "validSyntax": true, "disposable": true}"#,
+            RiskAssessment::Reject,
+            RiskAssessment::Accept,
+        ),
+        (
+            r#"{"validMailbox": "true", "validSyntax": true, "roleBased": true}"#,
+            RiskAssessment::Review,
+            RiskAssessment::Accept,
+        ),
+        (
+            r#"{"validMailbox": "true", "validSyntax": true, "catchAll": true}"#,
+            RiskAssessment::Review,
+            RiskAssessment::Accept,
+        ),
+        (
+            r#"{"validMailbox": "unknown", "validSyntax": true, "reason": "TIMED_OUT"}"#,
+            RiskAssessment::Review,
+            RiskAssessment::Review,
+        ),
+        (
+            r#"{"validMailbox": "unknown", "validSyntax": true, "reason": "THROTTLED"}"#,
+            RiskAssessment::Review,
+            RiskAssessment::Review,
+        ),
+        (
+            r#"{"validMailbox": "unknown", "validSyntax": true, "disposable": true}"#,
+            RiskAssessment::Reject,
+            RiskAssessment::Review,
+        ),
+        (
+            r#"{"validMailbox": "TRUE", "validSyntax": true}"#,
+            RiskAssessment::Accept,
+            RiskAssessment::Accept,
+        ),
+        (r#"{}"#, RiskAssessment::Review, RiskAssessment::Review),
+    ];
+
+    for (body, expected_default, expected_permissive) in cases {
+        let response: ValidateAddressResponseBody = serde_json::from_str(body).unwrap();
+
+        assert_eq!(
+            response.assess(&default_policy),
+            expected_default,
+            "{}",
+            body
+        );
+        assert_eq!(
+            response.assess(&permissive_policy),
+            expected_permissive,
+            "{}",
+            body
+        );
+    }
+}
+
+#[test]
+fn response_models_deserialize_from_empty_object() {
+    assert_deserializes_empty::<GetAttachmentsResponseBody>();
+    assert_deserializes_empty::<MessageAttachment>();
+    assert_deserializes_empty::<BulkInfo>();
+    assert_deserializes_empty::<BulkStatusInfo>();
+    assert_deserializes_empty::<DnsRecord>();
+    assert_deserializes_empty::<Domain>();
+    assert_deserializes_empty::<GetBulksResponseBody>();
+    assert_deserializes_empty::<GetDeliveryReportsResponseBody>();
+    assert_deserializes_empty::<GetDomainsResponseBody>();
+    assert_deserializes_empty::<GetLogsResponseBody>();
+    assert_deserializes_empty::<GetScheduledMessagesResponseBody>();
+    assert_deserializes_empty::<GetScheduledStatusResponseBody>();
+    assert_deserializes_empty::<GetSuppressionsResponseBody>();
+    assert_deserializes_empty::<Log>();
+    assert_deserializes_empty::<Price>();
+    assert_deserializes_empty::<Report>();
+    assert_deserializes_empty::<ReportError>();
+    assert_deserializes_empty::<ScheduledMessage>();
+    assert_deserializes_empty::<SendResponseBody>();
+    assert_deserializes_empty::<SentMessageDetails>();
+    assert_deserializes_empty::<Status>();
+    assert_deserializes_empty::<Suppression>();
+    assert_deserializes_empty::<Template>();
+    assert_deserializes_empty::<Tracking>();
+    assert_deserializes_empty::<ValidateAddressResponseBody>();
+}
+
+#[test]
+fn send_request_body_wire_names() {
+    let mut request_body = get_dummy_send_email_request_body();
+    request_body.recipients = Some(vec![EmailRecipient::new("other@company.com")]);
+    request_body.headers = Some(std::collections::HashMap::from([(
+        "X-Campaign".to_string(),
+        "spring".to_string(),
+    )]));
+
+    assert_wire_names(&request_body);
+    assert_eq!(SendRequestBody::wire_name("amp_html"), Some("ampHtml"));
+}
+
+#[test]
+fn update_tracking_request_body_wire_names() {
+    let mut request_body = UpdateTrackingRequestBody::new();
+    request_body.opens = Some(true);
+    request_body.clicks = Some(true);
+    request_body.unsubscribe = Some(false);
+
+    assert_wire_names(&request_body);
+    assert_eq!(UpdateTrackingRequestBody::wire_name("opens"), Some("open"));
+}
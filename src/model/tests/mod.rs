@@ -1,8 +1,43 @@
+// Helpers shared by the channel tests are only all used when every channel is compiled.
+#![cfg_attr(
+    not(all(
+        feature = "sms",
+        feature = "email",
+        feature = "whatsapp",
+        feature = "voice"
+    )),
+    allow(dead_code, unused_imports)
+)]
+
+#[cfg(test)]
+mod round_trip;
+
+#[cfg(test)]
+mod codes;
+
+#[cfg(test)]
+mod query;
+
 #[cfg(test)]
+#[cfg(feature = "sms")]
 mod sms;
 
 #[cfg(test)]
+#[cfg(feature = "whatsapp")]
 mod whatsapp;
 
 #[cfg(test)]
+#[cfg(feature = "email")]
 mod email;
+
+#[cfg(test)]
+#[cfg(feature = "sms")]
+mod numbers;
+
+#[cfg(test)]
+#[cfg(feature = "voice")]
+mod voice;
+
+#[cfg(test)]
+#[cfg(all(feature = "sms", feature = "email"))]
+mod common;
@@ -0,0 +1,127 @@
+use validator::Validate;
+
+use crate::model::numbers::*;
+use crate::model::tests::round_trip::{assert_deserializes_empty, assert_round_trip};
+use crate::model::ToQueryString;
+
+#[test]
+fn get_numbers_response_round_trip() {
+    assert_round_trip::<GetNumbersResponseBody>("numbers/get_numbers_response.json");
+}
+
+#[test]
+fn update_number_sms_configuration_request_round_trip() {
+    assert_round_trip::<UpdateNumberSmsConfigurationRequestBody>(
+        "numbers/update_number_sms_configuration_request.json",
+    );
+}
+
+#[test]
+fn deserialize_purchased_number() {
+    let json = r#"
+    {
+      "numberKey": "8F0792F86035A9F4290821F1EE6BC06A",
+      "number": "447860041117",
+      "country": "GB",
+      "countryName": "United Kingdom",
+      "type": "SHORT_CODE",
+      "capabilities": ["SMS", "MMS", "WHATSAPP"]
+    }
+    "#;
+
+    let number: PurchasedNumber = serde_json::from_str(json).unwrap();
+
+    assert_eq!(number.number.as_deref(), Some("447860041117"));
+    assert_eq!(number.number_type, Some(NumberType::ShortCode));
+    assert_eq!(
+        number.capabilities,
+        Some(vec![
+            NumberCapability::Sms,
+            NumberCapability::Mms,
+            NumberCapability::WhatsApp
+        ])
+    );
+}
+
+#[test]
+fn deserialize_purchased_number_unknown_values() {
+    let number: PurchasedNumber =
+        serde_json::from_str(r#"{"type": "SATELLITE", "capabilities": ["SMS", "RCS"]}"#).unwrap();
+
+    assert_eq!(number.number_type, Some(NumberType::Unknown));
+    assert_eq!(
+        number.capabilities,
+        Some(vec![NumberCapability::Sms, NumberCapability::Unknown])
+    );
+
+    let forwarding: SmsForwarding = serde_json::from_str(r#"{"type": "PIGEON"}"#).unwrap();
+    assert_eq!(forwarding.forward_type, SmsForwardType::Unknown);
+}
+
+#[test]
+fn get_numbers_query_parameters_valid() {
+    let mut query_parameters = GetNumbersQueryParameters::new();
+    query_parameters.limit = Some(10);
+    query_parameters.page = Some(0);
+
+    assert!(query_parameters.validate().is_ok());
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "limit=10&page=0"
+    );
+}
+
+#[test]
+fn get_numbers_query_parameters_big_limit() {
+    let mut query_parameters = GetNumbersQueryParameters::new();
+    query_parameters.limit = Some(1001);
+
+    assert!(query_parameters.validate().is_err());
+}
+
+#[test]
+fn number_sms_configuration_valid() {
+    let mut configuration = NumberSmsConfiguration::new();
+    configuration.keyword = Some("PROMO".to_string());
+    configuration.forwarding = Some(SmsForwarding::http_post("https://example.com/inbound"));
+
+    assert!(configuration.validate().is_ok());
+}
+
+#[test]
+fn number_sms_configuration_http_forward_without_url() {
+    let mut configuration = NumberSmsConfiguration::new();
+    configuration.forwarding = Some(SmsForwarding {
+        forward_type: SmsForwardType::HttpForwardGet,
+        url: None,
+    });
+
+    assert!(configuration.validate().is_err());
+}
+
+#[test]
+fn number_sms_configuration_pull_without_url() {
+    let mut configuration = NumberSmsConfiguration::new();
+    configuration.forwarding = Some(SmsForwarding {
+        forward_type: SmsForwardType::Pull,
+        url: None,
+    });
+
+    assert!(configuration.validate().is_ok());
+}
+
+#[test]
+fn number_sms_configuration_bad_url() {
+    let mut configuration = NumberSmsConfiguration::new();
+    configuration.forwarding = Some(SmsForwarding::http_post("not a url"));
+
+    assert!(configuration.validate().is_err());
+}
+
+#[test]
+fn response_models_deserialize_from_empty_object() {
+    assert_deserializes_empty::<GetNumbersResponseBody>();
+    assert_deserializes_empty::<NumberPrice>();
+    assert_deserializes_empty::<NumberSmsConfiguration>();
+    assert_deserializes_empty::<PurchasedNumber>();
+}
@@ -0,0 +1,115 @@
+use serde::Serialize;
+
+use crate::model::{BoolFormat, QueryBool, ToQueryString};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Filters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flag: Option<QueryBool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plain: Option<bool>,
+}
+
+impl ToQueryString for Filters {}
+
+fn query_string(flag: Option<QueryBool>) -> String {
+    Filters {
+        name: Some("some name".to_string()),
+        flag,
+        plain: None,
+    }
+    .to_query_string()
+    .unwrap()
+}
+
+#[test]
+fn bool_format_lowercase() {
+    let format = BoolFormat::Lowercase;
+
+    assert_eq!(
+        query_string(Some(QueryBool::new(true, format))),
+        "flag=true&name=some+name"
+    );
+    assert_eq!(
+        query_string(Some(QueryBool::new(false, format))),
+        "flag=false&name=some+name"
+    );
+    assert_eq!(
+        query_string(Some(QueryBool::from(true))),
+        "flag=true&name=some+name"
+    );
+}
+
+#[test]
+fn bool_format_capitalized() {
+    let format = BoolFormat::Capitalized;
+
+    assert_eq!(
+        query_string(Some(QueryBool::new(true, format))),
+        "flag=True&name=some+name"
+    );
+    assert_eq!(
+        query_string(Some(QueryBool::new(false, format))),
+        "flag=False&name=some+name"
+    );
+}
+
+#[test]
+fn bool_format_presence_only() {
+    let format = BoolFormat::PresenceOnly;
+
+    assert_eq!(
+        query_string(Some(QueryBool::new(true, format))),
+        "flag&name=some+name"
+    );
+    assert_eq!(
+        query_string(Some(QueryBool::new(false, format))),
+        "name=some+name"
+    );
+}
+
+#[test]
+fn absent_bool_has_no_key() {
+    assert_eq!(query_string(None), "name=some+name");
+
+    let filters = Filters {
+        name: None,
+        flag: None,
+        plain: Some(false),
+    };
+    assert_eq!(filters.to_query_string().unwrap(), "plain=false");
+}
+
+#[test]
+fn presence_only_bools_in_sequence() {
+    #[derive(Serialize)]
+    struct Flags {
+        flags: Vec<QueryBool>,
+    }
+    impl ToQueryString for Flags {}
+
+    let flags = Flags {
+        flags: vec![
+            QueryBool::new(true, BoolFormat::PresenceOnly),
+            QueryBool::new(false, BoolFormat::PresenceOnly),
+        ],
+    };
+
+    assert_eq!(flags.to_query_string().unwrap(), "flags");
+}
+
+#[test]
+fn presence_only_is_not_a_string_value() {
+    let filters = Filters {
+        name: Some("\u{0}present".to_string()),
+        flag: Some(QueryBool::new(true, BoolFormat::PresenceOnly)),
+        plain: None,
+    };
+
+    assert_eq!(filters.to_query_string().unwrap(), "flag&name=%00present");
+}
@@ -0,0 +1,127 @@
+//! Serde round-trip checks of response models against JSON fixtures stored in `tests/fixtures`.
+
+use std::any::type_name;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::model::WireNames;
+
+// Relative tolerance used when comparing numbers, so `f32` fields don't fail on precision noise.
+const NUMBER_TOLERANCE: f64 = 1e-6;
+
+/// Reads the raw content of a fixture from `tests/fixtures`, given its path relative to that
+/// directory.
+pub fn read_fixture(fixture: &str) -> Vec<u8> {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", fixture]
+        .iter()
+        .collect();
+
+    fs::read(&path)
+        .unwrap_or_else(|error| panic!("cannot read fixture {}: {}", path.display(), error))
+}
+
+/// Loads a JSON fixture from `tests/fixtures`, given its path relative to that directory.
+pub fn load_fixture(fixture: &str) -> Value {
+    serde_json::from_slice(&read_fixture(fixture))
+        .unwrap_or_else(|error| panic!("fixture {} is not valid JSON: {}", fixture, error))
+}
+
+/// Deserializes a fixture into `T`, serializes it back and asserts that the result is
+/// semantically equal to the fixture. Field ordering is ignored. On failure, every mismatching
+/// JSON path is reported.
+pub fn assert_round_trip<T: DeserializeOwned + Serialize>(fixture: &str) {
+    let expected = load_fixture(fixture);
+    let model: T = serde_json::from_value(expected.clone())
+        .unwrap_or_else(|error| panic!("fixture {} does not deserialize: {}", fixture, error));
+    let actual = serde_json::to_value(&model).unwrap();
+
+    let mut differences = Vec::new();
+    collect_differences("$", &expected, &actual, &mut differences);
+
+    assert!(
+        differences.is_empty(),
+        "fixture {} does not round-trip:\n{}",
+        fixture,
+        differences.join("\n")
+    );
+}
+
+fn collect_differences(
+    path: &str,
+    expected: &Value,
+    actual: &Value,
+    differences: &mut Vec<String>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let child = format!("{}.{}", path, key);
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        collect_differences(&child, expected_value, actual_value, differences)
+                    }
+                    None => differences.push(format!("  {}: missing after round-trip", child)),
+                }
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                differences.push(format!("  {}.{}: unexpected after round-trip", path, key));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected_item, actual_item)) in expected.iter().zip(actual).enumerate() {
+                let child = format!("{}[{}]", path, index);
+                collect_differences(&child, expected_item, actual_item, differences);
+            }
+        }
+        (Value::Number(expected), Value::Number(actual)) => {
+            let expected = expected.as_f64().unwrap_or_default();
+            let actual = actual.as_f64().unwrap_or_default();
+            if (expected - actual).abs() > NUMBER_TOLERANCE * expected.abs().max(1.0) {
+                differences.push(format!("  {}: expected {}, got {}", path, expected, actual));
+            }
+        }
+        (expected, actual) if expected != actual => {
+            differences.push(format!("  {}: expected {}, got {}", path, expected, actual));
+        }
+        _ => {}
+    }
+}
+
+/// Asserts that `T` deserializes from an empty JSON object, into its default value.
+pub fn assert_deserializes_empty<T>()
+where
+    T: DeserializeOwned + Default + PartialEq + std::fmt::Debug,
+{
+    let model: T = serde_json::from_str("{}").unwrap_or_else(|error| {
+        panic!(
+            "{} does not deserialize from {{}}: {}",
+            std::any::type_name::<T>(),
+            error
+        )
+    });
+
+    assert_eq!(model, T::default(), "{}", std::any::type_name::<T>());
+}
+
+/// Asserts that the wire names of `T` are exactly the keys serde writes for `model`, which must
+/// have every optional field set.
+pub fn assert_wire_names<T: WireNames + Serialize>(model: &T) {
+    let serialized = match serde_json::to_value(model).unwrap() {
+        Value::Object(fields) => fields,
+        value => panic!(
+            "{} is not serialized as an object: {}",
+            type_name::<T>(),
+            value
+        ),
+    };
+    let mut expected: Vec<&str> = serialized.keys().map(String::as_str).collect();
+    let mut actual: Vec<&str> = T::wire_names().iter().map(|(_, wire)| *wire).collect();
+    expected.sort_unstable();
+    actual.sort_unstable();
+
+    assert_eq!(actual, expected, "{}", type_name::<T>());
+}
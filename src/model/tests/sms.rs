@@ -1,6 +1,16 @@
+use std::time::Duration;
+
 use validator::Validate;
 
+use crate::model::common::{
+    NotifyContentType, RetryAdvice, RetryPolicy, DEFAULT_LONG_RETRY_DELAY,
+    DEFAULT_SHORT_RETRY_DELAY,
+};
 use crate::model::sms::*;
+use crate::model::tests::round_trip::{
+    assert_deserializes_empty, assert_round_trip, assert_wire_names, load_fixture,
+};
+use crate::model::{SendOutcome, ToQueryString, ValidateStrict, WireNames};
 
 const DUMMY_TEXT: &str = "Dummy text for tests. Some special chars: áéíø";
 
@@ -45,6 +55,24 @@ fn get_delivery_reports_query_parameters_big_limit() {
     assert!(parameters.validate().is_err())
 }
 
+#[test]
+fn get_delivery_reports_query_parameters_limit_bounds() {
+    let mut parameters = GetDeliveryReportsQueryParameters::new();
+
+    parameters.limit = Some(0);
+    assert!(parameters.validate().is_err());
+    parameters.limit = Some(1);
+    assert!(parameters.validate().is_ok());
+    parameters.limit = Some(GetDeliveryReportsQueryParameters::MAX_LIMIT);
+    assert!(parameters.validate().is_ok());
+    parameters.limit = Some(GetDeliveryReportsQueryParameters::MAX_LIMIT + 1);
+    let errors = parameters.validate().unwrap_err();
+    assert!(errors.to_string().contains(&format!(
+        "between 1 and {}",
+        GetDeliveryReportsQueryParameters::MAX_LIMIT
+    )));
+}
+
 #[test]
 fn send_request_body_valid() {
     let mut message = Message::new(vec![Destination::new("123456789012")]);
@@ -135,11 +163,53 @@ fn message_from_str() {
 fn send_request_body_zero_speed_limit_amount() {
     let message = Message::new(vec![Destination::new("123456789012")]);
     let mut request_body = SendRequestBody::new(vec![message]);
-    request_body.sending_speed_limit = Some(SpeedLimit::new(0));
+    request_body.sending_speed_limit = Some(SpeedLimit::unlimited());
 
     assert!(request_body.validate().is_ok());
 }
 
+#[test]
+fn speed_limit_amount_bounds() {
+    assert!(SpeedLimit::per_minute(1).validate().is_ok());
+    assert!(SpeedLimit::per_day(SpeedLimit::MAX_AMOUNT)
+        .validate()
+        .is_ok());
+    assert!(SpeedLimit::per_minute(0).validate().is_err());
+    assert!(SpeedLimit::per_hour(-1).validate().is_err());
+    assert!(SpeedLimit::new(-1).validate().is_err());
+    assert!(SpeedLimit::per_hour(SpeedLimit::MAX_AMOUNT + 1)
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn speed_limit_constructors() {
+    assert_eq!(SpeedLimit::per_minute(10).time_unit, Some(TimeUnit::MINUTE));
+    assert_eq!(SpeedLimit::per_hour(10).time_unit, Some(TimeUnit::HOUR));
+    assert_eq!(SpeedLimit::per_day(10).time_unit, Some(TimeUnit::DAY));
+    assert_eq!(
+        TimeUnit::HOUR.duration(),
+        std::time::Duration::from_secs(3600)
+    );
+}
+
+#[test]
+fn send_request_body_invalid_speed_limit() {
+    let message = Message::new(vec![Destination::new("123456789012")]);
+    let mut request_body = SendRequestBody::new(vec![message]);
+    request_body.sending_speed_limit = Some(SpeedLimit::per_minute(0));
+
+    assert!(request_body.validate().is_err());
+
+    let mut binary_request_body =
+        SendBinaryRequestBody::new(vec![BinaryMessage::new(vec![Destination::new(
+            "123456789012",
+        )])]);
+    binary_request_body.sending_speed_limit = Some(SpeedLimit::per_day(0));
+
+    assert!(binary_request_body.validate().is_err());
+}
+
 #[test]
 fn send_request_body_speed_limit_time_unit() {
     let message = Message::new(vec![Destination::new("123456789012")]);
@@ -154,6 +224,68 @@ fn send_request_body_speed_limit_time_unit() {
     assert!(serialized.contains(r#""amount":5,"timeUnit":"DAY""#));
 }
 
+#[test]
+fn speed_limit_serializes_default_time_unit() {
+    assert_eq!(
+        serde_json::to_value(SpeedLimit::new(5)).unwrap(),
+        serde_json::json!({"amount": 5, "timeUnit": "MINUTE"})
+    );
+    assert_eq!(
+        serde_json::to_value(SpeedLimit::per_hour(5)).unwrap(),
+        serde_json::json!({"amount": 5, "timeUnit": "HOUR"})
+    );
+    assert_eq!(
+        serde_json::to_value(SpeedLimit::unlimited()).unwrap(),
+        serde_json::json!({"amount": 0})
+    );
+
+    let speed_limit: SpeedLimit = serde_json::from_str(r#"{"amount": 5}"#).unwrap();
+    assert_eq!(speed_limit.time_unit, None);
+}
+
+#[test]
+fn speed_limit_effective_rate_per_minute() {
+    assert_eq!(
+        SpeedLimit::per_minute(30).effective_rate_per_minute(),
+        Some(30.0)
+    );
+    assert_eq!(SpeedLimit::new(30).effective_rate_per_minute(), Some(30.0));
+    assert_eq!(
+        SpeedLimit::per_hour(120).effective_rate_per_minute(),
+        Some(2.0)
+    );
+    assert_eq!(
+        SpeedLimit::per_day(2880).effective_rate_per_minute(),
+        Some(2.0)
+    );
+    assert_eq!(SpeedLimit::unlimited().effective_rate_per_minute(), None);
+}
+
+#[test]
+fn send_request_body_speed_limit_ceiling() {
+    let message = Message::new(vec![Destination::new("123456789012")]);
+    let mut request_body = SendRequestBody::new(vec![message]);
+    assert!(request_body.check_sending_speed_limit(10.0).is_ok());
+
+    request_body.sending_speed_limit = Some(SpeedLimit::per_minute(10));
+    assert!(request_body.check_sending_speed_limit(10.0).is_ok());
+
+    request_body.sending_speed_limit = Some(SpeedLimit::new(11));
+    let errors = request_body.check_sending_speed_limit(10.0).unwrap_err();
+    assert_eq!(
+        errors.field_errors()["sending_speed_limit"][0].code,
+        "speed_limit_rate"
+    );
+
+    request_body.sending_speed_limit = Some(SpeedLimit::per_day(1_000_000));
+    assert!(request_body.validate().is_ok());
+    assert!(request_body.check_sending_speed_limit(600.0).is_err());
+    assert!(request_body.check_sending_speed_limit(700.0).is_ok());
+
+    request_body.sending_speed_limit = Some(SpeedLimit::unlimited());
+    assert!(request_body.check_sending_speed_limit(10.0).is_err());
+}
+
 #[test]
 fn send_request_body_with_delivery_time_window() {
     let delivery_time_window =
@@ -184,7 +316,7 @@ fn send_request_body_delivery_time_window_no_days() {
 #[test]
 fn send_request_body_delivery_time_window_to_hour() {
     let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
-    delivery_time_window.to = Some(DeliveryTime::new(23, 59));
+    delivery_time_window.to = Some(DeliveryTime::new(23, 45));
 
     let mut message = Message::new(vec![Destination::new("123456789012")]);
     message.delivery_time_window = Some(delivery_time_window);
@@ -223,7 +355,7 @@ fn send_request_body_delivery_time_window_bad_to_minute() {
 #[test]
 fn send_request_body_delivery_time_window_from_hour() {
     let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
-    delivery_time_window.from = Some(DeliveryTime::new(23, 59));
+    delivery_time_window.from = Some(DeliveryTime::new(23, 45));
 
     let mut message = Message::new(vec![Destination::new("123456789012")]);
     message.delivery_time_window = Some(delivery_time_window);
@@ -259,6 +391,52 @@ fn send_request_body_delivery_time_window_bad_from_minute() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_request_body_delivery_time_window_to_not_quarter_hour() {
+    let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
+    delivery_time_window.to = Some(DeliveryTime::new(23, 46));
+
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.delivery_time_window = Some(delivery_time_window);
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    let errors = request_body.validate().unwrap_err();
+    assert!(errors.to_string().contains("0, 15, 30 or 45"));
+}
+
+#[test]
+fn send_request_body_delivery_time_window_from_not_quarter_hour() {
+    let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
+    delivery_time_window.from = Some(DeliveryTime::new(0, 46));
+    delivery_time_window.to = Some(DeliveryTime::new(23, 45));
+
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.delivery_time_window = Some(delivery_time_window);
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_request_body_delivery_time_window_allow_any_minute() {
+    let mut delivery_time_window = DeliveryTimeWindow::new(vec![DeliveryDay::MONDAY]);
+    delivery_time_window.from = Some(DeliveryTime::new(0, 46));
+    delivery_time_window.to = Some(DeliveryTime::new(23, 59));
+    delivery_time_window.allow_any_minute = true;
+
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.delivery_time_window = Some(delivery_time_window);
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    let serialized = serde_json::to_string(&request_body).unwrap();
+    assert!(request_body.validate().is_ok());
+    assert!(!serialized.contains("allowAnyMinute"));
+    assert!(!serialized.contains("allow_any_minute"));
+}
+
 #[test]
 fn send_request_body_long_callback_data() {
     let mut message = Message::new(vec![Destination::new("123456789012")]);
@@ -269,6 +447,68 @@ fn send_request_body_long_callback_data() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_request_body_callback_data_max_length() {
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.callback_data = Some("C".repeat(4000));
+    assert!(SendRequestBody::new(vec![message.clone()])
+        .validate()
+        .is_ok());
+
+    message.callback_data = Some("C".repeat(4001));
+    assert!(SendRequestBody::new(vec![message]).validate().is_err());
+}
+
+#[test]
+fn send_request_body_invalid_notify_url() {
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.notify_url = Some("not a url".to_string());
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_request_body_notify_fields_serialization() {
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.intermediate_report = Some(true);
+    message.notify_url = Some("https://some.url".to_string());
+    message.notify_content_type = Some(NotifyContentType::Xml);
+    message.callback_data = Some("some data".to_string());
+
+    let request_body = SendRequestBody::new(vec![message]);
+    let value = serde_json::to_value(&request_body).unwrap();
+    let message = &value["messages"][0];
+
+    assert!(request_body.validate().is_ok());
+    assert_eq!(message["intermediateReport"], true);
+    assert_eq!(message["notifyUrl"], "https://some.url");
+    assert_eq!(message["notifyContentType"], "application/xml");
+    assert_eq!(message["callbackData"], "some data");
+}
+
+#[test]
+fn send_request_body_notify_content_type_unsupported() {
+    let result = serde_json::from_str::<Message>(
+        r#"{"destinations": [{"to": "123456789012"}], "notifyContentType": "text/plain"}"#,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn send_over_query_parameters_long_callback_data() {
+    let mut query_parameters = SendOverQueryParametersQueryParameters::new(
+        "user",
+        "pass",
+        vec!["123456789012".to_string()],
+    );
+    query_parameters.callback_data = Some("C".repeat(4001));
+
+    assert!(query_parameters.validate().is_err());
+}
+
 #[test]
 fn send_binary_request_body_long_to() {
     let message = BinaryMessage::new(vec![Destination::new(&"123456789012".repeat(10))]);
@@ -425,3 +665,1290 @@ fn verify_phone_number_request_body_empty_pin() {
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn get_delivery_reports_query_parameters_query_string() {
+    let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+    query_parameters.bulk_id = Some("some-bulk-id".to_string());
+    query_parameters.message_id = Some("some-message-id".to_string());
+    query_parameters.limit = Some(10);
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "bulkId=some-bulk-id&limit=10&messageId=some-message-id"
+    );
+}
+
+#[test]
+fn get_delivery_reports_query_parameters_empty_query_string() {
+    let query_parameters = GetDeliveryReportsQueryParameters::new();
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn get_scheduled_query_parameters_query_string() {
+    let query_parameters = GetScheduledQueryParameters::new("some-bulk-id");
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "bulkId=some-bulk-id"
+    );
+}
+
+#[test]
+fn get_logs_query_parameters_query_string() {
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.to = Some("+385 91".to_string());
+    query_parameters.sent_until = Some("2022-10-01T00:00:00.000+0000".to_string());
+    query_parameters.mcc = Some("219".to_string());
+    query_parameters.mnc = Some("01".to_string());
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "mcc=219&mnc=01&sentUntil=2022-10-01T00%3A00%3A00.000%2B0000&to=%2B385+91"
+    );
+}
+
+#[test]
+fn get_logs_query_parameters_empty_query_string() {
+    let query_parameters = GetLogsQueryParameters::new();
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn get_inbound_reports_query_parameters_query_string() {
+    let mut query_parameters = GetInboundReportsQueryParameters::new();
+    query_parameters.limit = Some(100);
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "limit=100");
+}
+
+#[test]
+fn get_inbound_reports_query_parameters_empty_query_string() {
+    let query_parameters = GetInboundReportsQueryParameters::new();
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn send_over_query_parameters_query_parameters_query_string() {
+    let mut query_parameters = SendOverQueryParametersQueryParameters::new(
+        "user",
+        "pass",
+        vec!["41793026727".to_string(), "41793026834".to_string()],
+    );
+    query_parameters.text = Some("Hello world".to_string());
+    query_parameters.flash = Some(false);
+    query_parameters.language_code = Some("ES".to_string());
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "flash=false&languageCode=ES&password=pass&text=Hello+world&to=41793026727%2C41793026834&username=user"
+    );
+}
+
+#[test]
+fn send_pin_over_sms_query_parameters_query_string() {
+    let mut query_parameters = SendPinOverSmsQueryParameters::new();
+    query_parameters.nc_needed = Some(true);
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "ncNeeded=true");
+}
+
+#[test]
+fn send_pin_over_sms_query_parameters_empty_query_string() {
+    let query_parameters = SendPinOverSmsQueryParameters::new();
+
+    assert_eq!(query_parameters.to_query_string().unwrap(), "");
+}
+
+#[test]
+fn get_tfa_verification_status_query_parameters_query_string() {
+    let mut query_parameters = GetTfaVerificationStatusQueryParameters::new("41793026727");
+    query_parameters.verified = Some(true);
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "msisdn=41793026727&verified=true"
+    );
+}
+
+#[test]
+fn get_tfa_verification_status_query_parameters_false_flags() {
+    let mut query_parameters = GetTfaVerificationStatusQueryParameters::new("41793026727");
+    query_parameters.verified = Some(false);
+    query_parameters.sent = Some(false);
+
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "msisdn=41793026727&sent=false&verified=false"
+    );
+}
+
+#[test]
+fn send_response_body_round_trip() {
+    assert_round_trip::<SendResponseBody>("sms/send_response.json");
+}
+
+#[test]
+fn get_delivery_reports_response_body_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("sms/get_delivery_reports_response.json");
+}
+
+#[test]
+fn get_logs_response_body_round_trip() {
+    assert_round_trip::<GetLogsResponseBody>("sms/get_logs_response.json");
+}
+
+fn format_timestamp(time: chrono::DateTime<chrono::Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string()
+}
+
+#[test]
+fn get_logs_query_parameters_range_at_retention_boundary() {
+    let until = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_since = Some(format_timestamp(until - chrono::Duration::hours(48)));
+    query_parameters.sent_until = Some(format_timestamp(until));
+
+    assert!(query_parameters.validate().is_ok());
+}
+
+#[test]
+fn get_logs_query_parameters_range_over_retention() {
+    let until = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_since = Some(format_timestamp(
+        until - chrono::Duration::hours(48) - chrono::Duration::seconds(1),
+    ));
+    query_parameters.sent_until = Some(format_timestamp(until));
+
+    let errors = query_parameters.validate().unwrap_err();
+    let message = errors.to_string();
+    assert!(message.contains("48 hours"));
+}
+
+#[test]
+fn get_logs_query_parameters_inverted_range() {
+    let since = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_since = Some(format_timestamp(since));
+    query_parameters.sent_until = Some(format_timestamp(since - chrono::Duration::hours(2)));
+
+    assert!(query_parameters.validate().is_err());
+}
+
+#[test]
+fn get_logs_query_parameters_future_until() {
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_until = Some(format_timestamp(
+        chrono::Utc::now() + chrono::Duration::hours(1),
+    ));
+
+    assert!(query_parameters.validate().is_err());
+}
+
+#[test]
+fn get_logs_query_parameters_until_only() {
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_until = Some(format_timestamp(
+        chrono::Utc::now() - chrono::Duration::hours(72),
+    ));
+
+    assert!(query_parameters.validate().is_ok());
+}
+
+#[test]
+fn get_logs_query_parameters_since_only() {
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_since = Some(format_timestamp(
+        chrono::Utc::now() - chrono::Duration::hours(47),
+    ));
+    assert!(query_parameters.validate().is_ok());
+
+    query_parameters.sent_since = Some(format_timestamp(
+        chrono::Utc::now() - chrono::Duration::hours(49),
+    ));
+    let errors = query_parameters.validate().unwrap_err();
+    assert!(errors.to_string().contains("48 hours"));
+}
+
+#[test]
+fn get_logs_query_parameters_invalid_timestamp() {
+    let mut query_parameters = GetLogsQueryParameters::new();
+    query_parameters.sent_since = Some("yesterday".to_string());
+
+    assert!(query_parameters.validate().is_err());
+}
+
+#[test]
+fn single_text_request_body_valid() {
+    let request_body = SingleTextRequestBody::new(vec!["+41793026727".to_string()], DUMMY_TEXT);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn single_text_request_body_invalid_destination() {
+    let request_body = SingleTextRequestBody::new(
+        vec!["41793026727".to_string(), "0041-79".to_string()],
+        DUMMY_TEXT,
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn single_text_request_body_no_destinations() {
+    let request_body = SingleTextRequestBody::new(vec![], DUMMY_TEXT);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn parse_delivery_reports_xml_matches_json() {
+    use crate::model::tests::round_trip::read_fixture;
+
+    let from_xml = parse_delivery_reports_xml(&read_fixture("sms/delivery_reports.xml")).unwrap();
+    let from_json: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture("sms/get_delivery_reports_response.json")).unwrap();
+
+    assert_eq!(from_xml, from_json);
+}
+
+fn assert_strict_error(request_body: &SendRequestBody, code: &str) {
+    assert!(request_body.validate().is_ok());
+
+    let errors = request_body.validate_strict().unwrap_err();
+    let messages_errors = errors.field_errors()["messages"].clone();
+    assert!(messages_errors.iter().any(|error| error.code == code));
+}
+
+#[test]
+fn send_request_body_strict_valid() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.from = Some("InfoSMS".to_string());
+    message.callback_data = Some("some data".to_string());
+    message.validity_period = Some(MAX_VALIDITY_PERIOD_MINUTES);
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    assert!(request_body.validate_strict().is_ok());
+}
+
+#[test]
+fn send_request_body_strict_long_alphanumeric_sender() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.from = Some("InfoSMSSender".to_string());
+
+    assert_strict_error(&SendRequestBody::new(vec![message]), "strict_sender");
+}
+
+#[test]
+fn send_request_body_strict_callback_data_control_characters() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.callback_data = Some("some\u{0}data".to_string());
+
+    assert_strict_error(
+        &SendRequestBody::new(vec![message]),
+        "strict_callback_data_charset",
+    );
+}
+
+#[test]
+fn send_request_body_strict_destination_not_number() {
+    let message = Message::new(vec![Destination::new("john-doe")]);
+
+    assert_strict_error(&SendRequestBody::new(vec![message]), "strict_destination");
+}
+
+#[test]
+fn send_request_body_strict_long_validity_period() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.validity_period = Some(MAX_VALIDITY_PERIOD_MINUTES + 1);
+
+    assert_strict_error(
+        &SendRequestBody::new(vec![message]),
+        "strict_validity_period",
+    );
+}
+
+#[test]
+fn price_no_precision_loss() {
+    let json = r#"{"currency":"EUR","pricePerMessage":0.0051}"#;
+
+    let price: Price = serde_json::from_str(json).unwrap();
+
+    assert_eq!(price.price_per_message_f64(), Some(0.0051));
+    assert_eq!(serde_json::to_string(&price).unwrap(), json);
+}
+
+#[test]
+fn price_equality() {
+    let price: Price = serde_json::from_str(r#"{"pricePerMessage":0.0051}"#).unwrap();
+    let same: Price = serde_json::from_str(r#"{"pricePerMessage":0.00510}"#).unwrap();
+    let other: Price = serde_json::from_str(r#"{"pricePerMessage":0.0052}"#).unwrap();
+
+    assert_eq!(price, same);
+    assert_ne!(price, other);
+}
+
+#[test]
+fn get_delivery_reports_ported_response_body_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>(
+        "sms/get_delivery_reports_ported_response.json",
+    );
+}
+
+#[test]
+fn delivery_reports_network_details() {
+    let response_body: GetDeliveryReportsResponseBody = serde_json::from_value(load_fixture(
+        "sms/get_delivery_reports_ported_response.json",
+    ))
+    .unwrap();
+    let results = response_body.results.unwrap();
+
+    assert_eq!(results[0].sms_count, Some(2));
+    assert_eq!(results[0].mcc(), Some("310"));
+    assert_eq!(results[0].mnc(), Some("260"));
+    assert_eq!(results[0].ported, Some(true));
+    assert_eq!(results[0].network_name.as_deref(), Some("T-Mobile USA"));
+
+    assert_eq!(results[1].sms_count, Some(1));
+    assert!(results[1].mcc_mnc.is_none());
+    assert!(results[1].mcc().is_none());
+    assert!(results[1].mnc().is_none());
+    assert!(results[1].ported.is_none());
+}
+
+#[test]
+fn log_mcc_mnc_five_digits() {
+    let log: Log = serde_json::from_str(r#"{"mccMnc": "22801", "ported": false}"#).unwrap();
+
+    assert_eq!(log.mcc(), Some("228"));
+    assert_eq!(log.mnc(), Some("01"));
+    assert_eq!(log.ported, Some(false));
+}
+
+#[test]
+fn split_mcc_mnc_invalid() {
+    assert!(split_mcc_mnc("2280").is_none());
+    assert!(split_mcc_mnc("2280123").is_none());
+    assert!(split_mcc_mnc("228a1").is_none());
+}
+
+fn get_dummy_error(id: i32, name: Option<&str>, permanent: Option<bool>) -> Error {
+    Error {
+        id: Some(id),
+        name: name.map(str::to_string),
+        permanent,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn error_retry_advice_absent_subscriber() {
+    assert_eq!(
+        get_dummy_error(27, None, None).retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_LONG_RETRY_DELAY)
+    );
+    assert_eq!(
+        get_dummy_error(27, Some("EC_ABSENT_SUBSCRIBER"), Some(false)).retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_LONG_RETRY_DELAY)
+    );
+}
+
+#[test]
+fn error_retry_advice_throttling() {
+    assert_eq!(
+        get_dummy_error(9999, Some("EC_THROTTLING"), Some(false)).retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_SHORT_RETRY_DELAY)
+    );
+}
+
+#[test]
+fn error_retry_advice_unknown_subscriber() {
+    assert_eq!(
+        get_dummy_error(1, None, None).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+    assert_eq!(
+        get_dummy_error(1, Some("EC_UNKNOWN_SUBSCRIBER"), Some(true)).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+}
+
+#[test]
+fn error_retry_advice_permanent_over_name() {
+    assert_eq!(
+        get_dummy_error(27, Some("EC_ABSENT_SUBSCRIBER"), Some(true)).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+}
+
+#[test]
+fn error_retry_advice_unrecognized() {
+    assert_eq!(
+        get_dummy_error(9999, None, None).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+    assert_eq!(
+        get_dummy_error(9999, Some("EC_SOMETHING_NEW"), Some(false)).retry_advice(),
+        RetryAdvice::DoNotRetry
+    );
+    assert_eq!(Error::default().retry_advice(), RetryAdvice::DoNotRetry);
+}
+
+#[test]
+fn error_retry_advice_custom_policy() {
+    let policy = RetryPolicy {
+        short_delay: Duration::from_secs(30),
+        long_delay: Duration::from_secs(60 * 60),
+    };
+
+    assert_eq!(
+        get_dummy_error(27, None, None).retry_advice_with(&policy),
+        RetryAdvice::RetryLater(Duration::from_secs(60 * 60))
+    );
+    assert_eq!(
+        get_dummy_error(9999, Some("EC_THROTTLING"), None).retry_advice_with(&policy),
+        RetryAdvice::RetryLater(Duration::from_secs(30))
+    );
+    assert_eq!(
+        get_dummy_error(9999, Some("EC_SYSTEM_FAILURE"), None).retry_advice_with(&policy),
+        RetryAdvice::RetryNow
+    );
+}
+
+#[test]
+fn report_application_and_entity_ids() {
+    let body: GetDeliveryReportsResponseBody = serde_json::from_value(load_fixture(
+        "sms/get_delivery_reports_platform_response.json",
+    ))
+    .unwrap();
+    let results = body.results.unwrap();
+
+    assert_eq!(results[0].application_id.as_deref(), Some("marketing-app"));
+    assert_eq!(results[0].entity_id.as_deref(), Some("promotional-traffic"));
+    assert_eq!(results[1].application_id, None);
+    assert_eq!(results[1].entity_id, None);
+    assert_eq!(
+        results[1].error.as_ref().unwrap().retry_advice(),
+        RetryAdvice::RetryLater(DEFAULT_LONG_RETRY_DELAY)
+    );
+}
+
+fn get_destinations(count: usize) -> Vec<Destination> {
+    (0..count)
+        .map(|index| Destination::new(&format!("4179302{:04}", index)))
+        .collect()
+}
+
+#[test]
+fn send_request_body_split_into_chunks() {
+    let mut request_body = SendRequestBody::new(vec![
+        Message::new(get_destinations(3)),
+        Message::new(get_destinations(4)),
+    ]);
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+
+    let chunks = request_body.split_into_chunks(5);
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks
+        .iter()
+        .all(|chunk| chunk.bulk_id.as_deref() == Some("some-bulk-id")));
+    let sizes: Vec<Vec<usize>> = chunks
+        .iter()
+        .map(|chunk| {
+            chunk
+                .messages
+                .iter()
+                .map(|message| message.destinations.as_ref().unwrap().len())
+                .collect()
+        })
+        .collect();
+    assert_eq!(sizes, vec![vec![3, 2], vec![2]]);
+    assert_eq!(
+        chunks[1].messages[0].destinations.as_ref().unwrap()[0].to,
+        "41793020002"
+    );
+}
+
+#[test]
+fn send_request_body_split_into_chunks_fits() {
+    let request_body = SendRequestBody::new(vec![Message::new(get_destinations(3))]);
+
+    assert_eq!(request_body.split_into_chunks(1000), vec![request_body]);
+}
+
+#[test]
+fn destination_message_id_and_network_id_serialization() {
+    let mut first = Destination::with_message_id("41793026727", "message-1");
+    first.network_id = Some(13);
+    let second = Destination::with_message_id("41793026728", "message-2");
+    let request_body = SendRequestBody::new(vec![Message::new(vec![first, second])]);
+
+    let value = serde_json::to_value(&request_body).unwrap();
+    let destinations = &value["messages"][0]["destinations"];
+
+    assert_eq!(
+        destinations[0],
+        serde_json::json!({"messageId": "message-1", "networkId": 13, "to": "41793026727"})
+    );
+    assert_eq!(
+        destinations[1],
+        serde_json::json!({"messageId": "message-2", "to": "41793026728"})
+    );
+    assert_eq!(
+        serde_json::to_value(Destination::new("41793026727")).unwrap(),
+        serde_json::json!({"to": "41793026727"})
+    );
+}
+
+#[test]
+fn destination_message_id_too_long() {
+    let destination = Destination::with_message_id("41793026727", &"a".repeat(201));
+    let request_body = SendRequestBody::new(vec![Message::new(vec![destination])]);
+
+    assert!(request_body.validate().is_err());
+    assert!(
+        Destination::with_message_id("41793026727", &"a".repeat(200))
+            .validate()
+            .is_ok()
+    );
+}
+
+#[test]
+fn update_scheduled_status_request_body_targets() {
+    for status in [
+        ScheduledStatus::PENDING,
+        ScheduledStatus::PAUSED,
+        ScheduledStatus::CANCELED,
+    ] {
+        assert!(UpdateScheduledStatusRequestBody::new(status)
+            .validate()
+            .is_ok());
+    }
+    for status in [
+        ScheduledStatus::PROCESSING,
+        ScheduledStatus::FINISHED,
+        ScheduledStatus::FAILED,
+    ] {
+        assert!(UpdateScheduledStatusRequestBody::new(status)
+            .validate()
+            .is_err());
+        assert!(status.is_terminal() || status == ScheduledStatus::PROCESSING);
+    }
+    assert!(
+        UpdateScheduledStatusRequestBody::new(ScheduledStatus::Unknown)
+            .validate()
+            .is_err()
+    );
+}
+
+#[test]
+fn get_scheduled_status_unknown() {
+    let response: GetScheduledStatusResponseBody =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "status": "ARCHIVED"}"#).unwrap();
+
+    assert_eq!(response.status, Some(ScheduledStatus::Unknown));
+    assert!(!ScheduledStatus::Unknown.is_terminal());
+}
+
+#[test]
+fn tfa_message_template_unknown_values() {
+    let template: TfaMessageTemplate = serde_json::from_str(
+        r#"{
+          "language": "xx",
+          "messageText": "Your pin is {{pin}}",
+          "pinLength": 4,
+          "pinType": "EMOJI"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(template.language, Some(TfaLanguage::Unknown));
+    assert_eq!(template.pin_type, PinType::Unknown);
+}
+
+#[test]
+fn language_code_unknown() {
+    let language_code: LanguageCode = serde_json::from_str(r#""XX""#).unwrap();
+
+    assert_eq!(language_code, LanguageCode::Unknown);
+    assert_eq!(LanguageCode::parse("UNKNOWN"), None);
+    assert_eq!(
+        estimate_parts("Merhaba", Some(LanguageCode::Unknown), None),
+        estimate_parts("Merhaba", None, None)
+    );
+}
+
+#[test]
+fn message_language_serialization() {
+    let mut message = Message::new(vec![Destination::new("123456789012")]);
+    message.text = Some("Merhaba dünya".to_string());
+    message.language = Some(Language::new(LanguageCode::TR));
+    message.transliteration = Some("TURKISH".to_string());
+
+    let serialized = serde_json::to_value(&message).unwrap();
+
+    assert_eq!(
+        serialized["language"],
+        serde_json::json!({"languageCode": "TR"})
+    );
+    assert_eq!(serialized["transliteration"], "TURKISH");
+    assert!(SendRequestBody::new(vec![message]).validate().is_ok());
+
+    let deserialized: Language = serde_json::from_str(r#"{"languageCode": "PT"}"#).unwrap();
+    assert_eq!(deserialized.language_code, Some(LanguageCode::PT));
+}
+
+#[test]
+fn language_code_parse() {
+    assert_eq!(LanguageCode::parse("ES"), Some(LanguageCode::ES));
+    assert_eq!(
+        LanguageCode::parse("AUTODETECT"),
+        Some(LanguageCode::AUTODETECT)
+    );
+    assert_eq!(LanguageCode::parse("es"), None);
+    assert_eq!(LanguageCode::TR.as_str(), "TR");
+}
+
+#[test]
+fn send_over_query_parameters_invalid_language_code() {
+    let mut query_parameters = SendOverQueryParametersQueryParameters::new(
+        "user",
+        "pass",
+        vec!["41793026727".to_string()],
+    );
+    query_parameters.language_code = Some("XX".to_string());
+
+    assert!(query_parameters.validate().is_err());
+
+    query_parameters.language_code = Some(LanguageCode::TR.as_str().to_string());
+    assert!(query_parameters.validate().is_ok());
+}
+
+#[test]
+fn send_request_schedule_at_converts_to_utc() {
+    let mut request_body = SendRequestBody::new(vec![
+        Message::new(vec![Destination::new("41793026727")]),
+        Message::new(vec![Destination::new("41793026728")]),
+    ]);
+
+    request_body
+        .schedule_at(chrono::DateTime::parse_from_rfc3339("2099-06-15T10:00:00+05:30").unwrap());
+
+    for message in &request_body.messages {
+        assert_eq!(
+            message.send_at.as_deref(),
+            Some("2099-06-15T04:30:00.000+0000")
+        );
+    }
+    assert!(request_body.validate().is_ok());
+    assert!(request_body
+        .check_send_at(chrono::Duration::minutes(1))
+        .is_ok());
+}
+
+#[test]
+fn send_request_check_send_at_in_past() {
+    let mut request_body = SendRequestBody::new(vec![
+        Message::new(vec![Destination::new("41793026727")]),
+        Message::new(vec![Destination::new("41793026728")]),
+    ]);
+    request_body.schedule_at(chrono::Utc::now() - chrono::Duration::minutes(10));
+    request_body.messages[0].send_at = None;
+
+    let errors = request_body
+        .check_send_at(chrono::Duration::minutes(1))
+        .unwrap_err();
+    let message_errors = &errors.field_errors()["messages"];
+    assert_eq!(message_errors.len(), 1);
+    assert_eq!(message_errors[0].code, "send_at_in_past");
+    assert_eq!(message_errors[0].params["index"], 1);
+
+    assert!(request_body
+        .check_send_at(chrono::Duration::minutes(15))
+        .is_ok());
+}
+
+#[test]
+fn send_request_check_send_at_format() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.send_at = Some("2099-06-15 10:00".to_string());
+    let request_body = SendRequestBody::new(vec![message]);
+
+    let errors = request_body
+        .check_send_at(chrono::Duration::zero())
+        .unwrap_err();
+
+    assert_eq!(errors.field_errors()["messages"][0].code, "send_at_format");
+}
+
+#[test]
+fn get_scheduled_response_send_at_datetime() {
+    let expected = chrono::DateTime::parse_from_rfc3339("2022-10-01T12:30:00Z").unwrap();
+
+    for body in [
+        r#"{"bulkId": "some-bulk-id", "sendAt": "2022-10-01T12:30:00.000+0000"}"#,
+        r#"{"bulkId": "some-bulk-id", "sendAt": "2022-10-01T14:30:00+02:00"}"#,
+        r#"{"bulkId": "some-bulk-id", "sendAt": 1664627400000}"#,
+        r#"{"bulkId": "some-bulk-id", "sendAt": "1664627400000"}"#,
+    ] {
+        let response: GetScheduledResponseBody = serde_json::from_str(body).unwrap();
+
+        assert_eq!(
+            response.send_at_datetime(),
+            Some(expected.into()),
+            "{}",
+            body
+        );
+    }
+}
+
+#[test]
+fn message_max_parts_gsm7() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some("a".repeat(200));
+    assert!(message.validate().is_ok());
+
+    message.max_parts = Some(1);
+    let errors = message.validate().unwrap_err();
+    let error = &errors.errors()["__all__"];
+    match error {
+        validator::ValidationErrorsKind::Field(errors) => {
+            assert_eq!(errors[0].code, "max_parts");
+            assert_eq!(errors[0].params["parts"], 2);
+            assert_eq!(
+                errors[0].message.as_deref(),
+                Some("text needs an estimated 2 GSM-7 parts for 200 characters, more than max_parts 1")
+            );
+        }
+        _ => panic!("unexpected errors: {:?}", errors),
+    }
+
+    message.max_parts = Some(2);
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn message_max_parts_in_send_request() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some("a".repeat(200));
+    message.max_parts = Some(1);
+
+    let request_body = SendRequestBody::new(vec![message]);
+
+    assert!(request_body.validate().is_err());
+    assert!(!serde_json::to_string(&request_body)
+        .unwrap()
+        .contains("maxParts"));
+}
+
+#[test]
+fn estimate_parts_table() {
+    let cases = vec![
+        ("a".repeat(160), None, None, SmsEncoding::Gsm7, 160, 1),
+        ("a".repeat(161), None, None, SmsEncoding::Gsm7, 161, 2),
+        ("a".repeat(306), None, None, SmsEncoding::Gsm7, 306, 2),
+        ("a".repeat(307), None, None, SmsEncoding::Gsm7, 307, 3),
+        ("€".repeat(80), None, None, SmsEncoding::Gsm7, 160, 1),
+        ("ş".repeat(70), None, None, SmsEncoding::Ucs2, 70, 1),
+        ("ş".repeat(71), None, None, SmsEncoding::Ucs2, 71, 2),
+        (
+            "ş".repeat(71),
+            Some(LanguageCode::TR),
+            None,
+            SmsEncoding::Gsm7,
+            142,
+            1,
+        ),
+        (
+            "á".repeat(80),
+            Some(LanguageCode::AUTODETECT),
+            None,
+            SmsEncoding::Gsm7,
+            160,
+            2,
+        ),
+        (
+            "ş".repeat(71),
+            Some(LanguageCode::ES),
+            None,
+            SmsEncoding::Ucs2,
+            71,
+            2,
+        ),
+        (
+            "Привет".to_string(),
+            None,
+            Some("CYRILLIC"),
+            SmsEncoding::Gsm7,
+            6,
+            1,
+        ),
+        ("😀".repeat(35), None, None, SmsEncoding::Ucs2, 70, 1),
+    ];
+
+    for (text, language_code, transliteration, encoding, length, parts) in cases {
+        let estimate = estimate_parts(&text, language_code, transliteration);
+
+        assert_eq!(
+            estimate,
+            PartsEstimate {
+                encoding,
+                length,
+                parts
+            },
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn message_estimate_parts_ucs2_differs() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some("a".repeat(100));
+    let gsm7 = message.estimate_parts().unwrap();
+
+    message.text = Some(format!("{}ş", "a".repeat(99)));
+    let ucs2 = message.estimate_parts().unwrap();
+
+    assert_eq!((gsm7.encoding, gsm7.parts), (SmsEncoding::Gsm7, 1));
+    assert_eq!((ucs2.encoding, ucs2.parts), (SmsEncoding::Ucs2, 2));
+
+    message.max_parts = Some(1);
+    assert!(message.validate().is_err());
+
+    message.language = Some(Language::new(LanguageCode::TR));
+    assert_eq!(
+        message.estimate_parts().unwrap().encoding,
+        SmsEncoding::Gsm7
+    );
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn delivery_reports_empty_strings_as_none() {
+    let fixture = "sms/get_delivery_reports_empty_strings_response.json";
+    let response: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture(fixture)).unwrap();
+    let report = &response.results.unwrap()[0];
+
+    assert_eq!(report.bulk_id, None);
+    assert_eq!(report.from, None);
+    assert_eq!(report.mcc_mnc, None);
+    assert_eq!(report.callback_data, None);
+    assert_eq!(report.status.as_ref().unwrap().action, None);
+    assert_eq!(report.error.as_ref().unwrap().description, None);
+    assert_eq!(report.message_id.as_deref(), Some("MESSAGE-ID-123-xyz"));
+
+    let serialized = serde_json::to_value(report).unwrap();
+    for key in ["bulkId", "from", "mccMnc", "callbackData"] {
+        assert!(serialized.get(key).is_none(), "{}", key);
+    }
+    assert!(serialized["status"].get("action").is_none());
+    assert!(serialized["error"].get("description").is_none());
+}
+
+#[test]
+fn log_empty_strings_as_none() {
+    let log: Log =
+        serde_json::from_str(r#"{"bulkId": "", "networkName": "", "to": "41793026727"}"#).unwrap();
+
+    assert_eq!(log.bulk_id, None);
+    assert_eq!(log.network_name, None);
+    assert_eq!(
+        serde_json::to_string(&log).unwrap(),
+        r#"{"to":"41793026727"}"#
+    );
+}
+
+#[test]
+fn send_response_partitions_mixed_statuses() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"{
+            "bulkId": "some-bulk-id",
+            "messages": [
+                {"to": "41793026727", "status": {"groupId": 1, "groupName": "PENDING"}},
+                {"to": "41793026728", "status": {"groupId": 5, "groupName": "REJECTED"}},
+                {"to": "41793026729", "status": {"groupId": 3, "groupName": "DELIVERED"}},
+                {"to": "41793026730", "status": {"groupId": 2, "groupName": "UNDELIVERABLE"}}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let accepted: Vec<_> = response
+        .accepted()
+        .iter()
+        .map(|details| details.to.as_deref().unwrap())
+        .collect();
+    let rejected: Vec<_> = response
+        .rejected()
+        .iter()
+        .map(|details| details.to.as_deref().unwrap())
+        .collect();
+
+    assert_eq!(accepted, vec!["41793026727", "41793026729"]);
+    assert_eq!(rejected, vec!["41793026728", "41793026730"]);
+    assert!(!response.all_accepted());
+}
+
+#[test]
+fn send_response_all_accepted() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"{"messages": [
+            {"to": "41793026727", "status": {"groupId": 1}},
+            {"to": "41793026728", "status": {"groupId": 0}}
+        ]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(response.accepted().len(), 2);
+    assert!(response.rejected().is_empty());
+    assert!(response.all_accepted());
+}
+
+#[test]
+fn send_response_statusless_messages_rejected() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"{"messages": [
+            {"to": "41793026727"},
+            {"to": "41793026728", "status": {"groupName": "PENDING"}}
+        ]}"#,
+    )
+    .unwrap();
+
+    assert!(response.accepted().is_empty());
+    assert_eq!(response.rejected().len(), 2);
+    assert!(!response.all_accepted());
+
+    let empty = SendResponseBody::default();
+    assert!(empty.accepted().is_empty());
+    assert!(empty.rejected().is_empty());
+    assert!(!empty.all_accepted());
+}
+
+#[test]
+fn send_request_body_options_serialization() {
+    let mut request_body =
+        SendRequestBody::new(vec![Message::new(vec![Destination::new("41793026727")])]);
+    request_body.bulk_id = Some("some-bulk-id".to_string());
+    request_body.options = Some(Options {
+        conversion_tracking: Some(ConversionTracking::enabled("ONE_TIME_PIN")),
+    });
+
+    let value = serde_json::to_value(&request_body).unwrap();
+
+    assert_eq!(value["bulkId"], "some-bulk-id");
+    assert_eq!(
+        value["options"],
+        serde_json::json!({
+            "conversionTracking": {
+                "useConversionTracking": true,
+                "conversionTrackingName": "ONE_TIME_PIN"
+            }
+        })
+    );
+    assert!(request_body.validate().is_ok());
+
+    request_body.options = None;
+    let value = serde_json::to_value(&request_body).unwrap();
+    assert!(value.get("options").is_none());
+}
+
+#[test]
+fn send_request_body_conversion_tracking_without_name() {
+    let mut request_body =
+        SendRequestBody::new(vec![Message::new(vec![Destination::new("41793026727")])]);
+    let mut options = Options::new();
+    options.conversion_tracking = Some(ConversionTracking {
+        use_conversion_tracking: Some(true),
+        tracking_name: Some(" ".to_string()),
+    });
+    request_body.options = Some(options);
+
+    let errors = request_body.validate().unwrap_err();
+    assert!(errors.to_string().contains("conversionTrackingName"));
+
+    request_body.options = Some(Options {
+        conversion_tracking: Some(ConversionTracking {
+            use_conversion_tracking: Some(false),
+            tracking_name: None,
+        }),
+    });
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn parse_inbound_sms_borrowed_matches_owned() {
+    use crate::model::tests::round_trip::read_fixture;
+    use std::borrow::Cow;
+
+    let body = read_fixture("sms/inbound_messages.json");
+    let owned: GetInboundReportsResponseBody = serde_json::from_slice(&body).unwrap();
+    let borrowed = parse_inbound_sms_borrowed(&body).unwrap();
+
+    assert_eq!(borrowed.to_owned(), owned);
+
+    let message = &borrowed.results.as_ref().unwrap()[0];
+    assert!(matches!(message.message_id, Some(Cow::Borrowed(_))));
+    assert!(matches!(message.text, Some(Cow::Owned(_))));
+}
+
+#[test]
+fn parse_delivery_reports_borrowed_matches_owned() {
+    use crate::model::tests::round_trip::read_fixture;
+
+    for fixture in [
+        "sms/get_delivery_reports_response.json",
+        "sms/get_delivery_reports_ported_response.json",
+        "sms/get_delivery_reports_empty_strings_response.json",
+        "sms/get_delivery_reports_platform_response.json",
+    ] {
+        let body = read_fixture(fixture);
+        let owned: GetDeliveryReportsResponseBody = serde_json::from_slice(&body).unwrap();
+        let borrowed = parse_delivery_reports_borrowed(&body).unwrap();
+
+        assert_eq!(borrowed.to_owned(), owned, "{}", fixture);
+    }
+}
+
+#[test]
+fn response_models_deserialize_from_empty_object() {
+    assert_deserializes_empty::<Error>();
+    assert_deserializes_empty::<GetDeliveryReportsResponseBody>();
+    assert_deserializes_empty::<GetInboundReportsResponseBody>();
+    assert_deserializes_empty::<GetLogsResponseBody>();
+    assert_deserializes_empty::<GetScheduledStatusResponseBody>();
+    assert_deserializes_empty::<GetTfaVerificationStatusResponseBody>();
+    assert_deserializes_empty::<InboundSmsReport>();
+    assert_deserializes_empty::<Language>();
+    assert_deserializes_empty::<Log>();
+    assert_deserializes_empty::<crate::model::common::Paging>();
+    assert_deserializes_empty::<Preview>();
+    assert_deserializes_empty::<PreviewLanguageConfiguration>();
+    assert_deserializes_empty::<PreviewResponseBody>();
+    assert_deserializes_empty::<Price>();
+    assert_deserializes_empty::<Report>();
+    assert_deserializes_empty::<SendPinResponseBody>();
+    assert_deserializes_empty::<SendResponseBody>();
+    assert_deserializes_empty::<SentMessageDetails>();
+    assert_deserializes_empty::<Status>();
+    assert_deserializes_empty::<TfaApplicationConfiguration>();
+    assert_deserializes_empty::<TfaRegional>();
+    assert_deserializes_empty::<TfaVerification>();
+    assert_deserializes_empty::<Tracking>();
+    assert_deserializes_empty::<VerifyPhoneNumberResponseBody>();
+}
+
+#[test]
+fn message_apply_preview_configuration_send_body() {
+    let response: PreviewResponseBody =
+        serde_json::from_value(load_fixture("sms/preview_response.json")).unwrap();
+    let previews = response.previews.as_ref().unwrap();
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = response.original_text.clone();
+
+    message.apply_preview(&response, &previews[1]).unwrap();
+
+    assert_eq!(
+        Language::from(previews[1].configuration.as_ref().unwrap()),
+        Language::new(LanguageCode::TR)
+    );
+    assert_eq!(
+        serde_json::to_value(SendRequestBody::new(vec![message.clone()])).unwrap(),
+        serde_json::json!({
+            "messages": [{
+                "destinations": [{"to": "41793026727"}],
+                "text": "Mesajınız hazır, lütfen şubeye uğrayın.",
+                "language": {"languageCode": "TR"},
+                "transliteration": "TURKISH"
+            }]
+        })
+    );
+    assert!(message.validate().is_ok());
+
+    message.apply_preview(&response, &previews[0]).unwrap();
+
+    assert_eq!(message.language, None);
+    assert_eq!(message.transliteration, None);
+}
+
+#[test]
+fn message_apply_preview_different_text() {
+    let response: PreviewResponseBody =
+        serde_json::from_value(load_fixture("sms/preview_response.json")).unwrap();
+    let preview = &response.previews.as_ref().unwrap()[1];
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some("Some other text".to_string());
+
+    let error = message.apply_preview(&response, preview).unwrap_err();
+
+    assert_eq!(error.code, "preview_text");
+    assert_eq!(message.language, None);
+    assert_eq!(message.transliteration, None);
+}
+
+#[test]
+fn send_request_body_wire_names() {
+    let request_body = SendRequestBody {
+        bulk_id: Some("some-bulk-id".to_string()),
+        messages: vec![Message::new(vec![Destination::new("41793026727")])],
+        sending_speed_limit: Some(SpeedLimit::new(10)),
+        url_options: Some(UrlOptions {
+            shorten_url: Some(true),
+            track_clicks: None,
+            tracking_url: None,
+            remove_protocol: None,
+            custom_domain: None,
+        }),
+        tracking: Some(Tracking::new()),
+        options: Some(Options::new()),
+    };
+
+    assert_wire_names(&request_body);
+    assert_eq!(
+        SendRequestBody::wire_name("sending_speed_limit"),
+        Some("sendingSpeedLimit")
+    );
+}
+
+fn placeholders(pairs: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn destination_placeholders_serialization() {
+    let mut message = Message::new(vec![
+        Destination::with_placeholders(
+            "41793026727",
+            placeholders(&[("name", "John"), ("code", "1234")]),
+        ),
+        Destination::new("41793026728"),
+    ]);
+    message.text = Some("Hello {{name}}, your code is {{ code }}".to_string());
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "destinations": [{"to": "41793026727"}, {"to": "41793026728"}],
+            "text": "Hello {{name}}, your code is {{ code }}"
+        })
+    );
+}
+
+#[test]
+fn destination_placeholders_expanded() {
+    let mut message = Message::new(vec![
+        Destination::with_placeholders(
+            "41793026727",
+            placeholders(&[("name", "John"), ("code", "1234")]),
+        ),
+        Destination::with_placeholders("41793026728", placeholders(&[("name", "Jane")])),
+    ]);
+    message.text = Some("Hello {{name}}, your code is {{ code }}".to_string());
+    message.from = Some("InfoSMS".to_string());
+
+    let messages = message.expand_placeholders();
+
+    assert_eq!(
+        serde_json::to_value(&messages).unwrap(),
+        serde_json::json!([
+            {
+                "destinations": [{"to": "41793026727"}],
+                "from": "InfoSMS",
+                "text": "Hello John, your code is 1234"
+            },
+            {
+                "destinations": [{"to": "41793026728"}],
+                "from": "InfoSMS",
+                "text": "Hello Jane, your code is {{ code }}"
+            }
+        ])
+    );
+
+    let mut plain = Message::new(vec![Destination::new("41793026727")]);
+    plain.text = Some("Hello {{name}}".to_string());
+    assert_eq!(plain.expand_placeholders(), vec![plain]);
+}
+
+#[test]
+fn destination_placeholders_valid() {
+    let mut message = Message::new(vec![
+        Destination::with_placeholders("41793026727", placeholders(&[("name", "John")])),
+        Destination::with_placeholders(
+            "41793026728",
+            placeholders(&[("name", "Jane"), ("unused", "value")]),
+        ),
+    ]);
+    message.text = Some("Hello {{name}}!".to_string());
+
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn destination_placeholders_missing_key() {
+    let mut message = Message::new(vec![
+        Destination::with_placeholders(
+            "41793026727",
+            placeholders(&[("name", "John"), ("code", "1234")]),
+        ),
+        Destination::with_placeholders("41793026728", placeholders(&[("name", "Jane")])),
+    ]);
+    message.text = Some("Hello {{name}}, your code is {{code}}".to_string());
+
+    let errors = message.validate().unwrap_err();
+    let error = &errors.field_errors()["__all__"][0];
+
+    assert_eq!(error.code, "destination_placeholder");
+    assert_eq!(error.params["index"], 1);
+    assert_eq!(error.params["key"], "code");
+    assert_eq!(
+        error.message.as_deref(),
+        Some("destination 1 has no value for placeholder 'code'")
+    );
+}
+
+#[test]
+fn destination_placeholders_missing_map() {
+    let mut message = Message::new(vec![
+        Destination::new("41793026727"),
+        Destination::with_placeholders("41793026728", placeholders(&[("name", "Jane")])),
+    ]);
+    message.text = Some("Hello {{name}}".to_string());
+
+    let errors = message.validate().unwrap_err();
+    assert_eq!(errors.field_errors()["__all__"][0].params["index"], 0);
+
+    message.destinations = Some(vec![Destination::new("41793026727")]);
+    assert!(message.validate().is_ok());
+}
+
+#[test]
+fn destination_placeholders_checked_with_max_parts() {
+    let mut message = Message::new(vec![Destination::with_placeholders(
+        "41793026727",
+        placeholders(&[]),
+    )]);
+    message.text = Some(format!("{} {{{{name}}}}", "a".repeat(200)));
+    message.max_parts = Some(1);
+
+    let errors = message.validate().unwrap_err();
+    let codes: Vec<_> = errors.field_errors()["__all__"]
+        .iter()
+        .map(|error| error.code.to_string())
+        .collect();
+
+    assert_eq!(codes, vec!["max_parts", "destination_placeholder"]);
+}
@@ -0,0 +1,167 @@
+use validator::Validate;
+
+use crate::model::tests::round_trip::{
+    assert_deserializes_empty, assert_round_trip, load_fixture, read_fixture,
+};
+use crate::model::voice::*;
+use crate::model::ToQueryString;
+
+#[test]
+fn answered_call_report_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("voice/answered_call_report.json");
+}
+
+#[test]
+fn unanswered_call_report_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("voice/unanswered_call_report.json");
+}
+
+#[test]
+fn answered_call_report_dtmf_codes() {
+    let response_body: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture("voice/answered_call_report.json")).unwrap();
+    let report = &response_body.results.unwrap()[0];
+    let voice_call = report.voice_call.as_ref().unwrap();
+
+    assert_eq!(report.call_status(), CallStatus::Delivered);
+    assert_eq!(voice_call.dtmf_codes.as_deref(), Some("1,2,#"));
+    assert_eq!(voice_call.dtmf_digits(), vec!["1", "2", "#"]);
+    assert_eq!(voice_call.duration, Some(10));
+}
+
+#[test]
+fn unanswered_call_report() {
+    let response_body: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture("voice/unanswered_call_report.json")).unwrap();
+    let report = &response_body.results.unwrap()[0];
+    let voice_call = report.voice_call.as_ref().unwrap();
+
+    assert_eq!(report.call_status(), CallStatus::Undeliverable);
+    assert!(voice_call.answer_time.is_none());
+    assert!(voice_call.dtmf_digits().is_empty());
+    assert_eq!(
+        report.error.as_ref().unwrap().name.as_deref(),
+        Some("EC_VOICE_NO_ANSWER")
+    );
+}
+
+#[test]
+fn unknown_call_status() {
+    let status: Status = serde_json::from_str(r#"{"groupName": "SOMETHING_NEW"}"#).unwrap();
+
+    assert_eq!(status.group_name, Some(CallStatus::Unknown));
+}
+
+#[test]
+fn get_delivery_reports_query_parameters() {
+    let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+    query_parameters.bulk_id = Some("some-bulk-id".to_string());
+    query_parameters.limit = Some(10);
+
+    assert!(query_parameters.validate().is_ok());
+    assert_eq!(
+        query_parameters.to_query_string().unwrap(),
+        "bulkId=some-bulk-id&limit=10"
+    );
+
+    query_parameters.limit = Some(1001);
+    assert!(query_parameters.validate().is_err());
+}
+
+#[test]
+fn machine_answered_call_report_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("voice/machine_answered_call_report.json");
+}
+
+#[test]
+fn no_answer_call_report_round_trip() {
+    assert_round_trip::<GetDeliveryReportsResponseBody>("voice/no_answer_call_report.json");
+}
+
+#[test]
+fn parse_voice_reports_machine_answered() {
+    let reports =
+        parse_voice_reports(&read_fixture("voice/machine_answered_call_report.json")).unwrap();
+    let report = &reports[0];
+
+    assert_eq!(report.voice_status(), VoiceReportStatus::AnsweredMachine);
+    assert_eq!(
+        report.answered_at.as_deref(),
+        Some("2023-09-25T10:00:08.000+0000")
+    );
+    assert_eq!(report.duration, Some(30));
+}
+
+#[test]
+fn parse_voice_reports_no_answer() {
+    let reports = parse_voice_reports(&read_fixture("voice/no_answer_call_report.json")).unwrap();
+    let report = &reports[0];
+
+    assert_eq!(report.voice_status(), VoiceReportStatus::NoAnswer);
+    assert!(report.answered_at.is_none());
+    assert_eq!(report.duration, Some(0));
+}
+
+#[test]
+fn voice_report_status_of_pulled_reports() {
+    let response_body: GetDeliveryReportsResponseBody =
+        serde_json::from_value(load_fixture("voice/answered_call_report.json")).unwrap();
+
+    assert_eq!(
+        response_body.results.unwrap()[0].voice_status(),
+        VoiceReportStatus::Answered
+    );
+}
+
+#[test]
+fn voice_report_status_from_errors() {
+    let cases = vec![
+        (
+            r#"{"error": {"id": 5003, "name": "EC_VOICE_USER_BUSY"}}"#,
+            VoiceReportStatus::Busy,
+        ),
+        (
+            r#"{"status": {"groupName": "UNDELIVERABLE"}, "error": {"id": 5999, "name": "EC_SOMETHING_ELSE"}}"#,
+            VoiceReportStatus::Failed,
+        ),
+        (
+            r#"{"error": {"id": 5999, "name": "EC_SOMETHING_ELSE"}}"#,
+            VoiceReportStatus::Failed,
+        ),
+        (
+            r#"{"status": {"groupName": "PENDING"}}"#,
+            VoiceReportStatus::Pending,
+        ),
+        ("{}", VoiceReportStatus::Unknown),
+    ];
+
+    for (body, expected) in cases {
+        let report: Report = serde_json::from_str(body).unwrap();
+
+        assert_eq!(report.voice_status(), expected, "{}", body);
+    }
+}
+
+#[test]
+fn unknown_voice_report_status() {
+    let status: VoiceReportStatus = serde_json::from_str(r#""VOICEMAIL_FULL""#).unwrap();
+    assert_eq!(status, VoiceReportStatus::Unknown);
+
+    let status: VoiceReportStatus = serde_json::from_str(r#""ANSWERED_MACHINE""#).unwrap();
+    assert_eq!(status, VoiceReportStatus::AnsweredMachine);
+}
+
+#[test]
+fn parse_voice_reports_invalid_body() {
+    assert!(parse_voice_reports(b"not json").is_err());
+}
+
+#[test]
+fn response_models_deserialize_from_empty_object() {
+    assert_deserializes_empty::<Error>();
+    assert_deserializes_empty::<GetDeliveryReportsResponseBody>();
+    assert_deserializes_empty::<Price>();
+    assert_deserializes_empty::<Report>();
+    assert_deserializes_empty::<Status>();
+    assert_deserializes_empty::<VoiceCall>();
+}
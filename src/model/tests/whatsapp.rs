@@ -1,5 +1,6 @@
 use validator::Validate;
 
+use crate::model::tests::round_trip::{assert_deserializes_empty, assert_round_trip, read_fixture};
 use crate::model::whatsapp::*;
 
 fn get_dummy_send_template_request_body() -> SendTemplateRequestBody {
@@ -29,6 +30,7 @@ fn get_dummy_send_template_request_body() -> SendTemplateRequestBody {
         sms_failover: Some(SmsFailover {
             from: "666666666666".to_string(),
             text: "message text".to_string(),
+            validity_period: Some(720),
         }),
     };
 
@@ -416,6 +418,86 @@ fn send_template_request_body_message_long_id() {
     assert!(request_body.validate().is_err());
 }
 
+fn get_dummy_template_bulk(count: usize) -> SendTemplateRequestBody {
+    let template = get_dummy_send_template_request_body();
+    let messages = (0..count)
+        .map(|index| {
+            let mut message = template.messages[0].clone();
+            message.to = format!("4179302{:05}", index);
+            message.message_id = Some(format!("message-{}", index));
+            message.callback_data = Some(format!("customer-{}", index));
+            message
+        })
+        .collect();
+
+    SendTemplateRequestBody {
+        messages,
+        bulk_id: template.bulk_id,
+    }
+}
+
+#[test]
+fn send_template_request_body_bulk_indexed_error() {
+    let mut request_body = get_dummy_template_bulk(3);
+    request_body.messages[1]
+        .content
+        .template_data
+        .body
+        .placeholders = vec!["value1".to_string(), " ".to_string()];
+
+    let errors = request_body.validate().unwrap_err();
+    let field_errors = crate::api::format_validation_errors(&errors);
+
+    assert_eq!(field_errors.len(), 1);
+    assert_eq!(
+        field_errors[0].path,
+        "messages[1].content.templateData.body.placeholders"
+    );
+    assert_eq!(field_errors[0].message, "placeholder 1 must not be empty");
+    assert!(crate::api::SdkError::Validation(errors)
+        .to_string()
+        .contains("messages[1]"));
+}
+
+#[test]
+fn send_template_request_body_large_bulk() {
+    let request_body = get_dummy_template_bulk(250);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_template_request_body_into_chunks() {
+    let chunks = get_dummy_template_bulk(250).into_chunks(100);
+
+    assert_eq!(
+        chunks
+            .iter()
+            .map(|chunk| chunk.messages.len())
+            .collect::<Vec<_>>(),
+        vec![100, 100, 50]
+    );
+    assert_eq!(
+        chunks
+            .iter()
+            .map(|chunk| chunk.bulk_id.as_deref().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["bulk_id-1", "bulk_id-2", "bulk_id-3"]
+    );
+    assert_eq!(
+        chunks[1].messages[0].callback_data.as_deref(),
+        Some("customer-100")
+    );
+    assert!(chunks.iter().all(|chunk| chunk.validate().is_ok()));
+}
+
+#[test]
+fn send_template_request_body_into_chunks_small() {
+    let request_body = get_dummy_template_bulk(3);
+
+    assert_eq!(request_body.clone().into_chunks(100), vec![request_body]);
+}
+
 #[test]
 fn send_template_request_body_message_long_callback_data() {
     let mut request_body = get_dummy_send_template_request_body();
@@ -470,6 +552,46 @@ fn send_template_request_body_message_sms_fallback_long_text() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_template_request_body_message_sms_fallback_long_validity_period() {
+    let mut request_body = get_dummy_send_template_request_body();
+
+    request_body.messages[0]
+        .sms_failover
+        .as_mut()
+        .unwrap()
+        .validity_period = Some(2881);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_template_request_body_with_sms_failover_serialization() {
+    let request_body = get_dummy_send_template_request_body();
+
+    let serialized = serde_json::to_value(&request_body).unwrap();
+
+    assert_eq!(
+        serialized["messages"][0]["smsFailover"],
+        serde_json::json!({
+            "from": "666666666666",
+            "text": "message text",
+            "validityPeriod": 720
+        })
+    );
+}
+
+#[test]
+fn send_template_request_body_without_sms_failover_serialization() {
+    let mut request_body = get_dummy_send_template_request_body();
+    request_body.messages[0].sms_failover = None;
+
+    assert!(request_body.validate().is_ok());
+    let serialized = serde_json::to_value(&request_body).unwrap();
+
+    assert!(serialized["messages"][0].get("smsFailover").is_none());
+}
+
 #[test]
 fn send_template_request_body_message_content_no_template_name() {
     let mut request_body = get_dummy_send_template_request_body();
@@ -1252,6 +1374,91 @@ fn send_interactive_multiproduct_request_body_content_action_section_long_title(
     assert!(request_body.validate().is_err());
 }
 
+fn get_dummy_multiproduct_sections(products: &[usize]) -> Vec<InteractiveMultiproductSection> {
+    products
+        .iter()
+        .enumerate()
+        .map(|(index, count)| {
+            let mut section = InteractiveMultiproductSection::new(
+                (0..*count).map(|product| product.to_string()).collect(),
+            );
+            section.title = Some(format!("Section {}", index));
+            section
+        })
+        .collect()
+}
+
+fn get_schema_error_index(action: &InteractiveMultiproductAction) -> serde_json::Value {
+    let errors = action.validate().unwrap_err();
+
+    errors.field_errors()["__all__"][0].params["index"].clone()
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_content_action_max_products() {
+    let mut request_body = get_dummy_send_interactive_multiproduct_request_body();
+
+    request_body.content.action.sections = get_dummy_multiproduct_sections(&[10, 10, 10]);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_content_action_too_many_products() {
+    let mut request_body = get_dummy_send_interactive_multiproduct_request_body();
+
+    request_body.content.action.sections = get_dummy_multiproduct_sections(&[10, 10, 10, 1]);
+
+    assert!(request_body.validate().is_err());
+    assert_eq!(
+        get_schema_error_index(&request_body.content.action),
+        serde_json::json!(3)
+    );
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_content_action_section_no_title() {
+    let mut request_body = get_dummy_send_interactive_multiproduct_request_body();
+
+    request_body.content.action.sections = get_dummy_multiproduct_sections(&[1, 1, 1]);
+    request_body.content.action.sections[1].title = None;
+
+    assert!(request_body.validate().is_err());
+    assert_eq!(
+        get_schema_error_index(&request_body.content.action),
+        serde_json::json!(1)
+    );
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_content_action_empty_section() {
+    let mut request_body = get_dummy_send_interactive_multiproduct_request_body();
+
+    request_body.content.action.sections = get_dummy_multiproduct_sections(&[2, 0]);
+
+    let errors = request_body.content.action.validate().unwrap_err();
+    match &errors.errors()["sections"] {
+        validator::ValidationErrorsKind::List(sections) => {
+            assert_eq!(sections.keys().collect::<Vec<_>>(), vec![&1]);
+        }
+        _ => panic!("not a list error"),
+    }
+}
+
+#[test]
+fn send_interactive_product_request_body_round_trip() {
+    assert_round_trip::<SendInteractiveProductRequestBody>(
+        "whatsapp/send_interactive_product_request.json",
+    );
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_round_trip() {
+    assert_round_trip::<SendInteractiveMultiproductRequestBody>(
+        "whatsapp/send_interactive_multiproduct_request.json",
+    );
+}
+
 #[test]
 fn create_template_request_body_valid() {
     let structure = TemplateStructure::new(TemplateBody::new("hello"));
@@ -1312,3 +1519,365 @@ fn create_template_request_body_structure_many_buttons() {
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn sender_flagged_quality_deserialization() {
+    let sender: Sender = serde_json::from_str(
+        r#"
+        {
+          "sender": "441134960000",
+          "displayName": "Infobip",
+          "status": "CONNECTED",
+          "qualityRating": "FLAGGED",
+          "messagingLimit": "TIER_1K"
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(sender.sender.unwrap(), "441134960000");
+    assert_eq!(sender.status, Some(SenderStatus::Connected));
+    assert_eq!(sender.quality_rating, Some(SenderQualityRating::Flagged));
+}
+
+#[test]
+fn sender_webhook_configuration_valid() {
+    let request_body = SenderWebhookConfiguration::new("https://example.com/inbound");
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn sender_webhook_configuration_http_url() {
+    let request_body = SenderWebhookConfiguration::new("http://example.com/inbound");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn sender_webhook_configuration_invalid_url() {
+    let request_body = SenderWebhookConfiguration::new("https://");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn delivered_report_round_trip() {
+    assert_round_trip::<Reports>("whatsapp/delivered_report.json");
+}
+
+#[test]
+fn failed_report_round_trip() {
+    assert_round_trip::<Reports>("whatsapp/failed_report.json");
+}
+
+#[test]
+fn parse_delivered_report() {
+    let body = read_fixture("whatsapp/delivered_report.json");
+
+    let reports = parse_whatsapp_reports(&body).unwrap();
+
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(
+        report.status.as_ref().unwrap().group_name.as_deref(),
+        Some("DELIVERED")
+    );
+    assert_eq!(
+        report.conversation.as_ref().unwrap().category,
+        Some(ConversationCategory::Marketing)
+    );
+    assert_eq!(
+        report.price.as_ref().unwrap().price_per_message_f64(),
+        Some(0.0055)
+    );
+    assert_eq!(report.entity_id.as_deref(), Some("promotional-traffic"));
+}
+
+#[test]
+fn parse_failed_report() {
+    let body = read_fixture("whatsapp/failed_report.json");
+
+    let reports = parse_whatsapp_reports(&body).unwrap();
+
+    let error = reports[0].error.as_ref().unwrap();
+    assert_eq!(error.id, Some(7003));
+    assert_eq!(error.name.as_deref(), Some("EC_UNREGISTERED_USER"));
+    assert_eq!(error.permanent, Some(true));
+    assert!(reports[0].conversation.is_none());
+}
+
+#[test]
+fn parse_seen_report() {
+    let body = br#"{"results": [{"messageId": "some-id", "sentAt": "2023-09-25T16:00:00.000+0000", "seenAt": "2023-09-25T16:01:00.000+0000"}]}"#;
+
+    let reports = parse_whatsapp_reports(body).unwrap();
+
+    assert_eq!(
+        reports[0].seen_at.as_deref(),
+        Some("2023-09-25T16:01:00.000+0000")
+    );
+}
+
+#[test]
+fn conversation_category_unknown() {
+    let conversation: ReportConversation =
+        serde_json::from_str(r#"{"category": "REFERRAL_CONVERSION"}"#).unwrap();
+
+    assert_eq!(conversation.category, Some(ConversationCategory::Unknown));
+}
+
+#[test]
+fn template_unknown_values() {
+    let template: Template = serde_json::from_str(
+        r#"{
+          "id": "111",
+          "language": "xx_YY",
+          "status": "LIMITED",
+          "category": "AUTHENTICATION_PLUS",
+          "structure": {
+            "header": {"format": "CAROUSEL", "cards": 3},
+            "body": {"text": "Hello {{1}}"},
+            "buttons": [
+              {"type": "QUICK_REPLY", "text": "Yes"},
+              {"type": "CATALOG", "text": "View catalog"}
+            ],
+            "type": "INTERACTIVE"
+          }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(template.language, Some(TemplateLanguage::Unknown));
+    assert_eq!(template.status, Some(TemplateStatus::Unknown));
+    assert_eq!(template.category, Some(TemplateCategory::Unknown));
+
+    let structure = template.structure.unwrap();
+    assert_eq!(structure.header, Some(TemplateHeader::Unknown));
+    assert_eq!(structure.buttons.unwrap()[1], TemplateButton::Unknown);
+    assert_eq!(structure.template_type, Some(TemplateType::Unknown));
+}
+
+#[test]
+fn sender_unknown_values() {
+    let sender: Sender =
+        serde_json::from_str(r#"{"status": "MIGRATING", "qualityRating": "BLUE"}"#).unwrap();
+
+    assert_eq!(sender.status, Some(SenderStatus::Unknown));
+    assert_eq!(sender.quality_rating, Some(SenderQualityRating::Unknown));
+}
+
+#[test]
+fn inbound_text_message_round_trip() {
+    assert_round_trip::<InboundMessages>("whatsapp/inbound_text_message.json");
+}
+
+#[test]
+fn parse_inbound_text_message() {
+    let body = read_fixture("whatsapp/inbound_text_message.json");
+
+    let messages = parse_whatsapp_inbound_messages(&body).unwrap();
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].from.as_deref(), Some("385919998888"));
+    assert_eq!(messages[0].to.as_deref(), Some("447860099299"));
+    let content = messages[0].message.as_ref().unwrap();
+    assert_eq!(content.message_type.as_deref(), Some("TEXT"));
+    assert_eq!(content.text.as_deref(), Some("Hello, is my order shipped?"));
+}
+
+#[test]
+fn template_structure_check_data() {
+    let mut structure = TemplateStructure::new(TemplateBody::new("Hi {{1}}, code {{ 2 }}, {{1}}."));
+    structure.buttons = Some(vec![
+        TemplateButton::new_quick_reply("Yes"),
+        TemplateButton::new_url("Static", "https://www.example.com"),
+        TemplateButton::new_url("Dynamic", "https://www.example.com/{{1}}"),
+    ]);
+
+    assert_eq!(structure.body_placeholder_count(), 2);
+    assert_eq!(structure.button_parameter_counts(), (1, 1));
+
+    let mut data = TemplateData::new(TemplateBodyContent::new(vec![
+        "John".to_string(),
+        "1234".to_string(),
+    ]));
+    data.buttons = Some(vec![
+        TemplateButtonContent::new_quick_reply("yes"),
+        TemplateButtonContent::new_url("order-1"),
+    ]);
+    assert!(structure.check_data(&data).is_ok());
+
+    data.buttons = Some(vec![TemplateButtonContent::new_quick_reply("yes")]);
+    assert!(structure
+        .check_data(&data)
+        .unwrap_err()
+        .contains("dynamic URL"));
+
+    data.body.placeholders.pop();
+    assert!(structure
+        .check_data(&data)
+        .unwrap_err()
+        .contains("2 placeholders"));
+}
+
+#[test]
+fn template_button_parameter_counts() {
+    assert_eq!(TemplateButton::new_quick_reply("Yes").parameter_count(), 1);
+    assert_eq!(
+        TemplateButton::new_url("Track", "https://www.example.com/{{1}}").parameter_count(),
+        1
+    );
+    assert_eq!(
+        TemplateButton::new_url("Visit", "https://www.example.com").parameter_count(),
+        0
+    );
+    assert_eq!(
+        TemplateButton::new_number("Call", "41793026727").parameter_count(),
+        0
+    );
+}
+
+fn get_buttons_structure() -> TemplateStructure {
+    let mut structure = TemplateStructure::new(TemplateBody::new("Your order is ready."));
+    structure.buttons = Some(vec![
+        TemplateButton::new_number("Call", "41793026727"),
+        TemplateButton::new_url("Track", "https://www.example.com/{{1}}"),
+        TemplateButton::new_quick_reply("Thanks"),
+    ]);
+
+    structure
+}
+
+fn get_buttons_data(buttons: Vec<TemplateButtonContent>) -> TemplateData {
+    let mut data = TemplateData::new(TemplateBodyContent::new(vec![]));
+    data.buttons = Some(buttons);
+
+    data
+}
+
+#[test]
+fn template_structure_check_data_buttons() {
+    let structure = get_buttons_structure();
+
+    assert!(structure
+        .check_data(&get_buttons_data(vec![
+            TemplateButtonContent::new_url("order-1"),
+            TemplateButtonContent::new_quick_reply("thanks"),
+        ]))
+        .is_ok());
+}
+
+#[test]
+fn template_structure_check_data_button_mismatch() {
+    let structure = get_buttons_structure();
+
+    assert_eq!(
+        structure
+            .check_data(&get_buttons_data(vec![
+                TemplateButtonContent::new_quick_reply("thanks"),
+                TemplateButtonContent::new_url("order-1"),
+            ]))
+            .unwrap_err(),
+        "button 1 (dynamic URL) expects 1 parameter, but a quick reply parameter was given"
+    );
+    assert_eq!(
+        structure
+            .check_data(&get_buttons_data(vec![TemplateButtonContent::new_url(
+                "order-1"
+            )]))
+            .unwrap_err(),
+        "button 2 (quick reply) expects 1 parameter, but none was given"
+    );
+    assert_eq!(
+        structure
+            .check_data(&get_buttons_data(vec![
+                TemplateButtonContent::new_url("phone"),
+                TemplateButtonContent::new_url("order-1"),
+                TemplateButtonContent::new_quick_reply("thanks"),
+            ]))
+            .unwrap_err(),
+        "button 2 (quick reply) expects 1 parameter, but a URL parameter was given"
+    );
+
+    let mut static_structure = TemplateStructure::new(TemplateBody::new("Visit us."));
+    static_structure.buttons = Some(vec![TemplateButton::new_url(
+        "Visit",
+        "https://www.example.com",
+    )]);
+    assert_eq!(
+        static_structure
+            .check_data(&get_buttons_data(vec![TemplateButtonContent::new_url(
+                "order-1"
+            )]))
+            .unwrap_err(),
+        "buttons expect 0 parameters, but 1 were given; phone number and static URL buttons \
+         expect 0"
+    );
+}
+
+#[test]
+fn template_data_button_parameters_validation() {
+    let payload = "a".repeat(TemplateButtonContent::MAX_QUICK_REPLY_PAYLOAD_LENGTH);
+    assert!(
+        get_buttons_data(vec![TemplateButtonContent::new_quick_reply(&payload)])
+            .validate()
+            .is_ok()
+    );
+
+    let too_long = format!("{}a", payload);
+    let errors = get_buttons_data(vec![
+        TemplateButtonContent::new_url("order-1"),
+        TemplateButtonContent::new_quick_reply(&too_long),
+    ])
+    .validate()
+    .unwrap_err();
+    let error = &errors.field_errors()["buttons"][0];
+    assert_eq!(error.code, "quick_reply_payload_too_long");
+    assert_eq!(error.params["index"], 1);
+    assert!(error
+        .message
+        .as_ref()
+        .unwrap()
+        .starts_with("parameter of button 1 (quick reply)"));
+
+    let errors = get_buttons_data(vec![TemplateButtonContent::new_url(" ")])
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        errors.field_errors()["buttons"][0].code,
+        "empty_button_parameter"
+    );
+}
+
+#[test]
+fn template_data_buttons_serialization() {
+    let data = get_buttons_data(vec![
+        TemplateButtonContent::new_url("order-1"),
+        TemplateButtonContent::new_quick_reply("thanks"),
+    ]);
+
+    assert_eq!(
+        serde_json::to_value(&data).unwrap()["buttons"],
+        serde_json::json!([
+            {"type": "URL", "parameter": "order-1"},
+            {"type": "QUICK_REPLY", "parameter": "thanks"}
+        ])
+    );
+}
+
+#[test]
+fn response_models_deserialize_from_empty_object() {
+    assert_deserializes_empty::<CreateTemplateResponseBody>();
+    assert_deserializes_empty::<GetSendersResponseBody>();
+    assert_deserializes_empty::<GetTemplatesResponseBody>();
+    assert_deserializes_empty::<Report>();
+    assert_deserializes_empty::<ReportConversation>();
+    assert_deserializes_empty::<ReportError>();
+    assert_deserializes_empty::<ReportPrice>();
+    assert_deserializes_empty::<SendContentResponseBody>();
+    assert_deserializes_empty::<SendTemplateResponseBody>();
+    assert_deserializes_empty::<Sender>();
+    assert_deserializes_empty::<Status>();
+    assert_deserializes_empty::<Template>();
+}
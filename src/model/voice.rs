@@ -0,0 +1,362 @@
+//! Models for calling Voice endpoints.
+
+use serde_derive::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::api::SdkError;
+use crate::model::codes::{error_by_id, status_by_id, ErrorCodeInfo, StatusCodeInfo};
+use crate::model::common::{deserialize_money, money_to_f64, Money};
+use crate::model::{ApiResponse, ToQueryString};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeliveryReportsQueryParameters {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Maximum number of delivery reports to be returned, between 1 and 1000. Default is 50.
+    #[validate(range(min = 1, max = 1000, message = "limit must be between 1 and 1000"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl ToQueryString for GetDeliveryReportsQueryParameters {}
+
+impl GetDeliveryReportsQueryParameters {
+    pub fn new() -> Self {
+        GetDeliveryReportsQueryParameters {
+            bulk_id: None,
+            message_id: None,
+            limit: None,
+        }
+    }
+}
+
+impl Default for GetDeliveryReportsQueryParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Outcome of a call, given by the group of its status.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CallStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "UNDELIVERABLE")]
+    Undeliverable,
+    #[serde(rename = "DELIVERED")]
+    Delivered,
+    #[serde(rename = "EXPIRED")]
+    Expired,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// Call-specific outcome of a voice message, for retry policies that treat a busy line
+/// differently from an answering machine.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum VoiceReportStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "ANSWERED")]
+    Answered,
+    #[serde(rename = "ANSWERED_MACHINE")]
+    AnsweredMachine,
+    #[serde(rename = "BUSY")]
+    Busy,
+    #[serde(rename = "NO_ANSWER")]
+    NoAnswer,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl VoiceReportStatus {
+    // Returns the outcome given by the name of a voice error, if it is call-specific.
+    fn from_error_name(name: &str) -> Option<VoiceReportStatus> {
+        match name {
+            "EC_VOICE_NO_ANSWER" | "EC_NO_ANSWER" => Some(VoiceReportStatus::NoAnswer),
+            "EC_VOICE_USER_BUSY" | "EC_VOICE_BUSY" | "EC_USER_BUSY" => {
+                Some(VoiceReportStatus::Busy)
+            }
+            "EC_VOICE_ANSWERED_MACHINE" | "EC_ANSWERED_MACHINE" | "EC_MACHINE_DETECTED" => {
+                Some(VoiceReportStatus::AnsweredMachine)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+    /// Status group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Status group name, the outcome of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<CallStatus>,
+
+    /// Status ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Status name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Action that should be taken to eliminate the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+}
+
+impl Status {
+    /// Returns the details of the status code from `codes::STATUS_CODES`, if known.
+    pub fn info(&self) -> Option<&'static StatusCodeInfo> {
+        self.id.and_then(status_by_id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Error {
+    /// Error group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Error group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Error ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Error name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the error is permanent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanent: Option<bool>,
+}
+
+impl Error {
+    /// Returns the details of the error code from `codes::ERROR_CODES`, if known.
+    pub fn info(&self) -> Option<&'static ErrorCodeInfo> {
+        self.id.and_then(error_by_id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Price {
+    /// Price per one call.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_message: Option<Money>,
+
+    /// Price per second of the call.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_second: Option<Money>,
+
+    /// The currency in which the price is expressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+impl Price {
+    /// Returns the price per message as a `f64`, regardless of the `decimal` feature.
+    pub fn price_per_message_f64(&self) -> Option<f64> {
+        self.price_per_message.map(money_to_f64)
+    }
+}
+
+/// Voice-specific details of a call.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VoiceCall {
+    /// Feature used for the call, e.g. `Text-to-Speech` or `Voice Message`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+
+    /// Date and time when the call started ringing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+
+    /// Date and time when the call was answered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_time: Option<String>,
+
+    /// Date and time when the call ended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+
+    /// Duration of the answered call, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Duration of the charged part of the call, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charged_duration: Option<i64>,
+
+    /// Duration of the file played during the call, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_duration: Option<f64>,
+
+    /// Digits pressed by the callee, as sent by the API, e.g. `1,2,#`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dtmf_codes: Option<String>,
+}
+
+impl VoiceCall {
+    /// Returns the digits pressed by the callee, one per item, without separators.
+    pub fn dtmf_digits(&self) -> Vec<String> {
+        self.dtmf_codes
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Report {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// The number that was called.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// The number the call was made from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Date and time when the call was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Date and time when the final status of the call was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+
+    /// Date and time when the call was answered, by the callee or by an answering machine. Not
+    /// set for calls that were not answered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answered_at: Option<String>,
+
+    /// Duration of the call, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
+
+    /// Mobile country and network codes of the called number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc_mnc: Option<String>,
+
+    /// Custom data sent with the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Voice-specific details of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_call: Option<VoiceCall>,
+
+    /// Price of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Price>,
+
+    /// Status of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+
+    /// Error of the call, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+impl Report {
+    /// Returns the outcome of the call, or `CallStatus::Unknown` if the report has no status.
+    pub fn call_status(&self) -> CallStatus {
+        self.status
+            .as_ref()
+            .and_then(|status| status.group_name)
+            .unwrap_or(CallStatus::Unknown)
+    }
+
+    /// Returns the call-specific outcome of the call: `Busy`, `NoAnswer` and `AnsweredMachine`
+    /// are read from the error of the report, `Answered` from a delivered call, and `Failed` for
+    /// other errors.
+    pub fn voice_status(&self) -> VoiceReportStatus {
+        let error_name = self
+            .error
+            .as_ref()
+            .filter(|error| error.id != Some(0))
+            .and_then(|error| error.name.as_deref());
+        if let Some(status) = error_name.and_then(VoiceReportStatus::from_error_name) {
+            return status;
+        }
+
+        match self.call_status() {
+            CallStatus::Pending => VoiceReportStatus::Pending,
+            CallStatus::Delivered => VoiceReportStatus::Answered,
+            CallStatus::Undeliverable | CallStatus::Expired | CallStatus::Rejected => {
+                VoiceReportStatus::Failed
+            }
+            CallStatus::Unknown if error_name.is_some() => VoiceReportStatus::Failed,
+            CallStatus::Unknown => VoiceReportStatus::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetDeliveryReportsResponseBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Report>>,
+}
+
+impl ApiResponse for GetDeliveryReportsResponseBody {}
+
+/// Parses the body of a voice delivery report callback.
+pub fn parse_voice_reports(body: &[u8]) -> Result<Vec<Report>, SdkError> {
+    let reports: GetDeliveryReportsResponseBody = serde_json::from_slice(body)?;
+
+    Ok(reports.results.unwrap_or_default())
+}
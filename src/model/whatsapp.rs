@@ -1,8 +1,21 @@
 //! Models for calling WhatsApp endpoints.
 
+use std::fmt;
+
+use regex::Regex;
 use serde_derive::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::model::codes::{error_by_id, status_by_id, ErrorCodeInfo, StatusCodeInfo};
+pub use crate::model::common::SmsFailover;
+use crate::model::common::{deserialize_money, money_to_f64, Money};
+use crate::model::{ApiRequest, ApiResponse, DeliveryStatus};
+
+lazy_static! {
+    static ref HTTPS_URL: Regex = Regex::new(r"^https://").unwrap();
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{\{\s*(\d+)\s*\}\}").unwrap();
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct TextContent {
@@ -458,6 +471,8 @@ pub struct SendContentRequestBody<T: serde::Serialize + Validate> {
     pub notify_url: Option<String>,
 }
 
+impl<T: serde::Serialize + Validate> ApiRequest for SendContentRequestBody<T> {}
+
 pub type SendTextRequestBody = SendContentRequestBody<TextContent>;
 
 impl SendTextRequestBody {
@@ -922,10 +937,12 @@ pub struct InteractiveProductContent {
     pub action: InteractiveProductAction,
 
     /// Body of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[validate]
     pub body: Option<InteractiveBody>,
 
     /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[validate]
     pub footer: Option<InteractiveFooter>,
 }
@@ -980,7 +997,9 @@ pub struct InteractiveMultiproductSection {
     pub title: Option<String>,
 
     /// An array of product-unique identifiers as defined in the catalog. If product retailer ID
-    /// doesn't exist in your catalog, the product won't be displayed.
+    /// doesn't exist in your catalog, the product won't be displayed. Section must contain at
+    /// least one product.
+    #[validate(length(min = 1))]
     pub product_retailer_ids: Vec<String>,
 }
 
@@ -993,20 +1012,57 @@ impl InteractiveMultiproductSection {
     }
 }
 
+/// Maximum number of products of a multi-product message, across all of its sections.
+pub const MAX_MULTIPRODUCT_PRODUCTS: usize = 30;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_multiproduct_sections"))]
 pub struct InteractiveMultiproductAction {
     /// The ID that uniquely identifies the catalog registered with Meta and connected to the
     /// WhatsApp Business Account the sender belongs to.
     #[validate(length(min = 1))]
     pub catalog_id: String,
 
-    /// An array of multi-product sections.
+    /// An array of multi-product sections. There can be up to 10 sections, with up to
+    /// `MAX_MULTIPRODUCT_PRODUCTS` products between all of them.
     #[validate(length(min = 1, max = 10))]
     #[validate]
     pub sections: Vec<InteractiveMultiproductSection>,
 }
 
+// Checks the limits that span sections: a title for every section when there are several, and
+// the total number of products. The index of the first offending section is reported.
+fn validate_multiproduct_sections(
+    action: &InteractiveMultiproductAction,
+) -> Result<(), validator::ValidationError> {
+    if action.sections.len() > 1 {
+        if let Some(index) = action
+            .sections
+            .iter()
+            .position(|section| section.title.as_deref().map_or(true, str::is_empty))
+        {
+            let mut error = validator::ValidationError::new("multiproduct_section_title");
+            error.message = Some("every section needs a title when there are several".into());
+            error.add_param("index".into(), &index);
+            return Err(error);
+        }
+    }
+
+    let mut products = 0;
+    for (index, section) in action.sections.iter().enumerate() {
+        products += section.product_retailer_ids.len();
+        if products > MAX_MULTIPRODUCT_PRODUCTS {
+            let mut error = validator::ValidationError::new("multiproduct_products");
+            error.message = Some("a multi-product message can have at most 30 products".into());
+            error.add_param("index".into(), &index);
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
 impl InteractiveMultiproductAction {
     pub fn new(catalog_id: &str, sections: Vec<InteractiveMultiproductSection>) -> Self {
         InteractiveMultiproductAction {
@@ -1031,6 +1087,7 @@ pub struct InteractiveMultiproductContent {
     pub action: InteractiveMultiproductAction,
 
     /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[validate]
     pub footer: Option<InteractiveFooter>,
 }
@@ -1067,6 +1124,7 @@ impl SendInteractiveMultiproductRequestBody {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TemplateCategory {
     #[serde(rename = "ACCOUNT_UPDATE")]
     AccountUpdate,
@@ -1100,9 +1158,12 @@ pub enum TemplateCategory {
     Authentication,
     #[serde(rename = "UTILITY")]
     Utility,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TemplateLanguage {
     #[serde(rename = "af")]
     Af,
@@ -1246,91 +1307,94 @@ pub enum TemplateLanguage {
     Vi,
     #[serde(rename = "zu")]
     Zu,
-    #[serde(rename = "unknown")]
+    #[serde(rename = "unknown", other)]
     Unknown,
 }
 
-impl ToString for TemplateLanguage {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Af => String::from("af"),
-            Self::Sq => String::from("sq"),
-            Self::Ar => String::from("ar"),
-            Self::Az => String::from("az"),
-            Self::Bn => String::from("bn"),
-            Self::Bg => String::from("bg"),
-            Self::Ca => String::from("ca"),
-            Self::ZhCn => String::from("zh_CN"),
-            Self::ZhHk => String::from("zh_HK"),
-            Self::ZhTw => String::from("zh_TW"),
-            Self::Hr => String::from("hr"),
-            Self::Cs => String::from("cs"),
-            Self::Da => String::from("da"),
-            Self::Nl => String::from("nl"),
-            Self::En => String::from("en"),
-            Self::EnGb => String::from("en_GB"),
-            Self::EnUs => String::from("en_US"),
-            Self::Et => String::from("et"),
-            Self::Fil => String::from("fil"),
-            Self::Fi => String::from("fi"),
-            Self::Fr => String::from("fr"),
-            Self::Ka => String::from("ka"),
-            Self::De => String::from("de"),
-            Self::El => String::from("el"),
-            Self::Gu => String::from("gu"),
-            Self::Ha => String::from("ha"),
-            Self::He => String::from("he"),
-            Self::Hi => String::from("hi"),
-            Self::Hu => String::from("hu"),
-            Self::Id => String::from("id"),
-            Self::Ga => String::from("ga"),
-            Self::It => String::from("it"),
-            Self::Ja => String::from("ja"),
-            Self::Kn => String::from("kn"),
-            Self::Kk => String::from("kk"),
-            Self::RwRw => String::from("rw_RW"),
-            Self::Ko => String::from("ko"),
-            Self::KyKg => String::from("ky_KG"),
-            Self::Lo => String::from("lo"),
-            Self::Lv => String::from("lv"),
-            Self::Lt => String::from("lt"),
-            Self::Mk => String::from("mk"),
-            Self::Ms => String::from("ms"),
-            Self::Ml => String::from("ml"),
-            Self::Mr => String::from("mr"),
-            Self::Nb => String::from("nb"),
-            Self::Fa => String::from("fa"),
-            Self::Pl => String::from("pl"),
-            Self::PtBr => String::from("pt_BR"),
-            Self::PtPt => String::from("pt_PT"),
-            Self::Pa => String::from("pa"),
-            Self::Ro => String::from("ro"),
-            Self::Ru => String::from("ru"),
-            Self::Sr => String::from("sr"),
-            Self::Sk => String::from("sk"),
-            Self::Sl => String::from("sl"),
-            Self::Es => String::from("es"),
-            Self::EsAr => String::from("es_AR"),
-            Self::EsEs => String::from("es_ES"),
-            Self::EsMx => String::from("es_MX"),
-            Self::Sw => String::from("sw"),
-            Self::Sv => String::from("sv"),
-            Self::Ta => String::from("ta"),
-            Self::Te => String::from("te"),
-            Self::Th => String::from("th"),
-            Self::Tr => String::from("tr"),
-            Self::Uk => String::from("uk"),
-            Self::Ur => String::from("ur"),
-            Self::Uz => String::from("uz"),
-            Self::Vi => String::from("vi"),
-            Self::Zu => String::from("zu"),
-            Self::Unknown => String::from("unknown"),
-        }
+impl fmt::Display for TemplateLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let code = match self {
+            Self::Af => "af",
+            Self::Sq => "sq",
+            Self::Ar => "ar",
+            Self::Az => "az",
+            Self::Bn => "bn",
+            Self::Bg => "bg",
+            Self::Ca => "ca",
+            Self::ZhCn => "zh_CN",
+            Self::ZhHk => "zh_HK",
+            Self::ZhTw => "zh_TW",
+            Self::Hr => "hr",
+            Self::Cs => "cs",
+            Self::Da => "da",
+            Self::Nl => "nl",
+            Self::En => "en",
+            Self::EnGb => "en_GB",
+            Self::EnUs => "en_US",
+            Self::Et => "et",
+            Self::Fil => "fil",
+            Self::Fi => "fi",
+            Self::Fr => "fr",
+            Self::Ka => "ka",
+            Self::De => "de",
+            Self::El => "el",
+            Self::Gu => "gu",
+            Self::Ha => "ha",
+            Self::He => "he",
+            Self::Hi => "hi",
+            Self::Hu => "hu",
+            Self::Id => "id",
+            Self::Ga => "ga",
+            Self::It => "it",
+            Self::Ja => "ja",
+            Self::Kn => "kn",
+            Self::Kk => "kk",
+            Self::RwRw => "rw_RW",
+            Self::Ko => "ko",
+            Self::KyKg => "ky_KG",
+            Self::Lo => "lo",
+            Self::Lv => "lv",
+            Self::Lt => "lt",
+            Self::Mk => "mk",
+            Self::Ms => "ms",
+            Self::Ml => "ml",
+            Self::Mr => "mr",
+            Self::Nb => "nb",
+            Self::Fa => "fa",
+            Self::Pl => "pl",
+            Self::PtBr => "pt_BR",
+            Self::PtPt => "pt_PT",
+            Self::Pa => "pa",
+            Self::Ro => "ro",
+            Self::Ru => "ru",
+            Self::Sr => "sr",
+            Self::Sk => "sk",
+            Self::Sl => "sl",
+            Self::Es => "es",
+            Self::EsAr => "es_AR",
+            Self::EsEs => "es_ES",
+            Self::EsMx => "es_MX",
+            Self::Sw => "sw",
+            Self::Sv => "sv",
+            Self::Ta => "ta",
+            Self::Te => "te",
+            Self::Th => "th",
+            Self::Tr => "tr",
+            Self::Uk => "uk",
+            Self::Ur => "ur",
+            Self::Uz => "uz",
+            Self::Vi => "vi",
+            Self::Zu => "zu",
+            Self::Unknown => "unknown",
+        };
+
+        write!(f, "{}", code)
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "format")]
+#[non_exhaustive]
 pub enum TemplateHeader {
     #[serde(rename = "DOCUMENT")]
     Document {
@@ -1367,6 +1431,8 @@ pub enum TemplateHeader {
         #[serde(skip_serializing_if = "Option::is_none")]
         example: Option<String>,
     },
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
 impl TemplateHeader {
@@ -1416,6 +1482,7 @@ impl TemplateFooter {
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum TemplateButton {
     #[serde(rename = "PHONE_NUMBER")]
     Number {
@@ -1444,6 +1511,8 @@ pub enum TemplateButton {
         #[serde(skip_serializing_if = "Option::is_none")]
         example: Option<String>,
     },
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
 impl TemplateButton {
@@ -1465,6 +1534,40 @@ impl TemplateButton {
             example: None,
         }
     }
+
+    /// Returns the number of parameters a message sending the template must give for this button:
+    /// one for `quick reply` and `dynamic URL` buttons, none for phone number and static URL
+    /// buttons.
+    pub fn parameter_count(&self) -> usize {
+        match self {
+            TemplateButton::QuickReply { .. } => 1,
+            TemplateButton::Url { url, .. } if PLACEHOLDER.is_match(url) => 1,
+            _ => 0,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            TemplateButton::Number { .. } => "phone number",
+            TemplateButton::QuickReply { .. } => "quick reply",
+            TemplateButton::Url { url, .. } if PLACEHOLDER.is_match(url) => "dynamic URL",
+            TemplateButton::Url { .. } => "static URL",
+            TemplateButton::Unknown => "unknown",
+        }
+    }
+
+    fn accepts(&self, parameter: &TemplateButtonContent) -> bool {
+        matches!(
+            (self, parameter),
+            (
+                TemplateButton::QuickReply { .. },
+                TemplateButtonContent::QuickReply { .. }
+            ) | (
+                TemplateButton::Url { .. },
+                TemplateButtonContent::Url { .. }
+            )
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
@@ -1491,10 +1594,13 @@ impl TemplateBody {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TemplateType {
     TEXT,
     MEDIA,
     UNSUPPORTED,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
@@ -1533,6 +1639,96 @@ impl TemplateStructure {
             template_type: None,
         }
     }
+
+    /// Returns the number of distinct placeholders, like `{{1}}`, in the body text.
+    pub fn body_placeholder_count(&self) -> usize {
+        let mut numbers: Vec<&str> = PLACEHOLDER
+            .captures_iter(&self.body.text)
+            .filter_map(|captures| captures.get(1).map(|number| number.as_str()))
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        numbers.len()
+    }
+
+    /// Returns the number of `quick reply` buttons and of `dynamic URL` buttons, whose URL has a
+    /// placeholder. A message sending the template must give a parameter for each of them.
+    pub fn button_parameter_counts(&self) -> (usize, usize) {
+        let buttons = self.buttons.as_deref().unwrap_or_default();
+        let quick_replies = buttons
+            .iter()
+            .filter(|button| matches!(button, TemplateButton::QuickReply { .. }))
+            .count();
+        let dynamic_urls = buttons
+            .iter()
+            .filter(|button| {
+                matches!(button, TemplateButton::Url { url, .. } if PLACEHOLDER.is_match(url))
+            })
+            .count();
+
+        (quick_replies, dynamic_urls)
+    }
+
+    /// Checks that `data` gives as many body placeholders and button parameters as this structure
+    /// expects, returning a description of the first mismatch.
+    ///
+    /// Button parameters are matched in order with the buttons expecting one, as given by
+    /// `TemplateButton::parameter_count`: each `quick reply` button takes a quick reply parameter
+    /// and each `dynamic URL` button a URL parameter, while phone number and static URL buttons
+    /// take none. Mismatches name the index of the template button and its expected count.
+    pub fn check_data(&self, data: &TemplateData) -> Result<(), String> {
+        let expected = self.body_placeholder_count();
+        let given = data.body.placeholders.len();
+        if given != expected {
+            return Err(format!(
+                "body has {} placeholders, but {} values were given",
+                expected, given
+            ));
+        }
+
+        let buttons = self.buttons.as_deref().unwrap_or_default();
+        let mut parameters = data.buttons.as_deref().unwrap_or_default().iter();
+        for (index, button) in buttons.iter().enumerate() {
+            let expected = button.parameter_count();
+            if expected == 0 {
+                continue;
+            }
+            match parameters.next() {
+                Some(parameter) if button.accepts(parameter) => {}
+                Some(parameter) => {
+                    return Err(format!(
+                        "button {} ({}) expects {} parameter, but a {} parameter was given",
+                        index,
+                        button.kind(),
+                        expected,
+                        parameter.kind()
+                    ))
+                }
+                None => {
+                    return Err(format!(
+                        "button {} ({}) expects {} parameter, but none was given",
+                        index,
+                        button.kind(),
+                        expected
+                    ))
+                }
+            }
+        }
+
+        let extra = parameters.count();
+        if extra > 0 {
+            let expected: usize = buttons.iter().map(TemplateButton::parameter_count).sum();
+            return Err(format!(
+                "buttons expect {} parameters, but {} were given; phone number and static URL \
+                 buttons expect 0",
+                expected,
+                expected + extra
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
@@ -1553,6 +1749,8 @@ pub struct CreateTemplateRequestBody {
     pub structure: TemplateStructure,
 }
 
+impl ApiRequest for CreateTemplateRequestBody {}
+
 impl CreateTemplateRequestBody {
     pub fn new(
         name: &str,
@@ -1658,9 +1856,26 @@ pub struct TemplateBodyContent {
     /// Template's parameter values submitted in the same order as in the registered template.
     /// The value must not be null, but it can be an empty array, if the template was registered
     /// without placeholders. Values within the array must not be null or empty.
+    #[validate(custom = "validate_placeholders")]
     pub placeholders: Vec<String>,
 }
 
+// Checks that no placeholder value is empty, reporting the index of the first empty one.
+fn validate_placeholders(placeholders: &[String]) -> Result<(), validator::ValidationError> {
+    match placeholders
+        .iter()
+        .position(|placeholder| placeholder.trim().is_empty())
+    {
+        Some(index) => {
+            let mut error = validator::ValidationError::new("empty_placeholder");
+            error.message = Some(format!("placeholder {} must not be empty", index).into());
+            error.add_param("index".into(), &index);
+            Err(error)
+        }
+        None => Ok(()),
+    }
+}
+
 impl TemplateBodyContent {
     pub fn new(placeholders: Vec<String>) -> Self {
         TemplateBodyContent { placeholders }
@@ -1672,7 +1887,8 @@ impl TemplateBodyContent {
 pub enum TemplateButtonContent {
     #[serde(rename = "QUICK_REPLY")]
     QuickReply {
-        /// Payload of a `quick reply` button.
+        /// Payload of a `quick reply` button, the postback data sent back when the end-user hits
+        /// the button. Up to `MAX_QUICK_REPLY_PAYLOAD_LENGTH` characters.
         #[serde(rename = "parameter")]
         parameter: String,
     },
@@ -1685,6 +1901,24 @@ pub enum TemplateButtonContent {
 }
 
 impl TemplateButtonContent {
+    /// Maximum length, in characters, of the payload of a `quick reply` button.
+    pub const MAX_QUICK_REPLY_PAYLOAD_LENGTH: usize = 128;
+
+    /// Returns the parameter of the button.
+    pub fn parameter(&self) -> &str {
+        match self {
+            TemplateButtonContent::QuickReply { parameter } => parameter,
+            TemplateButtonContent::Url { parameter } => parameter,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            TemplateButtonContent::QuickReply { .. } => "quick reply",
+            TemplateButtonContent::Url { .. } => "URL",
+        }
+    }
+
     pub fn new_quick_reply(parameter: &str) -> Self {
         TemplateButtonContent::QuickReply {
             parameter: parameter.into(),
@@ -1712,11 +1946,55 @@ pub struct TemplateData {
 
     /// Template buttons. Should be defined in correct order, only if `quick reply` or
     /// `dynamic URL` buttons have been registered. It can have up to three `quick reply` buttons
-    /// or only one `dynamic URL` button.
+    /// or only one `dynamic URL` button. Parameters must not be empty, and `quick reply` payloads
+    /// are limited to `TemplateButtonContent::MAX_QUICK_REPLY_PAYLOAD_LENGTH` characters.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_button_parameters")]
     pub buttons: Option<Vec<TemplateButtonContent>>,
 }
 
+// Checks the button parameters, reporting the index of the first invalid one.
+fn validate_button_parameters(
+    buttons: &[TemplateButtonContent],
+) -> Result<(), validator::ValidationError> {
+    for (index, button) in buttons.iter().enumerate() {
+        let length = button.parameter().chars().count();
+        let problem = if button.parameter().trim().is_empty() {
+            Some(("empty_button_parameter", "must not be empty".to_string()))
+        } else if matches!(button, TemplateButtonContent::QuickReply { .. })
+            && length > TemplateButtonContent::MAX_QUICK_REPLY_PAYLOAD_LENGTH
+        {
+            Some((
+                "quick_reply_payload_too_long",
+                format!(
+                    "has {} characters, but at most {} are allowed",
+                    length,
+                    TemplateButtonContent::MAX_QUICK_REPLY_PAYLOAD_LENGTH
+                ),
+            ))
+        } else {
+            None
+        };
+
+        if let Some((code, problem)) = problem {
+            let mut error = validator::ValidationError::new(code);
+            error.message = Some(
+                format!(
+                    "parameter of button {} ({}) {}",
+                    index,
+                    button.kind(),
+                    problem
+                )
+                .into(),
+            );
+            error.add_param("index".into(), &index);
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
 impl TemplateData {
     pub fn new(body: TemplateBodyContent) -> Self {
         TemplateData {
@@ -1755,27 +2033,6 @@ impl TemplateContent {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct SmsFailover {
-    /// SMS sender number. Must be in international format.
-    #[validate(length(min = 1, max = 24))]
-    pub from: String,
-
-    /// Content of the SMS that will be sent.
-    #[validate(length(min = 1, max = 4096))]
-    pub text: String,
-}
-
-impl SmsFailover {
-    pub fn new(from: &str, text: &str) -> Self {
-        SmsFailover {
-            from: from.into(),
-            text: text.into(),
-        }
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct FailoverMessage {
@@ -1833,7 +2090,8 @@ impl FailoverMessage {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct SendTemplateRequestBody {
-    /// An array of messages being sent.
+    /// An array of messages being sent, each with its own recipient, placeholders, callback data
+    /// and notify URL. Use `into_chunks` to split a large bulk into smaller requests.
     #[validate(length(min = 1))]
     #[validate]
     pub messages: Vec<FailoverMessage>,
@@ -1844,6 +2102,8 @@ pub struct SendTemplateRequestBody {
     pub bulk_id: Option<String>,
 }
 
+impl ApiRequest for SendTemplateRequestBody {}
+
 impl SendTemplateRequestBody {
     pub fn new(messages: Vec<FailoverMessage>) -> Self {
         Self {
@@ -1851,10 +2111,36 @@ impl SendTemplateRequestBody {
             bulk_id: None,
         }
     }
+
+    /// Splits the request into requests of at most `max_messages` messages, keeping their order.
+    /// When the request is split and has a bulk ID, each part gets the bulk ID with a `-1`,
+    /// `-2`, ... suffix, since bulk IDs must be unique.
+    pub fn into_chunks(self, max_messages: usize) -> Vec<SendTemplateRequestBody> {
+        let max_messages = max_messages.max(1);
+        if self.messages.len() <= max_messages {
+            return vec![self];
+        }
+
+        let mut messages = self.messages.into_iter().peekable();
+        let mut chunks = Vec::new();
+        while messages.peek().is_some() {
+            let chunk: Vec<FailoverMessage> = messages.by_ref().take(max_messages).collect();
+            let bulk_id = self
+                .bulk_id
+                .as_ref()
+                .map(|bulk_id| format!("{}-{}", bulk_id, chunks.len() + 1));
+            chunks.push(SendTemplateRequestBody {
+                messages: chunk,
+                bulk_id,
+            });
+        }
+
+        chunks
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Status {
     /// Status group ID.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1881,8 +2167,21 @@ pub struct Status {
     pub description: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl Status {
+    /// Returns the details of the status code from `codes::STATUS_CODES`, if known.
+    pub fn info(&self) -> Option<&'static StatusCodeInfo> {
+        self.id.and_then(status_by_id)
+    }
+}
+
+impl DeliveryStatus for Status {
+    fn group_id(&self) -> Option<i32> {
+        self.group_id
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SendContentResponseBody {
     /// The destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1902,6 +2201,8 @@ pub struct SendContentResponseBody {
     pub status: Option<Status>,
 }
 
+impl ApiResponse for SendContentResponseBody {}
+
 pub type SendTextResponseBody = SendContentResponseBody;
 
 pub type SendDocumentResponseBody = SendContentResponseBody;
@@ -1928,6 +2229,7 @@ pub type SendInteractiveMultiproductResponseBody = SendContentResponseBody;
 
 /// Status of the template.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TemplateStatus {
     #[serde(rename = "APPROVED")]
     Approved,
@@ -1943,10 +2245,12 @@ pub enum TemplateStatus {
     Deleted,
     #[serde(rename = "DISABLED")]
     Disabled,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct CreateTemplateResponseBody {
     /// Template ID.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1973,8 +2277,10 @@ pub struct CreateTemplateResponseBody {
     pub structure: Option<TemplateStructure>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+impl ApiResponse for CreateTemplateResponseBody {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Template {
     /// Template ID.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2005,18 +2311,20 @@ pub struct Template {
     pub structure: Option<TemplateStructure>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetTemplatesResponseBody {
     /// List of all templates for given sender.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub templates: Option<Vec<Template>>,
 }
 
+impl ApiResponse for GetTemplatesResponseBody {}
+
 pub type SentMessageInfo = SendContentResponseBody;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SendTemplateResponseBody {
     /// Array of sent message objects, one object per every message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2027,3 +2335,380 @@ pub struct SendTemplateResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
 }
+
+impl ApiResponse for SendTemplateResponseBody {}
+
+/// Status of a WhatsApp sender.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SenderStatus {
+    #[serde(rename = "CONNECTED")]
+    Connected,
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "DISCONNECTED")]
+    Disconnected,
+    #[serde(rename = "RESTRICTED")]
+    Restricted,
+    #[serde(rename = "BANNED")]
+    Banned,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// Quality rating assigned by WhatsApp to a sender, based on how messages were received by
+/// recipients over the past seven days.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SenderQualityRating {
+    #[serde(rename = "GREEN")]
+    Green,
+    #[serde(rename = "YELLOW")]
+    Yellow,
+    #[serde(rename = "RED")]
+    Red,
+    #[serde(rename = "FLAGGED")]
+    Flagged,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Sender {
+    /// Sender phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+
+    /// Display name of the sender shown to recipients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Registration status of the sender.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SenderStatus>,
+
+    /// Quality rating of the sender.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality_rating: Option<SenderQualityRating>,
+
+    /// Maximum number of unique recipients the sender can message in a 24-hour period, e.g.
+    /// `TIER_1K`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messaging_limit: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GetSendersResponseBody {
+    /// List of WhatsApp senders registered for the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senders: Option<Vec<Sender>>,
+}
+
+impl ApiResponse for GetSendersResponseBody {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SenderWebhookConfiguration {
+    /// URL to which inbound messages and reports for the sender are forwarded. Must be a valid
+    /// URL starting with `https://`.
+    #[validate(url, regex = "HTTPS_URL")]
+    pub url: String,
+
+    /// Forward inbound messages to the webhook URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_inbound_messages: Option<bool>,
+
+    /// Forward delivery reports to the webhook URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_delivery_reports: Option<bool>,
+
+    /// Forward seen reports to the webhook URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_seen_reports: Option<bool>,
+}
+
+impl ApiRequest for SenderWebhookConfiguration {}
+impl ApiResponse for SenderWebhookConfiguration {}
+
+impl SenderWebhookConfiguration {
+    pub fn new(url: &str) -> Self {
+        SenderWebhookConfiguration {
+            url: url.into(),
+            forward_inbound_messages: None,
+            forward_delivery_reports: None,
+            forward_seen_reports: None,
+        }
+    }
+}
+
+pub type GetSenderWebhookResponseBody = SenderWebhookConfiguration;
+
+pub type UpdateSenderWebhookRequestBody = SenderWebhookConfiguration;
+
+pub type UpdateSenderWebhookResponseBody = SenderWebhookConfiguration;
+
+/// Pricing category of the WhatsApp conversation a message was charged in. Categories added to
+/// the API later are deserialized as `Unknown`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ConversationCategory {
+    #[serde(rename = "MARKETING")]
+    Marketing,
+    #[serde(rename = "UTILITY")]
+    Utility,
+    #[serde(rename = "AUTHENTICATION")]
+    Authentication,
+    #[serde(rename = "SERVICE")]
+    Service,
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+/// WhatsApp conversation a reported message was charged in.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReportConversation {
+    /// Conversation ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Pricing category of the conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<ConversationCategory>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReportPrice {
+    /// Price per one message.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_money",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price_per_message: Option<Money>,
+
+    /// The currency in which the price is expressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+impl ReportPrice {
+    /// Returns the price per message as a `f64`, regardless of the `decimal` feature.
+    pub fn price_per_message_f64(&self) -> Option<f64> {
+        self.price_per_message.map(money_to_f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReportError {
+    /// Error group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Error group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Error ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Error name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the error is permanent, so retrying the message will not help.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanent: Option<bool>,
+}
+
+impl ReportError {
+    /// Returns the details of the error code from `codes::ERROR_CODES`, if known.
+    pub fn info(&self) -> Option<&'static ErrorCodeInfo> {
+        self.id.and_then(error_by_id)
+    }
+}
+
+/// Delivery or seen report of a WhatsApp message, as sent to the configured callback URL.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Report {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// The destination address of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// The sender of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Date and time when the message was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Date and time when the final status of the message was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+
+    /// Date and time when the message was seen by the recipient. Only present in seen reports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seen_at: Option<String>,
+
+    /// The number of messages the content was split into.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Price of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<ReportPrice>,
+
+    /// Status of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+
+    /// Error of the message, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReportError>,
+
+    /// Channel the message was sent over, e.g. `WHATSAPP`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    /// Conversation the message was charged in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversation: Option<ReportConversation>,
+
+    /// Entity the message was sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application the message was sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Custom data sent with the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+}
+
+/// Body of a report callback, holding one or more reports.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reports {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Report>>,
+}
+
+/// Parses the body of a WhatsApp delivery or seen report callback.
+pub fn parse_whatsapp_reports(body: &[u8]) -> Result<Vec<Report>, crate::api::SdkError> {
+    let reports: Reports = serde_json::from_slice(body)?;
+
+    Ok(reports.results.unwrap_or_default())
+}
+
+/// Content of an inbound message. Only the fields of the message type are set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessageContent {
+    /// Type of the message, e.g. `TEXT` or `IMAGE`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+
+    /// Text of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Caption of a media message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// URL of the media of a media message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Contact details of the user that sent an inbound message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundContact {
+    /// Name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Message sent by a user to a WhatsApp sender of the account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessage {
+    /// Number of the user that sent the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Sender of the account that received the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Channel of the message, e.g. `WHATSAPP`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_type: Option<String>,
+
+    /// Date and time when the message was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<String>,
+
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Content of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<InboundMessageContent>,
+
+    /// Contact details of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<InboundContact>,
+
+    /// Price of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<ReportPrice>,
+}
+
+/// Body of an inbound message callback, holding one or more messages.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessages {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<InboundMessage>>,
+
+    /// Number of messages in the callback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Number of messages waiting to be forwarded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_message_count: Option<i32>,
+}
+
+/// Parses the body of a WhatsApp inbound message callback.
+pub fn parse_whatsapp_inbound_messages(
+    body: &[u8],
+) -> Result<Vec<InboundMessage>, crate::api::SdkError> {
+    let messages: InboundMessages = serde_json::from_slice(body)?;
+
+    Ok(messages.results.unwrap_or_default())
+}
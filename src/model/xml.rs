@@ -0,0 +1,116 @@
+//! Parsing of XML callback payloads, sent by Infobip when `notifyContentType` is
+//! `application/xml`.
+
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{DeError, Reader, Writer};
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+
+use crate::api::SdkError;
+
+#[derive(Deserialize)]
+struct ReportResponse<T> {
+    results: Option<Results<T>>,
+}
+
+#[derive(Deserialize)]
+struct Results<T> {
+    #[serde(rename = "result")]
+    result: Option<Vec<T>>,
+}
+
+fn xml_error(error: quick_xml::Error) -> SdkError {
+    SdkError::Xml(DeError::from(error))
+}
+
+// Rewrites a document so it can be deserialized into the JSON models: namespace prefixes and
+// `xmlns` declarations are dropped, and attributes are turned into child elements, since Infobip
+// sends some values as attributes and others as elements.
+fn normalize(xml: &[u8]) -> Result<Vec<u8>, SdkError> {
+    let mut reader = Reader::from_reader(xml);
+    reader.trim_text(true);
+    let mut writer = Writer::new(Vec::new());
+    let mut buffer = Vec::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buffer).map_err(xml_error)?;
+        match event {
+            Event::Start(element) => write_element(&mut writer, &element, false)?,
+            Event::Empty(element) => write_element(&mut writer, &element, true)?,
+            Event::End(element) => {
+                let name = element.local_name();
+                writer
+                    .write_event(Event::End(BytesEnd::new(String::from_utf8_lossy(
+                        name.as_ref(),
+                    ))))
+                    .map_err(xml_error)?;
+            }
+            Event::Text(text) => writer.write_event(Event::Text(text)).map_err(xml_error)?,
+            Event::CData(data) => writer.write_event(Event::CData(data)).map_err(xml_error)?,
+            Event::Eof => break,
+            _ => {}
+        }
+        buffer.clear();
+    }
+
+    Ok(writer.into_inner())
+}
+
+fn write_element(
+    writer: &mut Writer<Vec<u8>>,
+    element: &BytesStart,
+    empty: bool,
+) -> Result<(), SdkError> {
+    let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+    let mut children = Vec::new();
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|error| xml_error(error.into()))?;
+        if attribute.key.as_namespace_binding().is_some() {
+            continue;
+        }
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(xml_error)?.into_owned();
+        children.push((key, value));
+    }
+
+    if empty && children.is_empty() {
+        return writer
+            .write_event(Event::Empty(BytesStart::new(name)))
+            .map_err(xml_error);
+    }
+
+    writer
+        .write_event(Event::Start(BytesStart::new(name.clone())))
+        .map_err(xml_error)?;
+    for (key, value) in children {
+        writer
+            .write_event(Event::Start(BytesStart::new(key.clone())))
+            .map_err(xml_error)?;
+        writer
+            .write_event(Event::Text(BytesText::new(&value)))
+            .map_err(xml_error)?;
+        writer
+            .write_event(Event::End(BytesEnd::new(key)))
+            .map_err(xml_error)?;
+    }
+    if empty {
+        writer
+            .write_event(Event::End(BytesEnd::new(name)))
+            .map_err(xml_error)?;
+    }
+
+    Ok(())
+}
+
+// Parses a `<reportResponse><results><result>...</result></results></reportResponse>` document
+// into the list of reports it contains.
+pub(crate) fn parse_reports<T: DeserializeOwned>(xml: &[u8]) -> Result<Vec<T>, SdkError> {
+    let normalized = normalize(xml)?;
+    let text = String::from_utf8_lossy(&normalized);
+    let response: ReportResponse<T> = quick_xml::de::from_str(&text)?;
+
+    Ok(response
+        .results
+        .and_then(|results| results.result)
+        .unwrap_or_default())
+}
@@ -0,0 +1,138 @@
+//! Re-exports of the clients, the configuration and the most used models, to be glob imported
+//! with `use infobip_sdk::prelude::*;`.
+//!
+//! Models are prefixed with their channel, like `SmsSendRequestBody` and `EmailSendRequestBody`,
+//! since channels share model names. The names in this module are part of the stable API: they
+//! are only added to, never renamed or removed outside of a major release.
+//!
+//! Each channel example below is compiled as a doctest, so a renamed or removed alias breaks
+//! the build.
+//!
+//! # SMS example
+//! ```no_run
+//! use infobip_sdk::prelude::*;
+//!
+//! # #[cfg(feature = "sms")]
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+//!
+//! let mut message = SmsMessage::new(vec![SmsDestination::new("41793026727")]);
+//! message.text = Some("Your order has shipped.".to_string());
+//! let response: SdkResponse<SmsSendResponseBody> =
+//!     client.send(SmsSendRequestBody::new(vec![message])).await?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "sms"))]
+//! # fn main() {}
+//! ```
+//!
+//! # Email example
+//! ```no_run
+//! use infobip_sdk::prelude::*;
+//!
+//! # #[cfg(feature = "email")]
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+//!
+//! let mut request_body = EmailSendRequestBody::new("someone@domain.com");
+//! request_body.subject = Some("Order shipped".to_string());
+//! request_body.text = Some("Your order has shipped.".to_string());
+//! let response: SdkResponse<EmailSendResponseBody> = client.send(request_body).await?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "email"))]
+//! # fn main() {}
+//! ```
+//!
+//! # WhatsApp example
+//! ```no_run
+//! use infobip_sdk::prelude::*;
+//!
+//! # #[cfg(feature = "whatsapp")]
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = WhatsappClient::with_configuration(Configuration::from_env_api_key()?);
+//!
+//! let request_body = WhatsappSendTextRequestBody::new(
+//!     "441134960000",
+//!     "41793026727",
+//!     WhatsappTextContent::new("Your order has shipped."),
+//! );
+//! let response: SdkResponse<WhatsappSendTextResponseBody> =
+//!     client.send_text(request_body).await?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "whatsapp"))]
+//! # fn main() {}
+//! ```
+//!
+//! # Voice example
+//! ```no_run
+//! use infobip_sdk::prelude::*;
+//!
+//! # #[cfg(feature = "voice")]
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = VoiceClient::with_configuration(Configuration::from_env_api_key()?);
+//!
+//! let response: SdkResponse<VoiceGetDeliveryReportsResponseBody> = client
+//!     .get_delivery_reports(VoiceGetDeliveryReportsQueryParameters::new())
+//!     .await?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "voice"))]
+//! # fn main() {}
+//! ```
+
+pub use crate::api::client::InfobipClient;
+pub use crate::api::{ApiError, RequestOptions, SdkError, SdkResponse};
+pub use crate::configuration::{ApiKey, AuthMethod, BasicAuth, Configuration};
+pub use crate::model::{DeliveryStatus, SendOutcome, ValidateStrict};
+
+#[cfg(feature = "email")]
+pub use crate::api::email::EmailClient;
+#[cfg(feature = "email")]
+pub use crate::model::email::{
+    GetDeliveryReportsQueryParameters as EmailGetDeliveryReportsQueryParameters,
+    GetDeliveryReportsResponseBody as EmailGetDeliveryReportsResponseBody,
+    GetLogsQueryParameters as EmailGetLogsQueryParameters,
+    GetLogsResponseBody as EmailGetLogsResponseBody, Log as EmailLog, Report as EmailReport,
+    SendRequestBody as EmailSendRequestBody, SendResponseBody as EmailSendResponseBody,
+};
+
+#[cfg(feature = "sms")]
+pub use crate::api::numbers::NumbersClient;
+#[cfg(feature = "sms")]
+pub use crate::api::sms::{BlockingSmsClient, SmsClient};
+#[cfg(feature = "sms")]
+pub use crate::model::sms::{
+    Destination as SmsDestination,
+    GetDeliveryReportsQueryParameters as SmsGetDeliveryReportsQueryParameters,
+    GetDeliveryReportsResponseBody as SmsGetDeliveryReportsResponseBody,
+    GetLogsQueryParameters as SmsGetLogsQueryParameters,
+    GetLogsResponseBody as SmsGetLogsResponseBody, Log as SmsLog, Message as SmsMessage,
+    PreviewRequestBody as SmsPreviewRequestBody, PreviewResponseBody as SmsPreviewResponseBody,
+    Report as SmsReport, SendRequestBody as SmsSendRequestBody,
+    SendResponseBody as SmsSendResponseBody,
+};
+
+#[cfg(feature = "voice")]
+pub use crate::api::voice::VoiceClient;
+#[cfg(feature = "voice")]
+pub use crate::model::voice::{
+    GetDeliveryReportsQueryParameters as VoiceGetDeliveryReportsQueryParameters,
+    GetDeliveryReportsResponseBody as VoiceGetDeliveryReportsResponseBody, Report as VoiceReport,
+};
+
+#[cfg(feature = "whatsapp")]
+pub use crate::api::whatsapp::WhatsappClient;
+#[cfg(feature = "whatsapp")]
+pub use crate::model::whatsapp::{
+    FailoverMessage as WhatsappTemplateMessage,
+    SendTemplateRequestBody as WhatsappSendTemplateRequestBody,
+    SendTemplateResponseBody as WhatsappSendTemplateResponseBody,
+    SendTextRequestBody as WhatsappSendTextRequestBody,
+    SendTextResponseBody as WhatsappSendTextResponseBody, TextContent as WhatsappTextContent,
+};
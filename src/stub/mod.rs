@@ -0,0 +1,418 @@
+//! Offline stub of the Infobip API, enabled with the `stub` feature, to run examples and tests
+//! without credentials or network access.
+//!
+//! A `StubTransport` serves canned responses, registered per method and path, from a loopback
+//! HTTP listener, so any client built from its `configuration()` talks to it through the regular
+//! request pipeline. Every request it receives is recorded for inspection.
+//!
+//! # Example
+//! ```
+//! use infobip_sdk::api::sms::SmsClient;
+//! use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+//! use infobip_sdk::stub::StubTransport;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let stub = StubTransport::with_default_responses()?;
+//! let client = SmsClient::with_configuration(stub.configuration());
+//!
+//! let mut message = Message::new(vec![Destination::new("41793026727")]);
+//! message.text = Some("Hello from the stub!".to_string());
+//! let response = client.send(SendRequestBody::new(vec![message])).await?;
+//!
+//! assert_eq!(response.status, reqwest::StatusCode::OK);
+//! assert_eq!(response.body.bulk_id.as_deref(), Some("2034072219640523072"));
+//!
+//! let requests = stub.requests();
+//! assert_eq!(requests.len(), 1);
+//! assert_eq!(requests[0].path, "/sms/2/text/advanced");
+//! assert!(requests[0].body_text().contains("Hello from the stub!"));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::configuration::{ApiKey, Configuration};
+
+/// Canned response body of the SMS send endpoint.
+pub const SMS_SEND_RESPONSE: &str = include_str!("fixtures/sms_send_response.json");
+
+/// Canned response body of the SMS delivery reports endpoint.
+pub const SMS_GET_DELIVERY_REPORTS_RESPONSE: &str =
+    include_str!("fixtures/sms_get_delivery_reports_response.json");
+
+/// Canned response body of the email send endpoint.
+pub const EMAIL_SEND_RESPONSE: &str = include_str!("fixtures/email_send_response.json");
+
+// Body returned for requests without a canned response, in the format of API errors.
+const NOT_FOUND_RESPONSE: &str = r#"{"requestError":{"serviceException":{"messageId":"NOT_FOUND","text":"No canned response for this endpoint."}}}"#;
+
+/// Response served by a `StubTransport` for a method and path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CannedResponse {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// JSON body.
+    pub body: String,
+}
+
+/// Request received by a `StubTransport`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `POST`.
+    pub method: String,
+
+    /// Path of the request, without the query string.
+    pub path: String,
+
+    /// Query string of the request, if any.
+    pub query: Option<String>,
+
+    /// Headers of the request, with lowercase names.
+    pub headers: Vec<(String, String)>,
+
+    /// Body of the request.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Returns the first value of a header, matching the name case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the body as text, replacing invalid UTF-8.
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+#[derive(Default)]
+struct StubState {
+    responses: Mutex<HashMap<(String, String), CannedResponse>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+    shutdown: AtomicBool,
+}
+
+/// In-memory stand-in for the Infobip API. Clients built from `configuration()` send their
+/// requests to it, over a listener bound to the loopback interface. It can be shared between
+/// threads, and stops listening when dropped.
+///
+/// # Example
+/// ```
+/// use infobip_sdk::api::email::EmailClient;
+/// use infobip_sdk::model::email::SendRequestBody;
+/// use infobip_sdk::stub::StubTransport;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let stub = StubTransport::with_default_responses()?;
+/// let client = EmailClient::with_configuration(stub.configuration());
+///
+/// let mut request_body = SendRequestBody::new("someone@domain.com");
+/// request_body.from = Some("someone@company.com".to_string());
+/// request_body.subject = Some("Test subject".to_string());
+/// request_body.text = Some("Hello world!".to_string());
+///
+/// let response = client.send(request_body).await?;
+/// assert_eq!(response.body.bulk_id.as_deref(), Some("snxemd8u52byvsbqw5zp"));
+///
+/// let request = &stub.requests()[0];
+/// assert_eq!(request.header("authorization"), Some("App stub-api-key"));
+/// assert!(request.body_text().contains("Test subject"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct StubTransport {
+    address: SocketAddr,
+    state: Arc<StubState>,
+}
+
+impl StubTransport {
+    /// Starts a stub without canned responses; every request is answered with a 404.
+    pub fn start() -> io::Result<StubTransport> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(StubState::default());
+
+        let accept_state = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if accept_state.shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let connection_state = Arc::clone(&accept_state);
+                    thread::spawn(move || serve_connection(stream, &connection_state));
+                }
+            }
+        });
+
+        Ok(StubTransport { address, state })
+    }
+
+    /// Starts a stub answering the SMS send, SMS delivery reports and email send endpoints of the
+    /// enabled channels with the canned responses of this module.
+    ///
+    /// # Example
+    /// ```
+    /// use infobip_sdk::api::sms::SmsClient;
+    /// use infobip_sdk::model::sms::GetDeliveryReportsQueryParameters;
+    /// use infobip_sdk::stub::StubTransport;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stub = StubTransport::with_default_responses()?;
+    /// let client = SmsClient::with_configuration(stub.configuration());
+    ///
+    /// let mut query_parameters = GetDeliveryReportsQueryParameters::new();
+    /// query_parameters.bulk_id = Some("BULK-ID-123-xyz".to_string());
+    /// let response = client.get_delivery_reports(query_parameters).await?;
+    ///
+    /// let reports = response.body.results.unwrap_or_default();
+    /// assert_eq!(reports[0].message_id.as_deref(), Some("MESSAGE-ID-123-xyz"));
+    /// assert_eq!(
+    ///     stub.requests()[0].query.as_deref(),
+    ///     Some("bulkId=BULK-ID-123-xyz&limit=50")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_default_responses() -> io::Result<StubTransport> {
+        let stub = StubTransport::start()?;
+
+        #[cfg(feature = "sms")]
+        {
+            stub.respond(
+                reqwest::Method::POST,
+                crate::api::sms::PATH_SEND,
+                200,
+                SMS_SEND_RESPONSE,
+            );
+            stub.respond(
+                reqwest::Method::GET,
+                crate::api::sms::PATH_GET_DELIVERY_REPORTS,
+                200,
+                SMS_GET_DELIVERY_REPORTS_RESPONSE,
+            );
+        }
+
+        #[cfg(feature = "email")]
+        stub.respond(
+            reqwest::Method::POST,
+            crate::api::email::PATH_SEND,
+            200,
+            EMAIL_SEND_RESPONSE,
+        );
+
+        Ok(stub)
+    }
+
+    /// Registers the response served for requests with `method` to `path`, replacing any
+    /// previous one.
+    ///
+    /// # Example
+    /// ```
+    /// use infobip_sdk::api::sms::{BlockingSmsClient, PATH_PREVIEW};
+    /// use infobip_sdk::api::SdkError;
+    /// use infobip_sdk::model::sms::PreviewRequestBody;
+    /// use infobip_sdk::stub::StubTransport;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stub = StubTransport::start()?;
+    /// stub.respond(
+    ///     reqwest::Method::POST,
+    ///     PATH_PREVIEW,
+    ///     401,
+    ///     r#"{"requestError":{"serviceException":{"messageId":"UNAUTHORIZED"}}}"#,
+    /// );
+    /// let client = BlockingSmsClient::with_configuration(stub.configuration());
+    ///
+    /// let error = client.preview(PreviewRequestBody::new("Hello!")).unwrap_err();
+    /// assert!(matches!(error, SdkError::Unauthorized { .. }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn respond(&self, method: reqwest::Method, path: &str, status: u16, body: &str) {
+        self.state
+            .responses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(
+                (method.as_str().to_string(), path.to_string()),
+                CannedResponse {
+                    status,
+                    body: body.to_string(),
+                },
+            );
+    }
+
+    /// Returns the base URL of the stub, e.g. `http://127.0.0.1:36411`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Returns a `Configuration` pointing to the stub, with a placeholder API key.
+    pub fn configuration(&self) -> Configuration {
+        Configuration::with_api_key(self.base_url(), ApiKey::new("stub-api-key".to_string()))
+    }
+
+    /// Returns the requests received so far, in order of arrival.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Forgets the requests received so far.
+    pub fn clear_requests(&self) {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+}
+
+impl Drop for StubTransport {
+    fn drop(&mut self) {
+        self.state.shutdown.store(true, Ordering::SeqCst);
+        // Wakes up the accept loop, so it sees the shutdown flag.
+        let _ = TcpStream::connect(self.address);
+    }
+}
+
+// Serves the requests of a keep-alive connection until the client closes it.
+fn serve_connection(stream: TcpStream, state: &StubState) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(_) => return,
+    };
+    let mut reader = BufReader::new(stream);
+
+    while let Ok(Some(request)) = read_request(&mut reader) {
+        let response = state
+            .responses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&(request.method.clone(), request.path.clone()))
+            .cloned()
+            .unwrap_or_else(|| CannedResponse {
+                status: 404,
+                body: NOT_FOUND_RESPONSE.to_string(),
+            });
+        state
+            .requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(request);
+
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+            response.status,
+            reqwest::StatusCode::from_u16(response.status)
+                .ok()
+                .and_then(|status| status.canonical_reason())
+                .unwrap_or(""),
+            response.body.len()
+        );
+        let written = writer
+            .write_all(head.as_bytes())
+            .and_then(|_| writer.write_all(response.body.as_bytes()))
+            .and_then(|_| writer.flush());
+        if written.is_err() {
+            break;
+        }
+    }
+
+    let _ = writer.shutdown(Shutdown::Both);
+}
+
+// Reads the next request of a connection, or `None` once the client has closed it.
+fn read_request(reader: &mut BufReader<TcpStream>) -> io::Result<Option<RecordedRequest>> {
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line)? == 0 {
+        return Ok(None);
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.clone())
+    };
+    let body = if header("transfer-encoding").map_or(false, |value| value.contains("chunked")) {
+        read_chunked_body(reader)?
+    } else {
+        let length = header("content-length")
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        body
+    };
+
+    Ok(Some(RecordedRequest {
+        method,
+        path,
+        query,
+        headers,
+        body,
+    }))
+}
+
+fn read_chunked_body(reader: &mut BufReader<TcpStream>) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+
+    loop {
+        let mut size_line = String::new();
+        reader.read_line(&mut size_line)?;
+        let size = size_line.trim().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        let mut chunk = vec![0; size + 2];
+        reader.read_exact(&mut chunk)?;
+        chunk.truncate(size);
+        body.extend_from_slice(&chunk);
+
+        if size == 0 {
+            return Ok(body);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+// Checks that client methods reject response models passed as requests and the other way round
+// at compile time. The expected compiler errors are in the `.stderr` files next to each case, and
+// can be regenerated with `TRYBUILD=overwrite cargo test --test compile_fail`.
+
+#![cfg(feature = "sms")]
+
+#[test]
+fn request_response_markers() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,8 @@
+use infobip_sdk::api::sms::SmsClient;
+use infobip_sdk::model::sms::SendRequestBody;
+
+async fn echo(client: &SmsClient, request_body: SendRequestBody) -> SendRequestBody {
+    client.send(request_body).await.unwrap().body
+}
+
+fn main() {}
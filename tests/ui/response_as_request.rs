@@ -0,0 +1,8 @@
+use infobip_sdk::api::sms::SmsClient;
+use infobip_sdk::model::sms::SendResponseBody;
+
+async fn resend(client: &SmsClient, response_body: SendResponseBody) {
+    let _ = client.send(response_body).await;
+}
+
+fn main() {}